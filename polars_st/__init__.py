@@ -2,6 +2,7 @@
 
 from ._lib import __version__
 from .casting import st
+from .crs import *
 from .geodataframe import *
 from .geoexpr import *
 from .geolazyframe import *
@@ -4,4 +4,36 @@ __version__: str
 
 def get_crs_from_code(srid: int) -> str | None: ...
 def get_crs_authority(definition: str) -> tuple[str, str] | None: ...
+def get_srid_authority(srid: int) -> str: ...
 def to_python_dict(series: pl.Series) -> pl.Series: ...
+def to_topojson(
+    series: pl.Series,
+    object_name: str,
+    quantization: int | None = None,
+) -> str: ...
+def register_crs(definition: str, srid: int | None = None, overwrite: bool = False) -> int: ...
+def get_registered_crs(srid: int) -> str | None: ...
+def register_datum_grids(srid: int, grids: list[str]) -> None: ...
+def to_dxf(series: pl.Series, layers: pl.Series | None = None) -> str: ...
+def flatgeobuf_offsets(
+    path: str,
+    bbox: tuple[float, float, float, float] | None = None,
+) -> pl.Series: ...
+def read_flatgeobuf(
+    path: str,
+    offsets: pl.Series,
+    columns: list[str] | None = None,
+    read_geometry: bool = True,
+) -> pl.DataFrame: ...
+def read_gpx(contents: str, tracks: bool) -> pl.DataFrame: ...
+def to_ragged_array(
+    series: pl.Series,
+) -> tuple[int, int, pl.Series, list[pl.Series]] | None: ...
+def get_coordinates(series: pl.Series, dimension: int) -> pl.Series: ...
+def set_coordinates(series: pl.Series, coords: pl.Series, dimension: int) -> pl.Series: ...
+def from_ragged_array(
+    geometry_type: int,
+    coords: pl.Series,
+    dimension: int,
+    offsets: list[pl.Series],
+) -> pl.Series: ...
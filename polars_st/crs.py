@@ -0,0 +1,76 @@
+from __future__ import annotations
+
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from polars_st import _lib
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+__all__ = ["register_crs", "register_datum_grids"]
+
+
+def register_crs(definition: str, srid: int | None = None, *, overwrite: bool = False) -> int:
+    """Register a custom CRS, so that geometries can reference it by SRID.
+
+    Registered CRS take precedence over EPSG and ESRI codes in [`to_srid`][polars_st.GeoExprNameSpace.to_srid],
+    and their definition is written along with the geometry columns exported to Arrow, so
+    that custom and local CRS survive round trips. Registering the same definition twice
+    returns the same SRID.
+
+    Only a selection of ESRI codes is built in: world, continental and polar projections,
+    and Web Mercator. Other ESRI codes, such as the StatePlane zones (`ESRI:102600` to
+    `ESRI:102799`), have to be registered before use, unless polars-st is built with the
+    `proj` feature, which looks them up in the PROJ database.
+
+    Polars doesn't keep Arrow field metadata through expressions, so within a DataFrame the
+    CRS of a column is carried by the SRID of each geometry, which geometry operations
+    preserve. CRS found in the field metadata of imported Arrow tables are registered, and
+    written back to the field metadata on export.
+
+    Args:
+        definition: The CRS definition, as a PROJ string or a WKT string.
+        srid:
+            The SRID to assign to the CRS. If `None`, an SRID between 900000 and 999999 is
+            derived from the definition, so that a definition gets the same SRID in every
+            session.
+        overwrite:
+            Whether to replace the CRS an SRID already refers to, either an EPSG or ESRI code
+            or a CRS registered with another definition. Otherwise, a `ValueError` is raised.
+
+    Returns:
+        The SRID assigned to the CRS.
+
+    Examples:
+        >>> srid = st.register_crs("+proj=tmerc +lat_0=0 +lon_0=3 +ellps=GRS80 +units=m")
+        >>> gdf = st.GeoDataFrame(["SRID=4326;POINT (3 0)"])
+        >>> gdf.select(st.to_srid(srid=srid).st.to_wkt(rounding_precision=3)).item()
+        'POINT (0 0)'
+    """  # noqa: E501
+    return _lib.register_crs(definition, srid, overwrite)
+
+
+def register_datum_grids(srid: int, grids: str | Path | Sequence[str | Path]) -> None:
+    """Register datum shift grids, used to transform the coordinates of a CRS to WGS 84.
+
+    Transformations from or to the CRS of `srid` in [`to_srid`][polars_st.GeoExprNameSpace.to_srid]
+    then apply the first grid covering each coordinate, instead of the approximate datum
+    parameters of the CRS (`+towgs84`), for example to transform NAD27 coordinates
+    (`EPSG:4267`) with the NADCON `conus` grid. Registered grids take precedence over libproj
+    when polars-st is built with the `proj` feature.
+
+    Args:
+        srid: The SRID of the CRS, either an EPSG or ESRI code or a registered CRS.
+        grids:
+            The paths of the grids, either NTv2 files (`.gsb`) or NADCON grids, given by the
+            path of their `.las` or `.los` file. Grids shift geographic coordinates on the datum
+            of the CRS to WGS 84, or to a datum considered equivalent such as NAD83. An empty
+            list removes the grids registered for `srid`.
+
+    Examples:
+        >>> st.register_datum_grids(4267, "grids/conus.las")  # doctest: +SKIP
+    """  # noqa: E501
+    if isinstance(grids, (str, Path)):
+        grids = [grids]
+    _lib.register_datum_grids(srid, [str(grid) for grid in grids])
@@ -1,5 +1,8 @@
 from __future__ import annotations
 
+import json
+import math
+from pathlib import Path
 from typing import TYPE_CHECKING, Any, Literal, cast, overload
 
 import polars as pl
@@ -9,17 +12,20 @@ from polars.api import register_dataframe_namespace
 from polars.datatypes import N_INFER_DEFAULT
 from pyogrio import write_arrow
 
-from polars_st._lib import get_crs_from_code
+from polars_st._lib import get_crs_from_code, get_srid_authority, to_dxf, to_topojson
 from polars_st.casting import st
 from polars_st.geoseries import GeoSeries
 from polars_st.selectors import geom
+from polars_st.utils.geoarrow import geoarrow_wkb_field, to_geoarrow_table
+from polars_st.utils.srid import get_srid_projjson
 
 if TYPE_CHECKING:
     from io import BytesIO, IOBase
-    from pathlib import Path
+    from typing import IO
 
     import altair as alt
     import geopandas as gpd
+    import pyarrow as pa
     from altair.vegalite.v5.schema._config import MarkConfigKwds
     from polars._typing import (
         FrameInitTypes,
@@ -177,6 +183,90 @@ class GeoDataFrame(DataFrame, metaclass=GeoDataFrameMeta):
         ...
 
 
+GPKG_TYPES: dict[type[pl.DataType], str] = {
+    pl.Boolean: "BOOLEAN",
+    pl.Int8: "TINYINT",
+    pl.Int16: "SMALLINT",
+    pl.Int32: "MEDIUMINT",
+    pl.Int64: "INTEGER",
+    pl.UInt8: "SMALLINT",
+    pl.UInt16: "MEDIUMINT",
+    pl.UInt32: "INTEGER",
+    pl.Float32: "FLOAT",
+    pl.Float64: "DOUBLE",
+    pl.String: "TEXT",
+    pl.Binary: "BLOB",
+    pl.Date: "DATE",
+    pl.Datetime: "DATETIME",
+}
+
+ESRI_GEOMETRY_TYPES = {
+    "Point": "esriGeometryPoint",
+    "MultiPoint": "esriGeometryMultipoint",
+    "LineString": "esriGeometryPolyline",
+    "MultiLineString": "esriGeometryPolyline",
+    "Polygon": "esriGeometryPolygon",
+    "MultiPolygon": "esriGeometryPolygon",
+}
+
+GPKG_GEOMETRY_TYPES = {
+    "Point",
+    "LineString",
+    "Polygon",
+    "MultiPoint",
+    "MultiLineString",
+    "MultiPolygon",
+    "GeometryCollection",
+    "CircularString",
+    "CompoundCurve",
+    "CurvePolygon",
+    "MultiCurve",
+    "MultiSurface",
+    "Curve",
+    "Surface",
+}
+
+GPKG_METADATA_TABLES = """
+PRAGMA application_id = 1196444487;
+PRAGMA user_version = 10400;
+CREATE TABLE IF NOT EXISTS gpkg_spatial_ref_sys (
+    srs_name TEXT NOT NULL,
+    srs_id INTEGER PRIMARY KEY,
+    organization TEXT NOT NULL,
+    organization_coordsys_id INTEGER NOT NULL,
+    definition TEXT NOT NULL,
+    description TEXT
+);
+INSERT OR IGNORE INTO gpkg_spatial_ref_sys VALUES
+    ('Undefined cartesian SRS', -1, 'NONE', -1, 'undefined', NULL),
+    ('Undefined geographic SRS', 0, 'NONE', 0, 'undefined', NULL);
+CREATE TABLE IF NOT EXISTS gpkg_contents (
+    table_name TEXT NOT NULL PRIMARY KEY,
+    data_type TEXT NOT NULL,
+    identifier TEXT UNIQUE,
+    description TEXT DEFAULT '',
+    last_change DATETIME NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ','now')),
+    min_x DOUBLE,
+    min_y DOUBLE,
+    max_x DOUBLE,
+    max_y DOUBLE,
+    srs_id INTEGER,
+    CONSTRAINT fk_gc_r_srs_id FOREIGN KEY (srs_id) REFERENCES gpkg_spatial_ref_sys(srs_id)
+);
+CREATE TABLE IF NOT EXISTS gpkg_geometry_columns (
+    table_name TEXT NOT NULL,
+    column_name TEXT NOT NULL,
+    geometry_type_name TEXT NOT NULL,
+    srs_id INTEGER NOT NULL,
+    z TINYINT NOT NULL,
+    m TINYINT NOT NULL,
+    CONSTRAINT pk_geom_cols PRIMARY KEY (table_name, column_name),
+    CONSTRAINT fk_gc_tn FOREIGN KEY (table_name) REFERENCES gpkg_contents(table_name),
+    CONSTRAINT fk_gc_srs FOREIGN KEY (srs_id) REFERENCES gpkg_spatial_ref_sys (srs_id)
+);
+"""
+
+
 @register_dataframe_namespace("st")
 class GeoDataFrameNameSpace:
     def __init__(self, df: DataFrame) -> None:
@@ -198,8 +288,14 @@ class GeoDataFrameNameSpace:
             "covers",
             "covered_by",
             "contains_properly",
+            "dwithin",
+            "relate",
         ] = "intersects",
         *,
+        distance: float | None = None,
+        pattern: str | None = None,
+        distance_col: str | None = None,
+        algorithm: Literal["rtree", "sweep"] = "rtree",
         left_on: str | Expr | None = None,
         right_on: str | Expr | None = None,
         suffix: str = "_right",
@@ -221,6 +317,10 @@ class GeoDataFrameNameSpace:
                 on=on,
                 how=how,
                 predicate=predicate,
+                distance=distance,
+                pattern=pattern,
+                distance_col=distance_col,
+                algorithm=algorithm,
                 suffix=suffix,
                 validate=validate,
                 coalesce=coalesce,
@@ -229,6 +329,75 @@ class GeoDataFrameNameSpace:
             .pipe(lambda df: cast("GeoDataFrame", df))
         )
 
+    def conflate(
+        self,
+        other: DataFrame,
+        max_distance: float,
+        on: str | Expr = "geometry",
+        *,
+        left_on: str | Expr | None = None,
+        right_on: str | Expr | None = None,
+        distance_weight: float = 1.0,
+        overlap_weight: float = 1.0,
+        similarity: Expr | None = None,
+        similarity_weight: float = 1.0,
+        min_score: float | None = None,
+        score_name: str = "match_score",
+        suffix: str = "_right",
+    ) -> GeoDataFrame:
+        """Match the features of two layers one-to-one based on their similarity.
+
+        See [`GeoLazyFrameNameSpace.conflate`][polars_st.GeoLazyFrameNameSpace.conflate].
+
+        Examples:
+            >>> reference = st.GeoDataFrame({
+            ...     "name": ["Town hall", "Library"],
+            ...     "geometry": ["POINT (0 0)", "POINT (10 0)"],
+            ... })
+            >>> survey = st.GeoDataFrame({
+            ...     "name": ["library", "town hall", "school"],
+            ...     "geometry": ["POINT (9 1)", "POINT (1 0)", "POINT (50 50)"],
+            ... })
+            >>> reference.st.conflate(
+            ...     survey,
+            ...     max_distance=5,
+            ...     similarity=pl.col("name").str.to_lowercase() == pl.col("name_right"),
+            ... ).select("name", "name_right", "match_score")
+            shape: (2, 3)
+            ┌───────────┬────────────┬─────────────┐
+            │ name      ┆ name_right ┆ match_score │
+            │ ---       ┆ ---        ┆ ---         │
+            │ str       ┆ str        ┆ f64         │
+            ╞═══════════╪════════════╪═════════════╡
+            │ Town hall ┆ town hall  ┆ 0.9         │
+            │ Library   ┆ library    ┆ 0.858579    │
+            └───────────┴────────────┴─────────────┘
+        """
+        if not isinstance(other, DataFrame):
+            msg = f"expected `other` join table to be a DataFrame, got {type(other).__name__!r}"
+            raise TypeError(msg)
+
+        return (
+            self._df.lazy()
+            .pipe(st)
+            .conflate(
+                other=other.lazy(),
+                max_distance=max_distance,
+                on=on,
+                left_on=left_on,
+                right_on=right_on,
+                distance_weight=distance_weight,
+                overlap_weight=overlap_weight,
+                similarity=similarity,
+                similarity_weight=similarity_weight,
+                min_score=min_score,
+                score_name=score_name,
+                suffix=suffix,
+            )
+            .collect(_eager=True)
+            .pipe(lambda df: cast("GeoDataFrame", df))
+        )
+
     def to_wkt(
         self,
         *geometry_columns: str,
@@ -277,6 +446,7 @@ class GeoDataFrameNameSpace:
         output_dimension: Literal[2, 3, 4] = 3,
         byte_order: Literal[0, 1] | None = None,
         include_srid: bool = False,
+        flavor: Literal["extended", "iso"] = "extended",
     ) -> DataFrame:
         """Serialize the DataFrame geometry column as WKB.
 
@@ -287,9 +457,17 @@ class GeoDataFrameNameSpace:
                 output_dimension,
                 byte_order,
                 include_srid,
+                flavor,
             ),
         )
 
+    def to_ewkb_hex(self, *geometry_columns: str) -> DataFrame:
+        """Serialize the DataFrame geometry column as hex-encoded EWKB.
+
+        See [`GeoExprNameSpace.to_ewkb_hex`][polars_st.GeoExprNameSpace.to_ewkb_hex].
+        """
+        return self._df.with_columns(geom(*geometry_columns).st.to_ewkb_hex())
+
     def to_geojson(self, *geometry_columns: str, indent: int | None = None) -> DataFrame:
         """Serialize the DataFrame geometry column as GeoJSON.
 
@@ -297,6 +475,13 @@ class GeoDataFrameNameSpace:
         """
         return self._df.with_columns(geom(*geometry_columns).st.to_geojson(indent))
 
+    def to_esrijson(self, *geometry_columns: str, indent: int | None = None) -> DataFrame:
+        """Serialize the DataFrame geometry column as EsriJSON.
+
+        See [`GeoExprNameSpace.to_esrijson`][polars_st.GeoExprNameSpace.to_esrijson].
+        """
+        return self._df.with_columns(geom(*geometry_columns).st.to_esrijson(indent))
+
     def to_shapely(self, *geometry_columns: str) -> DataFrame:
         """Convert the DataFrame geometry column to a shapely representation.
 
@@ -358,6 +543,40 @@ class GeoDataFrameNameSpace:
             crs=crs,
         )
 
+    def to_arrow(self, *geometry_columns: str) -> pa.Table:
+        """Convert this DataFrame to an Arrow table with `geoarrow.wkb` geometry columns.
+
+        Geometry columns are serialized as WKB, and their fields are annotated with the
+        `geoarrow.wkb` extension name and their CRS, so that GeoArrow aware libraries
+        such as lonboard or GeoPandas recognize them as geometries rather than binary.
+        The write methods of this namespace annotate geometry columns the same way.
+
+        A `GeoDataFrame` is a Polars `DataFrame`, whose own `to_arrow` method and Arrow
+        stream export (`pa.table(gdf)`) don't know which columns are geometries, so they
+        export them as plain binary. Use `gdf.st.to_arrow()` or `pa.table(gdf.st)` instead.
+
+        Args:
+            geometry_columns: Names of the geometry columns. Defaults to `"geometry"`.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (1 2)"]).with_columns(st.set_srid(srid=4326))
+            >>> field = gdf.st.to_arrow().schema.field("geometry")
+            >>> field.metadata[b"ARROW:extension:name"]
+            b'geoarrow.wkb'
+            >>> field.metadata[b"ARROW:extension:metadata"]
+            b'{"crs": "EPSG:4326", "crs_type": "authority_code"}'
+        """
+        return to_geoarrow_table(self._df, geometry_columns or ["geometry"])
+
+    def __arrow_c_stream__(self, requested_schema: object | None = None) -> object:
+        """Export this DataFrame through the Arrow PyCapsule interface.
+
+        Geometry columns are annotated as in
+        [`to_arrow`][polars_st.GeoDataFrameNameSpace.to_arrow], which allows passing
+        `gdf.st` directly to libraries consuming Arrow streams, such as lonboard.
+        """
+        return self.to_arrow().__arrow_c_stream__(requested_schema)
+
     @property
     def __geo_interface__(self) -> dict:
         """Return a GeoJSON FeatureCollection [`dict`][] representation of the DataFrame.
@@ -473,7 +692,7 @@ class GeoDataFrameNameSpace:
             crs = None
 
         write_arrow(
-            self._df.to_arrow(),
+            to_geoarrow_table(self._df, [geometry_name]),
             path=path,
             layer=layer,
             driver=driver,
@@ -490,6 +709,268 @@ class GeoDataFrameNameSpace:
             **kwargs,
         )
 
+    def write_parquet(
+        self,
+        file: str | Path | IO[bytes],
+        *geometry_columns: str,
+        geography: bool = False,
+        compression: str = "zstd",
+        compression_level: int | None = None,
+        row_group_size: int | None = None,
+    ) -> None:
+        """Write the GeoDataFrame to Parquet, using the native geospatial logical types.
+
+        Geometry columns are written with the `GEOMETRY` logical type, or `GEOGRAPHY`
+        if `geography` is `True`, along with their CRS and bounding box statistics.
+
+        Args:
+            file: File path or writable file-like object to which the result will be
+                written.
+            geometry_columns: Names of the geometry columns. Defaults to `"geometry"`.
+            geography: Write geometries with spherical edges, as the `GEOGRAPHY` type.
+            compression: Compression codec to use.
+            compression_level: Compression level to use, codec-specific.
+            row_group_size: Maximum number of rows in each row group.
+        """
+        import pyarrow.parquet as pq
+
+        table = to_geoarrow_table(
+            self._df,
+            geometry_columns or ["geometry"],
+            edges="spherical" if geography else None,
+        )
+        pq.write_table(
+            table,
+            file,
+            compression=compression,
+            compression_level=compression_level,
+            row_group_size=row_group_size,
+        )
+
+    def write_geoarrow_ipc(
+        self,
+        file: str | Path | IO[bytes],
+        *geometry_columns: str,
+        compression: Literal["uncompressed", "lz4", "zstd"] = "zstd",
+    ) -> None:
+        """Write the GeoDataFrame to an Arrow IPC (Feather) file.
+
+        Geometry columns are written as `geoarrow.wkb` extension columns along with their
+        CRS, so that they can be read back by [`read_geoarrow_ipc`][polars_st.read_geoarrow_ipc]
+        or by any GeoArrow-aware reader.
+
+        Args:
+            file: File path or writable file-like object to which the result will be
+                written.
+            geometry_columns: Names of the geometry columns. Defaults to `"geometry"`.
+            compression: Compression codec to use.
+        """
+        import pyarrow.feather as feather
+
+        table = to_geoarrow_table(self._df, geometry_columns or ["geometry"])
+        feather.write_feather(table, file, compression=compression)
+
+    def write_geoparquet(
+        self,
+        file: str | Path | IO[bytes],
+        *geometry_columns: str,
+        write_covering_bbox: bool = False,
+        compression: str = "zstd",
+        compression_level: int | None = None,
+        row_group_size: int | None = None,
+    ) -> None:
+        """Write the GeoDataFrame to GeoParquet.
+
+        Geometry columns are written as WKB, along with the GeoParquet `geo` file metadata
+        describing their encoding, geometry types, CRS and bounding box. Writing the CRS
+        of geometries with an SRID requires `pyproj`, which is installed by the
+        `geoparquet` extra: `pip install polars-st[geoparquet]`.
+
+        Args:
+            file: File path or writable file-like object to which the result will be
+                written.
+            geometry_columns: Names of the geometry columns. Defaults to `"geometry"`.
+                The first one is the primary geometry column.
+            write_covering_bbox: Add a struct column with the bounding box of each
+                geometry, which lets readers skip row groups when filtering by bbox. It
+                is named `bbox` for the primary geometry column, and `<name>_bbox` for
+                the others.
+            compression: Compression codec to use.
+            compression_level: Compression level to use, codec-specific.
+            row_group_size: Maximum number of rows in each row group.
+        """
+        import pyarrow as pa
+        import pyarrow.parquet as pq
+
+        geometry_columns = geometry_columns or ("geometry",)
+        df = self._df
+        columns_metadata = {}
+        srids: dict[str, int | None] = {}
+        for name in geometry_columns:
+            summary = df.select(
+                srids=geom(name).st.srid().unique().drop_nulls().implode(),
+                types=pl.concat_str(
+                    geom(name).st.geometry_type().cast(pl.String),
+                    pl.when(geom(name).st.has_z()).then(pl.lit(" Z")).otherwise(pl.lit("")),
+                )
+                .unique()
+                .drop_nulls()
+                .sort()
+                .implode(),
+                bbox=geom(name).st.total_bounds(),
+            ).row(0, named=True)
+            match summary["srids"]:
+                case [] | [0]:
+                    srids[name], crs = None, None
+                case [srid]:
+                    srids[name], crs = srid, get_srid_projjson(srid)
+                case _:
+                    msg = f"Geometry column {name!r} with mixed SRIDs isn't supported"
+                    raise ValueError(msg)
+            column = {"encoding": "WKB", "geometry_types": summary["types"], "crs": crs}
+            if not any(math.isnan(value) for value in summary["bbox"]):
+                column["bbox"] = list(summary["bbox"])
+            if write_covering_bbox:
+                bbox_name = "bbox" if name == geometry_columns[0] else f"{name}_bbox"
+                bounds = geom(name).st.bounds()
+                df = df.with_columns(
+                    pl.struct(
+                        xmin=bounds.arr.get(0),
+                        ymin=bounds.arr.get(1),
+                        xmax=bounds.arr.get(2),
+                        ymax=bounds.arr.get(3),
+                    ).alias(bbox_name),
+                )
+                column["covering"] = {
+                    "bbox": {key: [bbox_name, key] for key in ("xmin", "ymin", "xmax", "ymax")},
+                }
+            columns_metadata[name] = column
+
+        metadata = {
+            "version": "1.1.0",
+            "primary_column": geometry_columns[0],
+            "columns": columns_metadata,
+        }
+        table = df.with_columns(geom(*geometry_columns).st.to_wkb(flavor="iso")).to_arrow()
+        for name in geometry_columns:
+            index = table.schema.get_field_index(name)
+            field = geoarrow_wkb_field(name, srids[name])
+            table = table.set_column(index, field, table.column(index).cast(pa.binary()))
+        table = table.replace_schema_metadata({
+            **(table.schema.metadata or {}),
+            b"geo": json.dumps(metadata).encode(),
+        })
+        pq.write_table(
+            table,
+            file,
+            compression=compression,
+            compression_level=compression_level,
+            row_group_size=row_group_size,
+        )
+
+    def write_geopackage(
+        self,
+        path: str | Path,
+        layer: str | None = None,
+        geometry_name: str = "geometry",
+    ) -> None:
+        """Write the GeoDataFrame as a layer of a GeoPackage.
+
+        The GeoPackage is created if it doesn't exist, along with its metadata tables.
+        The layer is registered in `gpkg_contents` and `gpkg_geometry_columns`, and
+        geometries are encoded in the GeoPackage binary format, as ISO WKB preceded by
+        their envelope.
+
+        Args:
+            path: Path to the GeoPackage file.
+            layer: Name of the layer to write. Defaults to the file name without extension.
+            geometry_name: Name of the geometry column.
+        """
+        import sqlite3
+        from contextlib import closing
+        from pathlib import Path
+
+        layer = layer or Path(path).stem
+        geometry_types = (
+            self._df.select(geom(geometry_name).st.geometry_type().unique().drop_nulls())
+            .to_series()
+            .to_list()
+        )
+        match geometry_types:
+            case [geometry_type] if geometry_type in GPKG_GEOMETRY_TYPES:
+                pass
+            case _:
+                geometry_type = "Geometry"
+        srids = self._df.select(geom(geometry_name).st.srid().unique().drop_nulls())
+        if len(srids) > 1:
+            msg = "DataFrame with mixed SRIDs aren't supported"
+            raise ValueError(msg)
+        srid = srids[0, 0] if len(srids) == 1 and srids[0, 0] != 0 else -1
+        has_z = self._df.select(geom(geometry_name).st.has_z().any()).item() or False
+        bounds = self._df.select(geom(geometry_name).st.total_bounds()).item().to_list()
+        bounds = [None if math.isnan(value) else value for value in bounds]
+
+        columns = {geometry_name: geometry_type.upper()}
+        for name, dtype in self._df.schema.items():
+            if name == geometry_name:
+                continue
+            if (sql_type := GPKG_TYPES.get(dtype.base_type())) is None:
+                msg = f"Unsupported dtype {dtype} for GeoPackage column {name!r}"
+                raise TypeError(msg)
+            columns[name] = sql_type
+        df = self._df.with_columns(
+            geom(geometry_name).st.set_srid(srid).st.to_gpkg(),
+            cs.date().dt.to_string("%Y-%m-%d"),
+            cs.datetime().dt.to_string("%Y-%m-%dT%H:%M:%S%.3fZ"),
+        )
+
+        def quote(name: str) -> str:
+            return '"{}"'.format(name.replace('"', '""'))
+
+        with closing(sqlite3.connect(path)) as connection, connection:
+            connection.executescript(GPKG_METADATA_TABLES)
+            if srid > 0 and not connection.execute(
+                "SELECT 1 FROM gpkg_spatial_ref_sys WHERE srs_id = ?",
+                (srid,),
+            ).fetchone():
+                definition = get_crs_from_code(srid)
+                if definition is None:
+                    msg = f"Couldn't find CRS information for SRID {srid}"
+                    raise ValueError(msg)
+                # Definitions must be WKT. CRS only known as a PROJ string are identified by
+                # their authority code, and their PROJ string is kept as the description.
+                description = None
+                if definition.lstrip().startswith("+"):
+                    definition, description = "undefined", definition
+                authority = get_srid_authority(srid)
+                connection.execute(
+                    "INSERT INTO gpkg_spatial_ref_sys VALUES (?, ?, ?, ?, ?, ?)",
+                    (f"{authority}:{srid}", srid, authority, srid, definition, description),
+                )
+            column_definitions = ", ".join(
+                f"{quote(name)} {sql_type}" for name, sql_type in columns.items()
+            )
+            connection.execute(
+                f"CREATE TABLE {quote(layer)} "  # noqa: S608
+                f"(fid INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, {column_definitions})",
+            )
+            connection.execute(
+                "INSERT INTO gpkg_contents "
+                "(table_name, data_type, identifier, min_x, min_y, max_x, max_y, srs_id) "
+                "VALUES (?, 'features', ?, ?, ?, ?, ?, ?)",
+                (layer, layer, *bounds, srid),
+            )
+            connection.execute(
+                "INSERT INTO gpkg_geometry_columns VALUES (?, ?, ?, ?, ?, 0)",
+                (layer, geometry_name, geometry_type.upper(), srid, int(has_z)),
+            )
+            names = ", ".join(quote(name) for name in columns)
+            placeholders = ", ".join("?" for _ in columns)
+            connection.executemany(
+                f"INSERT INTO {quote(layer)} ({names}) VALUES ({placeholders})",  # noqa: S608
+                df.select(list(columns)).iter_rows(),
+            )
+
     @overload
     def write_geojson(self, file: None = None) -> str: ...
 
@@ -552,6 +1033,160 @@ class GeoDataFrameNameSpace:
             geometry=geom().st.to_geojson().str.json_decode(),
         ).write_ndjson(file)
 
+    @overload
+    def write_esrijson(self, file: None = None) -> str: ...
+
+    @overload
+    def write_esrijson(self, file: IOBase | str | Path) -> None: ...
+
+    def write_esrijson(self, file: IOBase | str | Path | None = None) -> str | None:
+        """Serialize to EsriJSON FeatureSet representation.
+
+        The `geometryType` of the FeatureSet is derived from the geometry column, and its
+        `spatialReference` from the SRID of the geometries, if any. A FeatureSet can only
+        hold a single geometry type (multi-part geometries aside) and a single SRID, so an
+        error is raised otherwise.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame({
+            ...     "geometry": ["POINT(0 0)", "POINT(1 2)"],
+            ...     "name": ["Alice", "Bob"]
+            ... })
+            >>> esrijson = gdf.st.write_esrijson()
+            >>> print(esrijson)
+            {"geometryType":"esriGeometryPoint","features":[{"attributes":{"name":"Alice"},"geometry":{"x":0.0,"y":0.0}},{"attributes":{"name":"Bob"},"geometry":{"x":1.0,"y":2.0}}]}
+            <BLANKLINE>
+        """
+        geometry_types = {
+            ESRI_GEOMETRY_TYPES.get(geometry_type)
+            for geometry_type in self._df.select(geom().st.geometry_type()).to_series()
+            if geometry_type is not None
+        }
+        if len(geometry_types) > 1 or None in geometry_types:
+            msg = "EsriJSON FeatureSets can only contain points, lines or polygons of one type"
+            raise ValueError(msg)
+        srids = set(self._df.select(geom().st.srid()).to_series().drop_nulls()) - {0}
+        if len(srids) > 1:
+            msg = "EsriJSON FeatureSets can't contain geometries with different SRIDs"
+            raise ValueError(msg)
+
+        header = []
+        if geometry_types:
+            header.append(pl.lit(geometry_types.pop()).alias("geometryType"))
+        if srids:
+            header.append(pl.struct(wkid=pl.lit(srids.pop())).alias("spatialReference"))
+        return (
+            self._df.select(
+                attributes=pl.struct(cs.exclude(geom())) if len(self._df.columns) > 1 else None,
+                geometry=geom().st.to_esrijson().str.json_decode(),
+            )
+            .group_by(0)
+            .agg(features=pl.struct("attributes", "geometry"))
+            .select(*header, "features")
+            .write_ndjson(file)
+        )
+
+    @overload
+    def write_dxf(
+        self,
+        file: None = None,
+        geometry_name: str = "geometry",
+        layer_name: str | None = None,
+    ) -> str: ...
+
+    @overload
+    def write_dxf(
+        self,
+        file: IOBase | str | Path,
+        geometry_name: str = "geometry",
+        layer_name: str | None = None,
+    ) -> None: ...
+
+    def write_dxf(
+        self,
+        file: IOBase | str | Path | None = None,
+        geometry_name: str = "geometry",
+        layer_name: str | None = None,
+    ) -> str | None:
+        """Serialize to an ASCII DXF (R12) document, for use in CAD tools.
+
+        Points are written as `POINT` entities, and linestrings and polygon rings as
+        `POLYLINE` entities, closed for rings. Curved geometries are not supported.
+
+        Args:
+            file: File path or writable file-like object to which the result will be
+                written. If `None`, the result is returned as a string.
+            geometry_name: The name of the geometry column.
+            layer_name:
+                The name of a column holding the layer of each geometry. Geometries
+                are written on the default layer `0` if not set, or if the layer is null.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame({
+            ...     "geometry": ["POINT (1 2)", "LINESTRING (0 0, 1 1)"],
+            ...     "layer": ["Points", "Lines"],
+            ... })
+            >>> dxf = gdf.st.write_dxf(layer_name="layer")
+            >>> dxf.splitlines()[:12]
+            ['  0', 'SECTION', '  2', 'ENTITIES', '  0', 'POINT', '  8', 'Points', ' 10', '1', ' 20', '2']
+        """  # noqa: E501
+        layers = self._df[layer_name].cast(pl.String) if layer_name is not None else None
+        dxf = to_dxf(self._df[geometry_name], layers)
+        match file:
+            case None:
+                return dxf
+            case str() | Path():
+                Path(file).write_text(dxf, encoding="utf-8")
+            case _:
+                file.write(dxf)
+        return None
+
+    def to_topojson(
+        self,
+        geometry_name: str = "geometry",
+        object_name: str = "collection",
+        quantization: int | None = 10_000,
+    ) -> str:
+        """Serialize to a TopoJSON Topology.
+
+        The linework of all geometries is noded together, and split into arcs at
+        junctions, so that boundaries shared by adjacent polygons are only stored once.
+        Other columns are written as the `properties` of the topology geometries.
+
+        Args:
+            geometry_name: The name of the geometry column.
+            object_name: The name of the `GeometryCollection` object of the topology.
+            quantization:
+                If not `None`, positions are quantized to integers on a grid of
+                `quantization` by `quantization` cells spanning the topology bounding box,
+                and arcs are delta-encoded. Use `None` to keep the original coordinates.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame({
+            ...     "geometry": [
+            ...         "POLYGON ((0 0, 0 1, 1 1, 1 0, 0 0))",
+            ...         "POLYGON ((1 0, 1 1, 2 1, 2 0, 1 0))",
+            ...     ],
+            ...     "name": ["A", "B"],
+            ... })
+            >>> topojson = json.loads(gdf.st.to_topojson(quantization=None))
+            >>> topojson["arcs"]
+            [[[1.0, 1.0], [1.0, 0.0]], [[1.0, 0.0], [0.0, 0.0], [0.0, 1.0], [1.0, 1.0]], [[1.0, 1.0], [2.0, 1.0], [2.0, 0.0], [1.0, 0.0]]]
+            >>> [g["arcs"] for g in topojson["objects"]["collection"]["geometries"]]
+            [[[0, 1]], [[-1, 2]]]
+        """  # noqa: E501
+        topology = json.loads(to_topojson(self._df[geometry_name], object_name, quantization))
+        if len(self._df.columns) > 1:
+            properties = self._df.select(properties=pl.struct(cs.exclude(geometry_name)))
+            geometries = topology["objects"][object_name]["geometries"]
+            for geometry, line in zip(
+                geometries,
+                properties.write_ndjson().splitlines(),
+                strict=True,
+            ):
+                geometry["properties"] = json.loads(line)["properties"]
+        return json.dumps(topology, separators=(",", ":"))
+
     def plot(self, geometry_name: str = "geometry", **kwargs: Unpack[MarkConfigKwds]) -> alt.Chart:
         """Draw map plot.
 
@@ -594,5 +1229,18 @@ class GeoDataFrameNameSpace:
         """
         import altair as alt
 
-        chart = alt.Chart({"values": self.to_dicts(geometry_name)})
+        # Features go through JSON, so that properties such as datetimes or binary columns,
+        # which Altair can't serialize, are converted to strings.
+        properties = cs.exclude(geometry_name)
+        df = self._df.with_columns((cs.binary() & properties).bin.encode("hex"))
+        features = df.select(
+            type=pl.lit("Feature"),
+            geometry=geom(geometry_name).st.to_geojson(),
+            properties=pl.struct(properties) if len(df.columns) > 1 else None,
+        )
+        values = [json.loads(line) for line in features.write_ndjson().splitlines()]
+        for feature in values:
+            if feature["geometry"] is not None:
+                feature["geometry"] = json.loads(feature["geometry"])
+        chart = alt.Chart({"values": values})
         return chart.mark_geoshape(**kwargs).interactive()
@@ -13,9 +13,13 @@ from polars.plugins import register_plugin_function
 
 from polars_st import _lib
 from polars_st.geometry import GeometryType, PolarsGeometryType
+from polars_st.utils.shapely import to_shapely_array
 
 if TYPE_CHECKING:
-    from collections.abc import Sequence
+    from collections.abc import Callable, Sequence
+
+    import numpy as np
+    import numpy.typing as npt
 
     from polars_st.typing import (
         IntoExprColumn,
@@ -44,10 +48,12 @@ def register_plugin(op: str | None = None, is_aggregation: bool = False):  # noq
         def wrapper(self: GeoExprNameSpace, *args: P.args, **kwargs: P.kwargs):  # noqa: ANN202
             bound = sig.bind(self._expr, *args, **kwargs)
             bound.apply_defaults()
+            # Optional expressions left to `None` aren't passed to the plugin.
+            exprs = [bound.arguments[k] for k in expr_args if bound.arguments[k] is not None]
             return register_plugin_function(
                 plugin_path=Path(__file__).parent,
                 function_name=func_name,
-                args=[self._expr, *[bound.arguments[k] for k in expr_args]],
+                args=[self._expr, *exprs],
                 kwargs={k: bound.arguments[k] for k in other_args},
                 is_elementwise=not is_aggregation,
                 returns_scalar=is_aggregation,
@@ -142,16 +148,254 @@ class GeoExprNameSpace:
         """Return the area of each geometry."""
         ...
 
+    @register_plugin()
+    def area_geodesic(self) -> pl.Expr:
+        """Return the ellipsoidal area of each geometry, in square meters.
+
+        Geometries must be in EPSG:4326 (or have no SRID), with longitude / latitude
+        coordinates. Areas are computed on the WGS84 ellipsoid using Karney's algorithms.
+        Circular arcs are first replaced by segments deviating from them by at most
+        1e-7 degrees.
+        """
+        ...
+
     @register_plugin()
     def bounds(self) -> pl.Expr:
         """Return the bounds of each geometry."""
         ...
 
+    @register_plugin()
+    def bounds_3d(self) -> pl.Expr:
+        """Return the 3D bounds of each geometry.
+
+        Bounds are given as `[xmin, ymin, zmin, xmax, ymax, zmax]`, with NaN Z values
+            for geometries without Z.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING Z (0 1 5, 2 3 -1)"])
+            >>> gdf.select(st.geom().st.bounds_3d()).item().to_list()
+            [0.0, 1.0, -1.0, 2.0, 3.0, 5.0]
+        """
+        ...
+
+    @register_plugin()
+    def m_range(self) -> pl.Expr:
+        """Return the range of the M values of each geometry, as `[mmin, mmax]`.
+
+        The range is NaN for geometries without M.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING M (0 0 10, 1 1 30, 2 2 20)"])
+            >>> gdf.select(st.geom().st.m_range()).item().to_list()
+            [10.0, 30.0]
+        """
+        ...
+
+    def morton_index(
+        self,
+        extent: tuple[float, float, float, float] | None = None,
+        level: int = 16,
+    ) -> pl.Expr:
+        """Return the Morton (Z-order) index of the center of each geometry bounding box.
+
+        The centers are quantized on a grid of `2**level` cells along each axis, and the
+        bits of both cell coordinates are interleaved. Geometries close to each other tend
+        to have close indices, which makes it a cheap key to sort or partition geometries
+        by location. Null and empty geometries have a null index.
+
+        Args:
+            extent:
+                Bounds `(xmin, ymin, xmax, ymax)` covered by the grid. Defaults to the
+                total bounds of the geometries. Centers outside of the extent are clamped
+                to it.
+            level:
+                Number of bits of each cell coordinate, from 1 to 32.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POINT (0 0)",
+            ...     "POINT (1 0)",
+            ...     "POINT (0 1)",
+            ...     "POINT (1 1)",
+            ... ])
+            >>> gdf.select(st.morton_index(level=1)).to_series().to_list()
+            [0, 1, 2, 3]
+        """
+        if not 1 <= level <= 32:
+            msg = f"`level` must be between 1 and 32, got {level}"
+            raise ValueError(msg)
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="morton_index",
+            args=[self._expr],
+            kwargs={"extent": extent, "level": level},
+            # Without an extent, the index depends on the bounds of all geometries.
+            is_elementwise=extent is not None,
+        )
+
+    def spatial_partition(
+        self,
+        scheme: Literal["grid", "kdb"] = "grid",
+        n: int = 16,
+        extent: tuple[float, float, float, float] | None = None,
+    ) -> pl.Expr:
+        """Return the list of spatial partitions overlapped by each geometry bounding box.
+
+        Partitions tile the extent of the geometries, and geometries crossing the border
+        of partitions are assigned to each of them. Exploding the partitions of two frames
+        allows joining them partition by partition, with results found in several
+        partitions deduplicated afterwards. Null geometries have a null list, and empty
+        geometries an empty one.
+
+        Args:
+            scheme:
+                With `"grid"`, partitions are the cells of a regular grid of at least `n`
+                cells, numbered row by row. With `"kdb"`, partitions are the leaves of a
+                K-D-B tree of at most `n` leaves, splitting the extent at the median of
+                the geometries centers so that partitions hold similar numbers of
+                geometries.
+            n:
+                Number of partitions.
+            extent:
+                Bounds `(xmin, ymin, xmax, ymax)` covered by the partitions. Defaults to
+                the total bounds of the geometries. Geometries outside of the extent are
+                assigned to the closest partitions.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POINT (0 0)",
+            ...     "POINT (3 3)",
+            ...     "LINESTRING (0 0, 3 3)",
+            ... ])
+            >>> gdf.select(st.spatial_partition(n=4)).to_series().to_list()
+            [[0], [3], [0, 1, 2, 3]]
+        """
+        if n < 1:
+            msg = f"`n` must be at least 1, got {n}"
+            raise ValueError(msg)
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="spatial_partition",
+            args=[self._expr],
+            kwargs={"scheme": scheme, "n": n, "extent": extent},
+            is_elementwise=scheme == "grid" and extent is not None,
+        )
+
+    def to_h3(self, resolution: int) -> pl.Expr:
+        """Return the H3 cell containing each point, as a 64-bit integer.
+
+        Points must have longitude / latitude coordinates. Null and empty points have a
+        null cell.
+
+        Args:
+            resolution:
+                Resolution of the cells, from 0 to 15.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (-122.0553238 37.3615593)"])
+            >>> f"{gdf.select(st.to_h3(resolution=7)).item():x}"
+            '87283472bffffff'
+        """
+        if not 0 <= resolution <= 15:
+            msg = f"`resolution` must be between 0 and 15, got {resolution}"
+            raise ValueError(msg)
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="to_h3",
+            args=[self._expr],
+            kwargs={"resolution": resolution},
+            is_elementwise=True,
+        )
+
+    def h3_cover(self, resolution: int) -> pl.Expr:
+        """Return the sorted list of H3 cells covering each polygon.
+
+        Polygons must have longitude / latitude coordinates. Cells intersecting a
+        polygon are part of its cover, so that all its points are covered.
+
+        Args:
+            resolution:
+                Resolution of the cells, from 0 to 15.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (-122.0553238 37.3615593)"])
+            >>> cell = gdf.select(st.to_h3(resolution=7)).item()
+            >>> cells = pl.select(st.h3_to_boundary(pl.lit(cell, pl.UInt64)).st.h3_cover(7))
+            >>> cell in cells.item()
+            True
+        """
+        if not 0 <= resolution <= 15:
+            msg = f"`resolution` must be between 0 and 15, got {resolution}"
+            raise ValueError(msg)
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="h3_cover",
+            args=[self._expr],
+            kwargs={"resolution": resolution},
+            is_elementwise=True,
+        )
+
+    def tile_of(self, zoom: int) -> pl.Expr:
+        """Return the XYZ tile containing each point, as a struct of `z`, `x` and `y`.
+
+        Points must be in EPSG:3857 or EPSG:4326, which is assumed for points without
+        SRID. Points outside of the Web Mercator extent are assigned to the closest
+        tile. Null and empty points have a null tile.
+
+        Args:
+            zoom:
+                Zoom level of the tiles, from 0 to 30.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (10 10)", "POINT (-10 -10)"])
+            >>> gdf.select(st.tile_of(zoom=1)).unnest("geometry").rows()
+            [(1, 1, 0), (1, 0, 1)]
+        """
+        if not 0 <= zoom <= 30:
+            msg = f"`zoom` must be between 0 and 30, got {zoom}"
+            raise ValueError(msg)
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="tile_of",
+            args=[self._expr],
+            kwargs={"zoom": zoom},
+            is_elementwise=True,
+        )
+
     @register_plugin()
     def length(self) -> pl.Expr:
         """Return the length of each geometry."""
         ...
 
+    @register_plugin()
+    def length_geodesic(self) -> pl.Expr:
+        """Return the ellipsoidal length of each geometry, in meters.
+
+        Geometries must be in EPSG:4326 (or have no SRID), with longitude / latitude
+        coordinates. Lengths are computed on the WGS84 ellipsoid using Karney's algorithms,
+        after linearizing circular arcs to within 1e-7 degrees.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0 0, 1 0)"])
+            >>> gdf.select(st.length_geodesic().round()).item()
+            111319.0
+        """
+        ...
+
+    @register_plugin()
+    def length_3d(self) -> pl.Expr:
+        """Return the 3D length of each geometry, including the Z component.
+
+        Missing Z values are treated as 0, so that the length of 2D geometries is their
+            planar length.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING Z (0 0 0, 3 0 4)"])
+            >>> gdf.select(st.geom().st.length_3d()).item()
+            5.0
+        """
+        ...
+
     @register_plugin()
     def minimum_clearance(self) -> pl.Expr:
         """Return the geometry minimum clearance."""
@@ -253,6 +497,78 @@ class GeoExprNameSpace:
         """Return the list of parts for multipart geometries."""
         ...
 
+    @register_plugin()
+    def dump_points(self) -> pl.Expr:
+        """Return the list of vertices of each geometry, with their position in it.
+
+        Returns:
+            A list of structs with fields `point`, `part_index`, `ring_index` and
+                `vertex_index`. Parts are numbered within the top-level collection and
+                are `0` for single geometries, rings are numbered from the exterior ring
+                of polygons and are null for other geometries.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POLYGON ((0 0, 3 0, 0 3, 0 0), (1 1, 1 2, 2 1, 1 1))"])
+            >>> points = gdf.select(st.geom().st.dump_points()).explode("geometry")
+            >>> points = points.unnest("geometry").with_columns(st.geom("point").st.to_wkt())
+            >>> points.head(5)
+            shape: (5, 4)
+            ┌─────────────┬────────────┬────────────┬──────────────┐
+            │ point       ┆ part_index ┆ ring_index ┆ vertex_index │
+            │ ---         ┆ ---        ┆ ---        ┆ ---          │
+            │ str         ┆ u32        ┆ u32        ┆ u32          │
+            ╞═════════════╪════════════╪════════════╪══════════════╡
+            │ POINT (0 0) ┆ 0          ┆ 0          ┆ 0            │
+            │ POINT (3 0) ┆ 0          ┆ 0          ┆ 1            │
+            │ POINT (0 3) ┆ 0          ┆ 0          ┆ 2            │
+            │ POINT (0 0) ┆ 0          ┆ 0          ┆ 3            │
+            │ POINT (1 1) ┆ 0          ┆ 1          ┆ 0            │
+            └─────────────┴────────────┴────────────┴──────────────┘
+        """
+        ...
+
+    @register_plugin()
+    def collection_extract(self, type: Literal["point", "line", "polygon"]) -> GeoExpr:  # noqa: A002
+        """Extract the parts of each geometry of the given type.
+
+        This is useful to clean up the mixed collections returned by operations such
+        as [`intersection`][polars_st.GeoExprNameSpace.intersection]. Parts are
+        looked up in nested collections, and are returned as a multipart geometry for
+        collections. Single geometries are returned unchanged if they are of the given
+        type, and as an empty geometry of that type otherwise.
+
+        Args:
+            type: The type of the parts to extract.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "GEOMETRYCOLLECTION (POINT (0 0), POLYGON ((0 0, 1 0, 1 1, 0 0)))",
+            ...     "LINESTRING (0 0, 1 1)",
+            ... ])
+            >>> gdf.select(st.collection_extract(type="polygon").st.to_wkt()).to_series().to_list()
+            ['MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)))', 'POLYGON EMPTY']
+        """
+        ...
+
+    @register_plugin()
+    def unnest_collection(self) -> GeoExpr:
+        """Flatten nested geometry collections into a single level.
+
+        Collections whose parts are all of the same type are returned as the matching
+        multipart geometry, which many tools support better than geometry collections.
+        Other collections are returned as flat geometry collections, and single
+        geometries are returned unchanged. Empty parts are dropped.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "GEOMETRYCOLLECTION (POINT (0 0), GEOMETRYCOLLECTION (MULTIPOINT ((1 1))))",
+            ...     "GEOMETRYCOLLECTION (POINT (0 0), GEOMETRYCOLLECTION (LINESTRING (0 0, 1 1)))",
+            ... ])
+            >>> gdf.select(st.unnest_collection().st.to_wkt()).to_series().to_list()
+            ['MULTIPOINT ((0 0), (1 1))', 'GEOMETRYCOLLECTION (POINT (0 0), LINESTRING (0 0, 1 1))']
+        """
+        ...
+
     @register_plugin()
     def precision(self) -> pl.Expr:
         """Return the precision of each geometry."""
@@ -273,11 +589,73 @@ class GeoExprNameSpace:
             is_elementwise=True,
         ).pipe(lambda e: cast("GeoExpr", e))
 
+    def snap_to_grid(
+        self,
+        size_x: float,
+        size_y: float | None = None,
+        origin: Sequence[float] = (0.0, 0.0),
+    ) -> GeoExpr:
+        """Snap the coordinates of each geometry to a regular grid.
+
+        Unlike [`set_precision`][polars_st.GeoExprNameSpace.set_precision], geometries
+            aren't made valid again after snapping: vertices are only moved to the grid,
+            and consecutive vertices snapped to the same point are merged. This makes
+            coordinates of different layers line up exactly, for deduplication or
+            overlays. Holes collapsing to fewer than four vertices are removed, as are
+            polygons whose exterior ring collapses, which give an empty polygon on their
+            own.
+
+        Args:
+            size_x: Grid spacing along the `x` axis. A size of zero leaves `x`
+                coordinates untouched.
+            size_y: Grid spacing along the `y` axis, defaults to `size_x`.
+            origin: Coordinates of a grid node.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0.2 0.3, 0.9 1.2, 0.7 0.8, 2.6 2.4)"])
+            >>> gdf.select(st.geom().st.snap_to_grid(1.0).st.to_wkt())
+            shape: (1, 1)
+            ┌────────────────────────────┐
+            │ geometry                   │
+            │ ---                        │
+            │ str                        │
+            ╞════════════════════════════╡
+            │ LINESTRING (0 0, 1 1, 3 2) │
+            └────────────────────────────┘
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="snap_to_grid",
+            args=[self._expr],
+            kwargs={
+                "size_x": size_x,
+                "size_y": size_x if size_y is None else size_y,
+                "origin": list(origin),
+            },
+            is_elementwise=True,
+        ).pipe(lambda e: cast("GeoExpr", e))
+
     @register_plugin()
     def distance(self, other: IntoGeoExprColumn) -> pl.Expr:
         """Return the distance from each geometry to other."""
         ...
 
+    @register_plugin()
+    def distance_3d(self, other: IntoGeoExprColumn) -> pl.Expr:
+        """Return the 3D distance from each geometry to other, including the Z component.
+
+        Polygons are the planar surfaces bounded by their rings, so that a point above a
+        polygon is at its height from it, and missing Z values are treated as 0. Empty
+        geometries have a NaN distance.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT Z (0 0 4)"])
+            >>> line = st.from_wkt(pl.lit("LINESTRING Z (3 -1 0, 3 1 0)"))
+            >>> gdf.select(st.geom().st.distance_3d(line)).item()
+            5.0
+        """
+        ...
+
     @register_plugin()
     def hausdorff_distance(
         self,
@@ -308,6 +686,198 @@ class GeoExprNameSpace:
             is_elementwise=True,
         )
 
+    def azimuth(self, other: IntoGeoExprColumn, degrees: bool = False) -> pl.Expr:
+        """Return the planar azimuth from each point to the corresponding point in other.
+
+        Azimuths are measured clockwise from the north (positive `y` axis), in the range
+        [0, 2π) or [0, 360) if `degrees` is set. Empty geometries and coincident points
+        have a NaN azimuth, while other geometries than points raise an error.
+
+        Args:
+            other: Points to compute the azimuths to.
+            degrees: Whether to return azimuths in degrees rather than radians.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (1 1)", "POINT (0 -1)"])
+            >>> origin = st.from_wkt(pl.lit("POINT (0 0)"))
+            >>> gdf.select(st.geom().st.azimuth(origin, degrees=True)).to_series().to_list()
+            [225.0, 0.0]
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="azimuth",
+            args=[self._expr, other],
+            kwargs={"degrees": degrees},
+            is_elementwise=True,
+        )
+
+    def azimuth_geodesic(self, other: IntoGeoExprColumn, degrees: bool = False) -> pl.Expr:
+        """Return the geodesic azimuth from each point to the corresponding point in other.
+
+        Points must be in EPSG:4326 (or have no SRID), with longitude / latitude
+        coordinates. The forward azimuth of the geodesic between both points is
+        computed on the WGS84 ellipsoid, clockwise from the north. Like for
+        [`azimuth`][polars_st.GeoExprNameSpace.azimuth], empty geometries have a NaN
+        azimuth.
+
+        Args:
+            other: Points to compute the azimuths to.
+            degrees: Whether to return azimuths in degrees rather than radians.
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="azimuth_geodesic",
+            args=[self._expr, other],
+            kwargs={"degrees": degrees},
+            is_elementwise=True,
+        )
+
+    def knn(self, other: IntoGeoExprColumn, k: int = 1) -> pl.Expr:
+        """Return the `k` nearest geometries of other to each geometry.
+
+        Each result is a list of structs with the `index` of the neighbor in
+        `other` and its `distance`, ordered from the closest to the furthest.
+        Unlike most binary operations, `other` doesn't need to have the same
+        length as the geometries.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (0 0)", "POINT (10 0)"])
+            >>> others = st.GeoSeries(["POINT (1 0)", "POINT (7 0)", "POINT (4 0)"])
+            >>> gdf.select(st.geom().st.knn(others)).to_series().to_list()
+            [[{'index': 0, 'distance': 1.0}], [{'index': 1, 'distance': 3.0}]]
+        """
+        other = pl.col(other) if isinstance(other, str) else pl.lit(other)
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="knn",
+            args=[self._expr, other.implode()],
+            kwargs={"k": k},
+        )
+
+    def count_matches(
+        self,
+        other: IntoGeoExprColumn,
+        predicate: Literal[
+            "intersects_bbox",
+            "intersects",
+            "within",
+            "contains",
+            "overlaps",
+            "crosses",
+            "touches",
+            "covers",
+            "covered_by",
+            "contains_properly",
+            "dwithin",
+        ] = "intersects",
+        *,
+        distance: float | None = None,
+    ) -> pl.Expr:
+        """Return the number of geometries of other satisfying a predicate with each geometry.
+
+        Candidates are found with a spatial index over `other`, and the predicate
+        is evaluated the same way as in [`sjoin`][polars_st.GeoDataFrameNameSpace.sjoin],
+        without building the joined pairs. Unlike most binary operations, `other`
+        doesn't need to have the same length as the geometries.
+
+        Args:
+            other: Geometries to count.
+            predicate: Spatial predicate between each geometry and geometries of other.
+            distance: Maximum distance for the `dwithin` predicate.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+            ...     "POLYGON ((10 10, 12 10, 12 12, 10 12, 10 10))",
+            ... ])
+            >>> others = st.GeoSeries(["POINT (1 1)", "POINT (1.5 0.5)", "POINT (5 5)"])
+            >>> gdf.select(st.geom().st.count_matches(others)).to_series().to_list()
+            [2, 0]
+        """
+        if (predicate == "dwithin") != (distance is not None):
+            msg = "`distance` must be given if and only if `predicate` is 'dwithin'"
+            raise ValueError(msg)
+        other = pl.col(other) if isinstance(other, str) else pl.lit(other)
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="count_matches",
+            args=[self._expr, other.implode()],
+            kwargs={"predicate": predicate, "distance": distance},
+        )
+
+    def pairs_within(self, distance: float) -> pl.Expr:
+        """Return all pairs of geometries within a given distance of each other.
+
+        Each pair is a struct with the `left_index` and `right_index` of the two
+        geometries, the first being lower than the second, and their `distance`.
+        Null and empty geometries are never part of a pair.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (0 0)", "POINT (1 0)", "POINT (5 0)", "POINT (6 0)"])
+            >>> gdf.select(st.geom().st.pairs_within(1)).unnest("geometry").rows()
+            [(0, 1, 1.0), (2, 3, 1.0)]
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="pairs_within",
+            args=[self._expr],
+            kwargs={"distance": distance},
+            changes_length=True,
+        )
+
+    def is_duplicate_geometry(self, tolerance: float | None = None) -> pl.Expr:
+        """Return `True` for geometries equal to another geometry.
+
+        Geometries are compared with [`equals`][polars_st.GeoExprNameSpace.equals],
+        so that geometries with a different vertex order are still equal, or with
+        [`equals_exact`][polars_st.GeoExprNameSpace.equals_exact] if a `tolerance`
+        is given. Null geometries are equal to each other.
+
+        Args:
+            tolerance: Maximum distance between the vertices of equal geometries.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+            ...     "POLYGON ((1 0, 1 1, 0 0, 1 0))",
+            ...     "POINT (0 0)",
+            ... ])
+            >>> gdf.select(st.geom().st.is_duplicate_geometry()).to_series().to_list()
+            [True, True, False]
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="is_duplicate_geometry",
+            args=[self._expr],
+            kwargs={"tolerance": tolerance},
+        )
+
+    def unique_geometries(self, tolerance: float | None = None) -> GeoExpr:
+        """Return the geometries without duplicates, keeping the first one of each.
+
+        Geometries are compared as in
+        [`is_duplicate_geometry`][polars_st.GeoExprNameSpace.is_duplicate_geometry].
+
+        Args:
+            tolerance: Maximum distance between the vertices of equal geometries.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "LINESTRING (0 0, 1 1)",
+            ...     "POINT (0 0)",
+            ...     "LINESTRING (1 1, 0 0)",
+            ... ])
+            >>> gdf.select(st.geom().st.unique_geometries().st.to_wkt()).to_series().to_list()
+            ['LINESTRING (0 0, 1 1)', 'POINT (0 0)']
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="unique_geometries",
+            args=[self._expr],
+            kwargs={"tolerance": tolerance},
+            changes_length=True,
+        ).pipe(lambda e: cast("GeoExpr", e))
+
     # Projection operations
 
     @register_plugin()
@@ -315,6 +885,26 @@ class GeoExprNameSpace:
         """Return the geometry SRID."""
         ...
 
+    @register_plugin()
+    def crs_info(self) -> pl.Expr:
+        """Return a description of the CRS of each geometry, from its SRID.
+
+        The result is a struct with the following fields, which are null when the SRID is
+        neither a known EPSG or ESRI code nor a registered CRS:
+
+        - `authority`: The authority of the CRS, such as `"EPSG"` or `"ESRI"`.
+        - `name`: The name of the CRS.
+        - `units`: The units of the coordinates, such as `"degree"`, `"m"` or `"us-ft"`.
+        - `is_geographic`: Whether the coordinates are longitudes and latitudes.
+        - `axis_order`: The directions of the CRS axes, such as `"east,north"`.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["SRID=3857;POINT (0 0)"])
+            >>> gdf.select(st.crs_info().struct.field("units", "is_geographic")).row(0)
+            ('m', False)
+        """
+        ...
+
     @register_plugin()
     def set_srid(self, srid: IntoIntegerExpr) -> GeoExpr:
         """Set the SRID of each geometry to a given value.
@@ -330,19 +920,179 @@ class GeoExprNameSpace:
         ).pipe(lambda s: cast("GeoExpr", s))
 
     @register_plugin()
-    def to_srid(self, srid: IntoIntegerExpr) -> GeoExpr:
+    def to_srid(
+        self,
+        srid: IntoIntegerExpr,
+        pipeline: str | None = None,
+        antimeridian: Literal["ignore", "shift", "split"] = "ignore",
+    ) -> GeoExpr:
         """Transform the coordinates of each geometry into a new CRS.
 
+        Z values are transformed along with the horizontal coordinates. CRS with
+        orthometric heights can be registered with [`register_crs`][polars_st.register_crs],
+        either as PROJ strings with a `+geoidgrids=path/to/geoid.gtx` parameter, or as WKT
+        compound CRS (`COMPD_CS`) whose vertical CRS has a `PROJ4_GRIDS` extension. Heights
+        are then converted between the ellipsoid and the geoid given by the GTX grids.
+        Datum shift grids registered for the source or destination SRID with
+        [`register_datum_grids`][polars_st.register_datum_grids] replace the datum parameters
+        of the CRS, for example to transform NAD27 coordinates with the NADCON grids.
+
+        When polars-st is built with the `proj` feature, transformations between CRS that
+        libproj knows go through libproj, which picks the most accurate datum transformation
+        available. This also holds for [`bounds_to_srid`][polars_st.GeoExprNameSpace.bounds_to_srid],
+        [`transform`][polars_st.GeoExprNameSpace.transform] and
+        [`utm_zone`][polars_st.GeoExprNameSpace.utm_zone], while explicit `pipeline`s always
+        run on the built-in implementation.
+
         Args:
             srid: The srid code of the new CRS
-        """
+            pipeline:
+                An explicit PROJ pipeline (`+proj=pipeline +step ...`) used to transform
+                the coordinates, instead of the transformation looked up from the source and
+                destination EPSG codes. Projection, `unitconvert`, `axisswap`, `cart` and
+                `helmert` steps are supported, as well as `hgridshift` steps with NTv2 or NADCON
+                grid files (`+grids=path/to/grid.gsb`, with optional grids prefixed by `@`) and
+                `vgridshift` steps with GTX grid files. As with PROJ, coordinates are passed
+                as is to the first step, so pipelines from geographic CRS usually start with
+                a `unitconvert` step from degrees to radians. Parameters that the steps
+                don't support raise an error instead of being ignored.
+            antimeridian:
+                How to handle geometries crossing the antimeridian when transforming into a
+                geographic CRS. With `"shift"`, longitudes are unwrapped so that consecutive
+                vertices are never more than 180° apart, and may extend past ±180°. With
+                `"split"`, geometries are also cut along the antimeridian into multi-part
+                geometries within [-180°, 180°]. With `"ignore"`, longitudes are kept as
+                returned by the transformation.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["SRID=4326;POINT (1 2)"])
+            >>> pipeline = "+proj=pipeline +step +proj=axisswap +order=2,1"
+            >>> gdf.select(st.geom().st.to_srid(4326, pipeline=pipeline).st.to_ewkt()).item()
+            'SRID=4326;POINT (2 1)'
+        """  # noqa: E501
         return register_plugin_function(
             plugin_path=Path(__file__).parent,
             function_name="to_srid",
             args=[self._expr, srid],
+            kwargs={"pipeline": pipeline, "antimeridian": antimeridian},
             is_elementwise=True,
         ).pipe(lambda e: cast("GeoExpr", e))
 
+    @register_plugin()
+    def bounds_to_srid(self, srid: IntoIntegerExpr, densify_points: int = 21) -> pl.Expr:
+        """Return the bounds of each geometry, transformed into a new CRS.
+
+        Only the envelope of each geometry is transformed, which is much cheaper than
+        transforming whole geometries. Points are added along the envelope edges before the
+        transformation, since straight edges usually become curves in the new CRS.
+
+        Args:
+            srid: The srid code of the new CRS
+            densify_points: The number of points added along each edge of the envelopes.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["SRID=4326;LINESTRING (0 0, 10 10)"])
+            >>> gdf.select(st.bounds_to_srid(srid=3857).round(-3)).item().to_list()
+            [0.0, 0.0, 1113000.0, 1119000.0]
+        """
+        ...
+
+    @register_plugin()
+    def transform(self, from_crs: str, to_crs: str) -> GeoExpr:
+        """Transform the coordinates of each geometry from a CRS into another.
+
+        Unlike [`to_srid`][polars_st.GeoExprNameSpace.to_srid], the SRID of the geometries
+        is ignored, which allows using custom and local CRS. The SRID of the result is
+        set to the EPSG or ESRI code of `to_crs` if it has one, or `to_crs` is registered with
+        [`register_crs`][polars_st.register_crs] otherwise.
+
+        Args:
+            from_crs: The source CRS, as a PROJ string, a WKT string, or an EPSG or ESRI
+                authority code such as `"EPSG:4326"` or `"ESRI:102003"`.
+            to_crs: The destination CRS, in any of the formats supported for `from_crs`.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (2 1)"])
+            >>> gdf = gdf.select(st.geom().st.transform("+proj=longlat +datum=WGS84", "EPSG:3857"))
+            >>> gdf.select(st.geom().st.srid()).item()
+            3857
+        """
+        ...
+
+    def transform_with(
+        self,
+        func: Callable[[npt.NDArray[np.float64]], npt.ArrayLike],
+        include_z: bool = False,
+    ) -> GeoExpr:
+        """Transform the coordinates of each geometry with a function of coordinate arrays.
+
+        The function is called once per batch of geometries, with the coordinates of all
+        the geometries of the batch as an array of shape `(N, 2)`, or `(N, 3)` with
+        `include_z`, and must return the transformed coordinates as an array of the same
+        shape. This allows using vectorized transformations, such as a `pyproj`
+        `Transformer`, without calling Python for each geometry. Missing Z values are
+        NaN, and returned Z values are ignored for geometries without Z. The SRID of the
+        geometries is kept as is.
+
+        Args:
+            func: The function transforming the coordinates.
+            include_z: Whether to pass and return Z values.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (1 2)", "LINESTRING (0 0, 1 1)"])
+            >>> gdf = gdf.select(st.transform_with(func=lambda coords: coords * 10))
+            >>> gdf.select(st.to_wkt()).to_series().to_list()
+            ['POINT (10 20)', 'LINESTRING (0 0, 10 10)']
+        """
+        import numpy as np
+
+        dimension = 3 if include_z else 2
+
+        def apply(s: pl.Series) -> pl.Series:
+            coords = _lib.get_coordinates(s, dimension).to_numpy().reshape(-1, dimension)
+            result = np.asarray(func(coords), dtype=np.float64)
+            if result.shape != coords.shape:
+                msg = f"Expected coordinates of shape {coords.shape}, got {result.shape}"
+                raise ValueError(msg)
+            return _lib.set_coordinates(s, pl.Series(result.ravel()), dimension)
+
+        res = self._expr.map_batches(apply, return_dtype=pl.Binary, is_elementwise=True)
+        return cast("GeoExpr", res)
+
+    @register_plugin()
+    def utm_zone(self) -> pl.Expr:
+        """Return the EPSG code of the WGS 84 UTM zone of each geometry.
+
+        The zone is the one containing the center of the geometry bounding box, following
+        the exceptions around Norway and Svalbard. Codes are `326xx` in the northern
+        hemisphere and `327xx` in the southern one. Geometries without SRID are assumed to
+        be in EPSG:4326.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (2.35 48.85)", "POINT (-70.6 -33.4)"])
+            >>> gdf.select(st.utm_zone()).to_series().to_list()
+            [32631, 32719]
+        """
+        ...
+
+    def to_utm(self, by: Literal["geometry", "frame"] = "geometry") -> GeoExpr:
+        """Transform the coordinates of each geometry into its UTM zone.
+
+        Args:
+            by:
+                With `"geometry"`, each geometry is transformed into its own UTM zone, as
+                returned by [`utm_zone`][polars_st.GeoExprNameSpace.utm_zone]. With
+                `"frame"`, all geometries are transformed into the UTM zone of the
+                geometries as a whole, so that they share the same SRID.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["SRID=4326;POINT (2 1)", "SRID=4326;POINT (7 1)"])
+            >>> gdf.select(st.to_utm(by="frame").st.srid()).to_series().to_list()
+            [32631, 32631]
+        """
+        zone = self.utm_zone() if by == "geometry" else self.collect().st.utm_zone()
+        return self.to_srid(zone)
+
     # Serialization
 
     @register_plugin()
@@ -397,6 +1147,7 @@ class GeoExprNameSpace:
         output_dimension: Literal[2, 3, 4] = 3,
         byte_order: Literal[0, 1] | None = None,
         include_srid: bool = False,
+        flavor: Literal["extended", "iso"] = "extended",
     ) -> pl.Expr:
         """Serialize each geometry as WKB (Well-Known Binary).
 
@@ -411,6 +1162,25 @@ class GeoExprNameSpace:
             include_srid:
                 If True, the SRID is be included in WKB (this is an extension
                 to the OGC WKB specification).
+            flavor:
+                `"extended"` flags Z and M dimensions in the geometry type as PostGIS
+                does, while `"iso"` uses the ISO type codes (e.g. 1001 for a Point Z),
+                as required by GeoPackage or GeoParquet. ISO WKB can't include the SRID.
+        """
+        ...
+
+    @register_plugin()
+    def to_ewkb_hex(self) -> pl.Expr:
+        """Serialize each geometry as hex-encoded EWKB (Extended Well-Known Binary).
+
+        The output matches the text representation of PostGIS geometries, and can be
+        used as input of `COPY`: it is little endian, and includes the SRID and the Z/M
+        dimensions of geometries.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (1 2)"]).st.set_srid(4326)
+            >>> gdf.select(st.geom().st.to_ewkb_hex()).item()
+            '0101000020E6100000000000000000F03F0000000000000040'
         """
         ...
 
@@ -426,12 +1196,202 @@ class GeoExprNameSpace:
         """
         ...
 
+    def to_geojson_feature(
+        self,
+        properties: IntoExprColumn | None = None,
+        feature_id: IntoExprColumn | None = None,
+    ) -> pl.Expr:
+        """Serialize each geometry as a complete GeoJSON Feature.
+
+        Args:
+            properties:
+                Struct expression whose fields are written as the feature properties.
+                Properties are `null` if not set.
+            feature_id:
+                Expression written as the feature `id` member. The member is omitted if
+                not set.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame({
+            ...     "geometry": ["POINT (1 2)", None],
+            ...     "id": [1, 2],
+            ...     "name": ["Alice", "Bob"],
+            ... })
+            >>> features = gdf.select(
+            ...     st.geom().st.to_geojson_feature(pl.struct("name"), "id")
+            ... )
+            >>> for feature in features.to_series():
+            ...     print(feature)
+            {"type":"Feature","id":1,"properties":{"name":"Alice"},"geometry":{"type":"Point","coordinates":[1.0,2.0]}}
+            {"type":"Feature","id":2,"properties":{"name":"Bob"},"geometry":null}
+        """  # noqa: E501
+        members = pl.lit("")
+        if feature_id is not None:
+            feature_id = wrap_expr(parse_into_expression(feature_id))
+            members = (
+                pl.struct(feature_id.alias("id"))
+                .struct.json_encode()
+                .str.strip_prefix("{")
+                .str.strip_suffix("}")
+                + ","
+            )
+        if properties is not None:
+            properties = wrap_expr(parse_into_expression(properties))
+            properties = properties.struct.json_encode().fill_null("null")
+        else:
+            properties = pl.lit("null")
+        return pl.format(
+            '{"type":"Feature",{}"properties":{},"geometry":{}}',
+            members,
+            properties,
+            self.to_geojson().fill_null("null"),
+        )
+
+    @register_plugin()
+    def to_esrijson(self, indent: int | None = None) -> pl.Expr:
+        """Serialize each geometry as an EsriJSON geometry object.
+
+        Polygon exterior rings are written clockwise and holes counter-clockwise, as
+        required by the ArcGIS REST API. The SRID, if any, is written as the `wkid` of the
+        spatial reference. EsriJSON has no geometry collections, which are written as null,
+        and curved geometries are not supported.
+
+        Args:
+            indent:
+                If indent is not `None`, then EsriJSON will be pretty-printed.
+                An indent level of 0 will only insert newlines. `None` (the default)
+                outputs the most compact representation.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "SRID=4326;POINT (1 2)",
+            ...     "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+            ... ])
+            >>> for esrijson in gdf.select(st.geom().st.to_esrijson()).to_series():
+            ...     print(esrijson)
+            {"x":1.0,"y":2.0,"spatialReference":{"wkid":4326}}
+            {"rings":[[[0.0,0.0],[0.0,2.0],[2.0,2.0],[2.0,0.0],[0.0,0.0]]]}
+        """
+        ...
+
+    @register_plugin()
+    def to_kml(self) -> pl.Expr:
+        """Serialize each geometry as a KML geometry element.
+
+        KML coordinates are always longitudes and latitudes in EPSG:4326, so geometries
+            with another SRID raise an error and must be transformed first with
+            [`to_srid`][polars_st.GeoExprNameSpace.to_srid]. Geometries without an SRID
+            are written as is. Multi-part geometries and collections are written as
+            `MultiGeometry` elements.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POINT (1 2)",
+            ...     "MULTIPOINT ((0 0), (1 1))",
+            ... ])
+            >>> for kml in gdf.select(st.geom().st.to_kml()).to_series():
+            ...     print(kml)
+            <Point><coordinates>1,2</coordinates></Point>
+            <MultiGeometry><Point><coordinates>0,0</coordinates></Point><Point><coordinates>1,1</coordinates></Point></MultiGeometry>
+        """
+        ...
+
+    @register_plugin()
+    def to_gml(self) -> pl.Expr:
+        """Serialize each geometry as a GML 3.2 geometry element.
+
+        The geometry SRID is written as `srsName`. Multi-part geometries are written as
+        `MultiPoint`, `MultiCurve`, `MultiSurface` and `MultiGeometry` elements.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POINT (1 2)",
+            ...     "LINESTRING (0 0, 1 1)",
+            ... ]).st.set_srid(4326)
+            >>> for gml in gdf.select(st.geom().st.to_gml()).to_series():
+            ...     print(gml)
+            <gml:Point xmlns:gml="http://www.opengis.net/gml/3.2" srsName="EPSG:4326"><gml:pos>1 2</gml:pos></gml:Point>
+            <gml:LineString xmlns:gml="http://www.opengis.net/gml/3.2" srsName="EPSG:4326"><gml:posList>0 0 1 1</gml:posList></gml:LineString>
+        """  # noqa: E501
+        ...
+
+    @register_plugin()
+    def to_gpkg(self) -> pl.Expr:
+        """Serialize each geometry as GeoPackage binary.
+
+        The GeoPackage header holds the geometry SRID as `srs_id`, and the XY envelope
+        of the geometry.
+        """
+        ...
+
+    @register_plugin()
+    def to_spatialite(self) -> pl.Expr:
+        """Serialize each geometry as a SpatiaLite geometry BLOB.
+
+        Blobs are little endian, with the geometry SRID and its MBR (minimum bounding
+        rectangle) in the header, and uncompressed coordinates. Nested geometry
+        collections are not supported by the format.
+        """
+        ...
+
+    @register_plugin()
+    def to_svg(self, view_box: bool = False, flip_y: bool = True) -> pl.Expr:
+        """Render each geometry as SVG elements.
+
+        Points are rendered as `circle` elements, and lines and polygons as `path`
+        elements. Multi-part points and collections are grouped in a `g` element.
+
+        Args:
+            view_box:
+                If `True`, wrap the elements in an `svg` element with a `viewBox` fitted
+                to the geometry bounds, and a stroke width and point radius proportional
+                to its size, so that it can be displayed as is.
+            flip_y:
+                If `True`, y coordinates are negated, as the SVG y axis points down.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POINT (1 2)",
+            ...     "POLYGON ((0 0, 0 1, 1 1, 0 0))",
+            ... ])
+            >>> for svg in gdf.select(st.geom().st.to_svg()).to_series():
+            ...     print(svg)
+            <circle cx="1" cy="-2" r="1"/>
+            <path d="M0 0 L0 -1 L1 -1 L0 0 Z" fill-rule="evenodd"/>
+        """
+        ...
+
+    @register_plugin()
+    def to_geoarrow(
+        self,
+        geometry_type: GeometryType,
+        dimensions: Literal["xy", "xyz", "xym", "xyzm"] = "xy",
+    ) -> pl.Expr:
+        """Convert each geometry to a GeoArrow native encoding, with separated coordinates.
+
+        Coordinates are stored in a struct of `x`, `y` and optional `z` and `m` fields,
+        nested in lists according to the geometry type. Single-part geometries are
+        accepted for the matching multi-part type, and empty points are encoded with
+        NaN coordinates.
+
+        Args:
+            geometry_type:
+                The output geometry type, which must be `"Point"`, `"LineString"`,
+                `"Polygon"`, `"MultiPoint"`, `"MultiLineString"` or `"MultiPolygon"`.
+            dimensions:
+                The coordinate fields to output. Missing dimensions are filled with NaN.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (0 1)", "POINT (2 3)"])
+            >>> gdf.select(st.geom().st.to_geoarrow("Point")).to_series().to_list()
+            [{'x': 0.0, 'y': 1.0}, {'x': 2.0, 'y': 3.0}]
+        """
+        ...
+
     def to_shapely(self) -> pl.Expr:
         """Convert each geometry to a Shapely object."""
-        import shapely
-
         return self._expr.map_batches(
-            lambda s: pl.Series(s.name, shapely.from_wkb(s), dtype=pl.Object()),
+            lambda s: pl.Series(s.name, to_shapely_array(s), dtype=pl.Object()),
             return_dtype=pl.Object(),
             is_elementwise=True,
         )
@@ -484,6 +1444,25 @@ class GeoExprNameSpace:
         """Return `True` for each geometry with `m` coordinate values."""
         ...
 
+    @register_plugin()
+    def has_curves(self) -> pl.Expr:
+        """Return `True` for each geometry containing circular arcs.
+
+        Curved geometries, such as `CircularString` or `CurvePolygon`, often come from
+        CAD files or databases, and aren't supported by most operations. They can be
+        linearized with [`curve_to_linear`][polars_st.GeoExprNameSpace.curve_to_linear].
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "CIRCULARSTRING (0 0, 1 1, 2 0)",
+            ...     "COMPOUNDCURVE ((0 0, 1 0), (1 0, 2 0))",
+            ...     "LINESTRING (0 0, 1 0)",
+            ... ])
+            >>> gdf.select(st.has_curves()).to_series().to_list()
+            [True, False, False]
+        """
+        ...
+
     @register_plugin()
     def is_ccw(self) -> pl.Expr:
         """Return `True` for linear geometries with counter-clockwise coord sequence."""
@@ -510,13 +1489,67 @@ class GeoExprNameSpace:
         ...
 
     @register_plugin()
-    def is_valid(self) -> pl.Expr:
-        """Return `True` for valid geometries."""
+    def is_valid(self) -> pl.Expr:
+        """Return `True` for valid geometries."""
+        ...
+
+    @register_plugin()
+    def is_valid_reason(self) -> pl.Expr:
+        """Return an explanation string for the invalidity of each geometry."""
+        ...
+
+    @register_plugin()
+    def is_valid_detail(self) -> pl.Expr:
+        """Return the validity of each geometry, with the reason and location of errors.
+
+        Returns:
+            A struct with fields `valid`, `reason` and `location`. The `reason` and
+                `location` of valid geometries are null, as well as the `location` of
+                errors that aren't tied to a specific point.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POLYGON ((0 0, 2 2, 2 0, 0 2, 0 0))",
+            ...     "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+            ... ])
+            >>> detail = gdf.select(st.geom().st.is_valid_detail()).unnest("geometry")
+            >>> detail.select("valid", "reason", st.geom("location").st.to_wkt())
+            shape: (2, 3)
+            ┌───────┬───────────────────┬─────────────┐
+            │ valid ┆ reason            ┆ location    │
+            │ ---   ┆ ---               ┆ ---         │
+            │ bool  ┆ str               ┆ str         │
+            ╞═══════╪═══════════════════╪═════════════╡
+            │ false ┆ Self-intersection ┆ POINT (1 1) │
+            │ true  ┆ null              ┆ null        │
+            └───────┴───────────────────┴─────────────┘
+        """
+        ...
+
+    @register_plugin()
+    def wkb_is_parseable(self) -> pl.Expr:
+        """Return `True` for binary values that can be parsed as WKB or EWKB.
+
+        Unlike other operations, this never raises on corrupt values, which allows
+        quarantining them before running any geometry operation.
+
+        Examples:
+            >>> df = pl.Series("geometry", [
+            ...     bytes.fromhex("0101000000000000000000f03f0000000000000040"),
+            ...     b"corrupt",
+            ... ]).to_frame()
+            >>> df.select(st.geom().st.wkb_is_parseable()).to_series().to_list()
+            [True, False]
+        """
         ...
 
     @register_plugin()
-    def is_valid_reason(self) -> pl.Expr:
-        """Return an explanation string for the invalidity of each geometry."""
+    def wkb_parse_error(self) -> pl.Expr:
+        """Return the reason why binary values can't be parsed as WKB or EWKB.
+
+        The result is null for values that can be parsed. Unlike other operations, this
+        never raises on corrupt values.
+        """
         ...
 
     # Binary predicates
@@ -670,9 +1703,93 @@ class GeoExprNameSpace:
         mitre_limit: float = 5.0,
         single_sided: bool = False,
     ) -> GeoExpr:
-        """Return a buffer around each geometry."""
+        """Return a buffer around each geometry.
+
+        Args:
+            distance: Buffer distance. Negative distances shrink polygons.
+            quad_segs: Number of segments used to approximate a quarter circle.
+            cap_style: Buffer cap style.
+            join_style: Buffer join style.
+            mitre_limit: Limit on the mitre ratio used for very sharp corners.
+            single_sided: Buffer lines on a single side only, the left one for positive
+                distances and the right one for negative distances. The cap style is
+                ignored, and ends are always flat.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0 0, 10 0)"])
+            >>> left = st.geom().st.buffer(2, single_sided=True)
+            >>> gdf.select(left.st.bounds()).item().to_list()
+            [0.0, 0.0, 10.0, 2.0]
+        """
+        ...
+
+    @register_plugin()
+    def buffer_geodesic(
+        self,
+        distance_m: IntoNumericExpr,
+        quad_segs: int = 8,
+        cap_style: Literal["round", "square", "flat"] = "round",
+        join_style: Literal["round", "mitre", "bevel"] = "round",
+        mitre_limit: float = 5.0,
+        single_sided: bool = False,
+        antimeridian: Literal["ignore", "shift", "split"] = "split",
+    ) -> GeoExpr:
+        """Return a buffer of `distance_m` meters around each geometry.
+
+        Geometries must be in EPSG:4326 (or have no SRID), with longitude / latitude
+        coordinates. Each geometry is buffered in an azimuthal equidistant projection
+        centered on its centroid, so distances are accurate near the geometry but drift
+        for buffers spanning thousands of kilometers. Buffers crossing the antimeridian
+        are handled as in [`to_srid`][polars_st.GeoExprNameSpace.to_srid], and are split
+        by default.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT (2.35 48.85)"])
+            >>> area = st.buffer_geodesic(distance_m=500).st.area_geodesic()
+            >>> round(gdf.select(area).item() / 1e5, 1)
+            7.8
+        """
         ...
 
+    def buffer_rings(
+        self,
+        distances: Sequence[float],
+        quad_segs: int = 8,
+        cap_style: Literal["round", "square", "flat"] = "round",
+        join_style: Literal["round", "mitre", "bevel"] = "round",
+        mitre_limit: float = 5.0,
+        dissolve: bool = False,
+    ) -> pl.Expr:
+        """Return a list of concentric ring polygons around each geometry.
+
+        The first ring is the buffer at the first distance, and each subsequent ring is
+        the area between two consecutive buffer distances.
+
+        Args:
+            distances: Buffer distances, in strictly ascending order.
+            quad_segs: Number of segments used to approximate a quarter circle.
+            cap_style: Buffer cap style.
+            join_style: Buffer join style.
+            mitre_limit: Limit on the mitre ratio used for very sharp corners.
+            dissolve: Dissolve the rings of all geometries, and return a single list.
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="buffer_rings",
+            args=[self._expr],
+            kwargs={
+                "distances": [float(d) for d in distances],
+                "dissolve": dissolve,
+                "quad_segs": quad_segs,
+                "cap_style": cap_style,
+                "join_style": join_style,
+                "mitre_limit": mitre_limit,
+                "single_sided": False,
+            },
+            is_elementwise=not dissolve,
+            returns_scalar=dissolve,
+        )
+
     @register_plugin()
     def offset_curve(
         self,
@@ -708,6 +1825,38 @@ class GeoExprNameSpace:
             is_elementwise=True,
         ).pipe(lambda e: cast("GeoExpr", e))
 
+    @register_plugin()
+    def subdivide(self, max_vertices: int = 256) -> pl.Expr:
+        """Split each geometry into a list of pieces with at most `max_vertices` vertices.
+
+        Geometries are recursively clipped by halves of their bounding box, so that the
+            pieces are small and compact. Joining points against subdivided polygons is
+            much faster than against the original ones, as each piece has a tight
+            envelope and few vertices to test.
+
+        Args:
+            max_vertices: Maximum number of vertices of each piece, at least 5.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POLYGON ((0 0, 1 0, 2 0, 3 0, 4 0, 4 1, 3 1, 2 1, 1 1, 0 1, 0 0))"
+            ... ])
+            >>> pieces = st.geom().st.subdivide(8)
+            >>> gdf.select(
+            ...     pieces.list.len().alias("pieces"),
+            ...     pieces.explode().st.count_coordinates().max().alias("max_vertices"),
+            ... )
+            shape: (1, 2)
+            ┌────────┬──────────────┐
+            │ pieces ┆ max_vertices │
+            │ ---    ┆ ---          │
+            │ u32    ┆ u32          │
+            ╞════════╪══════════════╡
+            │ 2      ┆ 7            │
+            └────────┴──────────────┘
+        """
+        ...
+
     @register_plugin()
     def convex_hull(self) -> GeoExpr:
         """Return the convex hull of each geometry."""
@@ -721,6 +1870,74 @@ class GeoExprNameSpace:
     @register_plugin()
     def segmentize(self, max_segment_length: IntoNumericExpr) -> GeoExpr: ...
 
+    @register_plugin()
+    def segmentize_geodesic(
+        self,
+        max_length_m: IntoNumericExpr,
+        antimeridian: Literal["ignore", "shift", "split"] = "shift",
+    ) -> GeoExpr:
+        """Densify each geometry along the geodesics between its vertices.
+
+        New vertices are added along the WGS84 geodesics so that no segment is longer
+        than `max_length_m` meters, which keeps long lines such as flight paths or cables
+        correct once projected. Geometries must be in EPSG:4326 (or have no SRID), with
+        longitude / latitude coordinates. Z and M values are interpolated linearly.
+        Curved geometries are linearized, and returned as their linear counterparts.
+        Lines crossing the antimeridian are handled as in
+        [`to_srid`][polars_st.GeoExprNameSpace.to_srid], and are shifted by default.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0 0, 1 0)"])
+            >>> gdf.select(st.segmentize_geodesic(max_length_m=10_000).st.count_points()).item()
+            13
+        """
+        ...
+
+    @register_plugin()
+    def curve_to_linear(self, tolerance: IntoNumericExpr) -> GeoExpr:
+        """Replace the circular arcs of each geometry by line segments.
+
+        Arcs are split so that the segments deviate from them by at most `tolerance`,
+        and curved geometry types are replaced by their linear counterpart, such as
+        `LineString` for `CircularString` and `CompoundCurve`, or `Polygon` for
+        `CurvePolygon`. Z and M values are interpolated linearly, and linear
+        geometries are returned unchanged.
+
+        Args:
+            tolerance: Maximum distance between the arcs and their segments.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["CIRCULARSTRING (0 0, 1 1, 2 0)"])
+            >>> linear = gdf.select(st.curve_to_linear(tolerance=0.1))
+            >>> linear.select(st.has_curves(), st.count_points()).row(0)
+            (False, 5)
+        """
+        ...
+
+    @register_plugin()
+    def linear_to_curve(self, tolerance: IntoNumericExpr) -> GeoExpr:
+        """Replace the runs of vertices lying on circular arcs by actual arcs.
+
+        This is the inverse of [`curve_to_linear`][polars_st.GeoExprNameSpace.curve_to_linear],
+        which re-expresses densified arcs compactly, for example before storing them
+        in a database supporting curves. Arcs are recognized from at least 4
+        consecutive vertices deviating from a common circle by at most `tolerance`,
+        turning in the same direction by at most 45° at a time. Lines and polygons
+        containing arcs become `CircularString`, `CompoundCurve` or `CurvePolygon`,
+        and other geometries are returned unchanged.
+
+        Args:
+            tolerance: Maximum distance between the vertices and the arcs.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["CIRCULARSTRING (0 0, 1 1, 2 0)"])
+            >>> linear = gdf.select(st.curve_to_linear(tolerance=0.01))
+            >>> curve = linear.select(st.linear_to_curve(tolerance=1e-9))
+            >>> curve.select(st.geometry_type(), st.count_points()).row(0)
+            ('CircularString', 3)
+        """
+        ...
+
     @register_plugin()
     def envelope(self) -> GeoExpr:
         """Return the envelope of each geometry."""
@@ -732,8 +1949,37 @@ class GeoExprNameSpace:
     @register_plugin()
     def build_area(self) -> GeoExpr: ...
 
-    @register_plugin()
-    def make_valid(self) -> GeoExpr: ...
+    def make_valid(
+        self,
+        method: Literal["linework", "structure"] = "linework",
+        keep_collapsed: bool = True,
+    ) -> GeoExpr:
+        """Repair invalid geometries.
+
+        Args:
+            method: Repair algorithm. `"linework"` nodes all the lines and builds
+                valid geometries from them, keeping every vertex of the input.
+                `"structure"` rebuilds polygons from the union of their shells minus
+                their holes, which preserves the expected areas better.
+            keep_collapsed: Whether to keep the components that collapsed to a
+                lower dimension, such as zero-area polygons collapsing to lines. Only
+                supported by the `"structure"` method.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POLYGON ((0 0, 1 1, 1 2, 1 1, 0 0))"])
+            >>> gdf.select(st.geom().st.make_valid().st.to_wkt()).item()
+            'MULTILINESTRING ((0 0, 1 1), (1 1, 1 2))'
+            >>> valid = st.geom().st.make_valid(method="structure", keep_collapsed=False)
+            >>> gdf.select(valid.st.to_wkt()).item()
+            'POLYGON EMPTY'
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="make_valid",
+            args=[self._expr],
+            kwargs={"method": method, "keep_collapsed": keep_collapsed},
+            is_elementwise=True,
+        ).pipe(lambda e: cast("GeoExpr", e))
 
     @register_plugin()
     def normalize(self) -> GeoExpr: ...
@@ -746,6 +1992,33 @@ class GeoExprNameSpace:
         """Return a point that intersects of each geometry."""
         ...
 
+    @register_plugin()
+    def polylabel(self, tolerance: IntoNumericExpr = 1.0) -> GeoExpr:
+        """Return the pole of inaccessibility of each polygon.
+
+        The pole of inaccessibility is the center of the largest circle inscribed in the
+            polygon, which makes it a better anchor than the centroid or
+            [`point_on_surface`][polars_st.GeoExprNameSpace.point_on_surface] for
+            placing labels on concave shapes.
+
+        Args:
+            tolerance: Precision of the computed point, which must be positive.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POLYGON ((0 0, 6 0, 0 8, 0 0))"])
+            >>> label = st.geom().st.polylabel(0.01)
+            >>> gdf.select(label.st.x().round(1), label.st.y().round(1))
+            shape: (1, 2)
+            ┌─────┬─────┐
+            │ x   ┆ y   │
+            │ --- ┆ --- │
+            │ f64 ┆ f64 │
+            ╞═════╪═════╡
+            │ 2.0 ┆ 2.0 │
+            └─────┴─────┘
+        """
+        ...
+
     @register_plugin()
     def remove_repeated_points(self, tolerance: IntoNumericExpr = 0.0) -> GeoExpr:
         """Remove the repeated points for each geometry."""
@@ -765,6 +2038,65 @@ class GeoExprNameSpace:
         """Simplify each geometry with a given tolerance."""
         ...
 
+    @register_plugin()
+    def simplify_vw(self, area_tolerance: IntoNumericExpr) -> GeoExpr:
+        """Simplify each geometry with the Visvalingam–Whyatt algorithm.
+
+        Vertices forming the smallest triangles with their neighbours are removed first,
+            as long as the area of those triangles is below `area_tolerance`. This gives
+            smoother shapes than [`simplify`][polars_st.GeoExprNameSpace.simplify],
+            which is usually preferred for cartographic generalization. Lines keep at
+            least 2 vertices and rings 4, but the topology isn't preserved.
+
+        Args:
+            area_tolerance: Area below which vertices are removed.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0 0, 1 0.1, 2 0, 3 2, 4 0)"])
+            >>> gdf.select(st.geom().st.simplify_vw(0.5).st.to_wkt())
+            shape: (1, 1)
+            ┌─────────────────────────────────┐
+            │ geometry                        │
+            │ ---                             │
+            │ str                             │
+            ╞═════════════════════════════════╡
+            │ LINESTRING (0 0, 2 0, 3 2, 4 0) │
+            └─────────────────────────────────┘
+        """
+        ...
+
+    def simplify_coverage(self, tolerance: float, preserve_boundary: bool = False) -> GeoExpr:
+        """Simplify the polygons of a coverage together, without gaps or overlaps.
+
+        All the polygons of the column are expected to form a coverage, that is to only
+            touch along shared edges. Edges shared by two polygons are simplified
+            identically with the Visvalingam–Whyatt algorithm, so that no gaps or
+            overlaps appear between neighbours. Use it in a `group_by` or `over` context
+            to simplify several coverages independently. Empty polygons are returned as
+            is, and other geometry types raise an error.
+
+        Args:
+            tolerance: Distance tolerance, the square of which gives the area below
+                which vertices are removed.
+            preserve_boundary: Whether to keep the outer boundary of the coverage
+                unchanged.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "POLYGON ((0 0, 1 0.1, 2 0, 2 2, 1 2.1, 0 2, 0 0))",
+            ...     "POLYGON ((2 0, 4 0, 4 2, 2 2, 2 0))",
+            ... ])
+            >>> simplified = gdf.select(st.geom().st.simplify_coverage(0.5))
+            >>> simplified.select(st.count_coordinates()).to_series().to_list()
+            [5, 5]
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="simplify_coverage",
+            args=[self._expr],
+            kwargs={"tolerance": tolerance, "preserve_boundary": preserve_boundary},
+        ).pipe(lambda e: cast("GeoExpr", e))
+
     @register_plugin()
     def force_2d(self) -> GeoExpr:
         """Force the dimensionality of a geometry to 2D."""
@@ -775,6 +2107,19 @@ class GeoExprNameSpace:
         """Force the dimensionality of a geometry to 3D."""
         ...
 
+    @register_plugin()
+    def force_4d(self, z: IntoNumericExpr = 0.0, m: IntoNumericExpr = 0.0) -> GeoExpr:
+        """Force the dimensionality of a geometry to 4D, with both Z and M values.
+
+        Existing Z and M values are kept, and missing ones are set to `z` and `m`.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING Z (0 0 1, 1 1 2)"])
+            >>> gdf.select(st.geom().st.force_4d(m=5).st.to_wkt()).item()
+            'LINESTRING ZM (0 0 1 5, 1 1 2 5)'
+        """
+        ...
+
     @register_plugin()
     def flip_coordinates(self) -> GeoExpr:
         """Flip the x and y coordinates of each geometry."""
@@ -795,6 +2140,38 @@ class GeoExprNameSpace:
         """Return the shortest line between each geometry and other."""
         ...
 
+    @register_plugin()
+    def longest_line(self, other: IntoGeoExprColumn) -> GeoExpr:
+        """Return the longest line between each geometry and other.
+
+        The line goes from the point of each geometry to the point of other that are
+            the farthest apart, and its length is the maximum distance between both
+            geometries.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POLYGON ((0 0, 2 0, 2 1, 0 1, 0 0))"])
+            >>> other = st.from_wkt(pl.lit("POINT (3 0)"))
+            >>> gdf.select(st.geom().st.longest_line(other).st.to_wkt()).item()
+            'LINESTRING (0 1, 3 0)'
+        """
+        ...
+
+    @register_plugin()
+    def closest_point(self, other: IntoGeoExprColumn) -> GeoExpr:
+        """Return the point of each geometry that is the closest to other.
+
+        This is the first point of the
+            [`shortest_line`][polars_st.GeoExprNameSpace.shortest_line], without
+            building the line itself.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0 0, 2 0)"])
+            >>> other = st.from_wkt(pl.lit("POINT (1 1)"))
+            >>> gdf.select(st.geom().st.closest_point(other).st.to_wkt()).item()
+            'POINT (1 0)'
+        """
+        ...
+
     # Affine tranforms
 
     def affine_transform(self, matrix: IntoExprColumn | Sequence[float]) -> GeoExpr:
@@ -872,6 +2249,29 @@ class GeoExprNameSpace:
             is_elementwise=True,
         ).pipe(lambda e: cast("GeoExpr", e))
 
+    @register_plugin()
+    def align_to_mrr(self) -> pl.Expr:
+        """Rotate each geometry so that its minimum rotated rectangle is axis-aligned.
+
+        Geometries are rotated around the center of their minimum rotated rectangle, so
+        that its longest side is parallel to the `x` axis. Returns a struct with the
+        rotated `geometry` and the applied rotation `angle`, in degrees.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0 0, 0 2)"])
+            >>> aligned = gdf.select(st.geom().st.align_to_mrr()).unnest("geometry")
+            >>> aligned.select(st.geom().st.to_wkt(), "angle")
+            shape: (1, 2)
+            ┌────────────────────────┬───────┐
+            │ geometry               ┆ angle │
+            │ ---                    ┆ ---   │
+            │ str                    ┆ f64   │
+            ╞════════════════════════╪═══════╡
+            │ LINESTRING (1 1, -1 1) ┆ 90.0  │
+            └────────────────────────┴───────┘
+        """
+        ...
+
     # Linestring operations
 
     @register_plugin()
@@ -879,7 +2279,32 @@ class GeoExprNameSpace:
         self,
         distance: IntoNumericExpr,
         normalized: bool = False,
-    ) -> GeoExpr: ...
+    ) -> GeoExpr:
+        """Return a point at the given distance along each linear geometry.
+
+        If `distance` is a list column, a list of points is returned for each geometry,
+            with one point per distance.
+
+        Args:
+            distance: Distance along the line, or list of distances.
+            normalized: If `True`, distances are fractions of the line length.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(
+            ...     {"geometry": ["LINESTRING (0 0, 2 0, 2 3)"], "stations": [[1.0, 3.0]]}
+            ... )
+            >>> gdf.select(st.geom().st.interpolate("stations").explode().st.to_wkt())
+            shape: (2, 1)
+            ┌─────────────┐
+            │ geometry    │
+            │ ---         │
+            │ str         │
+            ╞═════════════╡
+            │ POINT (1 0) │
+            │ POINT (2 1) │
+            └─────────────┘
+        """
+        ...
 
     @register_plugin()
     def project(
@@ -894,6 +2319,127 @@ class GeoExprNameSpace:
     @register_plugin()
     def shared_paths(self, other: IntoGeoExprColumn) -> GeoExpr: ...
 
+    @register_plugin()
+    def split(self, blade: IntoGeoExprColumn) -> GeoExpr:
+        """Split each geometry by a blade, returning a GeometryCollection of the pieces.
+
+        Lines can be split by points, lines or the boundary of polygons, and polygons
+            can be split by lines. Points of the blade only split lines they lie on.
+
+        Args:
+            blade: Geometry used to split.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))"])
+            >>> blade = st.from_wkt(pl.lit("LINESTRING (1 -1, 1 3)"))
+            >>> gdf.select(st.geom().st.split(blade).st.parts().list.len())
+            shape: (1, 1)
+            ┌──────────┐
+            │ geometry │
+            │ ---      │
+            │ u32      │
+            ╞══════════╡
+            │ 2        │
+            └──────────┘
+        """
+        ...
+
+    def points_along(
+        self,
+        interval: float | None = None,
+        *,
+        fractions: Sequence[float] | None = None,
+    ) -> pl.Expr:
+        """Return a list of points along each linear geometry or polygon boundary.
+
+        Points are either evenly spaced by `interval`, starting from the beginning of
+        each line, or placed at the given `fractions` of each line length.
+
+        Args:
+            interval: Distance between two consecutive points along the line.
+            fractions: Fractions of the line length, between 0 and 1, at which to
+                place the points.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0 0, 2 0, 2 3)"])
+            >>> gdf.select(st.geom().st.points_along(2).explode().st.to_wkt())
+            shape: (3, 1)
+            ┌─────────────┐
+            │ geometry    │
+            │ ---         │
+            │ str         │
+            ╞═════════════╡
+            │ POINT (0 0) │
+            │ POINT (2 0) │
+            │ POINT (2 2) │
+            └─────────────┘
+        """
+        if (interval is None) == (fractions is None):
+            msg = "either `interval` or `fractions` must be given"
+            raise ValueError(msg)
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="points_along",
+            args=[self._expr],
+            kwargs={
+                "interval": interval,
+                "fractions": None if fractions is None else list(fractions),
+            },
+            is_elementwise=True,
+        )
+
+    @register_plugin()
+    def transects(self, spacing: float, length: float) -> pl.Expr:
+        """Return a list of lines perpendicular to each linear geometry at regular intervals.
+
+        Args:
+            spacing: Distance between two consecutive transects along the line.
+            length: Length of each transect, centered on the line.
+        """
+        ...
+
+    @register_plugin()
+    def segments(self) -> pl.Expr:
+        """Return the list of two-point segments of each linear geometry or polygon boundary.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0 0, 1 0, 1 1)"])
+            >>> gdf.select(st.geom().st.segments().explode().st.to_wkt())
+            shape: (2, 1)
+            ┌───────────────────────┐
+            │ geometry              │
+            │ ---                   │
+            │ str                   │
+            ╞═══════════════════════╡
+            │ LINESTRING (0 0, 1 0) │
+            │ LINESTRING (1 0, 1 1) │
+            └───────────────────────┘
+        """
+        ...
+
+    @register_plugin()
+    def segment_azimuths(self) -> pl.Expr:
+        """Return the azimuth of each segment of each linear geometry or polygon boundary.
+
+        Azimuths are expressed in degrees, clockwise from the north (positive `y` axis),
+        in the range [0, 360). Zero-length segments have a NaN azimuth. The returned
+        list is aligned with the output of
+        [`segments`][polars_st.GeoExprNameSpace.segments].
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["LINESTRING (0 0, 0 1, 1 1, 1 0)"])
+            >>> gdf.select(st.geom().st.segment_azimuths())
+            shape: (1, 1)
+            ┌────────────────────┐
+            │ geometry           │
+            │ ---                │
+            │ list[f64]          │
+            ╞════════════════════╡
+            │ [0.0, 90.0, 180.0] │
+            └────────────────────┘
+        """
+        ...
+
     # Aggregations
 
     @register_plugin(is_aggregation=True)
@@ -902,8 +2448,61 @@ class GeoExprNameSpace:
         ...
 
     @register_plugin(is_aggregation=True)
-    def collect(self, into: GeometryType | None = None) -> GeoExpr:
-        """Aggregate geometries into a single collection."""
+    def collect(
+        self,
+        into: GeometryType | None = None,
+        mixed_dimensions: Literal["promote", "drop", "error"] = "promote",
+    ) -> GeoExpr:
+        """Aggregate geometries into a single collection.
+
+        Args:
+            into:
+                The type of the collection. By default, the most specific collection type
+                of the geometries is used.
+            mixed_dimensions:
+                How to handle geometries that don't all have the same Z and M dimensions.
+                With `"promote"`, the missing dimensions are added with values of 0. With
+                `"drop"`, the dimensions not shared by all geometries are dropped. With
+                `"error"`, an error is raised. Empty geometries are ignored.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame(["POINT Z (0 1 2)", "POINT (3 4)"])
+            >>> gdf.select(st.geom().st.collect().st.to_wkt()).item()
+            'MULTIPOINT Z ((0 1 2), (3 4 0))'
+            >>> gdf.select(st.geom().st.collect(mixed_dimensions="drop").st.to_wkt()).item()
+            'MULTIPOINT ((0 1), (3 4))'
+        """
+        ...
+
+    @register_plugin(is_aggregation=True)
+    def make_line(self, order_by: IntoExprColumn | None = None) -> GeoExpr:
+        """Connect the points of all geometries, in order, into a single LineString.
+
+        Points, MultiPoints and LineStrings are supported. The LineString is empty
+        if there are less than two points.
+
+        Args:
+            order_by: Column by which geometries are sorted before being connected,
+                such as a timestamp. By default, geometries are connected in row order.
+
+        Examples:
+            >>> df = st.GeoDataFrame({
+            ...     "track": [0, 0, 1, 1, 0],
+            ...     "time": [1, 2, 2, 1, 0],
+            ...     "geometry": [
+            ...         "POINT (1 1)",
+            ...         "POINT (2 2)",
+            ...         "POINT (5 5)",
+            ...         "POINT (4 4)",
+            ...         "POINT (0 0)",
+            ...     ],
+            ... })
+            >>> df = df.group_by("track", maintain_order=True).agg(
+            ...     st.geom().st.make_line(order_by="time")
+            ... )
+            >>> df.st.to_wkt().rows()
+            [(0, 'LINESTRING (0 0, 1 1, 2 2)'), (1, 'LINESTRING (4 4, 5 5)')]
+        """
         ...
 
     @register_plugin(is_aggregation=True)
@@ -934,21 +2533,81 @@ class GeoExprNameSpace:
     @register_plugin(is_aggregation=True)
     def polygonize(self) -> GeoExpr: ...
 
-    @register_plugin(is_aggregation=True)
     def voronoi_polygons(
         self,
         tolerance: float = 0.0,
         extend_to: bytes | None = None,
         only_edges: bool = False,
+        per_geometry: bool = False,
     ) -> GeoExpr:
-        """Return a Voronoi diagram of all geometries vertices."""
-        ...
+        """Return a Voronoi diagram of all geometries vertices.
+
+        Args:
+            tolerance: Snapping tolerance used to improve the robustness of the
+                diagram.
+            extend_to: WKB of a geometry whose envelope the diagram is extended to.
+            only_edges: Whether to return a MultiLineString of the edges instead of a
+                GeometryCollection of polygons.
+            per_geometry: Whether to compute the diagram of the vertices of each
+                geometry separately, instead of the vertices of all geometries together.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "MULTIPOINT ((0 0), (1 0))",
+            ...     "MULTIPOINT ((0 0), (1 0), (1 1), (0 1))",
+            ... ])
+            >>> polygons = st.geom().st.voronoi_polygons(per_geometry=True)
+            >>> gdf.select(polygons.st.count_geometries()).to_series().to_list()
+            [2, 4]
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="voronoi_polygons",
+            args=[self._expr],
+            kwargs={
+                "tolerance": tolerance,
+                "extend_to": extend_to,
+                "only_edges": only_edges,
+                "per_geometry": per_geometry,
+            },
+            is_elementwise=per_geometry,
+            returns_scalar=not per_geometry,
+        ).pipe(lambda e: cast("GeoExpr", e))
 
-    @register_plugin(is_aggregation=True)
     def delaunay_triangles(
         self,
         tolerance: float = 0.0,
         only_edges: bool = False,
+        per_geometry: bool = False,
     ) -> GeoExpr:
-        """Return a Delaunay triangulation of all geometries vertices."""
-        ...
+        """Return a Delaunay triangulation of all geometries vertices.
+
+        Args:
+            tolerance: Snapping tolerance used to improve the robustness of the
+                triangulation.
+            only_edges: Whether to return a MultiLineString of the edges instead of a
+                GeometryCollection of triangles.
+            per_geometry: Whether to triangulate the vertices of each geometry
+                separately, instead of the vertices of all geometries together.
+
+        Examples:
+            >>> gdf = st.GeoDataFrame([
+            ...     "MULTIPOINT ((0 0), (1 0), (0 1))",
+            ...     "MULTIPOINT ((0 0), (1 0), (1 1), (0 1))",
+            ... ])
+            >>> triangles = st.geom().st.delaunay_triangles(per_geometry=True)
+            >>> gdf.select(triangles.st.count_geometries()).to_series().to_list()
+            [1, 2]
+        """
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="delaunay_triangles",
+            args=[self._expr],
+            kwargs={
+                "tolerance": tolerance,
+                "only_edges": only_edges,
+                "per_geometry": per_geometry,
+            },
+            is_elementwise=per_geometry,
+            returns_scalar=not per_geometry,
+        ).pipe(lambda e: cast("GeoExpr", e))
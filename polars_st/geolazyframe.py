@@ -4,6 +4,7 @@ from pathlib import Path
 from typing import TYPE_CHECKING, Literal, cast
 
 import polars as pl
+import polars.selectors as cs
 from polars import Expr, LazyFrame
 from polars._utils.parse import parse_into_expression
 from polars.api import register_lazyframe_namespace
@@ -11,6 +12,7 @@ from polars.datatypes import N_INFER_DEFAULT
 from polars.plugins import register_plugin_function
 
 from polars_st.geodataframe import GeoDataFrame
+from polars_st.selectors import geom
 
 if TYPE_CHECKING:
     from polars._typing import (
@@ -77,8 +79,14 @@ class GeoLazyFrameNameSpace:
             "covers",
             "covered_by",
             "contains_properly",
+            "dwithin",
+            "relate",
         ] = "intersects",
         *,
+        distance: float | None = None,
+        pattern: str | None = None,
+        distance_col: str | None = None,
+        algorithm: Literal["rtree", "sweep"] = "rtree",
         left_on: str | Expr | None = None,
         right_on: str | Expr | None = None,
         suffix: str = "_right",
@@ -88,7 +96,19 @@ class GeoLazyFrameNameSpace:
         allow_parallel: bool = True,
         force_parallel: bool = False,
     ) -> LazyFrame:
-        """Perform a spatial join operation with another LazyFrame."""
+        """Perform a spatial join operation with another LazyFrame.
+
+        Candidate pairs are found by default with an R-tree over the smaller frame.
+        With `algorithm="sweep"`, they are found instead by sorting the bounding
+        boxes of both frames and sweeping through them, which needs less memory
+        for very large inputs: candidate pairs are evaluated by chunks as soon as
+        they are found, and no index is built.
+
+        With `predicate="relate"`, pairs are matched by a DE-9IM `pattern`, such as
+        `"T*F**F***"`, which is the `within` relationship.
+        Only pairs with intersecting bounding boxes are considered, so patterns
+        matching disjoint geometries won't match them.
+        """
         if not isinstance(other, LazyFrame):
             msg = f"expected `other` join table to be a LazyFrame, not a {type(other).__name__!r}"
             raise TypeError(msg)
@@ -97,6 +117,14 @@ class GeoLazyFrameNameSpace:
             msg = """Use of `how="cross" not supported on sjoin.`"""
             raise ValueError(msg)
 
+        if (predicate == "dwithin") != (distance is not None):
+            msg = "`distance` must be given if and only if `predicate` is 'dwithin'"
+            raise ValueError(msg)
+
+        if (predicate == "relate") != (pattern is not None):
+            msg = "`pattern` must be given if and only if `predicate` is 'relate'"
+            raise ValueError(msg)
+
         left_expr = left_on or on
         right_expr = right_on or on
 
@@ -107,11 +135,23 @@ class GeoLazyFrameNameSpace:
             msg = "spatial join expressions should not return multiple output"
             raise ValueError(msg)
 
+        left_expr = pl.col(left_expr) if isinstance(left_expr, str) else left_expr
+        right_expr = pl.col(right_expr) if isinstance(right_expr, str) else right_expr
+
+        index_columns = [
+            pl.nth(0).struct[0].alias("_sjoin_index_left"),
+            pl.nth(0).struct[1].alias("_sjoin_index_right"),
+        ]
+        if distance_col is not None:
+            index_columns.append(pl.nth(0).struct[2].alias("_sjoin_distance"))
+
+        # Each side is imploded into a single list so that the plugin sees the
+        # actual length of both frames, and can emit the unmatched rows itself.
         sjoin_index = (
             pl.concat(
                 [
-                    self._lf.select(_sjoin_geom_left=left_expr),
-                    other.select(_sjoin_geom_right=right_expr),
+                    self._lf.select(_sjoin_geom_left=left_expr.implode()),
+                    other.select(_sjoin_geom_right=right_expr.implode()),
                 ],
                 how="horizontal",
             )
@@ -120,39 +160,207 @@ class GeoLazyFrameNameSpace:
                     plugin_path=Path(__file__).parent,
                     function_name="sjoin",
                     args=["_sjoin_geom_left", "_sjoin_geom_right"],
-                    kwargs={"predicate": predicate},
-                    is_elementwise=True,
+                    kwargs={
+                        "predicate": predicate,
+                        "how": how,
+                        "distance": distance,
+                        "include_distance": distance_col is not None,
+                        "algorithm": algorithm,
+                        "pattern": pattern,
+                    },
+                    changes_length=True,
                 ),
             )
-            .select(
-                _sjoin_index_left=pl.nth(0).struct[0],
-                _sjoin_index_right=pl.nth(0).struct[1],
-            )
+            .select(index_columns)
         )
 
-        return (
-            sjoin_index.join(
-                self._lf.with_row_index("_sjoin_index_left"),
-                on="_sjoin_index_left",
-                how="full",
-                suffix=suffix,
-                coalesce=coalesce,
-                allow_parallel=allow_parallel,
-                force_parallel=force_parallel,
-            )
-            .join(
+        result = sjoin_index.join(
+            self._lf.with_row_index("_sjoin_index_left"),
+            on="_sjoin_index_left",
+            how="left",
+            suffix=suffix,
+            coalesce=coalesce,
+            allow_parallel=allow_parallel,
+            force_parallel=force_parallel,
+        )
+        if how not in {"semi", "anti"}:
+            result = result.join(
                 other.with_row_index("_sjoin_index_right"),
                 on="_sjoin_index_right",
-                how=how,
+                how="left",
+                suffix=suffix,
                 validate=validate,
                 nulls_equal=nulls_equal,
                 coalesce=coalesce,
                 allow_parallel=allow_parallel,
                 force_parallel=force_parallel,
             )
+        result = result.drop(cs.starts_with("_sjoin_index_"))
+        if distance_col is not None:
+            distance_expr = pl.col("_sjoin_distance").alias(distance_col)
+            result = result.select(pl.exclude("_sjoin_distance"), distance_expr)
+        return result
+
+    def conflate(
+        self,
+        other: LazyFrame,
+        max_distance: float,
+        on: str | Expr = "geometry",
+        *,
+        left_on: str | Expr | None = None,
+        right_on: str | Expr | None = None,
+        distance_weight: float = 1.0,
+        overlap_weight: float = 1.0,
+        similarity: Expr | None = None,
+        similarity_weight: float = 1.0,
+        min_score: float | None = None,
+        score_name: str = "match_score",
+        suffix: str = "_right",
+    ) -> LazyFrame:
+        """Match the features of two layers one-to-one based on their similarity.
+
+        Every pair of features within `max_distance` of each other is scored, and pairs
+        are then greedily assigned by decreasing score so that each feature of either
+        layer is part of at most one match. Pairs with the same score are assigned in
+        the order of the left, then right features. The score is the weighted mean of:
+
+        - a distance score, `1 - distance / max_distance`,
+        - an overlap score, the intersection over union of the features area,
+        - an optional `similarity` expression, evaluated on the joined candidate pairs.
+
+        Scores that are null for a pair, such as the overlap score when one of the
+        features has no area, are left out of its mean.
+
+        The greedy assignment doesn't maximize the total score of the matches, as an
+        optimal assignment would: a feature can take the best candidate of another one,
+        which is then matched to a worse candidate, or left unmatched.
+
+        Args:
+            other: LazyFrame to match features with.
+            max_distance: Maximum distance between two matched features.
+            on: Name of the geometry columns in both frames.
+            left_on: Name of the left geometry column.
+            right_on: Name of the right geometry column.
+            distance_weight: Weight of the distance score.
+            overlap_weight: Weight of the overlap score.
+            similarity: Expression returning a score between 0 and 1 for each candidate
+                pair, such as a name similarity. Columns from `other` with conflicting
+                names can be referred to with `suffix`.
+            similarity_weight: Weight of the similarity score.
+            min_score: Discard candidate pairs with a score lower than this value.
+            score_name: Name of the output score column.
+            suffix: Suffix to append to columns with a duplicate name.
+        """
+        if not isinstance(other, LazyFrame):
+            msg = f"expected `other` join table to be a LazyFrame, not a {type(other).__name__!r}"
+            raise TypeError(msg)
+
+        left_expr = left_on or on
+        right_expr = right_on or on
+
+        if (
+            parse_into_expression(left_expr).meta_has_multiple_outputs()
+            or parse_into_expression(right_expr).meta_has_multiple_outputs()
+        ):
+            msg = "spatial join expressions should not return multiple output"
+            raise ValueError(msg)
+
+        candidates = (
+            pl.concat(
+                [
+                    self._lf.select(_conflate_geom_left=left_expr),
+                    other.select(_conflate_geom_right=right_expr),
+                ],
+                how="horizontal",
+            )
+            .select(
+                register_plugin_function(
+                    plugin_path=Path(__file__).parent,
+                    function_name="conflation_candidates",
+                    args=["_conflate_geom_left", "_conflate_geom_right"],
+                    kwargs={"max_distance": max_distance},
+                    is_elementwise=True,
+                ),
+            )
+            .select(
+                _conflate_index_left=pl.nth(0).struct[0],
+                _conflate_index_right=pl.nth(0).struct[1],
+                _conflate_distance=pl.nth(0).struct[2],
+                _conflate_overlap=pl.nth(0).struct[3],
+            )
+        )
+
+        distance_score = (
+            1.0 - pl.col("_conflate_distance") / max_distance if max_distance > 0 else pl.lit(1.0)
+        )
+        scores = [
+            (distance_score, distance_weight),
+            (pl.col("_conflate_overlap"), overlap_weight),
+        ]
+        if similarity is not None:
+            scores.append((similarity.cast(pl.Float64), similarity_weight))
+        if sum(weight for _, weight in scores) <= 0:
+            msg = "the sum of the score weights should be strictly positive"
+            raise ValueError(msg)
+        score = pl.sum_horizontal(expr * weight for expr, weight in scores) / pl.sum_horizontal(
+            pl.when(expr.is_not_null()).then(weight) for expr, weight in scores
+        )
+
+        matches = (
+            candidates.join(
+                self._lf.with_row_index("_conflate_index_left"),
+                on="_conflate_index_left",
+            )
+            .join(
+                other.with_row_index("_conflate_index_right"),
+                on="_conflate_index_right",
+                suffix=suffix,
+            )
+            .with_columns(score.alias(score_name))
+        )
+        if min_score is not None:
+            matches = matches.filter(pl.col(score_name) >= min_score)
+
+        return (
+            matches.filter(
+                register_plugin_function(
+                    plugin_path=Path(__file__).parent,
+                    function_name="greedy_match",
+                    args=["_conflate_index_left", "_conflate_index_right", score_name],
+                ),
+            )
+            .sort("_conflate_index_left")
             .drop(
-                "_sjoin_index_left",
-                "_sjoin_index_right",
-                "_sjoin_index_left_right",
+                "_conflate_index_left",
+                "_conflate_index_right",
+                "_conflate_distance",
+                "_conflate_overlap",
             )
         )
+
+    def sink_geojsonl(self, path: str | Path, geometry_name: str = "geometry") -> None:
+        """Stream the LazyFrame to a newline-delimited GeoJSON (GeoJSONSeq) file.
+
+        Features are serialized and written batch by batch as they are produced by the
+        streaming engine, so that the serialized output is never held in memory. Columns
+        other than the geometry are written as feature properties.
+
+        Args:
+            path: File path to which the file should be written.
+            geometry_name: Name of the geometry column.
+
+        Examples:
+            >>> lf = st.GeoLazyFrame({
+            ...     "geometry": ["POINT(0 0)", "POINT(1 2)"],
+            ...     "name": ["Alice", "Bob"]
+            ... })
+            >>> lf.st.sink_geojsonl("points.geojsonl")  # doctest: +SKIP
+        """
+        columns = self._lf.collect_schema().names()
+        properties = pl.struct(cs.exclude(geometry_name)) if len(columns) > 1 else None
+        feature = geom(geometry_name).st.to_geojson_feature(properties)
+        self._lf.select(feature=feature).sink_csv(
+            path,
+            include_header=False,
+            quote_style="never",
+        )
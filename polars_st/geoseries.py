@@ -22,10 +22,12 @@ from polars_st.parsing import (
 )
 
 if TYPE_CHECKING:
-    from collections.abc import Sequence
+    from collections.abc import Callable, Sequence
 
     import altair as alt
     import geopandas as gpd
+    import numpy as np
+    import numpy.typing as npt
     from altair.vegalite.v5.schema._config import MarkConfigKwds
     from polars._typing import PolarsDataType
     from typing_extensions import Unpack
@@ -254,16 +256,75 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.area`][polars_st.GeoExprNameSpace.area]."""
         ...
 
+    @dispatch
+    def area_geodesic(self) -> pl.Series:
+        """See [`GeoExprNameSpace.area_geodesic`][polars_st.GeoExprNameSpace.area_geodesic]."""  # noqa: E501
+        ...
+
     @dispatch
     def bounds(self) -> pl.Series:
         """See [`GeoExprNameSpace.bounds`][polars_st.GeoExprNameSpace.bounds]."""
         ...
 
+    @dispatch
+    def bounds_3d(self) -> pl.Series:
+        """See [`GeoExprNameSpace.bounds_3d`][polars_st.GeoExprNameSpace.bounds_3d]."""
+        ...
+
+    @dispatch
+    def m_range(self) -> pl.Series:
+        """See [`GeoExprNameSpace.m_range`][polars_st.GeoExprNameSpace.m_range]."""
+        ...
+
+    @dispatch
+    def morton_index(
+        self,
+        extent: tuple[float, float, float, float] | None = None,
+        level: int = 16,
+    ) -> pl.Series:
+        """See [`GeoExprNameSpace.morton_index`][polars_st.GeoExprNameSpace.morton_index]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def spatial_partition(
+        self,
+        scheme: Literal["grid", "kdb"] = "grid",
+        n: int = 16,
+        extent: tuple[float, float, float, float] | None = None,
+    ) -> pl.Series:
+        """See [`GeoExprNameSpace.spatial_partition`][polars_st.GeoExprNameSpace.spatial_partition]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def to_h3(self, resolution: int) -> pl.Series:
+        """See [`GeoExprNameSpace.to_h3`][polars_st.GeoExprNameSpace.to_h3]."""
+        ...
+
+    @dispatch
+    def h3_cover(self, resolution: int) -> pl.Series:
+        """See [`GeoExprNameSpace.h3_cover`][polars_st.GeoExprNameSpace.h3_cover]."""
+        ...
+
+    @dispatch
+    def tile_of(self, zoom: int) -> pl.Series:
+        """See [`GeoExprNameSpace.tile_of`][polars_st.GeoExprNameSpace.tile_of]."""
+        ...
+
     @dispatch
     def length(self) -> pl.Series:
         """See [`GeoExprNameSpace.length`][polars_st.GeoExprNameSpace.length]."""
         ...
 
+    @dispatch
+    def length_geodesic(self) -> pl.Series:
+        """See [`GeoExprNameSpace.length_geodesic`][polars_st.GeoExprNameSpace.length_geodesic]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def length_3d(self) -> pl.Series:
+        """See [`GeoExprNameSpace.length_3d`][polars_st.GeoExprNameSpace.length_3d]."""
+        ...
+
     @dispatch
     def minimum_clearance(self) -> pl.Series:
         """See [`GeoExprNameSpace.minimum_clearance`][polars_st.GeoExprNameSpace.minimum_clearance]."""  # noqa: E501
@@ -344,6 +405,21 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.parts`][polars_st.GeoExprNameSpace.parts]."""
         ...
 
+    @dispatch
+    def dump_points(self) -> pl.Series:
+        """See [`GeoExprNameSpace.dump_points`][polars_st.GeoExprNameSpace.dump_points]."""
+        ...
+
+    @dispatch
+    def collection_extract(self, type: Literal["point", "line", "polygon"]) -> GeoSeries:  # noqa: A002
+        """See [`GeoExprNameSpace.collection_extract`][polars_st.GeoExprNameSpace.collection_extract]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def unnest_collection(self) -> GeoSeries:
+        """See [`GeoExprNameSpace.unnest_collection`][polars_st.GeoExprNameSpace.unnest_collection]."""  # noqa: E501
+        ...
+
     @dispatch
     def precision(self) -> pl.Series:
         """See [`GeoExprNameSpace.precision`][polars_st.GeoExprNameSpace.precision]."""
@@ -358,11 +434,26 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.set_precision`][polars_st.GeoExprNameSpace.set_precision]."""
         ...
 
+    @dispatch
+    def snap_to_grid(
+        self,
+        size_x: float,
+        size_y: float | None = None,
+        origin: Sequence[float] = (0.0, 0.0),
+    ) -> GeoSeries:
+        """See [`GeoExprNameSpace.snap_to_grid`][polars_st.GeoExprNameSpace.snap_to_grid]."""
+        ...
+
     @dispatch
     def distance(self, other: IntoGeoExprColumn) -> pl.Series:
         """See [`GeoExprNameSpace.distance`][polars_st.GeoExprNameSpace.distance]."""
         ...
 
+    @dispatch
+    def distance_3d(self, other: IntoGeoExprColumn) -> pl.Series:
+        """See [`GeoExprNameSpace.distance_3d`][polars_st.GeoExprNameSpace.distance_3d]."""
+        ...
+
     @dispatch
     def hausdorff_distance(
         self,
@@ -381,6 +472,59 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.frechet_distance`][polars_st.GeoExprNameSpace.frechet_distance]."""  # noqa: E501
         ...
 
+    @dispatch
+    def azimuth(self, other: IntoGeoExprColumn, degrees: bool = False) -> pl.Series:
+        """See [`GeoExprNameSpace.azimuth`][polars_st.GeoExprNameSpace.azimuth]."""
+        ...
+
+    @dispatch
+    def azimuth_geodesic(self, other: IntoGeoExprColumn, degrees: bool = False) -> pl.Series:
+        """See [`GeoExprNameSpace.azimuth_geodesic`][polars_st.GeoExprNameSpace.azimuth_geodesic]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def knn(self, other: IntoGeoExprColumn, k: int = 1) -> pl.Series:
+        """See [`GeoExprNameSpace.knn`][polars_st.GeoExprNameSpace.knn]."""
+        ...
+
+    @dispatch
+    def count_matches(
+        self,
+        other: IntoGeoExprColumn,
+        predicate: Literal[
+            "intersects_bbox",
+            "intersects",
+            "within",
+            "contains",
+            "overlaps",
+            "crosses",
+            "touches",
+            "covers",
+            "covered_by",
+            "contains_properly",
+            "dwithin",
+        ] = "intersects",
+        *,
+        distance: float | None = None,
+    ) -> pl.Series:
+        """See [`GeoExprNameSpace.count_matches`][polars_st.GeoExprNameSpace.count_matches]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def pairs_within(self, distance: float) -> pl.Series:
+        """See [`GeoExprNameSpace.pairs_within`][polars_st.GeoExprNameSpace.pairs_within]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def is_duplicate_geometry(self, tolerance: float | None = None) -> pl.Series:
+        """See [`GeoExprNameSpace.is_duplicate_geometry`][polars_st.GeoExprNameSpace.is_duplicate_geometry]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def unique_geometries(self, tolerance: float | None = None) -> GeoSeries:
+        """See [`GeoExprNameSpace.unique_geometries`][polars_st.GeoExprNameSpace.unique_geometries]."""  # noqa: E501
+        ...
+
     # Projection operations
 
     @dispatch
@@ -388,16 +532,55 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.srid`][polars_st.GeoExprNameSpace.srid]."""
         ...
 
+    @dispatch
+    def crs_info(self) -> pl.Series:
+        """See [`GeoExprNameSpace.crs_info`][polars_st.GeoExprNameSpace.crs_info]."""
+        ...
+
     @dispatch
     def set_srid(self, srid: IntoIntegerExpr) -> GeoSeries:
         """See [`GeoExprNameSpace.set_srid`][polars_st.GeoExprNameSpace.set_srid]."""
         ...
 
     @dispatch
-    def to_srid(self, srid: IntoIntegerExpr) -> GeoSeries:
+    def to_srid(
+        self,
+        srid: IntoIntegerExpr,
+        pipeline: str | None = None,
+        antimeridian: Literal["ignore", "shift", "split"] = "ignore",
+    ) -> GeoSeries:
         """See [`GeoExprNameSpace.to_srid`][polars_st.GeoExprNameSpace.to_srid]."""
         ...
 
+    @dispatch
+    def bounds_to_srid(self, srid: IntoIntegerExpr, densify_points: int = 21) -> pl.Series:
+        """See [`GeoExprNameSpace.bounds_to_srid`][polars_st.GeoExprNameSpace.bounds_to_srid]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def transform(self, from_crs: str, to_crs: str) -> GeoSeries:
+        """See [`GeoExprNameSpace.transform`][polars_st.GeoExprNameSpace.transform]."""
+        ...
+
+    @dispatch
+    def transform_with(
+        self,
+        func: Callable[[npt.NDArray[np.float64]], npt.ArrayLike],
+        include_z: bool = False,
+    ) -> GeoSeries:
+        """See [`GeoExprNameSpace.transform_with`][polars_st.GeoExprNameSpace.transform_with]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def utm_zone(self) -> pl.Series:
+        """See [`GeoExprNameSpace.utm_zone`][polars_st.GeoExprNameSpace.utm_zone]."""
+        ...
+
+    @dispatch
+    def to_utm(self, by: Literal["geometry", "frame"] = "geometry") -> GeoSeries:
+        """See [`GeoExprNameSpace.to_utm`][polars_st.GeoExprNameSpace.to_utm]."""
+        ...
+
     # Serialization
 
     @dispatch
@@ -428,15 +611,69 @@ class GeoSeriesNameSpace:
         output_dimension: Literal[2, 3, 4] = 3,
         byte_order: Literal[0, 1] | None = None,
         include_srid: bool = False,
+        flavor: Literal["extended", "iso"] = "extended",
     ) -> pl.Series:
         """See [`GeoExprNameSpace.to_wkb`][polars_st.GeoExprNameSpace.to_wkb]."""
         ...
 
+    @dispatch
+    def to_ewkb_hex(self) -> pl.Series:
+        """See [`GeoExprNameSpace.to_ewkb_hex`][polars_st.GeoExprNameSpace.to_ewkb_hex]."""
+        ...
+
     @dispatch
     def to_geojson(self, indent: int | None = None) -> pl.Series:
         """See [`GeoExprNameSpace.to_geojson`][polars_st.GeoExprNameSpace.to_geojson]."""
         ...
 
+    @dispatch
+    def to_geojson_feature(
+        self,
+        properties: IntoExprColumn | None = None,
+        feature_id: IntoExprColumn | None = None,
+    ) -> pl.Series:
+        """See [`GeoExprNameSpace.to_geojson_feature`][polars_st.GeoExprNameSpace.to_geojson_feature]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def to_esrijson(self, indent: int | None = None) -> pl.Series:
+        """See [`GeoExprNameSpace.to_esrijson`][polars_st.GeoExprNameSpace.to_esrijson]."""
+        ...
+
+    @dispatch
+    def to_kml(self) -> pl.Series:
+        """See [`GeoExprNameSpace.to_kml`][polars_st.GeoExprNameSpace.to_kml]."""
+        ...
+
+    @dispatch
+    def to_gml(self) -> pl.Series:
+        """See [`GeoExprNameSpace.to_gml`][polars_st.GeoExprNameSpace.to_gml]."""
+        ...
+
+    @dispatch
+    def to_gpkg(self) -> pl.Series:
+        """See [`GeoExprNameSpace.to_gpkg`][polars_st.GeoExprNameSpace.to_gpkg]."""
+        ...
+
+    @dispatch
+    def to_spatialite(self) -> pl.Series:
+        """See [`GeoExprNameSpace.to_spatialite`][polars_st.GeoExprNameSpace.to_spatialite]."""
+        ...
+
+    @dispatch
+    def to_svg(self, view_box: bool = False, flip_y: bool = True) -> pl.Series:
+        """See [`GeoExprNameSpace.to_svg`][polars_st.GeoExprNameSpace.to_svg]."""
+        ...
+
+    @dispatch
+    def to_geoarrow(
+        self,
+        geometry_type: GeometryType,
+        dimensions: Literal["xy", "xyz", "xym", "xyzm"] = "xy",
+    ) -> pl.Series:
+        """See [`GeoExprNameSpace.to_geoarrow`][polars_st.GeoExprNameSpace.to_geoarrow]."""
+        ...
+
     @dispatch
     def to_shapely(self) -> pl.Series:
         """See [`GeoExprNameSpace.to_shapely`][polars_st.GeoExprNameSpace.to_shapely]."""
@@ -495,6 +732,11 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.has_m`][polars_st.GeoExprNameSpace.has_m]."""
         ...
 
+    @dispatch
+    def has_curves(self) -> pl.Series:
+        """See [`GeoExprNameSpace.has_curves`][polars_st.GeoExprNameSpace.has_curves]."""
+        ...
+
     @dispatch
     def is_ccw(self) -> pl.Series:
         """See [`GeoExprNameSpace.is_ccw`][polars_st.GeoExprNameSpace.is_ccw]."""
@@ -530,6 +772,21 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.is_valid_reason`][polars_st.GeoExprNameSpace.is_valid_reason]."""
         ...
 
+    @dispatch
+    def is_valid_detail(self) -> pl.Series:
+        """See [`GeoExprNameSpace.is_valid_detail`][polars_st.GeoExprNameSpace.is_valid_detail]."""
+        ...
+
+    @dispatch
+    def wkb_is_parseable(self) -> pl.Series:
+        """See [`GeoExprNameSpace.wkb_is_parseable`][polars_st.GeoExprNameSpace.wkb_is_parseable]."""
+        ...
+
+    @dispatch
+    def wkb_parse_error(self) -> pl.Series:
+        """See [`GeoExprNameSpace.wkb_parse_error`][polars_st.GeoExprNameSpace.wkb_parse_error]."""
+        ...
+
     # Binary predicates
 
     @dispatch
@@ -688,6 +945,33 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.buffer`][polars_st.GeoExprNameSpace.buffer]."""
         ...
 
+    @dispatch
+    def buffer_geodesic(
+        self,
+        distance_m: IntoNumericExpr,
+        quad_segs: int = 8,
+        cap_style: Literal["round", "square", "flat"] = "round",
+        join_style: Literal["round", "mitre", "bevel"] = "round",
+        mitre_limit: float = 5.0,
+        single_sided: bool = False,
+        antimeridian: Literal["ignore", "shift", "split"] = "split",
+    ) -> GeoSeries:
+        """See [`GeoExprNameSpace.buffer_geodesic`][polars_st.GeoExprNameSpace.buffer_geodesic]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def buffer_rings(
+        self,
+        distances: Sequence[float],
+        quad_segs: int = 8,
+        cap_style: Literal["round", "square", "flat"] = "round",
+        join_style: Literal["round", "mitre", "bevel"] = "round",
+        mitre_limit: float = 5.0,
+        dissolve: bool = False,
+    ) -> pl.Series:
+        """See [`GeoExprNameSpace.buffer_rings`][polars_st.GeoExprNameSpace.buffer_rings]."""
+        ...
+
     @dispatch
     def offset_curve(
         self,
@@ -720,6 +1004,11 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.clip_by_rect`][polars_st.GeoExprNameSpace.clip_by_rect]."""
         ...
 
+    @dispatch
+    def subdivide(self, max_vertices: int = 256) -> pl.Series:
+        """See [`GeoExprNameSpace.subdivide`][polars_st.GeoExprNameSpace.subdivide]."""
+        ...
+
     @dispatch
     def convex_hull(self) -> GeoSeries:
         """See [`GeoExprNameSpace.convex_hull`][polars_st.GeoExprNameSpace.convex_hull]."""
@@ -735,6 +1024,25 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.segmentize`][polars_st.GeoExprNameSpace.segmentize]."""
         ...
 
+    @dispatch
+    def segmentize_geodesic(
+        self,
+        max_length_m: IntoNumericExpr,
+        antimeridian: Literal["ignore", "shift", "split"] = "shift",
+    ) -> GeoSeries:
+        """See [`GeoExprNameSpace.segmentize_geodesic`][polars_st.GeoExprNameSpace.segmentize_geodesic]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def curve_to_linear(self, tolerance: IntoNumericExpr) -> GeoSeries:
+        """See [`GeoExprNameSpace.curve_to_linear`][polars_st.GeoExprNameSpace.curve_to_linear]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def linear_to_curve(self, tolerance: IntoNumericExpr) -> GeoSeries:
+        """See [`GeoExprNameSpace.linear_to_curve`][polars_st.GeoExprNameSpace.linear_to_curve]."""  # noqa: E501
+        ...
+
     @dispatch
     def envelope(self) -> GeoSeries:
         """See [`GeoExprNameSpace.envelope`][polars_st.GeoExprNameSpace.envelope]."""
@@ -751,7 +1059,11 @@ class GeoSeriesNameSpace:
         ...
 
     @dispatch
-    def make_valid(self) -> GeoSeries:
+    def make_valid(
+        self,
+        method: Literal["linework", "structure"] = "linework",
+        keep_collapsed: bool = True,
+    ) -> GeoSeries:
         """See [`GeoExprNameSpace.make_valid`][polars_st.GeoExprNameSpace.make_valid]."""
         ...
 
@@ -770,6 +1082,11 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.point_on_surface`][polars_st.GeoExprNameSpace.point_on_surface]."""  # noqa: E501
         ...
 
+    @dispatch
+    def polylabel(self, tolerance: IntoNumericExpr = 1.0) -> GeoSeries:
+        """See [`GeoExprNameSpace.polylabel`][polars_st.GeoExprNameSpace.polylabel]."""
+        ...
+
     @dispatch
     def remove_repeated_points(self, tolerance: IntoNumericExpr = 0.0) -> GeoSeries:
         """See [`GeoExprNameSpace.remove_repeated_points`][polars_st.GeoExprNameSpace.remove_repeated_points]."""  # noqa: E501
@@ -789,6 +1106,16 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.simplify`][polars_st.GeoExprNameSpace.simplify]."""
         ...
 
+    @dispatch
+    def simplify_vw(self, area_tolerance: IntoNumericExpr) -> GeoSeries:
+        """See [`GeoExprNameSpace.simplify_vw`][polars_st.GeoExprNameSpace.simplify_vw]."""
+        ...
+
+    @dispatch
+    def simplify_coverage(self, tolerance: float, preserve_boundary: bool = False) -> GeoSeries:
+        """See [`GeoExprNameSpace.simplify_coverage`][polars_st.GeoExprNameSpace.simplify_coverage]."""  # noqa: E501
+        ...
+
     @dispatch
     def force_2d(self) -> GeoSeries:
         """See [`GeoExprNameSpace.force_2d`][polars_st.GeoExprNameSpace.force_2d]."""
@@ -799,6 +1126,11 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.force_3d`][polars_st.GeoExprNameSpace.force_3d]."""
         ...
 
+    @dispatch
+    def force_4d(self, z: IntoNumericExpr = 0.0, m: IntoNumericExpr = 0.0) -> GeoSeries:
+        """See [`GeoExprNameSpace.force_4d`][polars_st.GeoExprNameSpace.force_4d]."""
+        ...
+
     @dispatch
     def flip_coordinates(self) -> GeoSeries:
         """See [`GeoExprNameSpace.flip_coordinates`][polars_st.GeoExprNameSpace.flip_coordinates]."""  # noqa: E501
@@ -823,6 +1155,16 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.shortest_line`][polars_st.GeoExprNameSpace.shortest_line]."""
         ...
 
+    @dispatch
+    def longest_line(self, other: IntoGeoExprColumn) -> GeoSeries:
+        """See [`GeoExprNameSpace.longest_line`][polars_st.GeoExprNameSpace.longest_line]."""
+        ...
+
+    @dispatch
+    def closest_point(self, other: IntoGeoExprColumn) -> GeoSeries:
+        """See [`GeoExprNameSpace.closest_point`][polars_st.GeoExprNameSpace.closest_point]."""
+        ...
+
     # Affine transforms
 
     @dispatch
@@ -871,6 +1213,11 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.skew`][polars_st.GeoExprNameSpace.skew]."""
         ...
 
+    @dispatch
+    def align_to_mrr(self) -> pl.Series:
+        """See [`GeoExprNameSpace.align_to_mrr`][polars_st.GeoExprNameSpace.align_to_mrr]."""
+        ...
+
     # LineString operations
 
     @dispatch
@@ -904,6 +1251,36 @@ class GeoSeriesNameSpace:
         """See [`GeoExprNameSpace.shared_paths`][polars_st.GeoExprNameSpace.shared_paths]."""
         ...
 
+    @dispatch
+    def split(self, blade: IntoGeoExprColumn) -> GeoSeries:
+        """See [`GeoExprNameSpace.split`][polars_st.GeoExprNameSpace.split]."""
+        ...
+
+    @dispatch
+    def points_along(
+        self,
+        interval: float | None = None,
+        *,
+        fractions: Sequence[float] | None = None,
+    ) -> pl.Series:
+        """See [`GeoExprNameSpace.points_along`][polars_st.GeoExprNameSpace.points_along]."""  # noqa: E501
+        ...
+
+    @dispatch
+    def transects(self, spacing: float, length: float) -> pl.Series:
+        """See [`GeoExprNameSpace.transects`][polars_st.GeoExprNameSpace.transects]."""
+        ...
+
+    @dispatch
+    def segments(self) -> pl.Series:
+        """See [`GeoExprNameSpace.segments`][polars_st.GeoExprNameSpace.segments]."""
+        ...
+
+    @dispatch
+    def segment_azimuths(self) -> pl.Series:
+        """See [`GeoExprNameSpace.segment_azimuths`][polars_st.GeoExprNameSpace.segment_azimuths]."""  # noqa: E501
+        ...
+
     # Aggregations
 
     @dispatch
@@ -912,10 +1289,19 @@ class GeoSeriesNameSpace:
         ...
 
     @dispatch
-    def collect(self, into: GeometryType | None = None) -> GeoSeries:
+    def collect(
+        self,
+        into: GeometryType | None = None,
+        mixed_dimensions: Literal["promote", "drop", "error"] = "promote",
+    ) -> GeoSeries:
         """See [`GeoExprNameSpace.collect`][polars_st.GeoExprNameSpace.collect]."""
         ...
 
+    @dispatch
+    def make_line(self, order_by: IntoExprColumn | None = None) -> GeoSeries:
+        """See [`GeoExprNameSpace.make_line`][polars_st.GeoExprNameSpace.make_line]."""
+        ...
+
     @dispatch
     def union_all(self, grid_size: float | None = None) -> GeoSeries:
         """See [`GeoExprNameSpace.union_all`][polars_st.GeoExprNameSpace.union_all]."""
@@ -952,6 +1338,7 @@ class GeoSeriesNameSpace:
         tolerance: float = 0.0,
         extend_to: bytes | None = None,
         only_edges: bool = False,
+        per_geometry: bool = False,
     ) -> GeoSeries:
         """See [`GeoExprNameSpace.voronoi_polygons`][polars_st.GeoExprNameSpace.voronoi_polygons]."""  # noqa: E501
         ...
@@ -961,6 +1348,7 @@ class GeoSeriesNameSpace:
         self,
         tolerance: float = 0.0,
         only_edges: bool = False,
+        per_geometry: bool = False,
     ) -> GeoSeries:
         """See [`GeoExprNameSpace.delaunay_triangles`][polars_st.GeoExprNameSpace.delaunay_triangles]."""  # noqa: E501
         ...
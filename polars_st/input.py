@@ -1,29 +1,50 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING, cast, overload
+import json
+import math
+from typing import TYPE_CHECKING, Any, Literal, cast, overload
 
 import polars as pl
 from polars.dependencies import pandas as pd
 from pyogrio import read_arrow
 
+from polars_st import _lib
 from polars_st.casting import st
+from polars_st.parsing import from_geojson, from_gpkg, polygon, rectangle
 from polars_st.selectors import geom
+from polars_st.utils.geoarrow import from_geoarrow_table, get_crs_srid
 from polars_st.utils.srid import get_crs_srid_or_warn
 
 if TYPE_CHECKING:
-    from collections.abc import Sequence
+    from collections.abc import Iterator, Sequence
     from pathlib import Path
+    from typing import IO
 
     import geopandas as gpd
+    import pyarrow as pa
     from polars._typing import SchemaDict
 
     from polars_st.geodataframe import GeoDataFrame
+    from polars_st.geolazyframe import GeoLazyFrame
     from polars_st.geoseries import GeoSeries
 
 
 __all__ = [
     "from_geopandas",
+    "make_grid",
+    "make_hex_grid",
     "read_file",
+    "read_flatgeobuf",
+    "read_geoarrow_ipc",
+    "read_geojsonseq",
+    "read_geopackage",
+    "read_gpx",
+    "read_geoparquet",
+    "read_parquet",
+    "scan_flatgeobuf",
+    "scan_geojsonseq",
+    "scan_geopackage",
+    "scan_geoparquet",
 ]
 
 
@@ -137,6 +158,11 @@ def read_file(
         return_fids=return_fids,
     )
 
+    return st(from_ogr_table(table, metadata))._df  # noqa: SLF001
+
+
+def from_ogr_table(table: pa.Table, metadata: dict[str, Any]) -> pl.DataFrame:
+    """Convert an Arrow table read by `pyogrio` into a DataFrame of geometries."""
     import pyarrow as pa
 
     geometry_name = metadata["geometry_name"] or "wkb_geometry"
@@ -150,7 +176,464 @@ def read_file(
             res = res.rename({"wkb_geometry": "geometry"})
     else:
         res = cast("pl.DataFrame", pl.from_arrow(table))
-    return st(res)._df  # noqa: SLF001
+    return res
+
+
+def get_io_source_columns(with_columns: list[str], predicate: pl.Expr | None) -> list[str]:
+    """Return the columns an IO source must read to project and filter its batches."""
+    columns = list(with_columns)
+    if predicate is not None:
+        columns += [name for name in predicate.meta.root_names() if name not in columns]
+    return columns
+
+
+def apply_io_source_options(
+    batches: Iterator[pl.DataFrame],
+    with_columns: list[str] | None,
+    predicate: pl.Expr | None,
+    n_rows: int | None,
+) -> Iterator[pl.DataFrame]:
+    """Apply the predicate, projection and row limit requested from an IO source to batches."""
+    for batch in batches:
+        df = batch if predicate is None else batch.filter(predicate)
+        if with_columns is not None:
+            df = df.select(with_columns)
+        if n_rows is not None:
+            df = df.head(n_rows)
+            n_rows -= len(df)
+        yield df
+        if n_rows == 0:
+            return
+
+
+def scan_flatgeobuf(
+    path: Path | str,
+    /,
+    bbox: tuple[float, float, float, float] | None = None,
+) -> GeoLazyFrame:
+    """Lazily read a FlatGeobuf file into a GeoLazyFrame.
+
+    Files are read natively, without GDAL. Features are streamed in batches, and only
+    the columns and rows needed by the query are deserialized. When `bbox` is given and
+    the file has a spatial index, its packed Hilbert R-tree is searched so that only the
+    features intersecting the box are read. Features are returned in file order.
+
+    Args:
+        path: Path to a FlatGeobuf file.
+        bbox:
+            If present, only features whose bounding box intersects this box, given as
+            `(xmin, ymin, xmax, ymax)` in the CRS of the dataset, are returned.
+    """
+    from polars.io.plugins import register_io_source
+
+    path = str(path)
+    schema = _lib.read_flatgeobuf(path, pl.Series("offset", [], pl.UInt64)).schema
+
+    def source_generator(
+        with_columns: list[str] | None,
+        predicate: pl.Expr | None,
+        n_rows: int | None,
+        batch_size: int | None,
+    ) -> Iterator[pl.DataFrame]:
+        names = None if with_columns is None else get_io_source_columns(with_columns, predicate)
+        read_geometry = names is None or "geometry" in names
+        columns = None if names is None else [name for name in names if name != "geometry"]
+        offsets = _lib.flatgeobuf_offsets(path, bbox)
+        if n_rows is not None and predicate is None:
+            offsets = offsets.head(n_rows)
+        batch_size = batch_size or 65_536
+        batches = (
+            _lib.read_flatgeobuf(path, offsets.slice(start, batch_size), columns, read_geometry)
+            for start in range(0, len(offsets), batch_size)
+        )
+        yield from apply_io_source_options(batches, with_columns, predicate, n_rows)
+
+    return cast("GeoLazyFrame", register_io_source(source_generator, schema=schema))
+
+
+def read_flatgeobuf(
+    path: Path | str,
+    /,
+    columns: Sequence[str] | None = None,
+    bbox: tuple[float, float, float, float] | None = None,
+) -> GeoDataFrame:
+    """Read a FlatGeobuf file into a GeoDataFrame.
+
+    Args:
+        path: Path to a FlatGeobuf file.
+        columns: List of column names to read. Defaults to all columns.
+        bbox:
+            If present, only features whose bounding box intersects this box are read,
+            using the spatial index of the file when available.
+    """
+    lf = scan_flatgeobuf(path, bbox=bbox)
+    if columns is not None:
+        lf = lf.select(columns)
+    return st(lf.collect())._df  # noqa: SLF001
+
+
+def read_parquet(
+    source: str | Path | IO[bytes] | bytes,
+    /,
+    columns: Sequence[str] | None = None,
+) -> GeoDataFrame:
+    """Read a Parquet file with native `GEOMETRY` or `GEOGRAPHY` columns into a GeoDataFrame.
+
+    Geometry columns are detected from their Parquet logical type, and their CRS is
+    converted to an SRID when possible.
+
+    Args:
+        source: Path to a file, or a file-like object.
+        columns: List of column names to read. Defaults to all columns.
+    """
+    import pyarrow.parquet as pq
+
+    if isinstance(source, bytes):
+        import pyarrow as pa
+
+        source = pa.BufferReader(source)
+
+    table = pq.read_table(source, columns=columns, arrow_extensions_enabled=True)
+    return st(from_geoarrow_table(table))._df  # noqa: SLF001
+
+
+def read_geoarrow_ipc(
+    source: str | Path | IO[bytes] | bytes,
+    /,
+    columns: Sequence[str] | None = None,
+) -> GeoDataFrame:
+    """Read an Arrow IPC (Feather) file with GeoArrow columns into a GeoDataFrame.
+
+    Both `geoarrow.wkb` and native GeoArrow encoded columns are converted to geometries,
+    and their CRS is converted to an SRID when possible.
+
+    Args:
+        source: Path to a file, or a file-like object.
+        columns: List of column names to read. Defaults to all columns.
+    """
+    import pyarrow.feather as feather
+
+    if isinstance(source, bytes):
+        import pyarrow as pa
+
+        source = pa.BufferReader(source)
+
+    table = feather.read_table(source, columns=columns)
+    return st(from_geoarrow_table(table))._df  # noqa: SLF001
+
+
+def get_geoparquet_srid(column: dict) -> int | None:
+    """Return the SRID of a GeoParquet column, which defaults to `OGC:CRS84` if missing."""
+    if "crs" not in column:
+        return 4326
+    match column["crs"]:
+        case {"id": {"authority": "OGC", "code": "CRS84"}}:
+            return 4326
+        case crs:
+            return get_crs_srid(crs)
+
+
+def scan_geoparquet(
+    source: str | Path,
+    /,
+    columns: Sequence[str] | None = None,
+    bbox: tuple[float, float, float, float] | None = None,
+) -> GeoLazyFrame:
+    """Lazily read a GeoParquet file into a GeoLazyFrame.
+
+    Geometry columns are detected from the GeoParquet `geo` metadata, and their CRS is
+    converted to an SRID when possible. Only the `WKB` geometry encoding is supported.
+
+    Args:
+        source: Path to a file.
+        columns: List of column names to read. Defaults to all columns.
+        bbox:
+            If present, only rows whose primary geometry intersects this box, given as
+            `(xmin, ymin, xmax, ymax)` in the CRS of the dataset, are returned. When the
+            file has a bbox covering column, the filter on its values is pushed down to
+            the scan, so that row groups whose statistics fall outside the box are
+            skipped without being read.
+    """
+    import pyarrow.parquet as pq
+
+    schema_metadata = pq.read_schema(source).metadata or {}
+    if b"geo" not in schema_metadata:
+        msg = f"{source} is not a GeoParquet file: missing `geo` metadata"
+        raise ValueError(msg)
+    metadata = json.loads(schema_metadata[b"geo"])
+    geometry_columns: dict[str, dict] = metadata["columns"]
+    for name, column in geometry_columns.items():
+        if (encoding := column.get("encoding", "WKB")).upper() != "WKB":
+            msg = f"Unsupported encoding {encoding!r} for GeoParquet column {name!r}"
+            raise NotImplementedError(msg)
+
+    lf = pl.scan_parquet(source)
+    if bbox is not None:
+        xmin, ymin, xmax, ymax = bbox
+        primary_column = metadata["primary_column"]
+        predicate = geom(primary_column).st.intersects(
+            rectangle(pl.lit(xmin), pl.lit(ymin), pl.lit(xmax), pl.lit(ymax)),
+        )
+        if covering := geometry_columns[primary_column].get("covering", {}).get("bbox"):
+
+            def covering_field(key: str) -> pl.Expr:
+                column, *fields = covering[key]
+                expr = pl.col(column)
+                for field in fields:
+                    expr = expr.struct.field(field)
+                return expr
+
+            predicate = (
+                (covering_field("xmin") <= xmax)
+                & (covering_field("ymin") <= ymax)
+                & (covering_field("xmax") >= xmin)
+                & (covering_field("ymax") >= ymin)
+                & predicate
+            )
+        lf = lf.filter(predicate)
+    if columns is not None:
+        lf = lf.select(columns)
+
+    return cast(
+        "GeoLazyFrame",
+        lf.with_columns(
+            geom(name).st.set_srid(srid)
+            for name, column in geometry_columns.items()
+            if (columns is None or name in columns)
+            and (srid := get_geoparquet_srid(column)) is not None
+        ),
+    )
+
+
+def read_geoparquet(
+    source: str | Path,
+    /,
+    columns: Sequence[str] | None = None,
+    bbox: tuple[float, float, float, float] | None = None,
+) -> GeoDataFrame:
+    """Read a GeoParquet file into a GeoDataFrame.
+
+    See [`scan_geoparquet`][polars_st.scan_geoparquet] for the description of the arguments.
+    """
+    df = scan_geoparquet(source, columns=columns, bbox=bbox).collect()
+    return st(df)._df  # noqa: SLF001
+
+
+def scan_geojsonseq(
+    path: Path | str,
+    /,
+    infer_schema_length: int | None = 100,
+) -> GeoLazyFrame:
+    """Lazily read a newline-delimited GeoJSON (GeoJSONSeq) file into a GeoLazyFrame.
+
+    Features are read in batches of lines, so that files larger than memory can be
+    processed in streaming queries. Feature properties become columns, and the feature
+    geometry is parsed into the `geometry` column. Lines may be prefixed by the RS
+    character, as in RFC 8142.
+
+    Args:
+        path: Path to a GeoJSONSeq file.
+        infer_schema_length:
+            The number of features used to infer the properties schema. If set to
+            `None`, the whole file is read to infer the schema.
+    """
+    from itertools import islice
+    from pathlib import Path
+
+    from polars.io.plugins import register_io_source
+
+    def read_lines(file: IO[str]) -> Iterator[str]:
+        return (line for line in (line.strip().lstrip("\x1e") for line in file) if line)
+
+    def read_features(lines: list[str], properties: pl.Struct | None) -> pl.DataFrame:
+        features = pl.DataFrame({"feature": lines}, schema={"feature": pl.String()})
+        geometry = from_geojson(pl.col("feature").str.json_path_match("$.geometry"))
+        if properties is None:
+            return features.select(geometry=geometry)
+        return features.select(
+            properties=pl.col("feature").str.json_path_match("$.properties").str.json_decode(
+                properties,
+            ),
+            geometry=geometry,
+        ).unnest("properties")
+
+    with Path(path).open(encoding="utf-8") as file:
+        sample = pl.Series(list(islice(read_lines(file), infer_schema_length)), dtype=pl.String())
+    properties = sample.str.json_path_match("$.properties").drop_nulls()
+    properties_dtype = (
+        properties.str.json_decode(infer_schema_length=None).dtype if len(properties) else None
+    )
+    if not isinstance(properties_dtype, pl.Struct) or not properties_dtype.fields:
+        properties_dtype = None
+    schema = read_features([], properties_dtype).schema
+
+    def source_generator(
+        with_columns: list[str] | None,
+        predicate: pl.Expr | None,
+        n_rows: int | None,
+        batch_size: int | None,
+    ) -> Iterator[pl.DataFrame]:
+        with Path(path).open(encoding="utf-8") as file:
+            lines = read_lines(file)
+            batches = (
+                read_features(batch, properties_dtype)
+                for batch in iter(lambda: list(islice(lines, batch_size or 65_536)), [])
+            )
+            yield from apply_io_source_options(batches, with_columns, predicate, n_rows)
+
+    return cast("GeoLazyFrame", register_io_source(source_generator, schema=schema))
+
+
+def read_geojsonseq(
+    path: Path | str,
+    /,
+    infer_schema_length: int | None = 100,
+) -> GeoDataFrame:
+    """Read a newline-delimited GeoJSON (GeoJSONSeq) file into a GeoDataFrame.
+
+    See [`scan_geojsonseq`][polars_st.scan_geojsonseq] for the description of the arguments.
+    """
+    df = scan_geojsonseq(path, infer_schema_length=infer_schema_length).collect()
+    return st(df)._df  # noqa: SLF001
+
+
+GPKG_DTYPES: dict[str, pl.DataType] = {
+    "BOOLEAN": pl.Boolean(),
+    "TINYINT": pl.Int8(),
+    "SMALLINT": pl.Int16(),
+    "MEDIUMINT": pl.Int32(),
+    "INT": pl.Int64(),
+    "INTEGER": pl.Int64(),
+    "FLOAT": pl.Float32(),
+    "DOUBLE": pl.Float64(),
+    "REAL": pl.Float64(),
+    "BLOB": pl.Binary(),
+}
+
+
+def scan_geopackage(path: Path | str, /, layer: str | None = None) -> GeoLazyFrame:
+    """Lazily read a GeoPackage layer into a GeoLazyFrame.
+
+    Rows are streamed from the underlying SQLite database in batches, and only the
+    columns needed by the query are read. Geometries are decoded from the GeoPackage
+    binary format, and their SRID is set from the layer spatial reference system when
+    it is defined by the EPSG.
+
+    Args:
+        path: Path to a GeoPackage file.
+        layer: Name of the layer to read. Defaults to the first features layer.
+    """
+    import sqlite3
+    from contextlib import closing
+    from urllib.parse import quote
+
+    from polars.io.plugins import register_io_source
+
+    # Characters such as `?`, `#` and `%` would otherwise be parsed as part of the URI.
+    uri = f"file:{quote(str(path))}?mode=ro"
+    with closing(sqlite3.connect(uri, uri=True)) as connection:
+        if layer is None:
+            row = connection.execute(
+                "SELECT table_name FROM gpkg_contents WHERE data_type = 'features' LIMIT 1",
+            ).fetchone()
+            if row is None:
+                msg = f"No features layer found in {path}"
+                raise ValueError(msg)
+            layer = cast("str", row[0])
+        row = connection.execute(
+            "SELECT c.column_name, s.organization, s.organization_coordsys_id "
+            "FROM gpkg_geometry_columns c "
+            "LEFT JOIN gpkg_spatial_ref_sys s ON c.srs_id = s.srs_id "
+            "WHERE c.table_name = ?",
+            (layer,),
+        ).fetchone()
+        if row is None:
+            msg = f"Layer {layer!r} not found in {path}"
+            raise ValueError(msg)
+        geometry_name, organization, srid = row
+        if str(organization).upper() not in {"EPSG", "ESRI"}:
+            srid = 0
+        table_info = connection.execute(
+            "SELECT name, type FROM pragma_table_info(?)",
+            (layer,),
+        ).fetchall()
+    table_name = layer.replace('"', '""')
+
+    schema = pl.Schema({
+        name: pl.Binary()
+        if name == geometry_name
+        else GPKG_DTYPES.get(dtype.split("(")[0].upper(), pl.String())
+        for name, dtype in table_info
+    })
+
+    def source_generator(
+        with_columns: list[str] | None,
+        predicate: pl.Expr | None,
+        n_rows: int | None,
+        batch_size: int | None,
+    ) -> Iterator[pl.DataFrame]:
+        columns = get_io_source_columns(with_columns or list(schema), predicate)
+        batch_schema = pl.Schema({name: schema[name] for name in columns})
+        query = 'SELECT {} FROM "{}"'.format(
+            ", ".join('"{}"'.format(name.replace('"', '""')) for name in columns),
+            table_name,
+        )
+        if n_rows is not None and predicate is None:
+            query += f" LIMIT {n_rows}"
+        with closing(sqlite3.connect(uri, uri=True)) as connection:
+            cursor = connection.execute(query)
+            batches = (
+                pl.DataFrame(rows, schema=batch_schema, orient="row", strict=False)
+                for rows in iter(lambda: cursor.fetchmany(batch_size or 65_536), [])
+            )
+            if geometry_name in batch_schema:
+                batches = (
+                    df.with_columns(from_gpkg(geometry_name).st.set_srid(srid)) for df in batches
+                )
+            yield from apply_io_source_options(batches, with_columns, predicate, n_rows)
+
+    return cast("GeoLazyFrame", register_io_source(source_generator, schema=schema))
+
+
+def read_geopackage(
+    path: Path | str,
+    /,
+    layer: str | None = None,
+    columns: Sequence[str] | None = None,
+) -> GeoDataFrame:
+    """Read a GeoPackage layer into a GeoDataFrame.
+
+    Args:
+        path: Path to a GeoPackage file.
+        layer: Name of the layer to read. Defaults to the first features layer.
+        columns: List of column names to read. Defaults to all columns.
+    """
+    lf = scan_geopackage(path, layer=layer)
+    if columns is not None:
+        lf = lf.select(columns)
+    return st(lf.collect())._df  # noqa: SLF001
+
+
+def read_gpx(path: Path | str, /, *, tracks: bool = False) -> GeoDataFrame:
+    """Read a GPX file into a GeoDataFrame.
+
+    By default, the result has one row per waypoint and track point, with a `Point`
+    geometry and the `type`, `name`, `track_id`, `segment_id`, `elevation` and `time`
+    columns. Track points are named after their track, and are numbered by their track
+    and segment indices.
+
+    Args:
+        path: Path to a GPX file.
+        tracks:
+            If `True`, read one row per track instead, with the track segments
+            aggregated into a `MultiLineString` geometry, as well as the `name`,
+            `start_time` and `end_time` columns. Waypoints are not read, and segments
+            with less than two points are skipped.
+    """
+    from pathlib import Path
+
+    df = _lib.read_gpx(Path(path).read_text(encoding="utf-8"), tracks)
+    return st(df)._df  # noqa: SLF001
 
 
 @overload
@@ -220,3 +703,128 @@ def from_geopandas(
         if (crs := data[col].crs) and (srid := get_crs_srid_or_warn(str(crs)))
     )
     return st(res)._df  # noqa: SLF001
+
+
+def make_grid(
+    bounds: tuple[float, float, float, float],
+    cell_size: float | tuple[float, float] | None = None,
+    *,
+    nx: int | None = None,
+    ny: int | None = None,
+    srid: int | None = None,
+) -> GeoDataFrame:
+    """Create a GeoDataFrame of regular grid cells covering some bounds.
+
+    The grid is defined either by a `cell_size`, as a single size or a `(width,
+    height)` pair, in which case the last cells may extend past the bounds, or by
+    its number of columns `nx` and rows `ny`. Each cell has its `row` and `col`
+    indices, starting from the `ymin` and `xmin` sides.
+
+    Args:
+        bounds: Bounds of the grid, as `(xmin, ymin, xmax, ymax)`.
+        cell_size: Size of the grid cells.
+        nx: Number of columns, when no `cell_size` is given.
+        ny: Number of rows, when no `cell_size` is given.
+        srid: SRID of the grid cells.
+
+    Examples:
+        >>> gdf = st.make_grid((0, 0, 3, 2), cell_size=1)
+        >>> gdf.height
+        6
+        >>> gdf.select("row", "col", st.to_wkt()).row(4)
+        (1, 1, 'POLYGON ((1 1, 2 1, 2 2, 1 2, 1 1))')
+    """
+    xmin, ymin, xmax, ymax = bounds
+    if cell_size is not None and nx is None and ny is None:
+        width, height = cell_size if isinstance(cell_size, tuple) else (cell_size, cell_size)
+        if width <= 0 or height <= 0:
+            msg = "`cell_size` must be positive"
+            raise ValueError(msg)
+        nx = max(math.ceil((xmax - xmin) / width), 1)
+        ny = max(math.ceil((ymax - ymin) / height), 1)
+    elif cell_size is None and nx is not None and ny is not None:
+        if nx < 1 or ny < 1:
+            msg = "`nx` and `ny` must be at least 1"
+            raise ValueError(msg)
+        width, height = (xmax - xmin) / nx, (ymax - ymin) / ny
+    else:
+        msg = "either `cell_size`, or both `nx` and `ny` must be given"
+        raise ValueError(msg)
+
+    row, col = pl.col("row"), pl.col("col")
+    cell = rectangle(
+        xmin + col * width,
+        ymin + row * height,
+        xmin + (col + 1) * width,
+        ymin + (row + 1) * height,
+    )
+    if srid is not None:
+        cell = cell.st.set_srid(srid)
+    res = (
+        pl.DataFrame({"row": pl.int_range(ny, dtype=pl.UInt32, eager=True)})
+        .join(pl.DataFrame({"col": pl.int_range(nx, dtype=pl.UInt32, eager=True)}), how="cross")
+        .with_columns(geometry=cell)
+    )
+    return st(res)._df  # noqa: SLF001
+
+
+def make_hex_grid(
+    bounds: tuple[float, float, float, float],
+    size: float,
+    *,
+    orientation: Literal["flat", "pointy"] = "flat",
+    srid: int | None = None,
+) -> GeoDataFrame:
+    """Create a GeoDataFrame of hexagonal cells covering some bounds.
+
+    The first hexagon is centered on `(xmin, ymin)`. With flat-top hexagons, every
+    other column is shifted up by half a hexagon, and with pointy-top hexagons, every
+    other row is shifted right. Hexagons which only touch the bounds, or don't intersect
+    them at all, are left out.
+
+    Args:
+        bounds: Bounds to cover, as `(xmin, ymin, xmax, ymax)`.
+        size: Distance between the center and the vertices of the hexagons.
+        orientation: Whether hexagons have a `"flat"` or a `"pointy"` top.
+        srid: SRID of the grid cells.
+
+    Examples:
+        >>> gdf = st.make_hex_grid((0, 0, 10, 10), size=2)
+        >>> gdf.height
+        14
+        >>> gdf.select(st.area().round(3).unique()).item()
+        10.392
+    """
+    if size <= 0:
+        msg = "`size` must be positive"
+        raise ValueError(msg)
+    xmin, ymin, xmax, ymax = bounds
+    step, offset = 1.5 * size, math.sqrt(3) * size
+    start = 0 if orientation == "flat" else 30
+    angles = [math.radians(start + 60 * k) for k in range(6)]
+    angles.append(angles[0])
+    if orientation == "flat":
+        nx = math.ceil((xmax - xmin) / step) + 1
+        ny = math.ceil((ymax - ymin) / offset) + 1
+        cx = xmin + pl.col("col") * step
+        cy = ymin + (pl.col("row") + (pl.col("col") % 2) / 2) * offset
+    else:
+        nx = math.ceil((xmax - xmin) / offset) + 1
+        ny = math.ceil((ymax - ymin) / step) + 1
+        cx = xmin + (pl.col("col") + (pl.col("row") % 2) / 2) * offset
+        cy = ymin + pl.col("row") * step
+    ring = pl.concat_list(
+        pl.concat_arr(cx + size * math.cos(angle), cy + size * math.sin(angle))
+        for angle in angles
+    )
+    cell = polygon(ring, holes=pl.lit(None))
+    area = rectangle(pl.lit(xmin), pl.lit(ymin), pl.lit(xmax), pl.lit(ymax))
+    if srid is not None:
+        cell = cell.st.set_srid(srid)
+    res = (
+        pl.DataFrame({"row": pl.int_range(ny, dtype=pl.UInt32, eager=True)})
+        .join(pl.DataFrame({"col": pl.int_range(nx, dtype=pl.UInt32, eager=True)}), how="cross")
+        .with_columns(geometry=cell)
+        .filter(geom().st.intersects(area) & ~geom().st.touches(area))
+    )
+    return st(res)._df  # noqa: SLF001
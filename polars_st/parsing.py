@@ -1,30 +1,45 @@
 from __future__ import annotations
 
 from pathlib import Path
-from typing import TYPE_CHECKING, cast
+from typing import TYPE_CHECKING, Literal, cast
 
 import polars as pl
 from polars._utils.parse import parse_into_expression
 from polars._utils.wrap import wrap_expr
 from polars.plugins import register_plugin_function
 
+from polars_st.utils.shapely import from_shapely_array
+
 if TYPE_CHECKING:
     from polars._typing import IntoExprColumn
 
     from polars_st.geoexpr import GeoExpr
     from polars_st.geometry import GeometryType
-    from polars_st.typing import IntoNumericExpr
+    from polars_st.typing import IntoIntegerExpr, IntoNumericExpr
 
 
 __all__ = [
+    "circle",
     "circularstring",
     "circularstring",
+    "ellipse",
+    "from_bounds",
     "from_coords",
+    "from_esrijson",
+    "from_ewkb_hex",
     "from_ewkt",
+    "from_geoarrow",
     "from_geojson",
+    "from_gml",
+    "from_gpkg",
+    "from_kml",
+    "from_quadkey",
     "from_shapely",
+    "from_spatialite",
+    "from_tile",
     "from_wkb",
     "from_wkt",
+    "h3_to_boundary",
     "linestring",
     "multilinestring",
     "multipoint",
@@ -85,6 +100,8 @@ def point(coords: IntoExprColumn) -> GeoExpr:
 def multipoint(coords: IntoExprColumn) -> GeoExpr:
     """Create MultiPoint geometries from list of coordinates.
 
+    Coordinates can also be structs with `x`, `y` and optional `z` and `m` fields.
+
     Examples:
         >>> df = pl.DataFrame({
         ...     "coords": [
@@ -103,6 +120,10 @@ def multipoint(coords: IntoExprColumn) -> GeoExpr:
         │ MULTIPOINT ((0 1), (2 3)) │
         │ MULTIPOINT ((4 5), (6 7)) │
         └───────────────────────────┘
+
+        >>> df = pl.DataFrame({"coords": [[{"x": 0, "y": 1}, {"x": 2, "y": 3}]]})
+        >>> df.select(st.multipoint("coords").st.to_wkt()).item()
+        'MULTIPOINT ((0 1), (2 3))'
     """
     return register_plugin_function(
         plugin_path=Path(__file__).parent,
@@ -116,6 +137,9 @@ def multipoint(coords: IntoExprColumn) -> GeoExpr:
 def linestring(coords: IntoExprColumn) -> GeoExpr:
     """Create LineString geometries from lists of coordinates.
 
+    Coordinates can be lists or arrays of 2 to 4 numbers, which makes this the
+    inverse of [`coordinates`][polars_st.GeoExprNameSpace.coordinates].
+
     Examples:
         >>> df = pl.DataFrame({
         ...     "coords": [
@@ -218,9 +242,16 @@ def multilinestring(coords: IntoExprColumn) -> GeoExpr:
     ).pipe(lambda e: cast("GeoExpr", e))
 
 
-def polygon(coords: IntoExprColumn) -> GeoExpr:
+def polygon(coords: IntoExprColumn, holes: IntoExprColumn | None = None) -> GeoExpr:
     """Create Polygon geometries from lists of lists of coordinates.
 
+    When `holes` is given, `coords` is instead the exterior ring of each polygon,
+    and `holes` its list of interior rings. Rings must then be closed, and are
+    oriented counter-clockwise for exteriors and clockwise for holes.
+
+    Without `holes`, rings must be closed as well, but they are kept in the order
+    they are given, so that the orientation of existing data is preserved.
+
     Examples:
         >>> df = pl.DataFrame({
         ...     "coords": [
@@ -237,7 +268,22 @@ def polygon(coords: IntoExprColumn) -> GeoExpr:
         ╞════════════════════════════════╡
         │ POLYGON ((0 0, 2 4, 4 0, 0 0)) │
         └────────────────────────────────┘
+
+        >>> df = pl.DataFrame({
+        ...     "exterior": [[[0, 0], [4, 0], [4, 4], [0, 4], [0, 0]]],
+        ...     "holes": [[[[1, 1], [2, 1], [2, 2], [1, 1]]]],
+        ... })
+        >>> df = df.select(geometry=st.polygon("exterior", holes="holes"))
+        >>> df.st.to_wkt().item()
+        'POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 2, 2 1, 1 1))'
     """
+    if holes is not None:
+        return register_plugin_function(
+            plugin_path=Path(__file__).parent,
+            function_name="polygon_from_rings",
+            args=[coords, holes],
+            is_elementwise=True,
+        ).pipe(lambda e: cast("GeoExpr", e))
     return register_plugin_function(
         plugin_path=Path(__file__).parent,
         function_name="from_coords",
@@ -282,9 +328,153 @@ def rectangle(
     ).pipe(lambda e: cast("GeoExpr", e))
 
 
+def from_bounds(
+    xmin: IntoNumericExpr,
+    ymin: IntoNumericExpr,
+    xmax: IntoNumericExpr,
+    ymax: IntoNumericExpr,
+    srid: IntoIntegerExpr | None = None,
+) -> GeoExpr:
+    """Create rectangle Polygon geometries from bounds, optionally setting their SRID.
+
+    This is the inverse of [`bounds`][polars_st.GeoExprNameSpace.bounds], which is
+    convenient for building query windows or tile footprints.
+
+    Examples:
+        >>> gdf = st.GeoDataFrame(["LINESTRING (0 1, 2 3)"])
+        >>> names = ["xmin", "ymin", "xmax", "ymax"]
+        >>> bounds = gdf.select(st.bounds().arr.to_struct(names)).unnest("geometry")
+        >>> footprints = bounds.select(geometry=st.from_bounds(*names, srid=4326))
+        >>> footprints.st.to_ewkt().to_series().to_list()
+        ['SRID=4326;POLYGON ((0 1, 2 1, 2 3, 0 3, 0 1))']
+    """
+    result = rectangle(xmin, ymin, xmax, ymax)
+    if srid is not None:
+        result = result.st.set_srid(srid)
+    return result
+
+
+def circle(center: IntoExprColumn, radius: IntoNumericExpr, quad_segs: int = 8) -> GeoExpr:
+    """Create circular Polygon geometries from center coordinates and radiuses.
+
+    Circles are approximated by buffering the center points, see
+    [`buffer`][polars_st.GeoExprNameSpace.buffer].
+
+    Args:
+        center: Coordinates of the centers, as accepted by [`point`][polars_st.point].
+        radius: Radius of the circles.
+        quad_segs: Number of segments used to approximate a quarter circle.
+
+    Examples:
+        >>> df = pl.DataFrame({"center": [[0.0, 0.0]], "radius": [1.0]})
+        >>> df.select(st.circle("center", "radius", quad_segs=1).st.to_wkt()).item()
+        'POLYGON ((1 0, 0 -1, -1 0, 0 1, 1 0))'
+    """
+    return point(center).st.buffer(radius, quad_segs=quad_segs)
+
+
+def ellipse(
+    center: IntoExprColumn,
+    rx: IntoNumericExpr,
+    ry: IntoNumericExpr,
+    rotation: IntoNumericExpr = 0.0,
+    quad_segs: int = 8,
+) -> GeoExpr:
+    """Create elliptical Polygon geometries from center coordinates and semi-axes.
+
+    Ellipses are circles of radius 1 scaled along both axes, then rotated around their
+    center, see [`circle`][polars_st.circle].
+
+    Args:
+        center: Coordinates of the centers, as accepted by [`point`][polars_st.point].
+        rx: Semi-axis along the x axis, before rotation.
+        ry: Semi-axis along the y axis, before rotation.
+        rotation: Counterclockwise rotation of the ellipses, in degrees.
+        quad_segs: Number of segments used to approximate a quarter ellipse.
+
+    Examples:
+        >>> df = pl.DataFrame({"center": [[1.0, 1.0]]})
+        >>> df.select(st.ellipse("center", 2, 1, quad_segs=1).st.to_wkt()).item()
+        'POLYGON ((3 1, 1 0, -1 1, 1 2, 3 1))'
+    """
+    return (
+        circle(center, 1.0, quad_segs)
+        .st.scale(rx, ry, origin="center")
+        .st.rotate(rotation, origin="center")
+    )
+
+
+def from_tile(
+    z: IntoIntegerExpr,
+    x: IntoIntegerExpr,
+    y: IntoIntegerExpr,
+    srid: Literal[3857, 4326] = 3857,
+) -> GeoExpr:
+    """Create the Polygon geometries of XYZ tiles, in EPSG:3857 or EPSG:4326.
+
+    Tiles follow the scheme of web maps, with `x` and `y` counted from the top left
+    corner of the Web Mercator extent, at zoom levels up to 30.
+
+    Examples:
+        >>> df = pl.DataFrame({"z": [1], "x": [1], "y": [0]})
+        >>> df.select(st.from_tile("z", "x", "y", srid=4326).st.to_ewkt()).item()
+        'SRID=4326;POLYGON ((0 0, 180 0, 180 85.051129, 0 85.051129, 0 0))'
+    """
+    return register_plugin_function(
+        plugin_path=Path(__file__).parent,
+        function_name="from_tile",
+        args=[z, x, y],
+        kwargs={"srid": srid},
+        is_elementwise=True,
+    ).pipe(lambda e: cast("GeoExpr", e))
+
+
+def from_quadkey(quadkey: IntoExprColumn, srid: Literal[3857, 4326] = 3857) -> GeoExpr:
+    """Create the Polygon geometries of tiles given as Bing Maps quadkeys.
+
+    See [`from_tile`][polars_st.from_tile].
+
+    Examples:
+        >>> df = pl.DataFrame({"quadkey": ["1"]})
+        >>> df.select(st.from_quadkey("quadkey", srid=4326).st.to_ewkt()).item()
+        'SRID=4326;POLYGON ((0 0, 180 0, 180 85.051129, 0 85.051129, 0 0))'
+    """
+    return register_plugin_function(
+        plugin_path=Path(__file__).parent,
+        function_name="from_quadkey",
+        args=[quadkey],
+        kwargs={"srid": srid},
+        is_elementwise=True,
+    ).pipe(lambda e: cast("GeoExpr", e))
+
+
+def h3_to_boundary(cells: IntoExprColumn) -> GeoExpr:
+    """Create the Polygon geometries of H3 cells, in EPSG:4326.
+
+    Cells are given as 64-bit integers, such as those returned by
+    [`to_h3`][polars_st.GeoExprNameSpace.to_h3]. Vertices of cells crossing the
+    antimeridian aren't unwrapped.
+
+    Examples:
+        >>> df = pl.DataFrame({"cell": [0x87283472BFFFFFF]}, schema={"cell": pl.UInt64})
+        >>> df = df.select(geometry=st.h3_to_boundary("cell"))
+        >>> df.select(st.count_points(), st.geom().st.srid().alias("srid")).row(0)
+        (7, 4326)
+    """
+    return register_plugin_function(
+        plugin_path=Path(__file__).parent,
+        function_name="h3_to_boundary",
+        args=[cells],
+        is_elementwise=True,
+    ).pipe(lambda e: cast("GeoExpr", e))
+
+
 def from_coords(coords: IntoExprColumn, into: GeometryType | None = None) -> GeoExpr:
     """Create geometries from any coordinates.
 
+    Coordinates are either lists or arrays of 2 to 4 numbers, or structs with `x`,
+    `y` and optional `z` and `m` fields.
+
     Examples:
         >>> df = pl.DataFrame({"coords": [[0, 1], [0, 2]]})
         >>> df = df.select(geometry=st.from_coords("coords"))
@@ -320,20 +510,85 @@ def from_coords(coords: IntoExprColumn, into: GeometryType | None = None) -> Geo
     ).pipe(lambda e: cast("GeoExpr", e))
 
 
-def from_wkb(expr: IntoExprColumn) -> GeoExpr:
+def from_wkb(
+    expr: IntoExprColumn,
+    on_error: Literal["raise", "null", "repair"] = "raise",
+) -> GeoExpr:
     """Parse geometries from Well-Known Binary (WKB) representation.
 
+    Args:
+        expr:
+            The WKB or EWKB encoded geometries.
+        on_error:
+            What to do with blobs that can't be read. Besides corrupt blobs, this
+            includes well-formed blobs describing malformed geometries that GEOS refuses
+            to build, such as unclosed rings or rings with fewer than four points.
+            With `"raise"`, the query fails. With `"null"`, they are replaced by nulls,
+            while geometries that are read but invalid, such as self-intersecting
+            polygons, are kept as is. With `"repair"`, unreadable blobs are replaced by
+            nulls as well, and invalid geometries are made valid, as with
+            [`make_valid`][polars_st.GeoExprNameSpace.make_valid].
+
     Examples:
         >>> df = pl.read_database(
         ...     query="SELECT ST_AsEWKB(geom) AS geometry FROM test_data",
         ...     connection=user_conn,
         ... ) # doctest: +SKIP
         >>> gdf = df.select(st.from_wkb("geometry")) # doctest: +SKIP
+
+        >>> df = pl.Series("geometry", [
+        ...     bytes.fromhex("0101000000000000000000f03f0000000000000040"),
+        ...     b"corrupt",
+        ... ]).to_frame()
+        >>> gdf = df.select(st.from_wkb("geometry", on_error="null"))
+        >>> gdf.st.to_wkt()
+        shape: (2, 1)
+        ┌─────────────┐
+        │ geometry    │
+        │ ---         │
+        │ str         │
+        ╞═════════════╡
+        │ POINT (1 2) │
+        │ null        │
+        └─────────────┘
     """
     return register_plugin_function(
         plugin_path=Path(__file__).parent,
         function_name="from_wkb",
         args=[expr],
+        kwargs={"on_error": on_error},
+        is_elementwise=True,
+    ).pipe(lambda e: cast("GeoExpr", e))
+
+
+def from_ewkb_hex(expr: IntoExprColumn) -> GeoExpr:
+    r"""Parse geometries from hex-encoded Extended Well-Known Binary (EWKB) representation.
+
+    This is the representation of PostGIS geometries cast to text, as well as the input
+    format expected by `COPY`. The SRID and Z/M dimensions are preserved, and a leading
+    `\x`, as in the text representation of a `bytea`, is ignored.
+
+    Examples:
+        >>> df = pl.Series("geometry", [
+        ...     "0101000020E6100000000000000000F03F0000000000000040",
+        ...     "010100000000000000000000000000000000000000",
+        ... ]).to_frame()
+        >>> gdf = df.select(st.from_ewkb_hex("geometry"))
+        >>> gdf.st.to_ewkt()
+        shape: (2, 1)
+        ┌───────────────────────┐
+        │ geometry              │
+        │ ---                   │
+        │ str                   │
+        ╞═══════════════════════╡
+        │ SRID=4326;POINT (1 2) │
+        │ POINT (0 0)           │
+        └───────────────────────┘
+    """
+    return register_plugin_function(
+        plugin_path=Path(__file__).parent,
+        function_name="from_ewkb_hex",
+        args=[expr],
         is_elementwise=True,
     ).pipe(lambda e: cast("GeoExpr", e))
 
@@ -402,6 +657,217 @@ def from_geojson(expr: IntoExprColumn) -> GeoExpr:
     ).pipe(lambda e: cast("GeoExpr", e))
 
 
+def from_esrijson(expr: IntoExprColumn) -> GeoExpr:
+    """Parse geometries from EsriJSON representation.
+
+    Both EsriJSON geometry objects and feature objects (in which case the `geometry` member is
+    parsed) are supported, features with a null geometry giving null rows. The SRID is read from
+    the `wkid` of the spatial reference, if any.
+    Polygon rings are grouped by orientation: clockwise rings are exteriors, and counter-clockwise
+    rings are holes of the exterior ring containing them.
+
+    Examples:
+        >>> df = pl.Series("geometry", [
+        ...     '{"x": 1, "y": 2, "spatialReference": {"wkid": 4326}}',
+        ...     '{"paths": [[[0, 0], [1, 1]]]}',
+        ...     '{"rings": [[[0, 0], [0, 1], [1, 1], [0, 0]]]}',
+        ... ]).to_frame()
+        >>> gdf = df.select(st.from_esrijson("geometry"))
+        >>> gdf.st.to_ewkt()
+        shape: (3, 1)
+        ┌────────────────────────────────┐
+        │ geometry                       │
+        │ ---                            │
+        │ str                            │
+        ╞════════════════════════════════╡
+        │ SRID=4326;POINT (1 2)          │
+        │ LINESTRING (0 0, 1 1)          │
+        │ POLYGON ((0 0, 0 1, 1 1, 0 0)) │
+        └────────────────────────────────┘
+    """
+    return register_plugin_function(
+        plugin_path=Path(__file__).parent,
+        function_name="from_esrijson",
+        args=[expr],
+        is_elementwise=True,
+    ).pipe(lambda e: cast("GeoExpr", e))
+
+
+def from_gpkg(expr: IntoExprColumn) -> GeoExpr:
+    """Parse geometries from GeoPackage binary representation.
+
+    The GeoPackage header is decoded, and its `srs_id` is used as the geometry SRID. Note
+    that `srs_id` refers to the `gpkg_spatial_ref_sys` table of the GeoPackage, which
+    usually, but not necessarily, matches the EPSG code.
+
+    Examples:
+        >>> df = pl.Series("geometry", [
+        ...     bytes.fromhex("47500001e610000001010000000000000000000000000000000000f03f"),
+        ... ]).to_frame()
+        >>> gdf = df.select(st.from_gpkg("geometry"))
+        >>> gdf.st.to_ewkt()
+        shape: (1, 1)
+        ┌───────────────────────┐
+        │ geometry              │
+        │ ---                   │
+        │ str                   │
+        ╞═══════════════════════╡
+        │ SRID=4326;POINT (0 1) │
+        └───────────────────────┘
+    """
+    return register_plugin_function(
+        plugin_path=Path(__file__).parent,
+        function_name="from_gpkg",
+        args=[expr],
+        is_elementwise=True,
+    ).pipe(lambda e: cast("GeoExpr", e))
+
+
+def from_spatialite(expr: IntoExprColumn) -> GeoExpr:
+    """Parse geometries from the SpatiaLite internal BLOB representation.
+
+    Both big and little endian blobs are supported, as well as compressed linestrings
+    and polygons. The SRID stored in the blob header is used as the geometry SRID.
+
+    Examples:
+        >>> df = pl.Series("geometry", [
+        ...     bytes.fromhex(
+        ...         "0001e6100000000000000000f03f0000000000000040000000000000f03f0000000000000040"
+        ...         "7c01000000000000000000f03f0000000000000040fe"
+        ...     ),
+        ... ]).to_frame()
+        >>> gdf = df.select(st.from_spatialite("geometry"))
+        >>> gdf.st.to_ewkt()
+        shape: (1, 1)
+        ┌───────────────────────┐
+        │ geometry              │
+        │ ---                   │
+        │ str                   │
+        ╞═══════════════════════╡
+        │ SRID=4326;POINT (1 2) │
+        └───────────────────────┘
+    """
+    return register_plugin_function(
+        plugin_path=Path(__file__).parent,
+        function_name="from_spatialite",
+        args=[expr],
+        is_elementwise=True,
+    ).pipe(lambda e: cast("GeoExpr", e))
+
+
+def from_geoarrow(expr: IntoExprColumn, geometry_type: GeometryType) -> GeoExpr:
+    """Parse geometries from a GeoArrow native encoding.
+
+    Coordinates can either be separated, in a struct with `x`, `y` and optional `z`
+    and `m` fields, or interleaved, in an array of 2 to 4 values. They are nested in
+    lists according to the geometry type: none for points, one level for linestrings
+    and multipoints, two for polygons and multilinestrings, and three for
+    multipolygons.
+
+    Args:
+        expr:
+            The GeoArrow encoded geometries.
+        geometry_type:
+            The geometry type of the encoding, which must be `"Point"`,
+            `"LineString"`, `"Polygon"`, `"MultiPoint"`, `"MultiLineString"` or
+            `"MultiPolygon"`.
+
+    Examples:
+        >>> df = pl.DataFrame({"geometry": [[{"x": 0, "y": 1}, {"x": 2, "y": 3}]]})
+        >>> gdf = df.select(st.from_geoarrow("geometry", "LineString"))
+        >>> gdf.st.to_wkt()
+        shape: (1, 1)
+        ┌───────────────────────┐
+        │ geometry              │
+        │ ---                   │
+        │ str                   │
+        ╞═══════════════════════╡
+        │ LINESTRING (0 1, 2 3) │
+        └───────────────────────┘
+
+        >>> df = pl.DataFrame({"x": [0, 1], "y": [1, 2], "m": [10, 20]})
+        >>> gdf = df.select(geometry=st.from_geoarrow(pl.struct("x", "y", "m"), "Point"))
+        >>> gdf.st.to_wkt().to_series().to_list()
+        ['POINT M (0 1 10)', 'POINT M (1 2 20)']
+    """
+    return register_plugin_function(
+        plugin_path=Path(__file__).parent,
+        function_name="from_geoarrow",
+        args=[expr],
+        kwargs={"geometry_type": geometry_type},
+        is_elementwise=True,
+    ).pipe(lambda e: cast("GeoExpr", e))
+
+
+def from_gml(expr: IntoExprColumn) -> GeoExpr:
+    """Parse geometries from GML representation.
+
+    The first geometry element of each GML document or fragment is parsed, which makes
+    it possible to decode WFS or INSPIRE feature members directly. GML 3.2 elements
+    are supported, as well as GML 2 `coordinates`, `MultiLineString` and `MultiPolygon`.
+    The SRID is read from the closest `srsName` attribute holding an EPSG code.
+
+    Coordinates are read in the order they are written: some CRS identifiers, such as
+    `urn:ogc:def:crs:EPSG::4326`, imply a latitude/longitude axis order, in which case
+    [`flip_coordinates`][polars_st.GeoExprNameSpace.flip_coordinates] can be used.
+
+    Examples:
+        >>> df = pl.Series("geometry", [
+        ...     '<gml:Point srsName="EPSG:4326"><gml:pos>1 2</gml:pos></gml:Point>',
+        ...     "<LineString><posList>0 0 1 1</posList></LineString>",
+        ... ]).to_frame()
+        >>> gdf = df.select(st.from_gml("geometry"))
+        >>> gdf.st.to_ewkt()
+        shape: (2, 1)
+        ┌───────────────────────┐
+        │ geometry              │
+        │ ---                   │
+        │ str                   │
+        ╞═══════════════════════╡
+        │ SRID=4326;POINT (1 2) │
+        │ LINESTRING (0 0, 1 1) │
+        └───────────────────────┘
+    """
+    return register_plugin_function(
+        plugin_path=Path(__file__).parent,
+        function_name="from_gml",
+        args=[expr],
+        is_elementwise=True,
+    ).pipe(lambda e: cast("GeoExpr", e))
+
+
+def from_kml(expr: IntoExprColumn) -> GeoExpr:
+    """Parse geometries from KML representation.
+
+    The first `Point`, `LineString`, `LinearRing`, `Polygon` or `MultiGeometry` element
+    of each KML document or fragment is parsed. As KML coordinates are always longitude
+    and latitude on the WGS84 datum, the SRID of parsed geometries is set to 4326.
+
+    Examples:
+        >>> df = pl.Series("geometry", [
+        ...     "<Point><coordinates>1,2</coordinates></Point>",
+        ...     "<Placemark><LineString><coordinates>0,0 1,1</coordinates></LineString></Placemark>",
+        ... ]).to_frame()
+        >>> gdf = df.select(st.from_kml("geometry"))
+        >>> gdf.st.to_ewkt()
+        shape: (2, 1)
+        ┌─────────────────────────────────┐
+        │ geometry                        │
+        │ ---                             │
+        │ str                             │
+        ╞═════════════════════════════════╡
+        │ SRID=4326;POINT (1 2)           │
+        │ SRID=4326;LINESTRING (0 0, 1 1) │
+        └─────────────────────────────────┘
+    """
+    return register_plugin_function(
+        plugin_path=Path(__file__).parent,
+        function_name="from_kml",
+        args=[expr],
+        is_elementwise=True,
+    ).pipe(lambda e: cast("GeoExpr", e))
+
+
 def from_shapely(expr: IntoExprColumn) -> GeoExpr:
     """Parse geometries from shapely objects.
 
@@ -422,11 +888,9 @@ def from_shapely(expr: IntoExprColumn) -> GeoExpr:
         │ POINT (1 2) │
         └─────────────┘
     """
-    import shapely
-
     expr = wrap_expr(parse_into_expression(expr))
     res = expr.map_batches(
-        lambda s: pl.Series(s.name, list(shapely.to_wkb(s.to_numpy(), include_srid=True))),
+        from_shapely_array,
         return_dtype=pl.Binary,
         is_elementwise=True,
     )
@@ -5,8 +5,10 @@ from typing import TYPE_CHECKING, Literal
 from polars_st.selectors import geom
 
 if TYPE_CHECKING:
-    from collections.abc import Sequence
+    from collections.abc import Callable, Sequence
 
+    import numpy as np
+    import numpy.typing as npt
     import polars as pl
 
     from polars_st.geometry import GeometryType
@@ -21,16 +23,23 @@ if TYPE_CHECKING:
 
 __all__ = [
     "affine_transform",
+    "align_to_mrr",
     "area",
+    "area_geodesic",
     "boundary",
     "bounds",
+    "bounds_3d",
+    "bounds_to_srid",
     "buffer",
+    "buffer_geodesic",
+    "buffer_rings",
     "build_area",
     "cast",
     "center",
     "centroid",
     "clip_by_rect",
     "collect",
+    "collection_extract",
     "concave_hull",
     "convex_hull",
     "coordinate_dimension",
@@ -41,19 +50,25 @@ __all__ = [
     "count_points",
     "coverage_union",
     "coverage_union_all",
+    "crs_info",
+    "curve_to_linear",
     "delaunay_triangles",
     "difference_all",
     "dimensions",
+    "dump_points",
     "envelope",
     "exterior_ring",
     "extract_unique_points",
     "flip_coordinates",
     "force_2d",
     "force_3d",
+    "force_4d",
     "geometry_type",
     "get_geometry",
     "get_interior_ring",
     "get_point",
+    "h3_cover",
+    "has_curves",
     "has_m",
     "has_z",
     "interior_rings",
@@ -65,44 +80,80 @@ __all__ = [
     "is_ring",
     "is_simple",
     "is_valid",
+    "is_valid_detail",
     "is_valid_reason",
     "length",
+    "length_3d",
+    "length_geodesic",
     "line_merge",
+    "linear_to_curve",
     "m",
+    "m_range",
+    "make_line",
     "make_valid",
     "minimum_clearance",
     "minimum_rotated_rectangle",
+    "morton_index",
     "multi",
     "node",
     "normalize",
     "offset_curve",
     "parts",
     "point_on_surface",
+    "points_along",
     "polygonize",
+    "polylabel",
     "precision",
     "remove_repeated_points",
     "reverse",
     "rotate",
     "scale",
+    "segment_azimuths",
     "segmentize",
+    "segmentize_geodesic",
+    "segments",
     "set_precision",
     "set_srid",
     "simplify",
+    "simplify_coverage",
+    "simplify_vw",
     "skew",
+    "snap_to_grid",
+    "spatial_partition",
     "srid",
+    "subdivide",
     "symmetric_difference_all",
+    "tile_of",
     "to_dict",
+    "to_esrijson",
+    "to_ewkb_hex",
     "to_ewkt",
+    "to_geoarrow",
     "to_geojson",
+    "to_geojson_feature",
+    "to_gml",
+    "to_gpkg",
+    "to_h3",
+    "to_kml",
     "to_shapely",
+    "to_spatialite",
     "to_srid",
+    "to_svg",
+    "to_utm",
     "to_wkb",
     "to_wkt",
     "total_bounds",
+    "transects",
+    "transform",
+    "transform_with",
     "translate",
     "unary_union",
     "union_all",
+    "unnest_collection",
+    "utm_zone",
     "voronoi_polygons",
+    "wkb_is_parseable",
+    "wkb_parse_error",
     "x",
     "y",
     "z",
@@ -129,16 +180,75 @@ def area(*columns: str) -> pl.Expr:
     return geom(*columns).st.area()
 
 
+def area_geodesic(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[area_geodesic()][polars_st.GeoExprNameSpace.area_geodesic]</code>."""  # noqa: E501
+    return geom(*columns).st.area_geodesic()
+
+
 def bounds(*columns: str) -> pl.Expr:
     """Syntactic sugar for <code>st.geom(columns).st.[bounds()][polars_st.GeoExprNameSpace.bounds]</code>."""  # noqa: E501
     return geom(*columns).st.bounds()
 
 
+def bounds_3d(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[bounds_3d()][polars_st.GeoExprNameSpace.bounds_3d]</code>."""  # noqa: E501
+    return geom(*columns).st.bounds_3d()
+
+
+def m_range(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[m_range()][polars_st.GeoExprNameSpace.m_range]</code>."""  # noqa: E501
+    return geom(*columns).st.m_range()
+
+
+def morton_index(
+    *columns: str,
+    extent: tuple[float, float, float, float] | None = None,
+    level: int = 16,
+) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[morton_index(...)][polars_st.GeoExprNameSpace.morton_index]</code>."""  # noqa: E501
+    return geom(*columns).st.morton_index(extent, level)
+
+
+def spatial_partition(
+    *columns: str,
+    scheme: Literal["grid", "kdb"] = "grid",
+    n: int = 16,
+    extent: tuple[float, float, float, float] | None = None,
+) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[spatial_partition(...)][polars_st.GeoExprNameSpace.spatial_partition]</code>."""  # noqa: E501
+    return geom(*columns).st.spatial_partition(scheme, n, extent)
+
+
+def to_h3(*columns: str, resolution: int) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_h3(...)][polars_st.GeoExprNameSpace.to_h3]</code>."""  # noqa: E501
+    return geom(*columns).st.to_h3(resolution)
+
+
+def h3_cover(*columns: str, resolution: int) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[h3_cover(...)][polars_st.GeoExprNameSpace.h3_cover]</code>."""  # noqa: E501
+    return geom(*columns).st.h3_cover(resolution)
+
+
+def tile_of(*columns: str, zoom: int) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[tile_of(...)][polars_st.GeoExprNameSpace.tile_of]</code>."""  # noqa: E501
+    return geom(*columns).st.tile_of(zoom)
+
+
 def length(*columns: str) -> pl.Expr:
     """Syntactic sugar for <code>st.geom(columns).st.[length()][polars_st.GeoExprNameSpace.length]</code>."""  # noqa: E501
     return geom(*columns).st.length()
 
 
+def length_geodesic(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[length_geodesic()][polars_st.GeoExprNameSpace.length_geodesic]</code>."""  # noqa: E501
+    return geom(*columns).st.length_geodesic()
+
+
+def length_3d(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[length_3d()][polars_st.GeoExprNameSpace.length_3d]</code>."""  # noqa: E501
+    return geom(*columns).st.length_3d()
+
+
 def minimum_clearance(*columns: str) -> pl.Expr:
     """Syntactic sugar for <code>st.geom(columns).st.[minimum_clearance()][polars_st.GeoExprNameSpace.minimum_clearance]</code>."""  # noqa: E501
     return geom(*columns).st.minimum_clearance()
@@ -219,6 +329,24 @@ def parts(*columns: str) -> pl.Expr:
     return geom(*columns).st.parts()
 
 
+def dump_points(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[dump_points()][polars_st.GeoExprNameSpace.dump_points]</code>."""  # noqa: E501
+    return geom(*columns).st.dump_points()
+
+
+def collection_extract(
+    *columns: str,
+    type: Literal["point", "line", "polygon"],  # noqa: A002
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[collection_extract(...)][polars_st.GeoExprNameSpace.collection_extract]</code>."""  # noqa: E501
+    return geom(*columns).st.collection_extract(type)
+
+
+def unnest_collection(*columns: str) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[unnest_collection()][polars_st.GeoExprNameSpace.unnest_collection]</code>."""  # noqa: E501
+    return geom(*columns).st.unnest_collection()
+
+
 def precision(*columns: str) -> pl.Expr:
     """Syntactic sugar for <code>st.geom(columns).st.[precision()][polars_st.GeoExprNameSpace.precision]</code>."""  # noqa: E501
     return geom(*columns).st.precision()
@@ -233,19 +361,72 @@ def set_precision(
     return geom(*columns).st.set_precision(grid_size, mode)
 
 
+def snap_to_grid(
+    *columns: str,
+    size_x: float,
+    size_y: float | None = None,
+    origin: Sequence[float] = (0.0, 0.0),
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[snap_to_grid(...)][polars_st.GeoExprNameSpace.snap_to_grid]</code>."""  # noqa: E501
+    return geom(*columns).st.snap_to_grid(size_x, size_y, origin)
+
+
 def srid(*columns: str) -> pl.Expr:
     """Syntactic sugar for <code>st.geom(columns).st.[srid()][polars_st.GeoExprNameSpace.srid]</code>."""  # noqa: E501
     return geom(*columns).st.srid()
 
 
+def crs_info(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[crs_info()][polars_st.GeoExprNameSpace.crs_info]</code>."""  # noqa: E501
+    return geom(*columns).st.crs_info()
+
+
 def set_srid(*columns: str, srid: IntoIntegerExpr) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[set_srid(...)][polars_st.GeoExprNameSpace.set_srid]</code>."""  # noqa: E501
     return geom(*columns).st.set_srid(srid)
 
 
-def to_srid(*columns: str, srid: IntoIntegerExpr) -> GeoExpr:
+def to_srid(
+    *columns: str,
+    srid: IntoIntegerExpr,
+    pipeline: str | None = None,
+    antimeridian: Literal["ignore", "shift", "split"] = "ignore",
+) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[to_srid(...)][polars_st.GeoExprNameSpace.to_srid]</code>."""  # noqa: E501
-    return geom(*columns).st.to_srid(srid)
+    return geom(*columns).st.to_srid(srid, pipeline, antimeridian)
+
+
+def bounds_to_srid(
+    *columns: str,
+    srid: IntoIntegerExpr,
+    densify_points: int = 21,
+) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[bounds_to_srid(...)][polars_st.GeoExprNameSpace.bounds_to_srid]</code>."""  # noqa: E501
+    return geom(*columns).st.bounds_to_srid(srid, densify_points)
+
+
+def transform(*columns: str, from_crs: str, to_crs: str) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[transform(...)][polars_st.GeoExprNameSpace.transform]</code>."""  # noqa: E501
+    return geom(*columns).st.transform(from_crs, to_crs)
+
+
+def transform_with(
+    *columns: str,
+    func: Callable[[npt.NDArray[np.float64]], npt.ArrayLike],
+    include_z: bool = False,
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[transform_with(...)][polars_st.GeoExprNameSpace.transform_with]</code>."""  # noqa: E501
+    return geom(*columns).st.transform_with(func, include_z)
+
+
+def utm_zone(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[utm_zone()][polars_st.GeoExprNameSpace.utm_zone]</code>."""  # noqa: E501
+    return geom(*columns).st.utm_zone()
+
+
+def to_utm(*columns: str, by: Literal["geometry", "frame"] = "geometry") -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_utm(...)][polars_st.GeoExprNameSpace.to_utm]</code>."""  # noqa: E501
+    return geom(*columns).st.to_utm(by)
 
 
 def to_wkt(
@@ -275,9 +456,15 @@ def to_wkb(
     output_dimension: Literal[2, 3, 4] = 3,
     byte_order: Literal[0, 1] | None = None,
     include_srid: bool = False,
+    flavor: Literal["extended", "iso"] = "extended",
 ) -> pl.Expr:
     """Syntactic sugar for <code>st.geom(columns).st.[to_wkb(...)][polars_st.GeoExprNameSpace.to_wkb]</code>."""  # noqa: E501
-    return geom(*columns).st.to_wkb(output_dimension, byte_order, include_srid)
+    return geom(*columns).st.to_wkb(output_dimension, byte_order, include_srid, flavor)
+
+
+def to_ewkb_hex(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_ewkb_hex()][polars_st.GeoExprNameSpace.to_ewkb_hex]</code>."""  # noqa: E501
+    return geom(*columns).st.to_ewkb_hex()
 
 
 def to_geojson(*columns: str, indent: int | None = None) -> pl.Expr:
@@ -285,6 +472,54 @@ def to_geojson(*columns: str, indent: int | None = None) -> pl.Expr:
     return geom(*columns).st.to_geojson(indent)
 
 
+def to_geojson_feature(
+    *columns: str,
+    properties: IntoExprColumn | None = None,
+    feature_id: IntoExprColumn | None = None,
+) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_geojson_feature(...)][polars_st.GeoExprNameSpace.to_geojson_feature]</code>."""  # noqa: E501
+    return geom(*columns).st.to_geojson_feature(properties, feature_id)
+
+
+def to_esrijson(*columns: str, indent: int | None = None) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_esrijson(...)][polars_st.GeoExprNameSpace.to_esrijson]</code>."""  # noqa: E501
+    return geom(*columns).st.to_esrijson(indent)
+
+
+def to_kml(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_kml()][polars_st.GeoExprNameSpace.to_kml]</code>."""  # noqa: E501
+    return geom(*columns).st.to_kml()
+
+
+def to_gml(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_gml()][polars_st.GeoExprNameSpace.to_gml]</code>."""  # noqa: E501
+    return geom(*columns).st.to_gml()
+
+
+def to_gpkg(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_gpkg()][polars_st.GeoExprNameSpace.to_gpkg]</code>."""  # noqa: E501
+    return geom(*columns).st.to_gpkg()
+
+
+def to_spatialite(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_spatialite()][polars_st.GeoExprNameSpace.to_spatialite]</code>."""  # noqa: E501
+    return geom(*columns).st.to_spatialite()
+
+
+def to_svg(*columns: str, view_box: bool = False, flip_y: bool = True) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_svg(...)][polars_st.GeoExprNameSpace.to_svg]</code>."""  # noqa: E501
+    return geom(*columns).st.to_svg(view_box, flip_y)
+
+
+def to_geoarrow(
+    *columns: str,
+    geometry_type: GeometryType,
+    dimensions: Literal["xy", "xyz", "xym", "xyzm"] = "xy",
+) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[to_geoarrow(...)][polars_st.GeoExprNameSpace.to_geoarrow]</code>."""  # noqa: E501
+    return geom(*columns).st.to_geoarrow(geometry_type, dimensions)
+
+
 def to_shapely(*columns: str) -> pl.Expr:
     """Syntactic sugar for <code>st.geom(columns).st.[to_shapely()][polars_st.GeoExprNameSpace.to_shapely]</code>."""  # noqa: E501
     return geom(*columns).st.to_shapely()
@@ -315,6 +550,11 @@ def has_m(*columns: str) -> pl.Expr:
     return geom(*columns).st.has_m()
 
 
+def has_curves(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[has_curves()][polars_st.GeoExprNameSpace.has_curves]</code>."""  # noqa: E501
+    return geom(*columns).st.has_curves()
+
+
 def is_ccw(*columns: str) -> pl.Expr:
     """Syntactic sugar for <code>st.geom(columns).st.[is_ccw()][polars_st.GeoExprNameSpace.is_ccw]</code>."""  # noqa: E501
     return geom(*columns).st.is_ccw()
@@ -350,6 +590,21 @@ def is_valid_reason(*columns: str) -> pl.Expr:
     return geom(*columns).st.is_valid_reason()
 
 
+def is_valid_detail(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[is_valid_detail()][polars_st.GeoExprNameSpace.is_valid_detail]</code>."""  # noqa: E501
+    return geom(*columns).st.is_valid_detail()
+
+
+def wkb_is_parseable(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[wkb_is_parseable()][polars_st.GeoExprNameSpace.wkb_is_parseable]</code>."""  # noqa: E501
+    return geom(*columns).st.wkb_is_parseable()
+
+
+def wkb_parse_error(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[wkb_parse_error()][polars_st.GeoExprNameSpace.wkb_parse_error]</code>."""  # noqa: E501
+    return geom(*columns).st.wkb_parse_error()
+
+
 def unary_union(*columns: str, grid_size: float | None = None) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[unary_union(...)][polars_st.GeoExprNameSpace.unary_union]</code>."""  # noqa: E501
     return geom(*columns).st.unary_union(grid_size)
@@ -385,6 +640,48 @@ def buffer(
     )
 
 
+def buffer_geodesic(
+    *columns: str,
+    distance_m: IntoNumericExpr,
+    quad_segs: int = 8,
+    cap_style: Literal["round", "square", "flat"] = "round",
+    join_style: Literal["round", "mitre", "bevel"] = "round",
+    mitre_limit: float = 5.0,
+    single_sided: bool = False,
+    antimeridian: Literal["ignore", "shift", "split"] = "split",
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[buffer_geodesic(...)][polars_st.GeoExprNameSpace.buffer_geodesic]</code>."""  # noqa: E501
+    return geom(*columns).st.buffer_geodesic(
+        distance_m,
+        quad_segs,
+        cap_style,
+        join_style,
+        mitre_limit,
+        single_sided,
+        antimeridian,
+    )
+
+
+def buffer_rings(
+    *columns: str,
+    distances: Sequence[float],
+    quad_segs: int = 8,
+    cap_style: Literal["round", "square", "flat"] = "round",
+    join_style: Literal["round", "mitre", "bevel"] = "round",
+    mitre_limit: float = 5.0,
+    dissolve: bool = False,
+) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[buffer_rings(...)][polars_st.GeoExprNameSpace.buffer_rings]</code>."""  # noqa: E501
+    return geom(*columns).st.buffer_rings(
+        distances,
+        quad_segs,
+        cap_style,
+        join_style,
+        mitre_limit,
+        dissolve,
+    )
+
+
 def offset_curve(
     *columns: str,
     distance: IntoNumericExpr,
@@ -417,6 +714,11 @@ def clip_by_rect(
     return geom(*columns).st.clip_by_rect(xmin, ymin, xmax, ymax)
 
 
+def subdivide(*columns: str, max_vertices: int = 256) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[subdivide(...)][polars_st.GeoExprNameSpace.subdivide]</code>."""  # noqa: E501
+    return geom(*columns).st.subdivide(max_vertices)
+
+
 def convex_hull(*columns: str) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[convex_hull()][polars_st.GeoExprNameSpace.convex_hull]</code>."""  # noqa: E501
     return geom(*columns).st.convex_hull()
@@ -432,6 +734,25 @@ def segmentize(*columns: str, max_segment_length: IntoNumericExpr) -> GeoExpr:
     return geom(*columns).st.segmentize(max_segment_length)
 
 
+def segmentize_geodesic(
+    *columns: str,
+    max_length_m: IntoNumericExpr,
+    antimeridian: Literal["ignore", "shift", "split"] = "shift",
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[segmentize_geodesic(...)][polars_st.GeoExprNameSpace.segmentize_geodesic]</code>."""  # noqa: E501
+    return geom(*columns).st.segmentize_geodesic(max_length_m, antimeridian)
+
+
+def curve_to_linear(*columns: str, tolerance: IntoNumericExpr) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[curve_to_linear(...)][polars_st.GeoExprNameSpace.curve_to_linear]</code>."""  # noqa: E501
+    return geom(*columns).st.curve_to_linear(tolerance)
+
+
+def linear_to_curve(*columns: str, tolerance: IntoNumericExpr) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[linear_to_curve(...)][polars_st.GeoExprNameSpace.linear_to_curve]</code>."""  # noqa: E501
+    return geom(*columns).st.linear_to_curve(tolerance)
+
+
 def envelope(*columns: str) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[envelope()][polars_st.GeoExprNameSpace.envelope]</code>."""  # noqa: E501
     return geom(*columns).st.envelope()
@@ -447,9 +768,13 @@ def build_area(*columns: str) -> GeoExpr:
     return geom(*columns).st.build_area()
 
 
-def make_valid(*columns: str) -> GeoExpr:
-    """Syntactic sugar for <code>st.geom(columns).st.[make_valid()][polars_st.GeoExprNameSpace.make_valid]</code>."""  # noqa: E501
-    return geom(*columns).st.make_valid()
+def make_valid(
+    *columns: str,
+    method: Literal["linework", "structure"] = "linework",
+    keep_collapsed: bool = True,
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[make_valid(...)][polars_st.GeoExprNameSpace.make_valid]</code>."""  # noqa: E501
+    return geom(*columns).st.make_valid(method, keep_collapsed)
 
 
 def normalize(*columns: str) -> GeoExpr:
@@ -467,6 +792,11 @@ def point_on_surface(*columns: str) -> GeoExpr:
     return geom(*columns).st.point_on_surface()
 
 
+def polylabel(*columns: str, tolerance: IntoNumericExpr = 1.0) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[polylabel(...)][polars_st.GeoExprNameSpace.polylabel]</code>."""  # noqa: E501
+    return geom(*columns).st.polylabel(tolerance)
+
+
 def remove_repeated_points(*columns: str, tolerance: IntoNumericExpr = 0.0) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[remove_repeated_points(...)][polars_st.GeoExprNameSpace.remove_repeated_points]</code>."""  # noqa: E501
     return geom(*columns).st.remove_repeated_points(tolerance)
@@ -486,6 +816,20 @@ def simplify(
     return geom(*columns).st.simplify(tolerance, preserve_topology)
 
 
+def simplify_vw(*columns: str, area_tolerance: IntoNumericExpr) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[simplify_vw(...)][polars_st.GeoExprNameSpace.simplify_vw]</code>."""  # noqa: E501
+    return geom(*columns).st.simplify_vw(area_tolerance)
+
+
+def simplify_coverage(
+    *columns: str,
+    tolerance: float,
+    preserve_boundary: bool = False,
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[simplify_coverage(...)][polars_st.GeoExprNameSpace.simplify_coverage]</code>."""  # noqa: E501
+    return geom(*columns).st.simplify_coverage(tolerance, preserve_boundary)
+
+
 def force_2d(*columns: str) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[force_2d()][polars_st.GeoExprNameSpace.force_2d]</code>."""  # noqa: E501
     return geom(*columns).st.force_2d()
@@ -496,6 +840,11 @@ def force_3d(*columns: str, z: IntoNumericExpr = 0.0) -> GeoExpr:
     return geom(*columns).st.force_3d(z)
 
 
+def force_4d(*columns: str, z: IntoNumericExpr = 0.0, m: IntoNumericExpr = 0.0) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[force_4d(...)][polars_st.GeoExprNameSpace.force_4d]</code>."""  # noqa: E501
+    return geom(*columns).st.force_4d(z, m)
+
+
 def flip_coordinates(*columns: str) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[flip_coordinates()][polars_st.GeoExprNameSpace.flip_coordinates]</code>."""  # noqa: E501
     return geom(*columns).st.flip_coordinates()
@@ -552,6 +901,11 @@ def skew(
     return geom(*columns).st.skew(x, y, z, origin)
 
 
+def align_to_mrr(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[align_to_mrr()][polars_st.GeoExprNameSpace.align_to_mrr]</code>."""  # noqa: E501
+    return geom(*columns).st.align_to_mrr()
+
+
 def interpolate(
     *columns: str,
     distance: IntoNumericExpr,
@@ -566,14 +920,47 @@ def line_merge(*columns: str, directed: bool = False) -> GeoExpr:
     return geom(*columns).st.line_merge(directed)
 
 
+def points_along(
+    *columns: str,
+    interval: float | None = None,
+    fractions: Sequence[float] | None = None,
+) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[points_along(...)][polars_st.GeoExprNameSpace.points_along]</code>."""  # noqa: E501
+    return geom(*columns).st.points_along(interval, fractions=fractions)
+
+
+def transects(*columns: str, spacing: float, length: float) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[transects(...)][polars_st.GeoExprNameSpace.transects]</code>."""  # noqa: E501
+    return geom(*columns).st.transects(spacing, length)
+
+
+def segments(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[segments()][polars_st.GeoExprNameSpace.segments]</code>."""  # noqa: E501
+    return geom(*columns).st.segments()
+
+
+def segment_azimuths(*columns: str) -> pl.Expr:
+    """Syntactic sugar for <code>st.geom(columns).st.[segment_azimuths()][polars_st.GeoExprNameSpace.segment_azimuths]</code>."""  # noqa: E501
+    return geom(*columns).st.segment_azimuths()
+
+
 def total_bounds(*columns: str) -> pl.Expr:
     """Syntactic sugar for <code>st.geom(columns).st.[total_bounds()][polars_st.GeoExprNameSpace.total_bounds]</code>."""  # noqa: E501
     return geom(*columns).st.total_bounds()
 
 
-def collect(*columns: str, into: GeometryType | None = None) -> GeoExpr:
-    """Syntactic sugar for <code>st.geom(columns).st.[collect()][polars_st.GeoExprNameSpace.collect]</code>."""  # noqa: E501
-    return geom(*columns).st.collect(into)
+def collect(
+    *columns: str,
+    into: GeometryType | None = None,
+    mixed_dimensions: Literal["promote", "drop", "error"] = "promote",
+) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[collect(...)][polars_st.GeoExprNameSpace.collect]</code>."""  # noqa: E501
+    return geom(*columns).st.collect(into, mixed_dimensions)
+
+
+def make_line(*columns: str, order_by: IntoExprColumn | None = None) -> GeoExpr:
+    """Syntactic sugar for <code>st.geom(columns).st.[make_line(...)][polars_st.GeoExprNameSpace.make_line]</code>."""  # noqa: E501
+    return geom(*columns).st.make_line(order_by)
 
 
 def union_all(*columns: str, grid_size: float | None = None) -> GeoExpr:
@@ -611,15 +998,17 @@ def voronoi_polygons(
     tolerance: float = 0.0,
     extend_to: bytes | None = None,
     only_edges: bool = False,
+    per_geometry: bool = False,
 ) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[voronoi_polygons(...)][polars_st.GeoExprNameSpace.voronoi_polygons]</code>."""  # noqa: E501
-    return geom(*columns).st.voronoi_polygons(tolerance, extend_to, only_edges)
+    return geom(*columns).st.voronoi_polygons(tolerance, extend_to, only_edges, per_geometry)
 
 
 def delaunay_triangles(
     *columns: str,
     tolerance: float = 0.0,
     only_edges: bool = False,
+    per_geometry: bool = False,
 ) -> GeoExpr:
     """Syntactic sugar for <code>st.geom(columns).st.[delaunay_triangles(...)][polars_st.GeoExprNameSpace.delaunay_triangles]</code>."""  # noqa: E501
-    return geom(*columns).st.delaunay_triangles(tolerance, only_edges)
+    return geom(*columns).st.delaunay_triangles(tolerance, only_edges, per_geometry)
@@ -0,0 +1,162 @@
+from __future__ import annotations
+
+import json
+from typing import TYPE_CHECKING, Any, cast
+
+import polars as pl
+
+from polars_st._lib import (
+    get_crs_authority,
+    get_registered_crs,
+    get_srid_authority,
+    register_crs,
+)
+from polars_st.parsing import from_geoarrow
+from polars_st.selectors import geom
+from polars_st.utils.srid import get_crs_srid_or_warn
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    import pyarrow as pa
+
+    from polars_st.geometry import GeometryType
+
+EXTENSION_NAME_KEY = b"ARROW:extension:name"
+EXTENSION_METADATA_KEY = b"ARROW:extension:metadata"
+GEOARROW_WKB = "geoarrow.wkb"
+GEOARROW_NATIVE: dict[str, GeometryType] = {
+    "geoarrow.point": "Point",
+    "geoarrow.linestring": "LineString",
+    "geoarrow.polygon": "Polygon",
+    "geoarrow.multipoint": "MultiPoint",
+    "geoarrow.multilinestring": "MultiLineString",
+    "geoarrow.multipolygon": "MultiPolygon",
+}
+
+
+def get_extension_name(field: pa.Field) -> str | None:
+    """Return the extension name of an Arrow field, registered or not."""
+    import pyarrow as pa
+
+    if isinstance(field.type, pa.BaseExtensionType):
+        return field.type.extension_name
+    if field.metadata and (name := field.metadata.get(EXTENSION_NAME_KEY)):
+        return name.decode()
+    return None
+
+
+def get_extension_metadata(field: pa.Field) -> dict[str, Any]:
+    """Return the deserialized extension metadata of an Arrow field."""
+    import pyarrow as pa
+
+    if isinstance(field.type, pa.ExtensionType):
+        metadata = field.type.__arrow_ext_serialize__()
+    elif field.metadata:
+        metadata = field.metadata.get(EXTENSION_METADATA_KEY)
+    else:
+        metadata = None
+    return json.loads(metadata) if metadata else {}
+
+
+def get_crs_srid(crs: str | dict | None) -> int | None:
+    """Return the SRID of a GeoArrow CRS, which can be a string or a PROJJSON object.
+
+    CRS without an EPSG code are registered with [`register_crs`][polars_st.register_crs].
+    """
+    match crs:
+        case None:
+            return None
+        case {"id": {"authority": "EPSG" | "ESRI", "code": code}}:
+            return int(code)
+        case dict():
+            return register_crs(json.dumps(crs))
+        case str() if get_crs_authority(crs) is None:
+            return register_crs(crs)
+        case _:
+            return get_crs_srid_or_warn(crs)
+
+
+def geoarrow_wkb_field(
+    name: str,
+    srid: int | None,
+    edges: str | None = None,
+) -> pa.Field:
+    """Return a binary Arrow field annotated as a `geoarrow.wkb` extension."""
+    import pyarrow as pa
+
+    extension_metadata: dict[str, Any] = {}
+    if srid and (definition := get_registered_crs(srid)) is not None:
+        is_projjson = definition.startswith("{")
+        extension_metadata["crs"] = json.loads(definition) if is_projjson else definition
+    elif srid:
+        extension_metadata["crs"] = f"{get_srid_authority(srid)}:{srid}"
+        extension_metadata["crs_type"] = "authority_code"
+    if edges is not None:
+        extension_metadata["edges"] = edges
+    return pa.field(
+        name,
+        pa.binary(),
+        metadata={
+            EXTENSION_NAME_KEY: GEOARROW_WKB.encode(),
+            EXTENSION_METADATA_KEY: json.dumps(extension_metadata).encode(),
+        },
+    )
+
+
+def from_geoarrow_table(table: pa.Table) -> pl.DataFrame:
+    """Convert an Arrow table with GeoArrow columns into a DataFrame of geometries.
+
+    Both `geoarrow.wkb` and native (`geoarrow.point`, `geoarrow.linestring`, ...)
+    encoded columns are converted.
+    """
+    import pyarrow as pa
+
+    srids: dict[str, int | None] = {}
+    native: dict[str, GeometryType] = {}
+    for index, field in enumerate(table.schema):
+        extension_name = get_extension_name(field)
+        if extension_name != GEOARROW_WKB and extension_name not in GEOARROW_NATIVE:
+            continue
+        srids[field.name] = get_crs_srid(get_extension_metadata(field).get("crs"))
+        column = table.column(index)
+        if isinstance(field.type, pa.BaseExtensionType):
+            column = pa.chunked_array([chunk.storage for chunk in column.chunks])
+        if extension_name == GEOARROW_WKB:
+            column = column.cast(pa.binary())
+        else:
+            native[field.name] = GEOARROW_NATIVE[extension_name]
+        table = table.set_column(index, pa.field(field.name, column.type), column)
+
+    df = cast("pl.DataFrame", pl.from_arrow(table))
+    return df.with_columns(
+        from_geoarrow(name, geometry_type) for name, geometry_type in native.items()
+    ).with_columns(
+        geom(name).st.set_srid(srid) for name, srid in srids.items() if srid is not None
+    )
+
+
+def to_geoarrow_table(
+    df: pl.DataFrame,
+    geometry_columns: Sequence[str],
+    edges: str | None = None,
+) -> pa.Table:
+    """Convert a DataFrame into an Arrow table with `geoarrow.wkb` geometry columns."""
+    import pyarrow as pa
+
+    srids = df.select(geom(*geometry_columns).st.srid().unique().drop_nulls().implode())
+    df = df.with_columns(geom(*geometry_columns).st.to_wkb(flavor="iso"))
+    table = df.to_arrow()
+    for name in geometry_columns:
+        match srids[name].item().to_list():
+            case []:
+                srid = None
+            case [srid]:
+                pass
+            case _:
+                msg = f"Geometry column {name!r} with mixed SRIDs isn't supported"
+                raise ValueError(msg)
+        index = table.schema.get_field_index(name)
+        field = geoarrow_wkb_field(name, srid, edges)
+        table = table.set_column(index, field, table.column(index).cast(pa.binary()))
+    return table
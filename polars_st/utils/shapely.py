@@ -0,0 +1,82 @@
+from __future__ import annotations
+
+import contextlib
+from typing import TYPE_CHECKING
+
+import numpy as np
+import polars as pl
+
+from polars_st import _lib
+
+if TYPE_CHECKING:
+    import numpy.typing as npt
+
+RAGGED_GEOMETRY_TYPES = {
+    1: "POINT",
+    2: "LINESTRING",
+    3: "POLYGON",
+    4: "MULTIPOINT",
+    5: "MULTILINESTRING",
+    6: "MULTIPOLYGON",
+}
+
+
+def to_shapely_array(s: pl.Series) -> npt.NDArray[np.object_]:
+    """Convert a Series of geometries into an array of shapely objects.
+
+    Geometries sharing a single type, or single and multi-part geometries of the
+    same family, are converted through shapely ragged arrays, which avoids creating
+    intermediate WKB objects for every geometry. Others, and geometries mixing 2D
+    and 3D coordinates, are converted from WKB.
+    """
+    import shapely
+
+    ragged = _lib.to_ragged_array(s)
+    if ragged is None:
+        return shapely.from_wkb(s)
+    type_id, dimension, coords, offsets = ragged
+    geoms = shapely.from_ragged_array(
+        shapely.GeometryType[RAGGED_GEOMETRY_TYPES[type_id]],
+        coords.to_numpy().reshape(-1, dimension),
+        tuple(offset.to_numpy() for offset in offsets) or None,
+    )
+    srids = s.st.srid().fill_null(0).to_numpy()
+    if srids.any():
+        geoms = shapely.set_srid(geoms, srids)
+    geoms[s.is_null().to_numpy()] = None
+    return geoms
+
+
+def from_shapely_array(s: pl.Series) -> pl.Series:
+    """Convert a Series of shapely objects into a Series of geometries.
+
+    Geometries are converted through shapely ragged arrays when shapely supports it,
+    and from WKB otherwise.
+    """
+    import shapely
+
+    geoms = s.to_numpy()
+    valid = ~shapely.is_missing(geoms)
+    has_m = hasattr(shapely, "has_m") and shapely.has_m(geoms).any()
+    ragged = None
+    if valid.any() and not has_m:
+        with contextlib.suppress(ValueError):
+            ragged = shapely.to_ragged_array(geoms[valid])
+    if ragged is None:
+        return pl.Series(s.name, list(shapely.to_wkb(geoms, include_srid=True)))
+
+    geometry_type, coords, offsets = ragged
+    type_id = next(k for k, v in RAGGED_GEOMETRY_TYPES.items() if v == geometry_type.name)
+    wkb = _lib.from_ragged_array(
+        type_id,
+        pl.Series(coords.ravel()),
+        coords.shape[1],
+        [pl.Series(offset) for offset in offsets],
+    )
+    srids = shapely.get_srid(geoms[valid])
+    if srids.any():
+        df = pl.DataFrame({"geometry": wkb, "srid": srids})
+        wkb = df.select(pl.col("geometry").st.set_srid(pl.col("srid"))).to_series()
+    if not valid.all():
+        wkb = pl.Series([None] * len(s), dtype=pl.Binary).scatter(np.flatnonzero(valid), wkb)
+    return wkb.alias(s.name)
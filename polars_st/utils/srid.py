@@ -2,7 +2,7 @@ from __future__ import annotations
 
 import logging
 
-from polars_st._lib import get_crs_authority
+from polars_st._lib import get_crs_authority, get_registered_crs, get_srid_authority
 
 logger = logging.getLogger(__name__)
 
@@ -21,3 +21,18 @@ def get_crs_srid_or_warn(crs: str) -> int | None:
         return None
 
     return int(code, base=10)
+
+
+def get_srid_projjson(srid: int) -> dict:
+    """Return the PROJJSON representation of the CRS with the given SRID. Requires `pyproj`."""
+    try:
+        from pyproj import CRS
+    except ImportError as e:
+        msg = (
+            "Writing the CRS as PROJJSON requires pyproj, "
+            "install it with `pip install polars-st[geoparquet]`"
+        )
+        raise ImportError(msg) from e
+
+    definition = get_registered_crs(srid) or f"{get_srid_authority(srid)}:{srid}"
+    return CRS.from_user_input(definition).to_json_dict()
@@ -0,0 +1,108 @@
+use geos::{CoordSeq, GResult, Geom, Geometry, GeometryTypes::*};
+
+use crate::{args::Antimeridian, functions::GeometryUtils};
+
+/// Shifts longitudes by multiples of 360° so that consecutive vertices are
+/// never more than 180° apart.
+///
+/// Once unwrapped, a ring around a pole ends a whole turn away from where it
+/// starts. Such rings are closed along the pole, which is the north pole if
+/// the ring mostly lies in the northern hemisphere, so that they still enclose
+/// it.
+fn unwrap_coords<T: Geom>(geom: &T, ring: bool) -> GResult<CoordSeq> {
+    let (has_z, has_m) = (geom.has_z()?, geom.has_m()?);
+    let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+    let mut coords = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+    let mut previous: Option<f64> = None;
+    let mut offset = 0.0;
+    for coord in coords.chunks_exact_mut(dimension) {
+        let mut lon = coord[0] + offset;
+        if let Some(previous) = previous {
+            let turns = ((lon - previous) / 360.0).round();
+            offset -= 360.0 * turns;
+            lon -= 360.0 * turns;
+        }
+        coord[0] = lon;
+        previous = Some(lon);
+    }
+    if ring && offset.abs() > 180.0 {
+        let (first, last) = (
+            coords[..dimension].to_vec(),
+            coords[coords.len() - dimension..].to_vec(),
+        );
+        let latitudes = coords.iter().skip(1).step_by(dimension);
+        let pole = match latitudes.sum::<f64>() >= 0.0 {
+            true => 90.0,
+            false => -90.0,
+        };
+        for (coord, lon) in [(&last, last[0]), (&first, first[0])] {
+            let mut pole_coord = coord.clone();
+            pole_coord[..2].copy_from_slice(&[lon, pole]);
+            coords.extend(pole_coord);
+        }
+        coords.extend(first);
+    }
+    CoordSeq::new_from_buffer(&coords, coords.len() / dimension, has_z, has_m)
+}
+
+/// Unwraps the longitudes of each line and ring of a geometry, so that those
+/// crossing the antimeridian extend past ±180° instead of wrapping around.
+fn shift<T: Geom>(geom: &T) -> GResult<Geometry> {
+    if geom.is_empty()? {
+        return Geom::clone(geom);
+    }
+    let parts = || {
+        (0..geom.get_num_geometries()?)
+            .map(|n| shift(&geom.get_geometry_n(n)?))
+            .try_collect::<Vec<_>>()
+    };
+    match geom.geometry_type()? {
+        LineString => Geometry::create_line_string(unwrap_coords(geom, false)?),
+        LinearRing => Geometry::create_linear_ring(unwrap_coords(geom, true)?),
+        Polygon => {
+            let exterior = shift(&geom.get_exterior_ring()?)?;
+            let interiors = (0..geom.get_num_interior_rings()?)
+                .map(|n| shift(&geom.get_interior_ring_n(n)?))
+                .try_collect::<Vec<_>>()?;
+            Geometry::create_polygon(exterior, interiors)
+        }
+        MultiLineString => Geometry::create_multiline_string(parts()?),
+        MultiPolygon => Geometry::create_multipolygon(parts()?),
+        GeometryCollection => Geometry::create_geometry_collection(parts()?),
+        _ => Geom::clone(geom),
+    }
+}
+
+/// Unwraps a geometry, then cuts it along the antimeridian and moves the
+/// parts past ±180° back into the [-180°, 180°] range.
+fn split(geom: &Geometry) -> GResult<Geometry> {
+    let shifted = shift(geom)?;
+    if shifted.is_empty()? {
+        return Ok(shifted);
+    }
+    let (x_min, x_max) = (shifted.get_x_min()?, shifted.get_x_max()?);
+    if x_min >= -180.0 && x_max <= 180.0 {
+        return Ok(shifted);
+    }
+    let (y_min, y_max) = (shifted.get_y_min()? - 1.0, shifted.get_y_max()? + 1.0);
+    let first = ((x_min + 180.0) / 360.0).floor() as i32;
+    let last = ((x_max - 180.0) / 360.0).ceil() as i32;
+    let mut parts = Vec::new();
+    for turn in first..=last {
+        let offset = 360.0 * f64::from(turn);
+        let part = shifted.clip_by_rect(offset - 180.0, y_min, offset + 180.0, y_max)?;
+        if !part.is_empty()? {
+            parts.push(part.translate(-offset, 0.0, 0.0)?);
+        }
+    }
+    Geometry::create_geometry_collection(parts)?.unary_union()
+}
+
+/// Fixes the geometries crossing the antimeridian in geographic coordinates.
+pub fn apply(geom: Geometry, antimeridian: Antimeridian) -> GResult<Geometry> {
+    match antimeridian {
+        Antimeridian::Ignore => Ok(geom),
+        Antimeridian::Shift => shift(&geom),
+        Antimeridian::Split => split(&geom),
+    }
+}
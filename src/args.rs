@@ -10,11 +10,32 @@ pub struct ToWktKwargs {
     pub old_3d: bool,
 }
 
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum OnError {
+    Raise,
+    Null,
+    Repair,
+}
+
+#[derive(Deserialize)]
+pub struct FromWkbKwargs {
+    pub on_error: OnError,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WkbFlavor {
+    Extended,
+    Iso,
+}
+
 #[derive(Deserialize)]
 pub struct ToWkbKwargs {
     pub output_dimension: i32,
     pub byte_order: Option<i32>,
     pub include_srid: bool,
+    pub flavor: WkbFlavor,
 }
 
 #[derive(Deserialize)]
@@ -22,6 +43,97 @@ pub struct ToGeoJsonKwargs {
     pub indent: Option<i32>,
 }
 
+#[derive(Deserialize)]
+pub struct ToEsriJsonKwargs {
+    pub indent: Option<usize>,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum GeoArrowDimensions {
+    Xy,
+    Xyz,
+    Xym,
+    Xyzm,
+}
+
+#[derive(Deserialize)]
+pub struct ToGeoArrowKwargs {
+    pub geometry_type: WKBGeometryType,
+    pub dimensions: GeoArrowDimensions,
+}
+
+#[derive(Deserialize)]
+pub struct FromGeoArrowKwargs {
+    pub geometry_type: WKBGeometryType,
+}
+
+#[derive(Deserialize)]
+pub struct ToSvgKwargs {
+    pub view_box: bool,
+    pub flip_y: bool,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum Antimeridian {
+    Ignore,
+    Shift,
+    Split,
+}
+
+#[derive(Deserialize)]
+pub struct ToSridKwargs {
+    pub pipeline: Option<String>,
+    pub antimeridian: Antimeridian,
+}
+
+#[derive(Deserialize)]
+pub struct MortonIndexKwargs {
+    pub extent: Option<[f64; 4]>,
+    pub level: u32,
+}
+
+#[derive(Deserialize)]
+pub struct H3Kwargs {
+    pub resolution: u8,
+}
+
+#[derive(Deserialize)]
+pub struct TileKwargs {
+    pub srid: i32,
+}
+
+#[derive(Deserialize)]
+pub struct TileOfKwargs {
+    pub zoom: u8,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartitionScheme {
+    Grid,
+    Kdb,
+}
+
+#[derive(Deserialize)]
+pub struct SpatialPartitionKwargs {
+    pub scheme: PartitionScheme,
+    pub n: usize,
+    pub extent: Option<[f64; 4]>,
+}
+
+#[derive(Deserialize)]
+pub struct BoundsToSridKwargs {
+    pub densify_points: u32,
+}
+
+#[derive(Deserialize)]
+pub struct CrsTransformKwargs {
+    pub from_crs: String,
+    pub to_crs: String,
+}
+
 #[derive(Deserialize, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum PrecisionMode {
@@ -46,11 +158,47 @@ pub struct SetPrecisionKwargs {
     pub mode: PrecisionMode,
 }
 
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MakeValidMethod {
+    Linework,
+    Structure,
+}
+
+impl From<MakeValidMethod> for geos::MakeValidMethod {
+    #[inline]
+    fn from(val: MakeValidMethod) -> Self {
+        match val {
+            MakeValidMethod::Linework => Self::Linework,
+            MakeValidMethod::Structure => Self::Structure,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct MakeValidKwargs {
+    pub method: MakeValidMethod,
+    pub keep_collapsed: bool,
+}
+
+#[derive(Deserialize)]
+pub struct SnapToGridKwargs {
+    pub size_x: f64,
+    pub size_y: f64,
+    pub origin: [f64; 2],
+}
+
 #[derive(Deserialize)]
 pub struct SimplifyKwargs {
     pub preserve_topology: bool,
 }
 
+#[derive(Deserialize)]
+pub struct SimplifyCoverageKwargs {
+    pub tolerance: f64,
+    pub preserve_boundary: bool,
+}
+
 #[derive(Deserialize)]
 pub struct DWithinKwargs {
     pub distance: f64,
@@ -61,6 +209,16 @@ pub struct DistanceDensifyKwargs {
     pub densify: Option<f64>,
 }
 
+#[derive(Deserialize)]
+pub struct AzimuthKwargs {
+    pub degrees: bool,
+}
+
+#[derive(Deserialize)]
+pub struct KnnKwargs {
+    pub k: usize,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum CapStyle {
@@ -123,6 +281,26 @@ impl TryInto<geos::BufferParams> for &BufferKwargs {
     }
 }
 
+#[derive(Deserialize)]
+pub struct BufferGeodesicKwargs {
+    pub antimeridian: Antimeridian,
+    #[serde(flatten)]
+    pub buffer: BufferKwargs,
+}
+
+#[derive(Deserialize)]
+pub struct SegmentizeGeodesicKwargs {
+    pub antimeridian: Antimeridian,
+}
+
+#[derive(Deserialize)]
+pub struct BufferRingsKwargs {
+    pub distances: Vec<f64>,
+    pub dissolve: bool,
+    #[serde(flatten)]
+    pub buffer: BufferKwargs,
+}
+
 #[derive(Deserialize)]
 pub struct OffsetCurveKwargs {
     pub quad_segs: i32,
@@ -141,6 +319,37 @@ pub struct InterpolateKwargs {
     pub normalized: bool,
 }
 
+#[derive(Deserialize)]
+pub struct PointsAlongKwargs {
+    pub interval: Option<f64>,
+    pub fractions: Option<Vec<f64>>,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtractType {
+    Point,
+    Line,
+    Polygon,
+}
+
+#[derive(Deserialize)]
+pub struct CollectionExtractKwargs {
+    #[serde(rename = "type")]
+    pub geometry_type: ExtractType,
+}
+
+#[derive(Deserialize)]
+pub struct SubdivideKwargs {
+    pub max_vertices: usize,
+}
+
+#[derive(Deserialize)]
+pub struct TransectsKwargs {
+    pub spacing: f64,
+    pub length: f64,
+}
+
 #[derive(Deserialize)]
 pub struct SetOperationKwargs {
     pub grid_size: Option<f64>,
@@ -155,6 +364,8 @@ pub struct EqualsExactKwargs {
 pub struct DelaunayTrianlesKwargs {
     pub only_edges: bool,
     pub tolerance: f64,
+    #[serde(default)]
+    pub per_geometry: bool,
 }
 
 #[derive(Deserialize)]
@@ -162,6 +373,8 @@ pub struct VoronoiKwargs {
     pub tolerance: f64,
     pub extend_to: Option<Vec<u8>>,
     pub only_edges: bool,
+    #[serde(default)]
+    pub per_geometry: bool,
 }
 
 #[derive(Deserialize)]
@@ -182,11 +395,57 @@ pub enum SpatialJoinPredicate {
     Covers,
     CoveredBy,
     ContainsProperly,
+    Dwithin,
+    Relate,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpatialJoinStrategy {
+    Inner,
+    Left,
+    Right,
+    Full,
+    Semi,
+    Anti,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpatialJoinAlgorithm {
+    Rtree,
+    Sweep,
 }
 
 #[derive(Deserialize)]
 pub struct SpatialJoinKwargs {
     pub predicate: SpatialJoinPredicate,
+    pub how: SpatialJoinStrategy,
+    pub distance: Option<f64>,
+    pub include_distance: bool,
+    pub algorithm: SpatialJoinAlgorithm,
+    pub pattern: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct CountMatchesKwargs {
+    pub predicate: SpatialJoinPredicate,
+    pub distance: Option<f64>,
+}
+
+#[derive(Deserialize)]
+pub struct PairsWithinKwargs {
+    pub distance: f64,
+}
+
+#[derive(Deserialize)]
+pub struct DuplicateGeometryKwargs {
+    pub tolerance: Option<f64>,
+}
+
+#[derive(Deserialize)]
+pub struct ConflationKwargs {
+    pub max_distance: f64,
 }
 
 #[derive(Deserialize)]
@@ -226,7 +485,19 @@ pub struct TransformKwargs {
     pub origin: TransformOrigin,
 }
 
+#[derive(Deserialize, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MixedDimensions {
+    #[default]
+    Promote,
+    Drop,
+    Error,
+}
+
 #[derive(Deserialize)]
 pub struct CollectKwargs {
     pub into: Option<WKBGeometryType>,
+    /// Unused by `from_coords`, which shares these kwargs.
+    #[serde(default)]
+    pub mixed_dimensions: MixedDimensions,
 }
@@ -1,9 +1,11 @@
+use std::borrow::Cow;
+
 use polars::prelude::arity::{
     broadcast_try_binary_elementwise, try_binary_elementwise, try_ternary_elementwise,
     try_unary_elementwise,
 };
 use polars::prelude::*;
-use polars_arrow::array::Array;
+use polars_arrow::array::{Array, StaticArray};
 
 #[inline]
 pub fn try_unary_elementwise_values_with_dtype<'a, T, V, F, K, E>(
@@ -84,6 +86,43 @@ where
     })
 }
 
+#[inline]
+pub fn broadcast_try_binary_elementwise_values_with_dtype<T, U, V, F, K, E>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<U>,
+    dtype: DataType,
+    mut op: F,
+) -> Result<ChunkedArray<V>, E>
+where
+    T: PolarsDataType,
+    U: PolarsDataType,
+    V: PolarsDataType,
+    ChunkedArray<T>: ChunkExpandAtIndex<T>,
+    ChunkedArray<U>: ChunkExpandAtIndex<U>,
+    F: for<'a> FnMut(T::Physical<'a>, U::Physical<'a>) -> Result<K, E>,
+    V::Array: ArrayFromIterDtype<Option<K>>,
+{
+    let len = lhs.len().max(rhs.len());
+    let lhs = match lhs.len() {
+        1 if len != 1 => Cow::Owned(lhs.new_from_index(0, len)),
+        _ => Cow::Borrowed(lhs),
+    };
+    let rhs = match rhs.len() {
+        1 if len != 1 => Cow::Owned(rhs.new_from_index(0, len)),
+        _ => Cow::Borrowed(rhs),
+    };
+    let arr: V::Array = lhs
+        .downcast_iter()
+        .flat_map(StaticArray::iter)
+        .zip(rhs.downcast_iter().flat_map(StaticArray::iter))
+        .map(|(a, b)| match (a, b) {
+            (Some(a), Some(b)) => op(a, b).map(Some),
+            _ => Ok(None),
+        })
+        .try_collect_arr_with_dtype(dtype.to_arrow(CompatLevel::newest()))?;
+    Ok(ChunkedArray::with_chunk(lhs.name().clone(), arr))
+}
+
 #[inline]
 pub fn try_ternary_elementwise_values<T, U, G, V, F, K, E>(
     ca1: &ChunkedArray<T>,
@@ -1,5 +1,20 @@
+use std::{
+    collections::HashMap,
+    ops::Range,
+    sync::{LazyLock, PoisonError, RwLock},
+};
+
 use proj4wkt::builder::{Builder, Node};
-use pyo3::prelude::*;
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use crate::{esri, ntv2::Grid};
+
+/// SRIDs assigned to the CRS registered without an explicit SRID.
+const CUSTOM_SRIDS: Range<i32> = 900_000..1_000_000;
+
+/// Definitions of the CRS registered with `register_crs`, by SRID. They take
+/// precedence over the EPSG definitions.
+static REGISTRY: LazyLock<RwLock<HashMap<i32, String>>> = LazyLock::new(Default::default);
 
 fn wkt_to_authority(i: &str) -> Option<(&str, &str)> {
     match Builder::new().parse(i) {
@@ -10,8 +25,8 @@ fn wkt_to_authority(i: &str) -> Option<(&str, &str)> {
 
 #[pyfunction]
 pub fn get_crs_authority(definition: &str) -> Option<(&str, &str)> {
-    if let Some(("EPSG", code)) = definition.split_once(':') {
-        Some(("EPSG", code))
+    if let Some((authority @ ("EPSG" | "ESRI"), code)) = definition.split_once(':') {
+        Some((authority, code))
     } else {
         wkt_to_authority(definition)
     }
@@ -19,8 +34,247 @@ pub fn get_crs_authority(definition: &str) -> Option<(&str, &str)> {
 
 #[pyfunction]
 pub fn get_crs_from_code(srid: i64) -> Option<String> {
+    if let Some(definition) = srid.try_into().ok().and_then(get_registered_crs) {
+        return Some(definition);
+    }
+    if let Some(def) = srid.try_into().ok().and_then(crs_definitions::from_code) {
+        return Some(def.wkt.to_owned());
+    }
     srid.try_into()
+        .ok()
+        .and_then(esri::from_code)
+        .map(|def| def.proj4.to_owned())
+}
+
+/// Returns the authority of an SRID that isn't registered: `ESRI` for the
+/// ESRI codes that aren't EPSG codes, and `EPSG` otherwise.
+#[pyfunction]
+pub fn get_srid_authority(srid: i32) -> &'static str {
+    let is_epsg = u16::try_from(srid)
         .ok()
         .and_then(crs_definitions::from_code)
-        .map(|def| def.wkt.to_owned())
+        .is_some();
+    match !is_epsg && esri::from_code(srid).is_some() {
+        true => "ESRI",
+        false => "EPSG",
+    }
+}
+
+/// FNV-1a hash of a definition, which unlike the hasher of the standard
+/// library doesn't change between processes.
+fn stable_hash(definition: &str) -> u64 {
+    definition
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+/// Returns whether an SRID is an EPSG or ESRI code.
+fn is_known_srid(srid: i32) -> bool {
+    u16::try_from(srid)
+        .ok()
+        .and_then(crs_definitions::from_code)
+        .is_some()
+        || esri::from_code(srid).is_some()
+}
+
+/// Registers a definition without an explicit SRID. The SRID is derived from
+/// the definition, rather than from the order of registration, so that files
+/// written by one process read back the same in another one.
+pub fn register_definition(definition: String) -> i32 {
+    let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+    if let Some((&srid, _)) = registry.iter().find(|(_, d)| **d == definition) {
+        return srid;
+    }
+    let len = CUSTOM_SRIDS.len() as u64;
+    let start = stable_hash(&definition) % len;
+    let srid = (0..len)
+        .map(|offset| CUSTOM_SRIDS.start + ((start + offset) % len) as i32)
+        .find(|srid| !registry.contains_key(srid))
+        .expect("SRIDs are exhausted");
+    registry.insert(srid, definition);
+    srid
+}
+
+#[pyfunction]
+#[pyo3(signature = (definition, srid=None, overwrite=false))]
+pub fn register_crs(definition: String, srid: Option<i32>, overwrite: bool) -> PyResult<i32> {
+    let Some(srid) = srid else {
+        return Ok(register_definition(definition));
+    };
+    let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+    let taken = match registry.get(&srid) {
+        Some(registered) => *registered != definition,
+        None => is_known_srid(srid),
+    };
+    if taken && !overwrite {
+        return Err(PyValueError::new_err(format!(
+            "SRID {srid} already refers to another CRS, use `overwrite=True` to replace it"
+        )));
+    }
+    registry.insert(srid, definition);
+    Ok(srid)
+}
+
+#[pyfunction]
+pub fn get_registered_crs(srid: i32) -> Option<String> {
+    let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+    registry.get(&srid).cloned()
+}
+
+/// Paths of the datum shift grids registered with `register_datum_grids`, by
+/// SRID.
+static DATUM_GRIDS: LazyLock<RwLock<HashMap<i32, Vec<String>>>> = LazyLock::new(Default::default);
+
+#[pyfunction]
+pub fn register_datum_grids(srid: i32, grids: Vec<String>) -> PyResult<()> {
+    let mut registry = DATUM_GRIDS.write().unwrap_or_else(PoisonError::into_inner);
+    if grids.is_empty() {
+        registry.remove(&srid);
+        return Ok(());
+    }
+    // Grids are loaded now, so that invalid files are reported on registration.
+    for path in &grids {
+        Grid::load(path).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    }
+    registry.insert(srid, grids);
+    Ok(())
+}
+
+pub fn get_datum_grids(srid: i32) -> Option<Vec<String>> {
+    let registry = DATUM_GRIDS.read().unwrap_or_else(PoisonError::into_inner);
+    registry.get(&srid).cloned()
+}
+
+/// Returns the PROJ string of a registered CRS, or of an EPSG or ESRI code.
+pub fn get_proj_string(srid: i32) -> Option<String> {
+    match get_registered_crs(srid) {
+        Some(definition) if definition.trim_start().starts_with('+') => Some(definition),
+        Some(definition) => proj4wkt::wkt_to_projstring(&definition).ok(),
+        None => match srid.try_into().ok().and_then(crs_definitions::from_code) {
+            Some(definition) => Some(definition.proj4.to_owned()),
+            None => esri::from_code(srid).map(|definition| definition.proj4.to_owned()),
+        },
+    }
+}
+
+/// Splits a WKT1 compound CRS into its horizontal CRS and the geoid grids of
+/// its vertical CRS, given by a `PROJ4_GRIDS` extension as written by GDAL.
+pub fn split_compound_wkt(wkt: &str) -> Option<(&str, Option<&str>)> {
+    let body = wkt.strip_prefix("COMPD_CS[")?;
+    let start = ["PROJCS[", "GEOGCS["]
+        .iter()
+        .filter_map(|node| body.find(node))
+        .min()?;
+    let mut depth = 0;
+    let end = body[start..].char_indices().find_map(|(i, c)| {
+        match c {
+            '[' => depth += 1,
+            ']' if depth == 1 => return Some(start + i + 1),
+            ']' => depth -= 1,
+            _ => {}
+        }
+        None
+    })?;
+    let grids = body.split("EXTENSION[").skip(1).find_map(|extension| {
+        // Splitting on quotes yields the quoted strings at odd positions.
+        let mut strings = extension.split('"').skip(1).step_by(2);
+        (strings.next()? == "PROJ4_GRIDS").then(|| strings.next())?
+    });
+    Some((&body[start..end], grids))
+}
+
+/// Description of a CRS, as returned by the `crs_info` expression.
+#[derive(Clone)]
+pub struct CrsInfo {
+    pub authority: Option<String>,
+    pub name: Option<String>,
+    pub units: Option<String>,
+    pub is_geographic: bool,
+    pub axis_order: Option<String>,
+}
+
+fn proj_param<'a>(projstring: &'a str, key: &str) -> Option<&'a str> {
+    projstring
+        .split_whitespace()
+        .filter_map(|param| param.strip_prefix('+')?.split_once('='))
+        .find_map(|(k, v)| (k == key).then_some(v))
+}
+
+/// Returns the directions of the outermost axes of a WKT CRS, which are the
+/// last ones in both WKT1 and WKT2.
+fn wkt_axis_order(wkt: &str) -> Option<String> {
+    let directions = wkt
+        .split("AXIS[")
+        .skip(1)
+        .filter_map(|axis| axis.split([',', ']']).nth(1))
+        .map(|direction| direction.trim().to_lowercase())
+        .collect::<Vec<_>>();
+    let count = if wkt.trim_start().starts_with("GEOCCS") {
+        3
+    } else {
+        2
+    };
+    match directions.len() {
+        // Axes default to east, north in WKT1.
+        0 => Some("east,north".into()),
+        n => Some(directions[n.saturating_sub(count)..].join(",")),
+    }
+}
+
+pub fn get_crs_info(srid: i32) -> Option<CrsInfo> {
+    let (wkt, projstring, authority) = match get_registered_crs(srid) {
+        Some(definition) if definition.trim_start().starts_with('+') => {
+            (None, Some(definition), None)
+        }
+        Some(definition) => {
+            let projstring = proj4wkt::wkt_to_projstring(&definition).ok();
+            let authority = wkt_to_authority(&definition).map(|(name, _)| name.to_owned());
+            (Some(definition), projstring, authority)
+        }
+        None => match srid.try_into().ok().and_then(crs_definitions::from_code) {
+            Some(definition) => (
+                Some(definition.wkt.to_owned()),
+                Some(definition.proj4.to_owned()),
+                Some("EPSG".into()),
+            ),
+            None => {
+                let definition = esri::from_code(srid)?;
+                let info = CrsInfo {
+                    authority: Some("ESRI".into()),
+                    name: Some(definition.name.into()),
+                    units: Some("m".into()),
+                    is_geographic: false,
+                    axis_order: Some("east,north".into()),
+                };
+                return Some(info);
+            }
+        },
+    };
+    let proj = projstring.as_deref().and_then(|p| proj_param(p, "proj"));
+    let is_geographic = matches!(proj, Some("longlat" | "latlong" | "lonlat" | "latlon"));
+    let units = match (is_geographic, &projstring) {
+        (true, _) => Some("degree".into()),
+        (false, Some(projstring)) => match proj_param(projstring, "units") {
+            Some(units) => Some(units.to_owned()),
+            None if proj_param(projstring, "to_meter").is_some() => None,
+            None => Some("m".into()),
+        },
+        (false, None) => None,
+    };
+    let axis_order = match &wkt {
+        Some(wkt) => wkt_axis_order(wkt),
+        None => Some("east,north".into()),
+    };
+    Some(CrsInfo {
+        authority,
+        name: wkt
+            .as_deref()
+            .and_then(|wkt| wkt.split('"').nth(1))
+            .map(Into::into),
+        units,
+        is_geographic,
+        axis_order,
+    })
 }
@@ -0,0 +1,341 @@
+use std::f64::consts::{PI, TAU};
+
+use geos::{CoordSeq, Error as GError, GResult, Geom, Geometry, GeometryTypes::*};
+
+/// Returns whether a geometry contains circular arcs.
+pub fn has_curves<T: Geom>(geom: &T) -> GResult<bool> {
+    match geom.geometry_type()? {
+        CircularString => Ok(!geom.is_empty()?),
+        CurvePolygon => {
+            if geom.is_empty()? {
+                return Ok(false);
+            }
+            if has_curves(&geom.get_exterior_ring()?)? {
+                return Ok(true);
+            }
+            for n in 0..geom.get_num_interior_rings()? {
+                if has_curves(&geom.get_interior_ring_n(n)?)? {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+        CompoundCurve | MultiCurve | MultiSurface | GeometryCollection => {
+            for n in 0..geom.get_num_geometries()? {
+                if has_curves(&geom.get_geometry_n(n)?)? {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Returns the center of the circle going through three points, or `None`
+/// if they are collinear.
+fn circle_center(a: &[f64], b: &[f64], c: &[f64]) -> Option<[f64; 2]> {
+    let d = 2.0 * (a[0] * (b[1] - c[1]) + b[0] * (c[1] - a[1]) + c[0] * (a[1] - b[1]));
+    if d.abs() <= f64::EPSILON * (a[0].abs() + b[0].abs() + c[0].abs()).max(1.0) {
+        return None;
+    }
+    let (a2, b2, c2) = (
+        a[0] * a[0] + a[1] * a[1],
+        b[0] * b[0] + b[1] * b[1],
+        c[0] * c[0] + c[1] * c[1],
+    );
+    Some([
+        (a2 * (b[1] - c[1]) + b2 * (c[1] - a[1]) + c2 * (a[1] - b[1])) / d,
+        (a2 * (c[0] - b[0]) + b2 * (a[0] - c[0]) + c2 * (b[0] - a[0])) / d,
+    ])
+}
+
+/// Appends the vertices of the arc of `center` going from `from` to `to`
+/// over `sweep` radians, excluding `from`. Z and M values are interpolated
+/// linearly.
+#[allow(clippy::cast_sign_loss, clippy::cast_precision_loss)]
+fn push_arc(
+    coords: &mut Vec<f64>,
+    center: [f64; 2],
+    from: &[f64],
+    to: &[f64],
+    sweep: f64,
+    tolerance: f64,
+) {
+    let radius = (from[0] - center[0]).hypot(from[1] - center[1]);
+    let start = (from[1] - center[1]).atan2(from[0] - center[0]);
+    let max_step = if tolerance < radius {
+        2.0 * (1.0 - tolerance / radius).acos()
+    } else {
+        PI
+    };
+    let segments = (sweep.abs() / max_step).ceil().max(1.0) as usize;
+    for i in 1..segments {
+        let fraction = i as f64 / segments as f64;
+        let angle = start + sweep * fraction;
+        coords.push(center[0] + radius * angle.cos());
+        coords.push(center[1] + radius * angle.sin());
+        coords.extend((2..from.len()).map(|d| from[d] + (to[d] - from[d]) * fraction));
+    }
+    coords.extend_from_slice(to);
+}
+
+/// Linearizes the arcs of a circular string, given as coordinates of
+/// `dimension` values, so that the vertices deviate from the arcs by at most
+/// `tolerance`. Middle points of arcs are kept.
+fn linearize_arcs(arcs: &[f64], dimension: usize, tolerance: f64) -> Vec<f64> {
+    let mut coords = arcs[..dimension.min(arcs.len())].to_vec();
+    for arc in arcs.windows(3 * dimension).step_by(2 * dimension) {
+        let (p0, rest) = arc.split_at(dimension);
+        let (p1, p2) = rest.split_at(dimension);
+        let full_circle = p0[0].to_bits() == p2[0].to_bits() && p0[1].to_bits() == p2[1].to_bits();
+        let center = if full_circle {
+            Some([(p0[0] + p1[0]) / 2.0, (p0[1] + p1[1]) / 2.0])
+        } else {
+            circle_center(p0, p1, p2)
+        };
+        let Some(center) = center else {
+            coords.extend_from_slice(p1);
+            coords.extend_from_slice(p2);
+            continue;
+        };
+        let cross = (p1[0] - p0[0]) * (p2[1] - p1[1]) - (p1[1] - p0[1]) * (p2[0] - p1[0]);
+        let angle = |p: &[f64]| (p[1] - center[1]).atan2(p[0] - center[0]);
+        // Full circles are drawn counter-clockwise.
+        let ccw = full_circle || cross > 0.0;
+        let sweep = |from: f64, to: f64| {
+            if ccw {
+                (to - from).rem_euclid(TAU)
+            } else {
+                -(from - to).rem_euclid(TAU)
+            }
+        };
+        let (a0, a1, a2) = (angle(p0), angle(p1), angle(p2));
+        push_arc(&mut coords, center, p0, p1, sweep(a0, a1), tolerance);
+        push_arc(&mut coords, center, p1, p2, sweep(a1, a2), tolerance);
+    }
+    coords
+}
+
+/// Returns the linearized coordinates of a linear or curved line.
+fn curve_coords<T: Geom>(geom: &T, dimension: usize, tolerance: f64) -> GResult<Vec<f64>> {
+    match geom.geometry_type()? {
+        _ if geom.is_empty()? => Ok(vec![]),
+        LineString | LinearRing => geom.get_coord_seq()?.as_buffer(Some(dimension)),
+        CircularString => {
+            let arcs = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+            Ok(linearize_arcs(&arcs, dimension, tolerance))
+        }
+        CompoundCurve => {
+            let mut coords = Vec::new();
+            for n in 0..geom.get_num_geometries()? {
+                let part = curve_coords(&geom.get_geometry_n(n)?, dimension, tolerance)?;
+                // Consecutive components share their end points.
+                let skip = if coords.is_empty() { 0 } else { dimension };
+                coords.extend_from_slice(&part[skip.min(part.len())..]);
+            }
+            Ok(coords)
+        }
+        other => Err(GError::GenericError(format!(
+            "Curves can't contain geometries of type {other:?}"
+        ))),
+    }
+}
+
+fn line_coord_seq<T: Geom>(geom: &T, tolerance: f64) -> GResult<CoordSeq> {
+    let (has_z, has_m) = (geom.has_z()?, geom.has_m()?);
+    let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+    let coords = curve_coords(geom, dimension, tolerance)?;
+    CoordSeq::new_from_buffer(&coords, coords.len() / dimension, has_z, has_m)
+}
+
+fn linearize_ring<T: Geom>(ring: &T, tolerance: f64) -> GResult<Geometry> {
+    Geometry::create_linear_ring(line_coord_seq(ring, tolerance)?)
+}
+
+/// Replaces the circular arcs of a geometry by line segments deviating from
+/// them by at most `tolerance`, and curved geometry types by their linear
+/// counterparts. Other geometries are returned as is.
+pub fn linearize<T: Geom>(geom: &T, tolerance: f64) -> GResult<Geometry> {
+    let parts = || {
+        (0..geom.get_num_geometries()?)
+            .map(|n| linearize(&geom.get_geometry_n(n)?, tolerance))
+            .try_collect::<Vec<_>>()
+    };
+    let mut linear = match geom.geometry_type()? {
+        CircularString | CompoundCurve if geom.is_empty()? => Geometry::create_empty_line_string(),
+        CurvePolygon if geom.is_empty()? => Geometry::create_empty_polygon(),
+        MultiCurve if geom.is_empty()? => Geometry::create_empty_collection(MultiLineString),
+        MultiSurface if geom.is_empty()? => Geometry::create_empty_collection(MultiPolygon),
+        CircularString | CompoundCurve => {
+            Geometry::create_line_string(line_coord_seq(geom, tolerance)?)
+        }
+        CurvePolygon => {
+            let exterior = linearize_ring(&geom.get_exterior_ring()?, tolerance)?;
+            let interiors = (0..geom.get_num_interior_rings()?)
+                .map(|n| linearize_ring(&geom.get_interior_ring_n(n)?, tolerance))
+                .try_collect::<Vec<_>>()?;
+            Geometry::create_polygon(exterior, interiors)
+        }
+        MultiCurve => Geometry::create_multiline_string(parts()?),
+        MultiSurface => Geometry::create_multipolygon(parts()?),
+        GeometryCollection => Geometry::create_geometry_collection(parts()?),
+        _ => return Geom::clone(geom),
+    }?;
+    linear.set_srid(geom.get_srid()?);
+    Ok(linear)
+}
+
+/// Largest angle between consecutive vertices for them to be recognized as
+/// part of an arc, so that regular polygons such as squares aren't.
+const MAX_ARC_STEP: f64 = PI / 4.0;
+
+/// Returns the index of the last vertex of the arc starting at vertex
+/// `start`, if at least 4 vertices deviate from a common circle by at most
+/// `tolerance` and turn in the same direction.
+fn arc_end(coords: &[f64], dimension: usize, start: usize, tolerance: f64) -> Option<usize> {
+    let len = coords.len() / dimension;
+    let coord = |i: usize| &coords[i * dimension..(i + 1) * dimension];
+    if start + 3 >= len {
+        return None;
+    }
+    let center = circle_center(coord(start), coord(start + 1), coord(start + 2))?;
+    let radius = (coord(start)[0] - center[0]).hypot(coord(start)[1] - center[1]);
+    let step = |from: &[f64], to: &[f64]| {
+        let (ax, ay) = (from[0] - center[0], from[1] - center[1]);
+        let (bx, by) = (to[0] - center[0], to[1] - center[1]);
+        (ax * by - ay * bx).atan2(ax * bx + ay * by)
+    };
+    let ccw = step(coord(start), coord(start + 1)) > 0.0;
+    let mut end = start;
+    while end + 1 < len {
+        let next = coord(end + 1);
+        let angle = step(coord(end), next);
+        if (angle > 0.0) != ccw
+            || angle.abs() > MAX_ARC_STEP
+            || ((next[0] - center[0]).hypot(next[1] - center[1]) - radius).abs() > tolerance
+        {
+            break;
+        }
+        end += 1;
+    }
+    (end >= start + 3).then_some(end)
+}
+
+/// Splits the coordinates of a line into runs of straight segments and runs
+/// of arcs, given as the coordinates of a circular string.
+fn recognize_arcs(coords: &[f64], dimension: usize, tolerance: f64) -> Vec<(bool, Vec<f64>)> {
+    let len = coords.len() / dimension;
+    let coord = |i: usize| &coords[i * dimension..(i + 1) * dimension];
+    let mut runs: Vec<(bool, Vec<f64>)> = Vec::new();
+    let mut push = |is_arc: bool, points: &[&[f64]]| match runs.last_mut() {
+        Some((last_is_arc, last)) if *last_is_arc == is_arc => {
+            points[1..].iter().for_each(|p| last.extend_from_slice(p));
+        }
+        _ => runs.push((is_arc, points.concat())),
+    };
+    let mut start = 0;
+    while start + 1 < len {
+        let Some(end) = arc_end(coords, dimension, start, tolerance) else {
+            push(false, &[coord(start), coord(start + 1)]);
+            start += 1;
+            continue;
+        };
+        let closed = coord(start)[..2]
+            .iter()
+            .zip(&coord(end)[..2])
+            .all(|(a, b)| a.to_bits() == b.to_bits());
+        // An arc can't end where it starts, so full circles are split in two.
+        if closed {
+            let mid = (start + end) / 2;
+            push(true, &[coord(start), coord((start + mid) / 2), coord(mid)]);
+            push(true, &[coord(mid), coord((mid + end) / 2), coord(end)]);
+        } else {
+            push(true, &[coord(start), coord((start + end) / 2), coord(end)]);
+        }
+        start = end;
+    }
+    runs
+}
+
+/// Returns the curve made of the arcs recognized in a line, or `None` if
+/// there isn't any.
+fn line_arcs<T: Geom>(geom: &T, tolerance: f64) -> GResult<Option<Geometry>> {
+    let (has_z, has_m) = (geom.has_z()?, geom.has_m()?);
+    let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+    let coords = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+    let runs = recognize_arcs(&coords, dimension, tolerance);
+    if !runs.iter().any(|(is_arc, _)| *is_arc) {
+        return Ok(None);
+    }
+    let mut parts = runs
+        .into_iter()
+        .map(|(is_arc, coords)| {
+            let seq = CoordSeq::new_from_buffer(&coords, coords.len() / dimension, has_z, has_m)?;
+            if is_arc {
+                Geometry::create_circular_string(seq)
+            } else {
+                Geometry::create_line_string(seq)
+            }
+        })
+        .try_collect::<Vec<_>>()?;
+    match parts.len() {
+        1 => Ok(parts.pop()),
+        _ => Geometry::create_compound_curve(parts).map(Some),
+    }
+}
+
+/// Returns the curve made of the arcs recognized in a ring, or the ring
+/// itself, and whether arcs were found.
+fn ring_to_curve<T: Geom>(ring: &T, tolerance: f64) -> GResult<(bool, Geometry)> {
+    match line_arcs(ring, tolerance)? {
+        Some(curve) => Ok((true, curve)),
+        None => Ok((false, Geom::clone(ring)?)),
+    }
+}
+
+/// Replaces the runs of vertices of a geometry lying on circular arcs, up to
+/// `tolerance`, by actual arcs, and linear geometry types by their curved
+/// counterparts when arcs were found. This is the inverse of [`linearize`].
+pub fn line_to_curve<T: Geom>(geom: &T, tolerance: f64) -> GResult<Geometry> {
+    let mut curved = match geom.geometry_type()? {
+        _ if geom.is_empty()? => return Geom::clone(geom),
+        LineString => match line_arcs(geom, tolerance)? {
+            Some(curve) => curve,
+            None => return Geom::clone(geom),
+        },
+        Polygon => {
+            let (mut found, exterior) = ring_to_curve(&geom.get_exterior_ring()?, tolerance)?;
+            let mut interiors = Vec::new();
+            for n in 0..geom.get_num_interior_rings()? {
+                let (ring_found, ring) = ring_to_curve(&geom.get_interior_ring_n(n)?, tolerance)?;
+                found |= ring_found;
+                interiors.push(ring);
+            }
+            if !found {
+                return Geom::clone(geom);
+            }
+            Geometry::create_curve_polygon(exterior, interiors)?
+        }
+        MultiLineString | MultiPolygon | GeometryCollection => {
+            let mut found = false;
+            let mut parts = Vec::new();
+            for n in 0..geom.get_num_geometries()? {
+                let part = line_to_curve(&geom.get_geometry_n(n)?, tolerance)?;
+                found |= has_curves(&part)?;
+                parts.push(part);
+            }
+            if !found {
+                return Geom::clone(geom);
+            }
+            match geom.geometry_type()? {
+                MultiLineString => Geometry::create_multicurve(parts),
+                MultiPolygon => Geometry::create_multisurface(parts),
+                _ => Geometry::create_geometry_collection(parts),
+            }?
+        }
+        _ => return Geom::clone(geom),
+    };
+    curved.set_srid(geom.get_srid()?);
+    Ok(curved)
+}
@@ -0,0 +1,112 @@
+use std::fmt::{Display, Write};
+
+use geos::{Error as GError, GResult, Geom, Geometry, GeometryTypes::*};
+
+const DEFAULT_LAYER: &str = "0";
+const CLOSED: u8 = 1;
+const POLYLINE_3D: u8 = 8;
+const VERTEX_3D: u8 = 32;
+
+fn group(out: &mut String, code: u16, value: impl Display) {
+    let _ = write!(out, "{code:>3}\n{value}\n");
+}
+
+fn write_point(coords: &[f64], has_z: bool, out: &mut String) {
+    group(out, 10, coords[0]);
+    group(out, 20, coords[1]);
+    if has_z {
+        group(out, 30, coords[2]);
+    }
+}
+
+/// Writes a `POLYLINE` entity followed by its `VERTEX` entities. Closed
+/// polylines don't repeat their first vertex.
+fn write_polyline<T: Geom>(geom: &T, closed: bool, layer: &str, out: &mut String) -> GResult<()> {
+    if geom.is_empty()? {
+        return Ok(());
+    }
+    let has_z = geom.has_z()?;
+    let dimension = 2 + usize::from(has_z);
+    let mut buffer = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+    if closed {
+        buffer.truncate(buffer.len() - dimension);
+    }
+    let mut flags = 0;
+    if closed {
+        flags |= CLOSED;
+    }
+    if has_z {
+        flags |= POLYLINE_3D;
+    }
+    group(out, 0, "POLYLINE");
+    group(out, 8, layer);
+    group(out, 66, 1);
+    group(out, 70, flags);
+    write_point(&[0.0, 0.0, 0.0], true, out);
+    for coords in buffer.chunks_exact(dimension) {
+        group(out, 0, "VERTEX");
+        group(out, 8, layer);
+        write_point(coords, has_z, out);
+        if has_z {
+            group(out, 70, VERTEX_3D);
+        }
+    }
+    group(out, 0, "SEQEND");
+    group(out, 8, layer);
+    Ok(())
+}
+
+fn write_entities<T: Geom>(geom: &T, layer: &str, out: &mut String) -> GResult<()> {
+    match geom.geometry_type()? {
+        Point => {
+            if !geom.is_empty()? {
+                let has_z = geom.has_z()?;
+                let coords = geom
+                    .get_coord_seq()?
+                    .as_buffer(Some(2 + usize::from(has_z)))?;
+                group(out, 0, "POINT");
+                group(out, 8, layer);
+                write_point(&coords, has_z, out);
+            }
+        }
+        LineString => write_polyline(geom, false, layer, out)?,
+        LinearRing => write_polyline(geom, true, layer, out)?,
+        Polygon => {
+            if !geom.is_empty()? {
+                write_polyline(&geom.get_exterior_ring()?, true, layer, out)?;
+                for n in 0..geom.get_num_interior_rings()? {
+                    write_polyline(&geom.get_interior_ring_n(n)?, true, layer, out)?;
+                }
+            }
+        }
+        MultiPoint | MultiLineString | MultiPolygon | GeometryCollection => {
+            for n in 0..geom.get_num_geometries()? {
+                write_entities(&geom.get_geometry_n(n)?, layer, out)?;
+            }
+        }
+        other => {
+            let msg = format!("DXF output doesn't support geometries of type {other:?}");
+            return Err(GError::GenericError(msg));
+        }
+    }
+    Ok(())
+}
+
+/// Serializes geometries as the entities of an ASCII DXF (R12) document.
+///
+/// Points are written as `POINT` entities, and linestrings and polygon rings
+/// as `POLYLINE` entities, closed for rings. Each geometry is written on its
+/// layer, or on the default layer `0`. M values are discarded.
+pub fn to_dxf(geoms: &[Option<Geometry>], layers: &[Option<&str>]) -> GResult<String> {
+    let mut out = String::new();
+    group(&mut out, 0, "SECTION");
+    group(&mut out, 2, "ENTITIES");
+    for (geom, layer) in geoms.iter().zip(layers) {
+        if let Some(geom) = geom {
+            write_entities(geom, layer.unwrap_or(DEFAULT_LAYER), &mut out)?;
+        }
+    }
+    group(&mut out, 0, "ENDSEC");
+    group(&mut out, 0, "EOF");
+    Ok(out)
+}
@@ -0,0 +1,45 @@
+/// A CRS defined by ESRI, with its name and PROJ string.
+pub struct Def {
+    pub code: i32,
+    pub name: &'static str,
+    pub proj4: &'static str,
+}
+
+const fn def(code: i32, name: &'static str, proj4: &'static str) -> Def {
+    Def { code, name, proj4 }
+}
+
+/// Commonly used ESRI CRS, which have no EPSG equivalent. Coverage is partial:
+/// the world, continental and polar projections and Web Mercator are built in,
+/// but not the thousands of other ESRI codes, such as the StatePlane zones
+/// (102600 to 102799). Those must be registered with `register_crs`, or are
+/// looked up by libproj with the `proj` feature.
+#[rustfmt::skip]
+const DEFINITIONS: &[Def] = &[
+    def(54009, "World_Mollweide", "+proj=moll +lon_0=0 +x_0=0 +y_0=0 +datum=WGS84 +units=m"),
+    def(54034, "World_Cylindrical_Equal_Area", "+proj=cea +lat_ts=0 +lon_0=0 +x_0=0 +y_0=0 +datum=WGS84 +units=m"),
+    def(102001, "Canada_Albers_Equal_Area_Conic", "+proj=aea +lat_0=40 +lon_0=-96 +lat_1=50 +lat_2=70 +x_0=0 +y_0=0 +datum=NAD83 +units=m"),
+    def(102002, "Canada_Lambert_Conformal_Conic", "+proj=lcc +lat_0=40 +lon_0=-96 +lat_1=50 +lat_2=70 +x_0=0 +y_0=0 +datum=NAD83 +units=m"),
+    def(102003, "USA_Contiguous_Albers_Equal_Area_Conic", "+proj=aea +lat_0=37.5 +lon_0=-96 +lat_1=29.5 +lat_2=45.5 +x_0=0 +y_0=0 +datum=NAD83 +units=m"),
+    def(102004, "USA_Contiguous_Lambert_Conformal_Conic", "+proj=lcc +lat_0=39 +lon_0=-96 +lat_1=33 +lat_2=45 +x_0=0 +y_0=0 +datum=NAD83 +units=m"),
+    def(102006, "NAD_1983_Alaska_Albers", "+proj=aea +lat_0=50 +lon_0=-154 +lat_1=55 +lat_2=65 +x_0=0 +y_0=0 +datum=NAD83 +units=m"),
+    def(102007, "Hawaii_Albers_Equal_Area_Conic", "+proj=aea +lat_0=13 +lon_0=-157 +lat_1=8 +lat_2=18 +x_0=0 +y_0=0 +datum=NAD83 +units=m"),
+    def(102008, "North_America_Albers_Equal_Area_Conic", "+proj=aea +lat_0=40 +lon_0=-96 +lat_1=20 +lat_2=60 +x_0=0 +y_0=0 +datum=NAD83 +units=m"),
+    def(102009, "North_America_Lambert_Conformal_Conic", "+proj=lcc +lat_0=40 +lon_0=-96 +lat_1=20 +lat_2=60 +x_0=0 +y_0=0 +datum=NAD83 +units=m"),
+    def(102013, "Europe_Albers_Equal_Area_Conic", "+proj=aea +lat_0=30 +lon_0=10 +lat_1=43 +lat_2=62 +x_0=0 +y_0=0 +ellps=intl +towgs84=-87,-98,-121,0,0,0,0 +units=m"),
+    def(102014, "Europe_Lambert_Conformal_Conic", "+proj=lcc +lat_0=30 +lon_0=10 +lat_1=43 +lat_2=62 +x_0=0 +y_0=0 +ellps=intl +towgs84=-87,-98,-121,0,0,0,0 +units=m"),
+    def(102016, "North_Pole_Azimuthal_Equidistant", "+proj=aeqd +lat_0=90 +lon_0=0 +x_0=0 +y_0=0 +datum=WGS84 +units=m"),
+    def(102017, "North_Pole_Lambert_Azimuthal_Equal_Area", "+proj=laea +lat_0=90 +lon_0=0 +x_0=0 +y_0=0 +datum=WGS84 +units=m"),
+    def(102018, "North_Pole_Stereographic", "+proj=stere +lat_0=90 +lon_0=0 +k=1 +x_0=0 +y_0=0 +datum=WGS84 +units=m"),
+    def(102019, "South_Pole_Azimuthal_Equidistant", "+proj=aeqd +lat_0=-90 +lon_0=0 +x_0=0 +y_0=0 +datum=WGS84 +units=m"),
+    def(102020, "South_Pole_Lambert_Azimuthal_Equal_Area", "+proj=laea +lat_0=-90 +lon_0=0 +x_0=0 +y_0=0 +datum=WGS84 +units=m"),
+    def(102021, "South_Pole_Stereographic", "+proj=stere +lat_0=-90 +lon_0=0 +k=1 +x_0=0 +y_0=0 +datum=WGS84 +units=m"),
+    def(102039, "USA_Contiguous_Albers_Equal_Area_Conic_USGS_version", "+proj=aea +lat_0=23 +lon_0=-96 +lat_1=29.5 +lat_2=45.5 +x_0=0 +y_0=0 +datum=NAD83 +units=m"),
+    def(102100, "WGS_1984_Web_Mercator_Auxiliary_Sphere", "+proj=merc +a=6378137 +b=6378137 +lat_ts=0 +lon_0=0 +x_0=0 +y_0=0 +k=1 +units=m +nadgrids=@null +wktext +no_defs"),
+    def(102113, "WGS_1984_Web_Mercator", "+proj=merc +a=6378137 +b=6378137 +lat_ts=0 +lon_0=0 +x_0=0 +y_0=0 +k=1 +units=m +nadgrids=@null +wktext +no_defs"),
+];
+
+/// Returns the definition of an ESRI CRS code.
+pub fn from_code(code: i32) -> Option<&'static Def> {
+    DEFINITIONS.iter().find(|def| def.code == code)
+}
@@ -0,0 +1,310 @@
+use geos::{CoordSeq, Error as GError, GResult, Geom, Geometry, GeometryTypes::*};
+use serde::Serialize;
+use serde_json::{json, ser::PrettyFormatter, Map, Serializer, Value};
+
+fn invalid(msg: &str) -> GError {
+    GError::GenericError(format!("Invalid EsriJSON: {msg}"))
+}
+
+/// Returns twice the signed area of a ring, positive when counter-clockwise.
+fn signed_area(coords: &[Vec<f64>]) -> f64 {
+    coords
+        .windows(2)
+        .map(|w| w[0][0] * w[1][1] - w[1][0] * w[0][1])
+        .sum()
+}
+
+/// Even-odd ray casting test of a point against a closed ring.
+fn ring_contains(ring: &[Vec<f64>], x: f64, y: f64) -> bool {
+    ring.windows(2).fold(false, |inside, w| {
+        let (x1, y1, x2, y2) = (w[0][0], w[0][1], w[1][0], w[1][1]);
+        if (y1 > y) != (y2 > y) && x < (x2 - x1) * (y - y1) / (y2 - y1) + x1 {
+            !inside
+        } else {
+            inside
+        }
+    })
+}
+
+fn coords_to_vec<T: Geom>(geom: &T, dimension: usize) -> GResult<Vec<Vec<f64>>> {
+    if geom.is_empty()? {
+        return Ok(vec![]);
+    }
+    let buffer = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+    Ok(buffer
+        .chunks_exact(dimension)
+        .map(<[f64]>::to_vec)
+        .collect())
+}
+
+/// EsriJSON rings are clockwise for exteriors and counter-clockwise for holes.
+fn ring_to_vec<T: Geom>(ring: &T, dimension: usize, exterior: bool) -> GResult<Vec<Vec<f64>>> {
+    let mut coords = coords_to_vec(ring, dimension)?;
+    if (signed_area(&coords) > 0.0) == exterior {
+        coords.reverse();
+    }
+    Ok(coords)
+}
+
+fn polygon_rings<T: Geom>(
+    polygon: &T,
+    dimension: usize,
+    rings: &mut Vec<Vec<Vec<f64>>>,
+) -> GResult<()> {
+    if polygon.is_empty()? {
+        return Ok(());
+    }
+    rings.push(ring_to_vec(&polygon.get_exterior_ring()?, dimension, true)?);
+    for n in 0..polygon.get_num_interior_rings()? {
+        rings.push(ring_to_vec(
+            &polygon.get_interior_ring_n(n)?,
+            dimension,
+            false,
+        )?);
+    }
+    Ok(())
+}
+
+/// Returns the EsriJSON geometry object of a geometry, or `None` for geometry
+/// collections, which EsriJSON can't represent.
+pub fn to_esrijson<T: Geom>(geom: &T) -> GResult<Option<Value>> {
+    let has_z = geom.has_z()?;
+    let has_m = geom.has_m()?;
+    let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+    let mut object = Map::new();
+    match geom.geometry_type()? {
+        Point => match coords_to_vec(geom, dimension)?.first() {
+            Some(coords) => {
+                object.insert("x".into(), coords[0].into());
+                object.insert("y".into(), coords[1].into());
+                if has_z {
+                    object.insert("z".into(), coords[2].into());
+                }
+                if has_m {
+                    object.insert("m".into(), coords[dimension - 1].into());
+                }
+            }
+            None => {
+                object.insert("x".into(), Value::Null);
+            }
+        },
+        MultiPoint => {
+            let mut points = vec![];
+            for n in 0..geom.get_num_geometries()? {
+                points.extend(coords_to_vec(&geom.get_geometry_n(n)?, dimension)?);
+            }
+            object.insert("points".into(), json!(points));
+        }
+        LineString | LinearRing => {
+            let paths = match geom.is_empty()? {
+                true => vec![],
+                false => vec![coords_to_vec(geom, dimension)?],
+            };
+            object.insert("paths".into(), json!(paths));
+        }
+        MultiLineString => {
+            let paths = (0..geom.get_num_geometries()?)
+                .map(|n| coords_to_vec(&geom.get_geometry_n(n)?, dimension))
+                .filter(|coords| !matches!(coords, Ok(c) if c.is_empty()))
+                .try_collect::<Vec<_>>()?;
+            object.insert("paths".into(), json!(paths));
+        }
+        Polygon => {
+            let mut rings = vec![];
+            polygon_rings(geom, dimension, &mut rings)?;
+            object.insert("rings".into(), json!(rings));
+        }
+        MultiPolygon => {
+            let mut rings = vec![];
+            for n in 0..geom.get_num_geometries()? {
+                polygon_rings(&geom.get_geometry_n(n)?, dimension, &mut rings)?;
+            }
+            object.insert("rings".into(), json!(rings));
+        }
+        GeometryCollection => return Ok(None),
+        other => {
+            let msg = format!("EsriJSON doesn't support geometries of type {other:?}");
+            return Err(GError::GenericError(msg));
+        }
+    }
+    if !object.contains_key("x") {
+        if has_z {
+            object.insert("hasZ".into(), true.into());
+        }
+        if has_m {
+            object.insert("hasM".into(), true.into());
+        }
+    }
+    match geom.get_srid()? {
+        0 => {}
+        srid => {
+            object.insert("spatialReference".into(), json!({ "wkid": srid }));
+        }
+    }
+    Ok(Some(Value::Object(object)))
+}
+
+/// Serializes an EsriJSON value, pretty-printed if `indent` is not `None`.
+pub fn write(value: &Value, indent: Option<usize>) -> GResult<String> {
+    let mut buffer = Vec::new();
+    let res = match indent {
+        None => serde_json::to_writer(&mut buffer, value),
+        Some(indent) => {
+            let indent = " ".repeat(indent);
+            let formatter = PrettyFormatter::with_indent(indent.as_bytes());
+            value.serialize(&mut Serializer::with_formatter(&mut buffer, formatter))
+        }
+    };
+    res.map_err(|e| GError::GenericError(e.to_string()))?;
+    String::from_utf8(buffer).map_err(|e| GError::GenericError(e.to_string()))
+}
+
+fn parse_coordinate(value: &Value, has_z: bool, has_m: bool) -> GResult<Vec<f64>> {
+    let values = value
+        .as_array()
+        .ok_or_else(|| invalid("expected a coordinate array"))?;
+    let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+    if values.len() < 2 {
+        return Err(invalid("coordinates must have at least two values"));
+    }
+    Ok((0..dimension)
+        .map(|i| values.get(i).and_then(Value::as_f64).unwrap_or(f64::NAN))
+        .collect())
+}
+
+fn parse_coordinates(value: &Value, has_z: bool, has_m: bool) -> GResult<Vec<Vec<f64>>> {
+    value
+        .as_array()
+        .ok_or_else(|| invalid("expected an array of coordinates"))?
+        .iter()
+        .map(|coord| parse_coordinate(coord, has_z, has_m))
+        .collect()
+}
+
+fn coord_seq(coords: &[Vec<f64>], has_z: bool, has_m: bool) -> GResult<CoordSeq> {
+    let buffer = coords.concat();
+    CoordSeq::new_from_buffer(&buffer, coords.len(), has_z, has_m)
+}
+
+fn parse_point(object: &Map<String, Value>) -> GResult<Geometry> {
+    let get = |key: &str| object.get(key).and_then(Value::as_f64);
+    let (Some(x), Some(y)) = (get("x"), get("y")) else {
+        return Geometry::create_empty_point();
+    };
+    let has_z = object.contains_key("z");
+    let has_m = object.contains_key("m");
+    let mut coords = vec![x, y];
+    if has_z {
+        coords.push(get("z").unwrap_or(f64::NAN));
+    }
+    if has_m {
+        coords.push(get("m").unwrap_or(f64::NAN));
+    }
+    Geometry::create_point(coord_seq(&[coords], has_z, has_m)?)
+}
+
+fn parse_rings(rings: &Value, has_z: bool, has_m: bool) -> GResult<Geometry> {
+    let rings = rings
+        .as_array()
+        .ok_or_else(|| invalid("expected an array of rings"))?
+        .iter()
+        .map(|ring| parse_coordinates(ring, has_z, has_m))
+        .filter(|ring| !matches!(ring, Ok(r) if r.is_empty()))
+        .try_collect::<Vec<_>>()?;
+
+    // Clockwise rings are exteriors, counter-clockwise rings are holes of
+    // the exterior that contains them.
+    let mut polygons: Vec<(&Vec<Vec<f64>>, Vec<&Vec<Vec<f64>>>)> = vec![];
+    for ring in &rings {
+        if signed_area(ring) <= 0.0 || polygons.is_empty() {
+            polygons.push((ring, vec![]));
+            continue;
+        }
+        let (x, y) = (ring[0][0], ring[0][1]);
+        let index = polygons
+            .iter()
+            .position(|(exterior, _)| ring_contains(exterior, x, y))
+            .unwrap_or(polygons.len() - 1);
+        polygons[index].1.push(ring);
+    }
+
+    let mut polygons = polygons
+        .into_iter()
+        .map(|(exterior, holes)| {
+            let exterior = Geometry::create_linear_ring(coord_seq(exterior, has_z, has_m)?)?;
+            let holes = holes
+                .into_iter()
+                .map(|hole| Geometry::create_linear_ring(coord_seq(hole, has_z, has_m)?))
+                .try_collect::<Vec<_>>()?;
+            Geometry::create_polygon(exterior, holes)
+        })
+        .try_collect::<Vec<_>>()?;
+    match polygons.len() {
+        0 => Geometry::create_empty_polygon(),
+        1 => Ok(polygons.remove(0)),
+        _ => Geometry::create_multipolygon(polygons),
+    }
+}
+
+fn parse_geometry(object: &Map<String, Value>) -> GResult<Geometry> {
+    let has_z = object.get("hasZ").and_then(Value::as_bool).unwrap_or(false);
+    let has_m = object.get("hasM").and_then(Value::as_bool).unwrap_or(false);
+    if object.contains_key("x") {
+        parse_point(object)
+    } else if let Some(points) = object.get("points") {
+        let points = parse_coordinates(points, has_z, has_m)?
+            .iter()
+            .map(|coords| Geometry::create_point(coord_seq(&[coords.clone()], has_z, has_m)?))
+            .try_collect::<Vec<_>>()?;
+        Geometry::create_multipoint(points)
+    } else if let Some(paths) = object.get("paths") {
+        let mut lines = paths
+            .as_array()
+            .ok_or_else(|| invalid("expected an array of paths"))?
+            .iter()
+            .map(|path| {
+                let coords = parse_coordinates(path, has_z, has_m)?;
+                Geometry::create_line_string(coord_seq(&coords, has_z, has_m)?)
+            })
+            .try_collect::<Vec<_>>()?;
+        match lines.len() {
+            1 => Ok(lines.remove(0)),
+            _ => Geometry::create_multiline_string(lines),
+        }
+    } else if let Some(rings) = object.get("rings") {
+        parse_rings(rings, has_z, has_m)
+    } else if object.contains_key("xmin") {
+        let get = |key: &str| object.get(key).and_then(Value::as_f64);
+        match (get("xmin"), get("ymin"), get("xmax"), get("ymax")) {
+            (Some(xmin), Some(ymin), Some(xmax), Some(ymax)) => {
+                Geometry::create_rectangle(xmin, ymin, xmax, ymax)
+            }
+            _ => Geometry::create_empty_polygon(),
+        }
+    } else {
+        Err(invalid("unknown geometry object"))
+    }
+}
+
+/// Parses an EsriJSON geometry, or the geometry of an EsriJSON feature, which
+/// is `None` when the geometry of the feature is null.
+pub fn from_esrijson(json: &str) -> GResult<Option<Geometry>> {
+    let value: Value = serde_json::from_str(json).map_err(|e| invalid(&e.to_string()))?;
+    let object = value
+        .as_object()
+        .ok_or_else(|| invalid("expected an object"))?;
+    let object = match object.get("geometry") {
+        Some(Value::Object(geometry)) => geometry,
+        Some(Value::Null) => return Ok(None),
+        _ => object,
+    };
+    let mut geom = parse_geometry(object)?;
+    if let Some(srid) = object
+        .get("spatialReference")
+        .and_then(|sr| sr.get("latestWkid").or_else(|| sr.get("wkid")))
+        .and_then(Value::as_i64)
+    {
+        geom.set_srid(srid as i32);
+    }
+    Ok(Some(geom))
+}
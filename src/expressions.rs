@@ -1,6 +1,7 @@
 use crate::{
     args,
     functions::{self, GeometryUtils},
+    geoarrow,
     wkb::WKBGeometryType,
 };
 use geos::{Geom, Geometry};
@@ -24,6 +25,20 @@ fn output_type_bounds(input_fields: &[Field]) -> PolarsResult<Field> {
     ))
 }
 
+fn output_type_bounds_3d(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::Array(D::Float64.into(), 6),
+    ))
+}
+
+fn output_type_m_range(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::Array(D::Float64.into(), 2),
+    ))
+}
+
 fn output_type_coordinates(input_fields: &[Field]) -> PolarsResult<Field> {
     Ok(Field::new(
         first_field_name(input_fields)?.clone(),
@@ -31,6 +46,13 @@ fn output_type_coordinates(input_fields: &[Field]) -> PolarsResult<Field> {
     ))
 }
 
+fn output_type_float_list(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::List(D::Float64.into()),
+    ))
+}
+
 fn output_type_geometry_list(input_fields: &[Field]) -> PolarsResult<Field> {
     Ok(Field::new(
         first_field_name(input_fields)?.clone(),
@@ -38,6 +60,16 @@ fn output_type_geometry_list(input_fields: &[Field]) -> PolarsResult<Field> {
     ))
 }
 
+fn output_type_geoarrow(
+    input_fields: &[Field],
+    kwargs: args::ToGeoArrowKwargs,
+) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        geoarrow::dtype(kwargs.geometry_type, kwargs.dimensions).map_err(to_compute_err)?,
+    ))
+}
+
 fn geometry_enum() -> DataType {
     static GEOMETRY_TYPES: [Option<&str>; 18] = [
         Some("Unknown"),
@@ -71,16 +103,135 @@ fn output_type_geometry_type(input_fields: &[Field]) -> PolarsResult<Field> {
     ))
 }
 
-fn output_type_sjoin(input_fields: &[Field]) -> PolarsResult<Field> {
+fn output_type_sjoin(
+    input_fields: &[Field],
+    kwargs: args::SpatialJoinKwargs,
+) -> PolarsResult<Field> {
+    let mut fields = vec![
+        Field::new("left_index".into(), D::UInt32),
+        Field::new("right_index".into(), D::UInt32),
+    ];
+    if kwargs.include_distance {
+        fields.push(Field::new("distance".into(), D::Float64));
+    }
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::Struct(fields),
+    ))
+}
+
+fn output_type_spatial_partition(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::List(Box::new(D::UInt32)),
+    ))
+}
+
+fn output_type_h3_cover(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::List(Box::new(D::UInt64)),
+    ))
+}
+
+fn output_type_tile_of(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::Struct(vec![
+            Field::new("z".into(), D::UInt8),
+            Field::new("x".into(), D::UInt32),
+            Field::new("y".into(), D::UInt32),
+        ]),
+    ))
+}
+
+fn output_type_interpolate(input_fields: &[Field]) -> PolarsResult<Field> {
+    let dtype = match input_fields.get(1).map(Field::dtype) {
+        Some(D::List(_)) => D::List(D::Binary.into()),
+        _ => D::Binary,
+    };
+    Ok(Field::new(first_field_name(input_fields)?.clone(), dtype))
+}
+
+fn output_type_knn(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::List(Box::new(D::Struct(vec![
+            Field::new("index".into(), D::UInt32),
+            Field::new("distance".into(), D::Float64),
+        ]))),
+    ))
+}
+
+fn output_type_pairs_within(input_fields: &[Field]) -> PolarsResult<Field> {
     Ok(Field::new(
         first_field_name(input_fields)?.clone(),
         D::Struct(vec![
             Field::new("left_index".into(), D::UInt32),
             Field::new("right_index".into(), D::UInt32),
+            Field::new("distance".into(), D::Float64),
         ]),
     ))
 }
 
+fn output_type_conflation_candidates(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::Struct(vec![
+            Field::new("left_index".into(), D::UInt32),
+            Field::new("right_index".into(), D::UInt32),
+            Field::new("distance".into(), D::Float64),
+            Field::new("overlap".into(), D::Float64),
+        ]),
+    ))
+}
+
+fn output_type_align_to_mrr(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::Struct(vec![
+            Field::new("geometry".into(), D::Binary),
+            Field::new("angle".into(), D::Float64),
+        ]),
+    ))
+}
+
+fn output_type_crs_info(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::Struct(vec![
+            Field::new("authority".into(), D::String),
+            Field::new("name".into(), D::String),
+            Field::new("units".into(), D::String),
+            Field::new("is_geographic".into(), D::Boolean),
+            Field::new("axis_order".into(), D::String),
+        ]),
+    ))
+}
+
+fn output_type_is_valid_detail(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::Struct(vec![
+            Field::new("valid".into(), D::Boolean),
+            Field::new("reason".into(), D::String),
+            Field::new("location".into(), D::Binary),
+        ]),
+    ))
+}
+
+fn output_type_dump_points(input_fields: &[Field]) -> PolarsResult<Field> {
+    Ok(Field::new(
+        first_field_name(input_fields)?.clone(),
+        D::List(Box::new(D::Struct(vec![
+            Field::new("point".into(), D::Binary),
+            Field::new("part_index".into(), D::UInt32),
+            Field::new("ring_index".into(), D::UInt32),
+            Field::new("vertex_index".into(), D::UInt32),
+        ]))),
+    ))
+}
+
 fn validate_inputs_length<const M: usize>(inputs: &[Series]) -> PolarsResult<&[Series; M]> {
     inputs
         .try_into()
@@ -93,9 +244,17 @@ fn validate_wkb(s: &Series) -> PolarsResult<&BinaryChunked> {
 }
 
 #[polars_expr(output_type=Binary)]
-fn from_wkb(inputs: &[Series]) -> PolarsResult<Series> {
+fn from_wkb(inputs: &[Series], kwargs: args::FromWkbKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
-    functions::from_wkb(inputs[0].binary()?)
+    functions::from_wkb(inputs[0].binary()?, kwargs.on_error)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+fn from_ewkb_hex(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    functions::from_ewkb_hex(inputs[0].str()?)
         .map_err(to_compute_err)
         .map(IntoSeries::into_series)
 }
@@ -124,6 +283,60 @@ fn from_geojson(inputs: &[Series]) -> PolarsResult<Series> {
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type=Binary)]
+pub fn from_gml(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    functions::from_gml(inputs[0].str()?)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn from_kml(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    functions::from_kml(inputs[0].str()?)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn from_gpkg(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    functions::from_gpkg(inputs[0].binary()?)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn from_spatialite(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    functions::from_spatialite(inputs[0].binary()?)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn from_geoarrow(inputs: &[Series], kwargs: args::FromGeoArrowKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let dtype =
+        geoarrow::cast_dtype(inputs[0].dtype(), kwargs.geometry_type).map_err(to_compute_err)?;
+    let array = inputs[0]
+        .strict_cast(&dtype)?
+        .rechunk()
+        .to_arrow(0, CompatLevel::newest());
+    functions::from_geoarrow(array.as_ref(), kwargs.geometry_type)
+        .map_err(to_compute_err)
+        .map(|ca| ca.with_name(inputs[0].name().clone()).into_series())
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn from_esrijson(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    functions::from_esrijson(inputs[0].str()?)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Binary)]
 fn rectangle(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -133,6 +346,25 @@ fn rectangle(inputs: &[Series]) -> PolarsResult<Series> {
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type=Binary)]
+fn from_tile(inputs: &[Series], kwargs: args::TileKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<3>(inputs)?;
+    let z = inputs[0].strict_cast(&D::UInt8)?;
+    let x = inputs[1].strict_cast(&D::UInt32)?;
+    let y = inputs[2].strict_cast(&D::UInt32)?;
+    functions::from_tile(z.u8().unwrap(), x.u32().unwrap(), y.u32().unwrap(), &kwargs)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+fn from_quadkey(inputs: &[Series], kwargs: args::TileKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    functions::from_quadkey(inputs[0].str()?, &kwargs)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Binary)]
 fn from_coords(inputs: &[Series], kwargs: args::CollectKwargs) -> PolarsResult<Series> {
     fn validate_point_coords(dtype: &DataType) -> PolarsResult<()> {
@@ -158,6 +390,26 @@ fn from_coords(inputs: &[Series], kwargs: args::CollectKwargs) -> PolarsResult<S
         }
     }
 
+    /// Returns the nesting depth of struct coordinates in lists, if coordinates
+    /// are structs.
+    fn struct_coords_depth(dtype: &DataType) -> Option<usize> {
+        match dtype {
+            D::Struct(_) => Some(0),
+            D::List(inner) => struct_coords_depth(inner).map(|depth| depth + 1),
+            _ => None,
+        }
+    }
+
+    fn geoarrow(coords: &Series, geometry_type: WKBGeometryType) -> PolarsResult<BinaryChunked> {
+        let dtype = geoarrow::cast_dtype(coords.dtype(), geometry_type).map_err(to_compute_err)?;
+        let array = coords
+            .strict_cast(&dtype)?
+            .rechunk()
+            .to_arrow(0, CompatLevel::newest());
+        functions::from_geoarrow(array.as_ref(), geometry_type)
+            .map_err(to_compute_err)
+            .map(|ca| ca.with_name(coords.name().clone()))
+    }
     fn point(coords: &Series) -> PolarsResult<BinaryChunked> {
         let coords = coords.cast(&D::List(D::Float64.into()))?;
         let coords = coords.list().unwrap();
@@ -193,6 +445,15 @@ fn from_coords(inputs: &[Series], kwargs: args::CollectKwargs) -> PolarsResult<S
     let inputs = validate_inputs_length::<1>(inputs)?;
     let coords = &inputs[0];
     match (kwargs.into, &coords.dtype()) {
+        (Some(g @ (Point | LineString | Polygon | MultiPoint | MultiLineString)), t)
+            if struct_coords_depth(t).is_some() =>
+        {
+            geoarrow(coords, g)
+        }
+        (None, t) if struct_coords_depth(t).is_some_and(|depth| depth <= 2) => {
+            let depth = struct_coords_depth(t).unwrap_or_default();
+            geoarrow(coords, [Point, LineString, Polygon][depth])
+        }
         (Some(Point), t) => validate_point_coords(t).and_then(|()| point(coords)),
         (Some(MultiPoint), t) => validate_line_coords(t).and_then(|()| multipoint(coords)),
         (Some(LineString), t) => validate_line_coords(t).and_then(|()| linestring(coords)),
@@ -210,6 +471,17 @@ fn from_coords(inputs: &[Series], kwargs: args::CollectKwargs) -> PolarsResult<S
     .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type=Binary)]
+fn polygon_from_rings(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let exterior = inputs[0].strict_cast(&D::List(D::List(D::Float64.into()).into()))?;
+    let holes =
+        inputs[1].strict_cast(&D::List(D::List(D::List(D::Float64.into()).into()).into()))?;
+    functions::polygon_from_rings(exterior.list()?, holes.list()?)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type_func=output_type_geometry_type)]
 fn geometry_type(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -269,6 +541,28 @@ fn set_srid(inputs: &[Series]) -> PolarsResult<Series> {
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type_func=output_type_crs_info)]
+fn crs_info(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::crs_info(wkb).map_err(to_compute_err).map(
+        |(authority, name, units, is_geographic, axis_order)| {
+            StructChunked::from_columns(
+                wkb.name().clone(),
+                wkb.len(),
+                &[
+                    authority.into_column(),
+                    name.into_column(),
+                    units.into_column(),
+                    is_geographic.into_column(),
+                    axis_order.into_column(),
+                ],
+            )
+            .map(IntoSeries::into_series)
+        },
+    )?
+}
+
 #[polars_expr(output_type=Float64)]
 fn x(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -401,6 +695,36 @@ fn parts(inputs: &[Series]) -> PolarsResult<Series> {
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type_func=output_type_dump_points)]
+fn dump_points(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::dump_points(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+fn collection_extract(
+    inputs: &[Series],
+    kwargs: args::CollectionExtractKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::collection_extract(wkb, kwargs.geometry_type)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+fn unnest_collection(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::unnest_collection(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Float64)]
 fn precision(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -421,6 +745,15 @@ fn set_precision(inputs: &[Series], kwargs: args::SetPrecisionKwargs) -> PolarsR
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type=Binary)]
+fn snap_to_grid(inputs: &[Series], kwargs: args::SnapToGridKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::snap_to_grid(wkb, &kwargs)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=String)]
 fn to_wkt(inputs: &[Series], kwargs: args::ToWktKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -448,6 +781,15 @@ fn to_wkb(inputs: &[Series], kwargs: args::ToWkbKwargs) -> PolarsResult<Series>
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type=String)]
+fn to_ewkb_hex(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::to_ewkb_hex(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=String)]
 fn to_geojson(inputs: &[Series], kwargs: args::ToGeoJsonKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -457,6 +799,67 @@ fn to_geojson(inputs: &[Series], kwargs: args::ToGeoJsonKwargs) -> PolarsResult<
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type=String)]
+pub fn to_kml(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::to_kml(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=String)]
+pub fn to_gml(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::to_gml(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn to_gpkg(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::to_gpkg(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn to_spatialite(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::to_spatialite(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type_func_with_kwargs=output_type_geoarrow)]
+pub fn to_geoarrow(inputs: &[Series], kwargs: args::ToGeoArrowKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::to_geoarrow(wkb, kwargs.geometry_type, kwargs.dimensions).map_err(to_compute_err)
+}
+
+#[polars_expr(output_type=String)]
+pub fn to_svg(inputs: &[Series], kwargs: args::ToSvgKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::to_svg(wkb, &kwargs)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=String)]
+pub fn to_esrijson(inputs: &[Series], kwargs: args::ToEsriJsonKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::to_esrijson(wkb, &kwargs)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[pyfunction]
 pub fn to_python_dict(
     py: Python,
@@ -468,6 +871,96 @@ pub fn to_python_dict(
         .map_err(Into::into)
 }
 
+#[pyfunction]
+#[pyo3(signature = (pyseries, object_name, quantization=None))]
+pub fn to_topojson(
+    pyseries: PySeries,
+    object_name: &str,
+    quantization: Option<u32>,
+) -> Result<String, PyPolarsErr> {
+    let wkb = validate_wkb(&pyseries.0)?;
+    functions::to_topojson(wkb, object_name, quantization)
+        .map_err(to_compute_err)
+        .map_err(Into::into)
+}
+
+#[pyfunction]
+#[pyo3(signature = (pyseries, layers=None))]
+pub fn to_dxf(pyseries: PySeries, layers: Option<PySeries>) -> Result<String, PyPolarsErr> {
+    let wkb = validate_wkb(&pyseries.0)?;
+    let layers = layers.as_ref().map(|layers| layers.0.str()).transpose()?;
+    functions::to_dxf(wkb, layers)
+        .map_err(to_compute_err)
+        .map_err(Into::into)
+}
+
+#[pyfunction]
+pub fn to_ragged_array(
+    pyseries: PySeries,
+) -> Result<Option<(u32, usize, PySeries, Vec<PySeries>)>, PyPolarsErr> {
+    let wkb = validate_wkb(&pyseries.0)?;
+    let Some((geometry_type, dimension, coords, offsets)) =
+        functions::to_ragged_array(wkb).map_err(to_compute_err)?
+    else {
+        return Ok(None);
+    };
+    let coords = Float64Chunked::from_vec("coords".into(), coords).into_series();
+    let offsets = offsets
+        .into_iter()
+        .map(|offsets| PySeries(Int64Chunked::from_vec("offsets".into(), offsets).into_series()))
+        .collect();
+    Ok(Some((
+        geometry_type.into(),
+        dimension,
+        PySeries(coords),
+        offsets,
+    )))
+}
+
+#[pyfunction]
+pub fn from_ragged_array(
+    geometry_type: u32,
+    coords: PySeries,
+    dimension: usize,
+    offsets: Vec<PySeries>,
+) -> Result<PySeries, PyPolarsErr> {
+    let geometry_type = WKBGeometryType::try_from(geometry_type).map_err(to_compute_err)?;
+    let coords = coords.0.strict_cast(&D::Float64)?;
+    let offsets = offsets
+        .iter()
+        .map(|offsets| offsets.0.strict_cast(&D::Int64))
+        .try_collect::<Vec<_>>()?;
+    let offsets = offsets.iter().map(Series::i64).try_collect::<Vec<_>>()?;
+    functions::from_ragged_array(geometry_type, coords.f64()?, dimension, &offsets)
+        .map_err(to_compute_err)
+        .map(|ca| PySeries(ca.into_series()))
+        .map_err(Into::into)
+}
+
+#[pyfunction]
+pub fn get_coordinates(pyseries: PySeries, dimension: usize) -> Result<PySeries, PyPolarsErr> {
+    let wkb = validate_wkb(&pyseries.0)?;
+    let coords = functions::get_coordinates(wkb, dimension).map_err(to_compute_err)?;
+    Ok(PySeries(
+        Float64Chunked::from_vec("coords".into(), coords).into_series(),
+    ))
+}
+
+#[pyfunction]
+pub fn set_coordinates(
+    pyseries: PySeries,
+    coords: PySeries,
+    dimension: usize,
+) -> Result<PySeries, PyPolarsErr> {
+    let wkb = validate_wkb(&pyseries.0)?;
+    let coords = coords.0.strict_cast(&D::Float64)?;
+    let coords = coords.f64()?.rechunk();
+    functions::set_coordinates(wkb, coords.cont_slice()?, dimension)
+        .map_err(to_compute_err)
+        .map(|ca| PySeries(ca.into_series()))
+        .map_err(Into::into)
+}
+
 #[polars_expr(output_type=Binary)]
 fn cast(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -500,6 +993,15 @@ fn area(inputs: &[Series]) -> PolarsResult<Series> {
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type=Float64)]
+fn area_geodesic(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::area_geodesic(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type_func=output_type_bounds)]
 fn bounds(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -509,6 +1011,82 @@ fn bounds(inputs: &[Series]) -> PolarsResult<Series> {
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type_func=output_type_bounds_3d)]
+fn bounds_3d(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::bounds_3d(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type_func=output_type_m_range)]
+fn m_range(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::m_range(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=UInt64)]
+fn morton_index(inputs: &[Series], kwargs: args::MortonIndexKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::morton_index(wkb, &kwargs)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type_func=output_type_spatial_partition)]
+fn spatial_partition(
+    inputs: &[Series],
+    kwargs: args::SpatialPartitionKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::spatial_partition(wkb, &kwargs)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=UInt64)]
+fn to_h3(inputs: &[Series], kwargs: args::H3Kwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::to_h3(wkb, &kwargs)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+fn h3_to_boundary(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let cells = inputs[0].strict_cast(&D::UInt64)?;
+    functions::h3_to_boundary(cells.u64().unwrap())
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type_func=output_type_h3_cover)]
+fn h3_cover(inputs: &[Series], kwargs: args::H3Kwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::h3_cover(wkb, &kwargs)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type_func=output_type_tile_of)]
+fn tile_of(inputs: &[Series], kwargs: args::TileOfKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let (z, x, y) = functions::tile_of(wkb, &kwargs).map_err(to_compute_err)?;
+    let columns = [z.into_column(), x.into_column(), y.into_column()];
+    StructChunked::from_columns(wkb.name().clone(), wkb.len(), &columns)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type_func=output_type_bounds)]
 fn total_bounds(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -535,6 +1113,24 @@ fn length(inputs: &[Series]) -> PolarsResult<Series> {
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type=Float64)]
+fn length_geodesic(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::length_geodesic(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Float64)]
+fn length_3d(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::length_3d(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Binary)]
 fn distance(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -545,36 +1141,129 @@ fn distance(inputs: &[Series]) -> PolarsResult<Series> {
         .map(IntoSeries::into_series)
 }
 
-#[polars_expr(output_type=Float64)]
-fn hausdorff_distance(
+#[polars_expr(output_type=Float64)]
+fn distance_3d(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let left = validate_wkb(&inputs[0])?;
+    let right = validate_wkb(&inputs[1])?;
+    functions::distance_3d(left, right)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Float64)]
+fn hausdorff_distance(
+    inputs: &[Series],
+    kwargs: args::DistanceDensifyKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let left = validate_wkb(&inputs[0])?;
+    let right = validate_wkb(&inputs[1])?;
+    match kwargs.densify {
+        Some(densify) => functions::hausdorff_distance_densify(left, right, densify),
+        None => functions::hausdorff_distance(left, right),
+    }
+    .map_err(to_compute_err)
+    .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Float64)]
+fn frechet_distance(
+    inputs: &[Series],
+    kwargs: args::DistanceDensifyKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let left = validate_wkb(&inputs[0])?;
+    let right = validate_wkb(&inputs[1])?;
+    match kwargs.densify {
+        Some(densify) => functions::frechet_distance_densify(left, right, densify),
+        None => functions::frechet_distance(left, right),
+    }
+    .map_err(to_compute_err)
+    .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Float64)]
+fn azimuth(inputs: &[Series], kwargs: args::AzimuthKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let left = validate_wkb(&inputs[0])?;
+    let right = validate_wkb(&inputs[1])?;
+    functions::azimuth(left, right, kwargs.degrees)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Float64)]
+fn azimuth_geodesic(inputs: &[Series], kwargs: args::AzimuthKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let left = validate_wkb(&inputs[0])?;
+    let right = validate_wkb(&inputs[1])?;
+    functions::azimuth_geodesic(left, right, kwargs.degrees)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type_func=output_type_knn)]
+fn knn(inputs: &[Series], kwargs: args::KnnKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    // The other geometries are given as a single list, as they don't need to
+    // have the same length as the input.
+    let other = inputs[1].list()?.rechunk().get_inner();
+    let other = validate_wkb(&other)?;
+    functions::knn(wkb, other, kwargs.k)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=UInt32)]
+fn count_matches(inputs: &[Series], kwargs: args::CountMatchesKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let other = inputs[1].list()?.rechunk().get_inner();
+    let other = validate_wkb(&other)?;
+    functions::count_matches(wkb, other, kwargs.predicate, kwargs.distance)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type_func=output_type_pairs_within)]
+fn pairs_within(inputs: &[Series], kwargs: args::PairsWithinKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let (left_index, right_index, distance) =
+        functions::pairs_within(wkb, kwargs.distance).map_err(to_compute_err)?;
+    let columns = [
+        left_index.into_column(),
+        right_index.into_column(),
+        distance.into_column(),
+    ];
+    StructChunked::from_columns(wkb.name().clone(), columns[0].len(), &columns)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Boolean)]
+fn is_duplicate_geometry(
     inputs: &[Series],
-    kwargs: args::DistanceDensifyKwargs,
+    kwargs: args::DuplicateGeometryKwargs,
 ) -> PolarsResult<Series> {
-    let inputs = validate_inputs_length::<2>(inputs)?;
-    let left = validate_wkb(&inputs[0])?;
-    let right = validate_wkb(&inputs[1])?;
-    match kwargs.densify {
-        Some(densify) => functions::hausdorff_distance_densify(left, right, densify),
-        None => functions::hausdorff_distance(left, right),
-    }
-    .map_err(to_compute_err)
-    .map(IntoSeries::into_series)
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::is_duplicate_geometry(wkb, kwargs.tolerance)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
 }
 
-#[polars_expr(output_type=Float64)]
-fn frechet_distance(
+#[polars_expr(output_type=Binary)]
+fn unique_geometries(
     inputs: &[Series],
-    kwargs: args::DistanceDensifyKwargs,
+    kwargs: args::DuplicateGeometryKwargs,
 ) -> PolarsResult<Series> {
-    let inputs = validate_inputs_length::<2>(inputs)?;
-    let left = validate_wkb(&inputs[0])?;
-    let right = validate_wkb(&inputs[1])?;
-    match kwargs.densify {
-        Some(densify) => functions::frechet_distance_densify(left, right, densify),
-        None => functions::frechet_distance(left, right),
-    }
-    .map_err(to_compute_err)
-    .map(IntoSeries::into_series)
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::unique_geometries(wkb, kwargs.tolerance)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
 }
 
 #[polars_expr(output_type=Float64)]
@@ -606,6 +1295,15 @@ fn has_m(inputs: &[Series]) -> PolarsResult<Series> {
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type=Boolean)]
+fn has_curves(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::has_curves(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Boolean)]
 fn is_ccw(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -669,6 +1367,38 @@ fn is_valid_reason(inputs: &[Series]) -> PolarsResult<Series> {
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type_func=output_type_is_valid_detail)]
+fn is_valid_detail(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let (valid, reason, location) = functions::is_valid_detail(wkb).map_err(to_compute_err)?;
+    let columns = [
+        valid.into_column(),
+        reason.into_column(),
+        location.into_column(),
+    ];
+    StructChunked::from_columns(wkb.name().clone(), wkb.len(), &columns)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Boolean)]
+fn wkb_is_parseable(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::wkb_is_parseable(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=String)]
+fn wkb_parse_error(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::wkb_parse_error(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Boolean)]
 fn crosses(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -1029,7 +1759,29 @@ fn polygonize(inputs: &[Series]) -> PolarsResult<Series> {
 fn collect(inputs: &[Series], kwargs: args::CollectKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
-    functions::collect(wkb, kwargs.into)
+    functions::collect(wkb, kwargs.into, kwargs.mixed_dimensions)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+fn make_line(inputs: &[Series]) -> PolarsResult<Series> {
+    // Geometries are connected in row order, or sorted by the optional `order_by` input.
+    let wkb = match inputs {
+        [wkb] => validate_wkb(wkb)?.clone(),
+        [wkb, order_by] => {
+            let wkb = validate_wkb(wkb)?;
+            polars_ensure!(
+                order_by.len() == wkb.len(),
+                ShapeMismatch: "`order_by` has length {}, expected {}", order_by.len(), wkb.len()
+            );
+            wkb.take(&order_by.arg_sort(SortOptions::default().with_maintain_order(true)))?
+        }
+        _ => polars_bail!(
+            InvalidOperation: "invalid number of arguments: expected 1 or 2, got {}", inputs.len()
+        ),
+    };
+    functions::make_line(&wkb)
         .map_err(to_compute_err)
         .map(IntoSeries::into_series)
 }
@@ -1054,6 +1806,26 @@ fn buffer(inputs: &[Series], kwargs: args::BufferKwargs) -> PolarsResult<Series>
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type=Binary)]
+fn buffer_geodesic(inputs: &[Series], kwargs: args::BufferGeodesicKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let distance = inputs[1].strict_cast(&D::Float64)?;
+    let distance = distance.f64().unwrap();
+    functions::buffer_geodesic(wkb, distance, &kwargs)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type_func=output_type_geometry_list)]
+fn buffer_rings(inputs: &[Series], kwargs: args::BufferRingsKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::buffer_rings(wkb, &kwargs)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Binary)]
 fn offset_curve(inputs: &[Series], kwargs: args::OffsetCurveKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -1092,6 +1864,15 @@ fn clip_by_rect(inputs: &[Series]) -> PolarsResult<Series> {
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type_func=output_type_geometry_list)]
+fn subdivide(inputs: &[Series], kwargs: args::SubdivideKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::subdivide(wkb, kwargs.max_vertices)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Binary)]
 fn centroid(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -1133,6 +1914,20 @@ fn segmentize(inputs: &[Series]) -> PolarsResult<Series> {
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type=Binary)]
+fn segmentize_geodesic(
+    inputs: &[Series],
+    kwargs: args::SegmentizeGeodesicKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let max_length = inputs[1].strict_cast(&D::Float64)?;
+    let max_length = max_length.f64().unwrap();
+    functions::segmentize_geodesic(wkb, max_length, &kwargs)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Binary)]
 fn envelope(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -1161,10 +1956,10 @@ fn build_area(inputs: &[Series]) -> PolarsResult<Series> {
 }
 
 #[polars_expr(output_type=Binary)]
-pub fn make_valid(inputs: &[Series]) -> PolarsResult<Series> {
+pub fn make_valid(inputs: &[Series], kwargs: args::MakeValidKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
-    functions::make_valid(wkb)
+    functions::make_valid(wkb, &kwargs)
         .map_err(to_compute_err)
         .map(IntoSeries::into_series)
 }
@@ -1196,6 +1991,17 @@ pub fn point_on_surface(inputs: &[Series]) -> PolarsResult<Series> {
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type=Binary)]
+pub fn polylabel(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let tolerance = inputs[1].strict_cast(&D::Float64)?;
+    let tolerance = tolerance.f64().unwrap();
+    functions::polylabel(wkb, tolerance)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Binary)]
 pub fn remove_repeated_points(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -1230,6 +2036,51 @@ pub fn simplify(inputs: &[Series], kwargs: args::SimplifyKwargs) -> PolarsResult
     .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type=Binary)]
+pub fn simplify_vw(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let tolerance = inputs[1].strict_cast(&D::Float64)?;
+    let tolerance = tolerance.f64().unwrap();
+    functions::simplify_vw(wkb, tolerance)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn curve_to_linear(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let tolerance = inputs[1].strict_cast(&D::Float64)?;
+    let tolerance = tolerance.f64().unwrap();
+    functions::curve_to_linear(wkb, tolerance)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn linear_to_curve(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let tolerance = inputs[1].strict_cast(&D::Float64)?;
+    let tolerance = tolerance.f64().unwrap();
+    functions::linear_to_curve(wkb, tolerance)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn simplify_coverage(
+    inputs: &[Series],
+    kwargs: args::SimplifyCoverageKwargs,
+) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::simplify_coverage(wkb, kwargs.tolerance, kwargs.preserve_boundary)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Binary)]
 pub fn force_2d(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -1250,6 +2101,19 @@ pub fn force_3d(inputs: &[Series]) -> PolarsResult<Series> {
         .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type=Binary)]
+pub fn force_4d(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<3>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let z = inputs[1].strict_cast(&D::Float64)?;
+    let z = z.f64().unwrap();
+    let m = inputs[2].strict_cast(&D::Float64)?;
+    let m = m.f64().unwrap();
+    functions::force_4d(wkb, z, m)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Binary)]
 pub fn snap(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<3>(inputs)?;
@@ -1338,6 +2202,23 @@ pub fn skew(inputs: &[Series], kwargs: args::TransformKwargs) -> PolarsResult<Se
     .map_err(to_compute_err)
     .map(IntoSeries::into_series)
 }
+
+#[polars_expr(output_type_func=output_type_align_to_mrr)]
+pub fn align_to_mrr(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::align_to_mrr(wkb)
+        .map_err(to_compute_err)
+        .map(|(geometry, angle)| {
+            StructChunked::from_columns(
+                wkb.name().clone(),
+                wkb.len(),
+                &[geometry.into_column(), angle.into_column()],
+            )
+            .map(IntoSeries::into_series)
+        })?
+}
+
 #[polars_expr(output_type=Binary)]
 pub fn affine_transform(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -1359,10 +2240,16 @@ pub fn affine_transform(inputs: &[Series]) -> PolarsResult<Series> {
     .map(IntoSeries::into_series)
 }
 
-#[polars_expr(output_type=Binary)]
+#[polars_expr(output_type_func=output_type_interpolate)]
 pub fn interpolate(inputs: &[Series], kwargs: args::InterpolateKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
+    if let D::List(_) = inputs[1].dtype() {
+        let distances = inputs[1].strict_cast(&D::List(D::Float64.into()))?;
+        return functions::interpolate_list(wkb, distances.list().unwrap(), kwargs.normalized)
+            .map_err(to_compute_err)
+            .map(IntoSeries::into_series);
+    }
     let distance = inputs[1].strict_cast(&D::Float64)?;
     let distance = distance.f64().unwrap();
     match kwargs.normalized {
@@ -1398,6 +2285,52 @@ pub fn line_merge(inputs: &[Series], kwargs: args::LineMergeKwargs) -> PolarsRes
     .map(IntoSeries::into_series)
 }
 
+#[polars_expr(output_type_func=output_type_geometry_list)]
+pub fn points_along(inputs: &[Series], kwargs: args::PointsAlongKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::points_along(wkb, kwargs.interval, kwargs.fractions.as_deref())
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type_func=output_type_geometry_list)]
+pub fn transects(inputs: &[Series], kwargs: args::TransectsKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::transects(wkb, kwargs.spacing, kwargs.length)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type_func=output_type_geometry_list)]
+pub fn segments(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::segments(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type_func=output_type_float_list)]
+pub fn segment_azimuths(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::segment_azimuths(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn split(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let blade = validate_wkb(&inputs[1])?;
+    functions::split(wkb, blade)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
 #[polars_expr(output_type=Binary)]
 pub fn shared_paths(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
@@ -1418,23 +2351,79 @@ pub fn shortest_line(inputs: &[Series]) -> PolarsResult<Series> {
         .map(IntoSeries::into_series)
 }
 
-#[polars_expr(output_type_func=output_type_sjoin)]
+#[polars_expr(output_type=Binary)]
+pub fn longest_line(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let left = validate_wkb(&inputs[0])?;
+    let right = validate_wkb(&inputs[1])?;
+    functions::longest_line(left, right)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Binary)]
+pub fn closest_point(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let left = validate_wkb(&inputs[0])?;
+    let right = validate_wkb(&inputs[1])?;
+    functions::closest_point(left, right)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type_func_with_kwargs=output_type_sjoin)]
 pub fn sjoin(inputs: &[Series], kwargs: args::SpatialJoinKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    // Both sides are given as single lists, so that frames of different
+    // lengths don't get padded with null geometries.
+    let left = inputs[0].list()?.rechunk().get_inner();
+    let right = inputs[1].list()?.rechunk().get_inner();
+    let left = validate_wkb(&left)?;
+    let right = validate_wkb(&right)?;
+    let (left_index, right_index, distance) =
+        functions::sjoin(left, right, &kwargs).map_err(to_compute_err)?;
+    let mut columns = vec![left_index.into_column(), right_index.into_column()];
+    if kwargs.include_distance {
+        columns.push(distance.into_column());
+    }
+    StructChunked::from_columns(left.name().clone(), columns[0].len(), &columns)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type_func=output_type_conflation_candidates)]
+pub fn conflation_candidates(
+    inputs: &[Series],
+    kwargs: args::ConflationKwargs,
+) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
     let left = validate_wkb(&inputs[0])?;
     let right = validate_wkb(&inputs[1])?;
-    functions::sjoin(left, right, kwargs.predicate)
+    functions::conflation_candidates(left, right, kwargs.max_distance)
         .map_err(to_compute_err)
-        .map(|(left_index, right_index)| {
+        .map(|(left_index, right_index, distance, overlap)| {
             StructChunked::from_columns(
                 left.name().clone(),
                 left_index.len(),
-                &[left_index.into_column(), right_index.into_column()],
+                &[
+                    left_index.into_column(),
+                    right_index.into_column(),
+                    distance.into_column(),
+                    overlap.into_column(),
+                ],
             )
             .map(IntoSeries::into_series)
         })?
 }
 
+#[polars_expr(output_type=Boolean)]
+pub fn greedy_match(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<3>(inputs)?;
+    let left = inputs[0].strict_cast(&D::UInt32)?;
+    let right = inputs[1].strict_cast(&D::UInt32)?;
+    let score = inputs[2].strict_cast(&D::Float64)?;
+    Ok(functions::greedy_match(left.u32()?, right.u32()?, score.f64()?).into_series())
+}
+
 #[polars_expr(output_type=Binary)]
 pub fn flip_coordinates(inputs: &[Series]) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<1>(inputs)?;
@@ -1445,13 +2434,42 @@ pub fn flip_coordinates(inputs: &[Series]) -> PolarsResult<Series> {
 }
 
 #[polars_expr(output_type=Binary)]
-pub fn to_srid(inputs: &[Series]) -> PolarsResult<Series> {
+pub fn to_srid(inputs: &[Series], kwargs: args::ToSridKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<2>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    let srid = inputs[1].strict_cast(&D::Int64)?;
+    let srid = srid.i64()?;
+
+    functions::to_srid(wkb, srid, &kwargs)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type=Int32)]
+pub fn utm_zone(inputs: &[Series]) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::utm_zone(wkb)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
+
+#[polars_expr(output_type_func=output_type_bounds)]
+pub fn bounds_to_srid(inputs: &[Series], kwargs: args::BoundsToSridKwargs) -> PolarsResult<Series> {
     let inputs = validate_inputs_length::<2>(inputs)?;
     let wkb = validate_wkb(&inputs[0])?;
     let srid = inputs[1].strict_cast(&D::Int64)?;
     let srid = srid.i64()?;
+    functions::bounds_to_srid(wkb, srid, &kwargs)
+        .map_err(to_compute_err)
+        .map(IntoSeries::into_series)
+}
 
-    functions::to_srid(wkb, srid)
+#[polars_expr(output_type=Binary)]
+pub fn transform(inputs: &[Series], kwargs: args::CrsTransformKwargs) -> PolarsResult<Series> {
+    let inputs = validate_inputs_length::<1>(inputs)?;
+    let wkb = validate_wkb(&inputs[0])?;
+    functions::transform(wkb, &kwargs)
         .map_err(to_compute_err)
         .map(IntoSeries::into_series)
 }
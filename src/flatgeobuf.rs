@@ -0,0 +1,758 @@
+use std::{
+    fs::File,
+    io::{BufReader, Read, Seek, SeekFrom},
+    ops::Range,
+};
+
+use geos::{Error as GError, GResult, Geom, Geometry};
+use polars::{error::to_compute_err, prelude::*};
+use pyo3::prelude::*;
+use pyo3_polars::{error::PyPolarsErr, PyDataFrame, PySeries};
+use scroll::{ctx::TryFromCtx, Endian, Pread};
+
+use crate::{crs, functions::GeometryUtils, gpx};
+
+/// Size of the nodes of the packed Hilbert R-tree: a bounding box and an offset.
+const NODE_ITEM_SIZE: usize = 40;
+
+fn invalid() -> GError {
+    GError::GenericError("Invalid FlatGeobuf file".into())
+}
+
+fn read<'a, T>(buf: &'a [u8], offset: usize) -> GResult<T>
+where
+    T: TryFromCtx<'a, Endian, Error = scroll::Error>,
+{
+    buf.pread_with(offset, Endian::Little)
+        .map_err(|_| invalid())
+}
+
+/// A table of a FlatBuffers buffer, whose fields are looked up by index in
+/// its vtable.
+#[derive(Clone, Copy)]
+struct Table<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Table<'a> {
+    /// Returns the root table of a buffer.
+    fn root(buf: &'a [u8]) -> GResult<Self> {
+        let pos = read::<u32>(buf, 0)? as usize;
+        Ok(Self { buf, pos })
+    }
+
+    /// Returns the position of a field, or `None` if it has its default value.
+    fn field(&self, index: usize) -> GResult<Option<usize>> {
+        let vtable = self.pos as i64 - i64::from(read::<i32>(self.buf, self.pos)?);
+        let vtable = usize::try_from(vtable).map_err(|_| invalid())?;
+        let entry = 4 + 2 * index;
+        if entry + 2 > usize::from(read::<u16>(self.buf, vtable)?) {
+            return Ok(None);
+        }
+        match read::<u16>(self.buf, vtable + entry)? {
+            0 => Ok(None),
+            offset => Ok(Some(self.pos + usize::from(offset))),
+        }
+    }
+
+    fn scalar<T>(&self, index: usize, default: T) -> GResult<T>
+    where
+        T: TryFromCtx<'a, Endian, Error = scroll::Error>,
+    {
+        match self.field(index)? {
+            Some(pos) => read(self.buf, pos),
+            None => Ok(default),
+        }
+    }
+
+    /// Returns the position a reference field points to.
+    fn reference(&self, index: usize) -> GResult<Option<usize>> {
+        self.field(index)?
+            .map(|pos| Ok(pos + read::<u32>(self.buf, pos)? as usize))
+            .transpose()
+    }
+
+    /// Returns the position of the first element of a vector field, and its
+    /// length.
+    fn vector(&self, index: usize) -> GResult<Option<(usize, usize)>> {
+        self.reference(index)?
+            .map(|pos| Ok((pos + 4, read::<u32>(self.buf, pos)? as usize)))
+            .transpose()
+    }
+
+    fn bytes(&self, index: usize) -> GResult<&'a [u8]> {
+        match self.vector(index)? {
+            Some((start, len)) => self.buf.get(start..start + len).ok_or_else(invalid),
+            None => Ok(&[]),
+        }
+    }
+
+    fn string(&self, index: usize) -> GResult<Option<&'a str>> {
+        match self.vector(index)? {
+            Some(_) => std::str::from_utf8(self.bytes(index)?)
+                .map(Some)
+                .map_err(|_| invalid()),
+            None => Ok(None),
+        }
+    }
+
+    fn values<T>(&self, index: usize, size: usize) -> GResult<Vec<T>>
+    where
+        T: TryFromCtx<'a, Endian, Error = scroll::Error>,
+    {
+        let Some((start, len)) = self.vector(index)? else {
+            return Ok(vec![]);
+        };
+        (0..len).map(|i| read(self.buf, start + i * size)).collect()
+    }
+
+    fn table(&self, index: usize) -> GResult<Option<Self>> {
+        Ok(self.reference(index)?.map(|pos| Self { pos, ..*self }))
+    }
+
+    fn tables(&self, index: usize) -> GResult<Vec<Self>> {
+        let Some((start, len)) = self.vector(index)? else {
+            return Ok(vec![]);
+        };
+        (0..len)
+            .map(|i| {
+                let pos = start + 4 * i;
+                let pos = pos + read::<u32>(self.buf, pos)? as usize;
+                Ok(Self { pos, ..*self })
+            })
+            .collect()
+    }
+}
+
+/// Types of the columns of a FlatGeobuf file.
+mod column_type {
+    pub const BYTE: u8 = 0;
+    pub const UBYTE: u8 = 1;
+    pub const BOOL: u8 = 2;
+    pub const SHORT: u8 = 3;
+    pub const USHORT: u8 = 4;
+    pub const INT: u8 = 5;
+    pub const UINT: u8 = 6;
+    pub const LONG: u8 = 7;
+    pub const ULONG: u8 = 8;
+    pub const FLOAT: u8 = 9;
+    pub const DOUBLE: u8 = 10;
+    pub const STRING: u8 = 11;
+    pub const JSON: u8 = 12;
+    pub const DATETIME: u8 = 13;
+    pub const BINARY: u8 = 14;
+}
+
+struct ColumnDef {
+    name: String,
+    kind: u8,
+}
+
+impl ColumnDef {
+    fn dtype(&self) -> GResult<DataType> {
+        use column_type::{
+            BINARY, BOOL, BYTE, DATETIME, DOUBLE, FLOAT, INT, JSON, LONG, SHORT, STRING, UBYTE,
+            UINT, ULONG, USHORT,
+        };
+        Ok(match self.kind {
+            BYTE => DataType::Int8,
+            UBYTE => DataType::UInt8,
+            BOOL => DataType::Boolean,
+            SHORT => DataType::Int16,
+            USHORT => DataType::UInt16,
+            INT => DataType::Int32,
+            UINT => DataType::UInt32,
+            LONG => DataType::Int64,
+            ULONG => DataType::UInt64,
+            FLOAT => DataType::Float32,
+            DOUBLE => DataType::Float64,
+            STRING | JSON => DataType::String,
+            DATETIME => DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+            BINARY => DataType::Binary,
+            _ => return Err(invalid()),
+        })
+    }
+}
+
+/// A property value, before it is collected into a column.
+enum Value {
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Bool(bool),
+    String(String),
+    Binary(Vec<u8>),
+}
+
+struct Header {
+    geometry_type: u8,
+    has_z: bool,
+    has_m: bool,
+    columns: Vec<ColumnDef>,
+    features_count: u64,
+    index_node_size: u16,
+    srid: i32,
+}
+
+impl Header {
+    fn parse(buf: &[u8]) -> GResult<Self> {
+        let header = Table::root(buf)?;
+        let columns = header
+            .tables(7)?
+            .into_iter()
+            .map(|column| {
+                Ok(ColumnDef {
+                    name: column.string(0)?.ok_or_else(invalid)?.to_owned(),
+                    kind: column.scalar(1, 0)?,
+                })
+            })
+            .collect::<GResult<_>>()?;
+        // CRS of other authorities than EPSG and ESRI are registered from their WKT.
+        let srid = match header.table(10)? {
+            Some(crs) => match (crs.string(0)?, crs.scalar::<i32>(1, 0)?, crs.string(4)?) {
+                (None, code @ 1.., _) => code,
+                (Some(org), code @ 1.., _)
+                    if org.eq_ignore_ascii_case("EPSG") || org.eq_ignore_ascii_case("ESRI") =>
+                {
+                    code
+                }
+                (_, _, Some(wkt)) => crs::register_definition(wkt.to_owned()),
+                _ => 0,
+            },
+            None => 0,
+        };
+        Ok(Self {
+            geometry_type: header.scalar(2, 0)?,
+            has_z: header.scalar::<u8>(3, 0)? != 0,
+            has_m: header.scalar::<u8>(4, 0)? != 0,
+            columns,
+            features_count: header.scalar(8, 0)?,
+            index_node_size: header.scalar(9, 16)?,
+            srid,
+        })
+    }
+}
+
+/// Returns the ranges of the nodes of each level of a packed Hilbert R-tree,
+/// from the leaves to the root, which is the first node.
+fn level_bounds(num_items: usize, node_size: usize) -> Vec<Range<usize>> {
+    let mut counts = vec![num_items];
+    let mut n = num_items;
+    loop {
+        n = n.div_ceil(node_size);
+        counts.push(n);
+        if n == 1 {
+            break;
+        }
+    }
+    let mut end = counts.iter().sum::<usize>();
+    counts
+        .into_iter()
+        .map(|count| {
+            let start = end - count;
+            end = start;
+            start..start + count
+        })
+        .collect()
+}
+
+/// Coordinates of a FlatGeobuf geometry, as flat arrays.
+struct Coords {
+    ends: Vec<u32>,
+    xy: Vec<f64>,
+    z: Vec<f64>,
+    m: Vec<f64>,
+}
+
+impl Coords {
+    fn new(geom: &Table) -> GResult<Self> {
+        Ok(Self {
+            ends: geom.values(0, 4)?,
+            xy: geom.values(1, 8)?,
+            z: geom.values(2, 8)?,
+            m: geom.values(3, 8)?,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.xy.len() / 2
+    }
+
+    /// Returns the ranges of coordinates of the rings or lines delimited by
+    /// `ends`, which is omitted when there is a single one.
+    fn parts(&self) -> Vec<Range<usize>> {
+        if self.ends.is_empty() {
+            return match self.len() {
+                0 => vec![],
+                len => vec![0..len],
+            };
+        }
+        let mut start = 0;
+        self.ends
+            .iter()
+            .map(|&end| {
+                let range = start..end as usize;
+                start = end as usize;
+                range
+            })
+            .collect()
+    }
+}
+
+/// Writes the ISO WKB of FlatGeobuf geometries.
+struct WkbWriter {
+    wkb: Vec<u8>,
+    has_z: bool,
+    has_m: bool,
+}
+
+impl WkbWriter {
+    fn header(&mut self, kind: u8) {
+        let dimension = match (self.has_z, self.has_m) {
+            (false, false) => 0,
+            (true, false) => 1000,
+            (false, true) => 2000,
+            (true, true) => 3000,
+        };
+        self.wkb.push(1);
+        self.wkb
+            .extend_from_slice(&(u32::from(kind) + dimension).to_le_bytes());
+    }
+
+    fn count(&mut self, count: usize) {
+        self.wkb.extend_from_slice(&(count as u32).to_le_bytes());
+    }
+
+    fn coords(&mut self, coords: &Coords, range: Range<usize>) -> GResult<()> {
+        for i in range {
+            let xy = coords.xy.get(2 * i..2 * i + 2).ok_or_else(invalid)?;
+            let mut values = xy.to_vec();
+            if self.has_z {
+                values.push(coords.z.get(i).copied().unwrap_or(f64::NAN));
+            }
+            if self.has_m {
+                values.push(coords.m.get(i).copied().unwrap_or(f64::NAN));
+            }
+            for value in values {
+                self.wkb.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, geom: &Table, kind: u8) -> GResult<()> {
+        let coords = Coords::new(geom)?;
+        self.header(kind);
+        match kind {
+            // Empty points have NaN coordinates.
+            1 if coords.len() == 0 => {
+                let dimensions = 2 + usize::from(self.has_z) + usize::from(self.has_m);
+                for _ in 0..dimensions {
+                    self.wkb.extend_from_slice(&f64::NAN.to_le_bytes());
+                }
+            }
+            1 => self.coords(&coords, 0..1)?,
+            2 | 8 => {
+                self.count(coords.len());
+                self.coords(&coords, 0..coords.len())?;
+            }
+            3 => {
+                let rings = coords.parts();
+                self.count(rings.len());
+                for ring in rings {
+                    self.count(ring.len());
+                    self.coords(&coords, ring)?;
+                }
+            }
+            4 => {
+                self.count(coords.len());
+                for i in 0..coords.len() {
+                    self.header(1);
+                    self.coords(&coords, i..i + 1)?;
+                }
+            }
+            5 => {
+                let lines = coords.parts();
+                self.count(lines.len());
+                for line in lines {
+                    self.header(2);
+                    self.count(line.len());
+                    self.coords(&coords, line)?;
+                }
+            }
+            6 => {
+                let polygons = geom.tables(7)?;
+                self.count(polygons.len());
+                for polygon in polygons {
+                    self.write(&polygon, 3)?;
+                }
+            }
+            // Collections and curves, whose parts have their own type.
+            7 | 9..=12 => {
+                let parts = geom.tables(7)?;
+                self.count(parts.len());
+                for part in parts {
+                    self.write(&part, part.scalar(6, 0)?)?;
+                }
+            }
+            _ => {
+                let msg = format!("Unsupported FlatGeobuf geometry type: {kind}");
+                return Err(GError::GenericError(msg));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the bounding box of the coordinates of a FlatGeobuf geometry and
+/// its parts, or `None` if it is empty.
+fn geometry_bounds(geom: &Table) -> GResult<Option<[f64; 4]>> {
+    let xy = geom.values::<f64>(1, 8)?;
+    let mut bounds = xy.chunks_exact(2).fold(None, |bounds, xy| {
+        let [x_min, y_min, x_max, y_max] = bounds.unwrap_or([
+            f64::INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NEG_INFINITY,
+        ]);
+        Some([
+            x_min.min(xy[0]),
+            y_min.min(xy[1]),
+            x_max.max(xy[0]),
+            y_max.max(xy[1]),
+        ])
+    });
+    for part in geom.tables(7)? {
+        if let Some([x_min, y_min, x_max, y_max]) = geometry_bounds(&part)? {
+            let [a, b, c, d] = bounds.unwrap_or([x_min, y_min, x_max, y_max]);
+            bounds = Some([a.min(x_min), b.min(y_min), c.max(x_max), d.max(y_max)]);
+        }
+    }
+    Ok(bounds)
+}
+
+fn intersects(a: [f64; 4], b: [f64; 4]) -> bool {
+    a[0] <= b[2] && a[1] <= b[3] && a[2] >= b[0] && a[3] >= b[1]
+}
+
+fn read_at(file: &mut BufReader<File>, path: &str, offset: u64, len: usize) -> GResult<Vec<u8>> {
+    let mut buf = vec![0; len];
+    file.seek(SeekFrom::Start(offset))
+        .and_then(|_| file.read_exact(&mut buf))
+        .map_err(|e| GError::GenericError(format!("Couldn't read {path:?}: {e}")))?;
+    Ok(buf)
+}
+
+/// A FlatGeobuf file, whose features are read on demand.
+struct Reader {
+    path: String,
+    file: BufReader<File>,
+    len: u64,
+    header: Header,
+    /// Position of the spatial index in the file.
+    index_offset: u64,
+    /// Position of the first feature in the file.
+    features_offset: u64,
+}
+
+impl Reader {
+    fn open(path: &str) -> GResult<Self> {
+        let error =
+            |e: std::io::Error| GError::GenericError(format!("Couldn't read {path:?}: {e}"));
+        let file = File::open(path).map_err(error)?;
+        let len = file.metadata().map_err(error)?.len();
+        let mut file = BufReader::new(file);
+        let start = read_at(&mut file, path, 0, 12)?;
+        if start[..3] != *b"fgb" || start[4..7] != *b"fgb" {
+            return Err(invalid());
+        }
+        let header_size = read::<u32>(&start, 8)?;
+        let header = Header::parse(&read_at(&mut file, path, 12, header_size as usize)?)?;
+        let mut reader = Self {
+            path: path.to_owned(),
+            file,
+            len,
+            header,
+            index_offset: 12 + u64::from(header_size),
+            features_offset: 0,
+        };
+        reader.features_offset = reader.index_offset + reader.index_size() as u64;
+        Ok(reader)
+    }
+
+    fn index_size(&self) -> usize {
+        let node_size = usize::from(self.header.index_node_size);
+        let num_items = self.header.features_count as usize;
+        if node_size < 2 || num_items == 0 {
+            return 0;
+        }
+        level_bounds(num_items, node_size)[0].end * NODE_ITEM_SIZE
+    }
+
+    /// Returns the buffer of the feature at an offset from the first feature,
+    /// without its size prefix, or `None` at the end of the file.
+    fn feature(&mut self, offset: u64) -> GResult<Option<Vec<u8>>> {
+        let position = self.features_offset + offset;
+        if position + 4 > self.len {
+            return Ok(None);
+        }
+        let size = read::<u32>(&read_at(&mut self.file, &self.path, position, 4)?, 0)?;
+        read_at(&mut self.file, &self.path, position + 4, size as usize).map(Some)
+    }
+
+    /// Returns the offsets of the features in file order, only keeping those
+    /// intersecting `bbox`. The spatial index is searched when the file has
+    /// one, otherwise features are filtered by the bounds of their geometry.
+    fn offsets(&mut self, bbox: Option<[f64; 4]>) -> GResult<Vec<u64>> {
+        let index_size = self.index_size();
+        if let (Some(bbox), 1..) = (bbox, index_size) {
+            let index = read_at(&mut self.file, &self.path, self.index_offset, index_size)?;
+            let node_size = usize::from(self.header.index_node_size);
+            let levels = level_bounds(self.header.features_count as usize, node_size);
+            let leaves = levels[0].start;
+            let mut offsets = vec![];
+            let mut queue = vec![(0, levels.len() - 1)];
+            while let Some((node, level)) = queue.pop() {
+                for pos in node..(node + node_size).min(levels[level].end) {
+                    let item = pos * NODE_ITEM_SIZE;
+                    let mut bounds = [0.0; 4];
+                    for (i, value) in bounds.iter_mut().enumerate() {
+                        *value = read(&index, item + 8 * i)?;
+                    }
+                    if !intersects(bounds, bbox) {
+                        continue;
+                    }
+                    let offset = read::<u64>(&index, item + 32)?;
+                    match pos >= leaves {
+                        true => offsets.push(offset),
+                        false => queue.push((offset as usize, level - 1)),
+                    }
+                }
+            }
+            offsets.sort_unstable();
+            return Ok(offsets);
+        }
+        let mut offsets = vec![];
+        let mut offset = 0;
+        while let Some(buf) = self.feature(offset)? {
+            let keep = match bbox {
+                Some(bbox) => {
+                    let geometry = Table::root(&buf)?.table(0)?;
+                    let bounds = geometry.map(|g| geometry_bounds(&g)).transpose()?;
+                    bounds
+                        .flatten()
+                        .is_some_and(|bounds| intersects(bounds, bbox))
+                }
+                None => true,
+            };
+            if keep {
+                offsets.push(offset);
+            }
+            offset += 4 + buf.len() as u64;
+        }
+        Ok(offsets)
+    }
+
+    fn geometry(&self, feature: &Table) -> GResult<Option<Vec<u8>>> {
+        let Some(geom) = feature.table(0)? else {
+            return Ok(None);
+        };
+        let kind = match geom.scalar::<u8>(6, 0)? {
+            0 => self.header.geometry_type,
+            kind => kind,
+        };
+        let mut writer = WkbWriter {
+            wkb: vec![],
+            has_z: self.header.has_z,
+            has_m: self.header.has_m,
+        };
+        writer.write(&geom, kind)?;
+        let mut geom = Geometry::new_from_wkb(&writer.wkb)?;
+        geom.set_srid(self.header.srid);
+        geom.to_ewkb().map(Some)
+    }
+
+    fn properties(&self, feature: &Table, row: &mut [Option<Value>]) -> GResult<()> {
+        use column_type::{
+            BINARY, BOOL, BYTE, DATETIME, DOUBLE, FLOAT, INT, JSON, LONG, SHORT, STRING, UBYTE,
+            UINT, ULONG, USHORT,
+        };
+        let buf = feature.bytes(1)?;
+        let mut pos = 0;
+        while pos < buf.len() {
+            let index = usize::from(read::<u16>(buf, pos)?);
+            let column = self.header.columns.get(index).ok_or_else(invalid)?;
+            pos += 2;
+            let (value, size) = match column.kind {
+                BYTE => (Value::Int(read::<i8>(buf, pos)?.into()), 1),
+                UBYTE => (Value::Int(read::<u8>(buf, pos)?.into()), 1),
+                BOOL => (Value::Bool(read::<u8>(buf, pos)? != 0), 1),
+                SHORT => (Value::Int(read::<i16>(buf, pos)?.into()), 2),
+                USHORT => (Value::Int(read::<u16>(buf, pos)?.into()), 2),
+                INT => (Value::Int(read::<i32>(buf, pos)?.into()), 4),
+                UINT => (Value::Int(read::<u32>(buf, pos)?.into()), 4),
+                LONG => (Value::Int(read::<i64>(buf, pos)?), 8),
+                ULONG => (Value::UInt(read::<u64>(buf, pos)?), 8),
+                FLOAT => (Value::Float(read::<f32>(buf, pos)?.into()), 4),
+                DOUBLE => (Value::Float(read::<f64>(buf, pos)?), 8),
+                STRING | JSON | DATETIME | BINARY => {
+                    let len = read::<u32>(buf, pos)? as usize;
+                    let bytes = buf.get(pos + 4..pos + 4 + len).ok_or_else(invalid)?;
+                    let value = match column.kind {
+                        BINARY => Value::Binary(bytes.to_vec()),
+                        _ => Value::String(String::from_utf8_lossy(bytes).into_owned()),
+                    };
+                    (value, 4 + len)
+                }
+                _ => return Err(invalid()),
+            };
+            row[index] = Some(value);
+            pos += size;
+        }
+        Ok(())
+    }
+
+    /// Reads the features at the given offsets, with their geometry if
+    /// `read_geometry` is true and the given property columns.
+    fn read(
+        &mut self,
+        offsets: &[u64],
+        columns: Option<Vec<String>>,
+        read_geometry: bool,
+    ) -> GResult<DataFrame> {
+        let mut geometries = vec![];
+        let mut values = self
+            .header
+            .columns
+            .iter()
+            .map(|_| Vec::with_capacity(offsets.len()))
+            .collect::<Vec<_>>();
+        for &offset in offsets {
+            let buf = self.feature(offset)?.ok_or_else(invalid)?;
+            let feature = Table::root(&buf)?;
+            if read_geometry {
+                geometries.push(self.geometry(&feature)?);
+            }
+            let mut row = self.header.columns.iter().map(|_| None).collect::<Vec<_>>();
+            self.properties(&feature, &mut row)?;
+            for (column, value) in values.iter_mut().zip(row) {
+                column.push(value);
+            }
+        }
+        let mut df_columns = vec![];
+        if read_geometry {
+            let geometry =
+                BinaryChunked::from_iter_options("geometry".into(), geometries.into_iter());
+            df_columns.push(geometry.into_column());
+        }
+        for (def, values) in self.header.columns.iter().zip(values) {
+            if columns.as_ref().is_none_or(|c| c.contains(&def.name)) {
+                df_columns.push(to_column(def, values)?);
+            }
+        }
+        DataFrame::new(df_columns).map_err(|e| GError::GenericError(e.to_string()))
+    }
+}
+
+fn to_column(def: &ColumnDef, values: Vec<Option<Value>>) -> GResult<Column> {
+    let name = def.name.as_str().into();
+    let series = match def.kind {
+        column_type::BOOL => values
+            .into_iter()
+            .map(|value| match value {
+                Some(Value::Bool(value)) => Some(value),
+                _ => None,
+            })
+            .collect::<BooleanChunked>()
+            .with_name(name)
+            .into_series(),
+        column_type::ULONG => values
+            .into_iter()
+            .map(|value| match value {
+                Some(Value::UInt(value)) => Some(value),
+                _ => None,
+            })
+            .collect::<UInt64Chunked>()
+            .with_name(name)
+            .into_series(),
+        column_type::FLOAT | column_type::DOUBLE => values
+            .into_iter()
+            .map(|value| match value {
+                Some(Value::Float(value)) => Some(value),
+                _ => None,
+            })
+            .collect::<Float64Chunked>()
+            .with_name(name)
+            .into_series(),
+        column_type::STRING | column_type::JSON => values
+            .into_iter()
+            .map(|value| match value {
+                Some(Value::String(value)) => Some(value),
+                _ => None,
+            })
+            .collect::<StringChunked>()
+            .with_name(name)
+            .into_series(),
+        column_type::DATETIME => values
+            .into_iter()
+            .map(|value| match value {
+                Some(Value::String(value)) => gpx::parse_time(&value),
+                _ => None,
+            })
+            .collect::<Int64Chunked>()
+            .with_name(name)
+            .into_series(),
+        column_type::BINARY => BinaryChunked::from_iter_options(
+            name,
+            values.into_iter().map(|value| match value {
+                Some(Value::Binary(value)) => Some(value),
+                _ => None,
+            }),
+        )
+        .into_series(),
+        _ => values
+            .into_iter()
+            .map(|value| match value {
+                Some(Value::Int(value)) => Some(value),
+                _ => None,
+            })
+            .collect::<Int64Chunked>()
+            .with_name(name)
+            .into_series(),
+    };
+    let series = series
+        .cast(&def.dtype()?)
+        .map_err(|e| GError::GenericError(e.to_string()))?;
+    Ok(series.into_column())
+}
+
+/// Returns the offsets of the features of a FlatGeobuf file intersecting a
+/// bounding box, using its packed Hilbert R-tree when it has one.
+#[pyfunction]
+#[pyo3(signature = (path, bbox=None))]
+pub fn flatgeobuf_offsets(path: &str, bbox: Option<[f64; 4]>) -> Result<PySeries, PyPolarsErr> {
+    let offsets = Reader::open(path)
+        .and_then(|mut reader| reader.offsets(bbox))
+        .map_err(to_compute_err)?;
+    Ok(PySeries(
+        UInt64Chunked::from_vec("offset".into(), offsets).into_series(),
+    ))
+}
+
+/// Reads the features of a FlatGeobuf file at the given offsets, as returned
+/// by `flatgeobuf_offsets`.
+#[pyfunction]
+#[pyo3(signature = (path, offsets, columns=None, read_geometry=true))]
+pub fn read_flatgeobuf(
+    path: &str,
+    offsets: PySeries,
+    columns: Option<Vec<String>>,
+    read_geometry: bool,
+) -> Result<PyDataFrame, PyPolarsErr> {
+    let offsets = offsets.0.u64()?.into_no_null_iter().collect::<Vec<_>>();
+    Reader::open(path)
+        .and_then(|mut reader| reader.read(&offsets, columns, read_geometry))
+        .map(PyDataFrame)
+        .map_err(|e| to_compute_err(e).into())
+}
@@ -1,30 +1,60 @@
-use std::collections::HashMap;
+use std::{
+    cell::{Cell, RefCell},
+    collections::{hash_map::Entry, HashMap, HashSet},
+    sync::{
+        atomic::{AtomicBool, Ordering as AtomicOrdering},
+        Arc, LazyLock, OnceLock,
+    },
+};
 
+#[cfg(feature = "proj")]
+use crate::libproj;
 use crate::{
+    antimeridian,
     args::{
-        BufferKwargs, ConcaveHullKwargs, DelaunayTrianlesKwargs, OffsetCurveKwargs,
-        SetPrecisionKwargs, SpatialJoinPredicate, ToGeoJsonKwargs, ToWkbKwargs, ToWktKwargs,
-        VoronoiKwargs,
+        Antimeridian, BoundsToSridKwargs, BufferGeodesicKwargs, BufferKwargs, BufferRingsKwargs,
+        ConcaveHullKwargs, CrsTransformKwargs, DelaunayTrianlesKwargs, ExtractType,
+        GeoArrowDimensions, H3Kwargs, MakeValidKwargs, MakeValidMethod, MixedDimensions,
+        MortonIndexKwargs, OffsetCurveKwargs, OnError, SegmentizeGeodesicKwargs,
+        SetPrecisionKwargs, SnapToGridKwargs, SpatialJoinAlgorithm, SpatialJoinKwargs,
+        SpatialJoinPredicate, SpatialJoinStrategy, SpatialPartitionKwargs, ToEsriJsonKwargs,
+        ToGeoJsonKwargs, ToSridKwargs, ToSvgKwargs, ToWkbKwargs, ToWktKwargs, VoronoiKwargs,
+        WkbFlavor,
     },
     arity::{
-        broadcast_try_binary_elementwise_values, broadcast_try_ternary_elementwise_values,
-        try_ternary_elementwise_values, try_unary_elementwise_values_with_dtype,
+        broadcast_try_binary_elementwise_values,
+        broadcast_try_binary_elementwise_values_with_dtype,
+        broadcast_try_ternary_elementwise_values, try_ternary_elementwise_values,
+        try_unary_elementwise_values_with_dtype,
     },
-    wkb::{WKBGeometryType, WKBHeader},
+    crs, curves, dxf, esri, esrijson, geoarrow, geodesic,
+    geoid::Geoid,
+    gml, h3, kml, measure_3d,
+    ntv2::Grid,
+    partition::{self, Bounds, Partitioner},
+    pipeline::{Pipeline, DATUM_PARAMS},
+    prepared,
+    rtree::RTree,
+    spatialite, svg, sweep,
+    tiles::{self, Tile},
+    topojson, visvalingam,
+    wkb::{self, GpkgHeader, WKBGeometryType, WKBHeader},
 };
 use geos::{
-    BufferParams, CoordSeq, Error as GError, GResult, GeoJSONWriter, Geom, Geometry,
+    BufferParams, ByteOrder, CoordSeq, Error as GError, GResult, GeoJSONWriter, Geom, Geometry,
     GeometryTypes::{self, *},
-    PreparedGeometry, STRtree, SpatialIndex, WKBWriter, WKTWriter,
+    MakeValidParams, OutputDimension, PreparedGeometry, WKBWriter, WKTWriter,
 };
 
 use polars::prelude::arity::{broadcast_try_binary_elementwise, try_unary_elementwise};
 use polars::prelude::*;
-use polars_arrow::array::{Array, BinaryViewArray};
-use proj4rs::errors::Error as ProjError;
+use polars_arrow::array::{Array, BinaryViewArray, FixedSizeListArray};
+use polars_arrow::bitmap::MutableBitmap;
+use polars_arrow::offset::Offsets;
 use proj4rs::Proj;
 use pyo3::prelude::*;
-use pyo3_polars::export::polars_core::utils::arrow::array::Float64Array;
+use pyo3_polars::export::polars_core::{utils::arrow::array::Float64Array, POOL};
+use rayon::prelude::*;
 
 pub trait GeometryUtils {
     fn to_ewkb(&self) -> GResult<Vec<u8>>;
@@ -255,8 +285,34 @@ where
     }
 }
 
-pub fn from_wkb(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
-    wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.to_ewkb())
+/// Parses WKB or EWKB blobs. Blobs which GEOS can't read, either because they
+/// are corrupt or because they describe malformed geometries such as unclosed
+/// rings, raise an error or give null depending on `on_error`.
+pub fn from_wkb(wkb: &BinaryChunked, on_error: OnError) -> GResult<BinaryChunked> {
+    if let OnError::Raise = on_error {
+        return wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.to_ewkb());
+    }
+    try_unary_elementwise(wkb, |wkb| {
+        let Some(Ok(geom)) = wkb.map(Geometry::new_from_wkb) else {
+            return Ok(None);
+        };
+        let geom = match on_error {
+            OnError::Repair if !geom.is_valid()? => {
+                let mut valid = geom.make_valid()?;
+                valid.set_srid(geom.get_srid()?);
+                valid
+            }
+            _ => geom,
+        };
+        geom.to_ewkb().map(Some)
+    })
+}
+
+pub fn from_ewkb_hex(hex: &StringChunked) -> GResult<BinaryChunked> {
+    hex.try_apply_nonnull_values_generic(|hex| {
+        let hex = hex.strip_prefix("\\x").unwrap_or(hex);
+        Geometry::new_from_hex(hex.as_bytes())?.to_ewkb()
+    })
 }
 
 pub fn from_wkt(wkt: &StringChunked) -> GResult<BinaryChunked> {
@@ -287,6 +343,75 @@ pub fn from_geojson(json: &StringChunked) -> GResult<BinaryChunked> {
     json.try_apply_nonnull_values_generic(|json| Geometry::new_from_geojson(json)?.to_ewkb())
 }
 
+pub fn from_gml(gml: &StringChunked) -> GResult<BinaryChunked> {
+    gml.try_apply_nonnull_values_generic(|gml| gml::from_gml(gml)?.to_ewkb())
+}
+
+pub fn from_kml(kml: &StringChunked) -> GResult<BinaryChunked> {
+    kml.try_apply_nonnull_values_generic(|kml| kml::from_kml(kml)?.to_ewkb())
+}
+
+pub fn from_gpkg(blob: &BinaryChunked) -> GResult<BinaryChunked> {
+    blob.try_apply_nonnull_values_generic(|blob| {
+        let header = GpkgHeader::try_from(blob)?;
+        let mut geom = Geometry::new_from_wkb(&blob[header.size..])?;
+        if header.srid > 0 {
+            geom.set_srid(header.srid);
+        }
+        geom.to_ewkb()
+    })
+}
+
+pub fn from_spatialite(blob: &BinaryChunked) -> GResult<BinaryChunked> {
+    blob.try_apply_nonnull_values_generic(|blob| spatialite::from_spatialite(blob)?.to_ewkb())
+}
+
+pub fn from_geoarrow(array: &dyn Array, geometry_type: WKBGeometryType) -> GResult<BinaryChunked> {
+    let decoder = geoarrow::Decoder::new(array, geometry_type)?;
+    (0..array.len())
+        .map(|n| decoder.get(n)?.map(|geom| geom.to_ewkb()).transpose())
+        .collect()
+}
+
+pub fn from_ragged_array(
+    geometry_type: WKBGeometryType,
+    coords: &Float64Chunked,
+    dimension: usize,
+    offsets: &[&Int64Chunked],
+) -> GResult<BinaryChunked> {
+    let coords = coords.rechunk();
+    let values = coords
+        .downcast_iter()
+        .next()
+        .ok_or_else(|| GError::GenericError("Invalid ragged array coordinates".into()))?;
+    let dtype = FixedSizeListArray::default_datatype(ArrowDataType::Float64, dimension);
+    let length = values.len() / dimension.max(1);
+    let mut array = FixedSizeListArray::new(dtype, length, values.clone().boxed(), None).boxed();
+    for offsets in offsets {
+        let offsets = Offsets::try_from(offsets.into_no_null_iter().collect::<Vec<_>>())
+            .map_err(|e| GError::GenericError(e.to_string()))?;
+        let dtype = LargeListArray::default_datatype(array.dtype().clone());
+        array = LargeListArray::new(dtype, offsets.into(), array, None).boxed();
+    }
+    from_geoarrow(array.as_ref(), geometry_type)
+}
+
+pub fn from_esrijson(json: &StringChunked) -> GResult<BinaryChunked> {
+    let wkb = json
+        .iter()
+        .map(|json| {
+            let Some(json) = json else { return Ok(None) };
+            esrijson::from_esrijson(json)?
+                .map(|geom| geom.to_ewkb())
+                .transpose()
+        })
+        .collect::<GResult<Vec<_>>>()?;
+    Ok(BinaryChunked::from_iter_options(
+        json.name().clone(),
+        wkb.into_iter(),
+    ))
+}
+
 pub fn rectangle(bounds: &ArrayChunked) -> GResult<BinaryChunked> {
     bounds.try_apply_nonnull_values_generic(|bounds| {
         let bounds = unsafe { bounds.as_any().downcast_ref_unchecked::<Float64Array>() };
@@ -298,6 +423,25 @@ pub fn rectangle(bounds: &ArrayChunked) -> GResult<BinaryChunked> {
     })
 }
 
+pub fn from_tile(
+    z: &UInt8Chunked,
+    x: &UInt32Chunked,
+    y: &UInt32Chunked,
+    params: &TileKwargs,
+) -> GResult<BinaryChunked> {
+    broadcast_try_ternary_elementwise_values(z, x, y, |z, x, y| {
+        Tile::new(z, x, y)?.to_polygon(params.srid)?.to_ewkb()
+    })
+}
+
+pub fn from_quadkey(quadkeys: &StringChunked, params: &TileKwargs) -> GResult<BinaryChunked> {
+    quadkeys.try_apply_nonnull_values_generic(|quadkey| {
+        Tile::from_quadkey(quadkey)?
+            .to_polygon(params.srid)?
+            .to_ewkb()
+    })
+}
+
 fn get_coordinate_type(dimension: usize) -> GResult<(bool, bool)> {
     match dimension {
         2 => Ok((false, false)),
@@ -407,6 +551,49 @@ pub fn polygon(coords: &ListChunked) -> GResult<BinaryChunked> {
     })
 }
 
+/// Builds a linear ring from coordinates, which must be closed, reversing it
+/// if it isn't oriented as requested.
+#[allow(clippy::float_cmp)]
+fn oriented_ring(coords: Box<dyn Array>, ccw: bool) -> GResult<Geometry> {
+    let coord_seq = get_coordinate_seq_from_array(coords)?;
+    let size = coord_seq.size()?;
+    if size == 0 {
+        return Geometry::create_linear_ring(coord_seq);
+    }
+    let is_closed = coord_seq.get_x(0)? == coord_seq.get_x(size - 1)?
+        && coord_seq.get_y(0)? == coord_seq.get_y(size - 1)?;
+    if !is_closed {
+        let msg = "invalid ring: first and last coordinates must be equal".into();
+        return Err(GError::GenericError(msg));
+    }
+    let ring = Geometry::create_linear_ring(coord_seq)?;
+    match ring.get_coord_seq()?.is_ccw()? == ccw {
+        true => Ok(ring),
+        false => ring.reverse(),
+    }
+}
+
+pub fn polygon_from_rings(exterior: &ListChunked, holes: &ListChunked) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise(exterior, holes, |exterior, holes| {
+        let Some(exterior) = exterior else {
+            return Ok(None);
+        };
+        let exterior = oriented_ring(exterior, true)?;
+        let interiors = match holes {
+            Some(holes) => {
+                let holes = unsafe { holes.as_any().downcast_ref_unchecked::<LargeListArray>() };
+                holes
+                    .iter()
+                    .flatten()
+                    .map(|hole| oriented_ring(hole, false))
+                    .try_collect()?
+            }
+            None => Vec::new(),
+        };
+        Some(Geometry::create_polygon(exterior, interiors)?.to_ewkb()).transpose()
+    })
+}
+
 pub fn get_type_id(wkb: &BinaryChunked) -> GResult<UInt32Chunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| {
         WKBHeader::try_from(wkb).map(|header| header.geometry_type as u32)
@@ -434,6 +621,42 @@ pub fn get_srid(wkb: &BinaryChunked) -> GResult<Int32Chunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| WKBHeader::try_from(wkb).map(|header| header.srid))
 }
 
+pub type CrsInfoColumns = (
+    StringChunked,
+    StringChunked,
+    StringChunked,
+    BooleanChunked,
+    StringChunked,
+);
+
+pub fn crs_info(wkb: &BinaryChunked) -> GResult<CrsInfoColumns> {
+    let mut cache: HashMap<i32, Option<crs::CrsInfo>> = HashMap::new();
+    let infos = wkb
+        .into_iter()
+        .map(|wkb| {
+            let Some(wkb) = wkb else { return Ok(None) };
+            let srid = WKBHeader::try_from(wkb)?.srid;
+            Ok(cache
+                .entry(srid)
+                .or_insert_with(|| crs::get_crs_info(srid))
+                .clone())
+        })
+        .try_collect::<Vec<_>>()?;
+    let string_column = |name: &str, f: fn(&crs::CrsInfo) -> Option<&str>| {
+        StringChunked::from_iter_options(name.into(), infos.iter().map(|i| i.as_ref().and_then(f)))
+    };
+    Ok((
+        string_column("authority", |i| i.authority.as_deref()),
+        string_column("name", |i| i.name.as_deref()),
+        string_column("units", |i| i.units.as_deref()),
+        BooleanChunked::from_iter_options(
+            "is_geographic".into(),
+            infos.iter().map(|i| i.as_ref().map(|i| i.is_geographic)),
+        ),
+        string_column("axis_order", |i| i.axis_order.as_deref()),
+    ))
+}
+
 pub fn set_srid(wkb: &BinaryChunked, srid: &Int32Chunked) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(wkb, srid, |wkb, srid| {
         let mut geom = Geometry::new_from_wkb(wkb)?;
@@ -684,6 +907,232 @@ pub fn get_parts(wkb: &BinaryChunked) -> GResult<ListChunked> {
     })
 }
 
+/// The vertices of a column of geometries, flattened, along with their
+/// position in the structure of their geometry.
+#[derive(Default)]
+struct DumpedPoints {
+    points: Vec<Vec<u8>>,
+    part_index: Vec<u32>,
+    ring_index: Vec<Option<u32>>,
+    vertex_index: Vec<u32>,
+}
+
+/// Returns the vertices of a point or a line, including the control points of
+/// circular arcs. Consecutive components of compound curves share their end
+/// points, which are returned once.
+fn vertex_coords<T: Geom>(geom: &T, dimension: usize) -> GResult<Vec<f64>> {
+    match geom.geometry_type()? {
+        Point | LineString | LinearRing | CircularString => {
+            geom.get_coord_seq()?.as_buffer(Some(dimension))
+        }
+        CompoundCurve => {
+            let mut coords = Vec::new();
+            for n in 0..geom.get_num_geometries()? {
+                let component = vertex_coords(&geom.get_geometry_n(n)?, dimension)?;
+                let skip = if coords.is_empty() { 0 } else { dimension };
+                coords.extend_from_slice(&component[skip.min(component.len())..]);
+            }
+            Ok(coords)
+        }
+        other => Err(GError::GenericError(format!(
+            "Can't dump the points of geometries of type {other:?}"
+        ))),
+    }
+}
+
+impl DumpedPoints {
+    fn push_coords<T: Geom>(&mut self, geom: &T, part: u32, ring: Option<u32>) -> GResult<()> {
+        let srid = geom.get_srid()?;
+        let (has_z, has_m) = (geom.has_z()?, geom.has_m()?);
+        let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+        let coords = vertex_coords(geom, dimension)?;
+        for (index, coord) in coords.chunks_exact(dimension).enumerate() {
+            let coord_seq = CoordSeq::new_from_buffer(coord, 1, has_z, has_m)?;
+            let mut point = Geometry::create_point(coord_seq)?;
+            point.set_srid(srid);
+            self.points.push(point.to_ewkb()?);
+            self.part_index.push(part);
+            self.ring_index.push(ring);
+            self.vertex_index.push(index as u32);
+        }
+        Ok(())
+    }
+
+    /// Pushes the vertices of a geometry. Parts are numbered from the
+    /// top-level collection, so that vertices of nested collections share the
+    /// index of their top-level part.
+    fn push<T: Geom>(&mut self, geom: &T, part: Option<u32>) -> GResult<()> {
+        match geom.geometry_type()? {
+            _ if geom.is_empty()? => Ok(()),
+            Polygon | CurvePolygon => {
+                let part = part.unwrap_or(0);
+                self.push_coords(&geom.get_exterior_ring()?, part, Some(0))?;
+                (0..geom.get_num_interior_rings()?).try_for_each(|n| {
+                    let ring = geom.get_interior_ring_n(n)?;
+                    self.push_coords(&ring, part, Some(n as u32 + 1))
+                })
+            }
+            MultiPoint | MultiLineString | MultiPolygon | MultiCurve | MultiSurface
+            | GeometryCollection => (0..geom.get_num_geometries()?).try_for_each(|n| {
+                let part = part.unwrap_or(n as u32);
+                self.push(&geom.get_geometry_n(n)?, Some(part))
+            }),
+            _ => self.push_coords(geom, part.unwrap_or(0), None),
+        }
+    }
+}
+
+/// Returns, for each geometry, the list of its vertices as points with
+/// their part, ring and vertex index.
+pub fn dump_points(wkb: &BinaryChunked) -> GResult<ListChunked> {
+    let to_geos_err = |e: PolarsError| GError::GenericError(e.to_string());
+    let mut offsets = Vec::with_capacity(wkb.len() + 1);
+    offsets.push(0i64);
+    let mut validity = MutableBitmap::with_capacity(wkb.len());
+    let mut dumped = DumpedPoints::default();
+    for wkb in wkb {
+        if let Some(wkb) = wkb {
+            dumped.push(&Geometry::new_from_wkb(wkb)?, None)?;
+        }
+        validity.push(wkb.is_some());
+        offsets.push(dumped.points.len() as i64);
+    }
+    let values = StructChunked::from_columns(
+        PlSmallStr::EMPTY,
+        dumped.points.len(),
+        &[
+            BinaryChunked::from_iter_values("point".into(), dumped.points.iter()).into_column(),
+            UInt32Chunked::from_vec("part_index".into(), dumped.part_index).into_column(),
+            UInt32Chunked::from_iter_options("ring_index".into(), dumped.ring_index.into_iter())
+                .into_column(),
+            UInt32Chunked::from_vec("vertex_index".into(), dumped.vertex_index).into_column(),
+        ],
+    )
+    .map_err(to_geos_err)?
+    .into_series()
+    .rechunk()
+    .to_arrow(0, CompatLevel::newest());
+    let dtype = LargeListArray::default_datatype(values.dtype().clone());
+    let offsets = Offsets::try_from(offsets).map_err(to_geos_err)?;
+    let array = LargeListArray::new(dtype, offsets.into(), values, validity.into());
+    Series::from_arrow(wkb.name().clone(), array.boxed())
+        .and_then(|s| s.list().cloned())
+        .map_err(to_geos_err)
+}
+
+fn is_collection(geometry_type: GeometryTypes) -> bool {
+    matches!(
+        geometry_type,
+        MultiPoint
+            | MultiLineString
+            | MultiCurve
+            | MultiPolygon
+            | MultiSurface
+            | GeometryCollection
+    )
+}
+
+fn extract_type(geometry_type: GeometryTypes) -> Option<ExtractType> {
+    match geometry_type {
+        Point => Some(ExtractType::Point),
+        LineString | LinearRing | CircularString | CompoundCurve => Some(ExtractType::Line),
+        Polygon | CurvePolygon => Some(ExtractType::Polygon),
+        _ => None,
+    }
+}
+
+/// Collects the non-empty parts of a geometry, looking into nested
+/// collections. Linear rings are collected as linestrings.
+fn flatten_parts<T: Geom>(geom: &T, parts: &mut Vec<Geometry>) -> GResult<()> {
+    match geom.geometry_type()? {
+        _ if geom.is_empty()? => {}
+        geometry_type if is_collection(geometry_type) => {
+            for n in 0..geom.get_num_geometries()? {
+                flatten_parts(&geom.get_geometry_n(n)?, parts)?;
+            }
+        }
+        LinearRing => parts.push(Geometry::create_line_string(geom.get_coord_seq()?)?),
+        _ => parts.push(Geom::clone(geom)?),
+    }
+    Ok(())
+}
+
+/// Creates the multipart geometry of parts of the given type, which is a
+/// `MultiCurve` or `MultiSurface` if some of them are curved.
+fn create_multi(into: ExtractType, parts: Vec<Geometry>) -> GResult<Geometry> {
+    let mut curved = false;
+    for part in &parts {
+        curved |= matches!(
+            part.geometry_type()?,
+            CircularString | CompoundCurve | CurvePolygon
+        );
+    }
+    match (into, curved) {
+        (ExtractType::Point, _) => Geometry::create_multipoint(parts),
+        (ExtractType::Line, false) => Geometry::create_multiline_string(parts),
+        (ExtractType::Line, true) => Geometry::create_multicurve(parts),
+        (ExtractType::Polygon, false) => Geometry::create_multipolygon(parts),
+        (ExtractType::Polygon, true) => Geometry::create_multisurface(parts),
+    }
+}
+
+/// Returns the parts of each geometry of the given type. Collections give
+/// the multipart geometry of their matching parts, and single geometries are
+/// returned as is, or as an empty geometry of the given type if they don't
+/// match.
+pub fn collection_extract(wkb: &BinaryChunked, into: ExtractType) -> GResult<BinaryChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let mut parts = Vec::new();
+        flatten_parts(&geom, &mut parts)?;
+        let mut matching = Vec::with_capacity(parts.len());
+        for part in parts {
+            if extract_type(part.geometry_type()?) == Some(into) {
+                matching.push(part);
+            }
+        }
+        let mut extracted = if is_collection(geom.geometry_type()?) {
+            create_multi(into, matching)?
+        } else if let Some(part) = matching.pop() {
+            part
+        } else {
+            match into {
+                ExtractType::Point => Geometry::create_empty_point(),
+                ExtractType::Line => Geometry::create_empty_line_string(),
+                ExtractType::Polygon => Geometry::create_empty_polygon(),
+            }?
+        };
+        extracted.set_srid(geom.get_srid()?);
+        extracted.to_ewkb()
+    })
+}
+
+/// Flattens nested collections into a single level. Collections whose parts
+/// are all of the same type give the corresponding multipart geometry, and
+/// other geometries are returned as is.
+pub fn unnest_collection(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        if !is_collection(geom.geometry_type()?) || geom.is_empty()? {
+            return geom.to_ewkb();
+        }
+        let mut parts = Vec::new();
+        flatten_parts(&geom, &mut parts)?;
+        let mut types = Vec::with_capacity(parts.len());
+        for part in &parts {
+            types.push(extract_type(part.geometry_type()?));
+        }
+        let mut unnested = match types.first() {
+            Some(Some(first)) if types.iter().all(|t| t == &Some(*first)) => {
+                create_multi(*first, parts)?
+            }
+            _ => Geometry::create_geometry_collection(parts)?,
+        };
+        unnested.set_srid(geom.get_srid()?);
+        unnested.to_ewkb()
+    })
+}
+
 pub fn get_precision(wkb: &BinaryChunked) -> GResult<Float64Chunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.get_precision())
 }
@@ -700,6 +1149,86 @@ pub fn set_precision(
     })
 }
 
+/// Snaps a geometry to a grid, then removes the consecutive vertices which
+/// were snapped together. Parts collapsing below their minimum number of
+/// vertices are dropped from their polygon or collection, and give an empty
+/// geometry otherwise.
+fn snap_geom<T: Geom>(geom: &T, snap_xy: &impl Fn(f64, f64) -> (f64, f64)) -> GResult<Geometry> {
+    if geom.is_empty()? {
+        return Geom::clone(geom);
+    }
+    let parts = || {
+        let mut parts = Vec::new();
+        for n in 0..geom.get_num_geometries()? {
+            let part = snap_geom(&geom.get_geometry_n(n)?, snap_xy)?;
+            if !part.is_empty()? {
+                parts.push(part);
+            }
+        }
+        GResult::Ok(parts)
+    };
+    match geom.geometry_type()? {
+        Polygon => {
+            let exterior = snap_geom(&geom.get_exterior_ring()?, snap_xy)?;
+            if exterior.is_empty()? {
+                return Geometry::create_empty_polygon();
+            }
+            let mut interiors = Vec::new();
+            for n in 0..geom.get_num_interior_rings()? {
+                let interior = snap_geom(&geom.get_interior_ring_n(n)?, snap_xy)?;
+                if !interior.is_empty()? {
+                    interiors.push(interior);
+                }
+            }
+            Geometry::create_polygon(exterior, interiors)
+        }
+        MultiPoint => Geometry::create_multipoint(parts()?),
+        MultiLineString => Geometry::create_multiline_string(parts()?),
+        MultiPolygon => Geometry::create_multipolygon(parts()?),
+        MultiCurve => Geometry::create_multicurve(parts()?),
+        MultiSurface => Geometry::create_multisurface(parts()?),
+        GeometryCollection => Geometry::create_geometry_collection(parts()?),
+        geometry_type => {
+            let snapped = geom.transform_xy(|x, y| Ok(snap_xy(x, y)))?;
+            // Removing the repeated points fails when too few vertices remain.
+            match snapped.remove_repeated_points(0.0) {
+                Ok(snapped) => Ok(snapped),
+                Err(_) => create_empty(geometry_type),
+            }
+        }
+    }
+}
+
+/// Snaps the coordinates of each geometry to a grid, then removes the
+/// consecutive vertices which were snapped together. A size of zero leaves
+/// the corresponding coordinate untouched.
+pub fn snap_to_grid(wkb: &BinaryChunked, params: &SnapToGridKwargs) -> GResult<BinaryChunked> {
+    let SnapToGridKwargs {
+        size_x,
+        size_y,
+        origin: [origin_x, origin_y],
+    } = *params;
+    if [size_x, size_y]
+        .iter()
+        .any(|size| !size.is_finite() || *size < 0.0)
+    {
+        return Err(GError::GenericError(format!(
+            "snap_to_grid sizes must be non-negative, got {size_x} and {size_y}"
+        )));
+    }
+    let snap = |value: f64, origin: f64, size: f64| match size > 0.0 {
+        true => origin + ((value - origin) / size).round() * size,
+        false => value,
+    };
+    let snap_xy = |x, y| (snap(x, origin_x, size_x), snap(y, origin_y, size_y));
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let mut snapped = snap_geom(&geom, &snap_xy)?;
+        snapped.set_srid(geom.get_srid()?);
+        snapped.to_ewkb()
+    })
+}
+
 pub fn to_wkt(wkb: &BinaryChunked, params: &ToWktKwargs) -> GResult<StringChunked> {
     let mut writer = WKTWriter::new()?;
     if let Some(rounding_precision) = params.rounding_precision {
@@ -732,6 +1261,11 @@ pub fn to_ewkt(wkb: &BinaryChunked, params: &ToWktKwargs) -> GResult<StringChunk
 }
 
 pub fn to_wkb(wkb: &BinaryChunked, params: &ToWkbKwargs) -> GResult<BinaryChunked> {
+    if params.flavor == WkbFlavor::Iso && params.include_srid {
+        return Err(GError::GenericError(
+            "ISO WKB can't include the SRID, use `flavor=\"extended\"`".into(),
+        ));
+    }
     let mut writer = WKBWriter::new()?;
     if let Some(byte_order) = params.byte_order {
         writer.set_wkb_byte_order(byte_order.try_into()?);
@@ -741,7 +1275,22 @@ pub fn to_wkb(wkb: &BinaryChunked, params: &ToWkbKwargs) -> GResult<BinaryChunke
     wkb.try_apply_nonnull_values_generic(|wkb| {
         let geom = Geometry::new_from_wkb(wkb)?;
         let res: Vec<u8> = writer.write_wkb(&geom)?.into();
-        Ok(res)
+        match params.flavor {
+            WkbFlavor::Extended => Ok(res),
+            WkbFlavor::Iso => wkb::to_iso(&res),
+        }
+    })
+}
+
+pub fn to_ewkb_hex(wkb: &BinaryChunked) -> GResult<StringChunked> {
+    let mut writer = WKBWriter::new()?;
+    writer.set_wkb_byte_order(ByteOrder::LittleEndian);
+    writer.set_include_SRID(true);
+    writer.set_output_dimension(OutputDimension::FourD);
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        String::from_utf8(writer.write_hex(&geom)?.into())
+            .map_err(|e| GError::GenericError(e.to_string()))
     })
 }
 
@@ -753,6 +1302,210 @@ pub fn to_geojson(wkb: &BinaryChunked, params: &ToGeoJsonKwargs) -> GResult<Stri
     })
 }
 
+/// Serializes each geometry as KML, whose coordinates are always longitudes
+/// and latitudes in EPSG:4326. Geometries without an SRID are taken as is.
+pub fn to_kml(wkb: &BinaryChunked) -> GResult<StringChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        match geom.get_srid()? {
+            0 | 4326 => kml::to_kml(&geom),
+            srid => Err(GError::GenericError(format!(
+                "KML geometries must be in EPSG:4326, got SRID {srid}, use `to_srid(4326)` first"
+            ))),
+        }
+    })
+}
+
+pub fn to_gml(wkb: &BinaryChunked) -> GResult<StringChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| gml::to_gml(&Geometry::new_from_wkb(wkb)?))
+}
+
+pub fn to_gpkg(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
+    let mut writer = WKBWriter::new()?;
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let is_empty = geom.is_empty()?;
+        // Little endian header, with an empty geometry flag or an XY envelope
+        let flags: u8 = if is_empty { 0b0001_0001 } else { 0b0000_0011 };
+        let mut blob = vec![b'G', b'P', 0, flags];
+        blob.extend(geom.get_srid()?.to_le_bytes());
+        if !is_empty {
+            let x_min = geom.get_x_min()?;
+            let x_max = geom.get_x_max()?;
+            let y_min = geom.get_y_min()?;
+            let y_max = geom.get_y_max()?;
+            for value in [x_min, x_max, y_min, y_max] {
+                blob.extend(value.to_le_bytes());
+            }
+        }
+        // GeoPackage geometries are ISO WKB, while GEOS writes Z and M as EWKB flags.
+        let ewkb: Vec<u8> = writer.write_wkb(&geom)?.into();
+        blob.extend(wkb::to_iso(&ewkb)?);
+        Ok(blob)
+    })
+}
+
+pub fn to_spatialite(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        spatialite::to_spatialite(&Geometry::new_from_wkb(wkb)?)
+    })
+}
+
+pub fn to_geoarrow(
+    wkb: &BinaryChunked,
+    geometry_type: WKBGeometryType,
+    dimensions: GeoArrowDimensions,
+) -> GResult<Series> {
+    let mut encoder = geoarrow::Encoder::new(geometry_type, dimensions)?;
+    for wkb in wkb {
+        match wkb {
+            Some(wkb) => encoder.push(&Geometry::new_from_wkb(wkb)?)?,
+            None => encoder.push_null()?,
+        }
+    }
+    encoder
+        .finish(wkb.name().clone())
+        .map_err(|e| GError::GenericError(e.to_string()))
+}
+
+/// Geometry type, coordinate dimension, interleaved coordinates and offsets of a
+/// shapely ragged array.
+pub type RaggedArray = (WKBGeometryType, usize, Vec<f64>, Vec<Vec<i64>>);
+
+/// Converts geometries to a shapely ragged array, or returns `None` if they don't
+/// share a single geometry type and coordinate dimension.
+pub fn to_ragged_array(wkb: &BinaryChunked) -> GResult<Option<RaggedArray>> {
+    let geoms = wkb
+        .iter()
+        .map(|wkb| wkb.map(Geometry::new_from_wkb).transpose())
+        .try_collect::<Vec<_>>()?;
+    let Some((geometry_type, has_z)) = geoarrow::common_type(&geoms)? else {
+        return Ok(None);
+    };
+    let dimensions = match has_z {
+        true => GeoArrowDimensions::Xyz,
+        false => GeoArrowDimensions::Xy,
+    };
+    let mut encoder = geoarrow::Encoder::new(geometry_type, dimensions)?;
+    for geom in &geoms {
+        match geom {
+            Some(geom) => encoder.push(geom)?,
+            None => encoder.push_null()?,
+        }
+    }
+    let (coords, offsets) = encoder.finish_ragged();
+    Ok(Some((
+        geometry_type,
+        2 + usize::from(has_z),
+        coords,
+        offsets,
+    )))
+}
+
+fn check_coordinates_dimension(dimension: usize) -> GResult<()> {
+    match dimension {
+        2 | 3 => Ok(()),
+        _ => Err(GError::GenericError(format!(
+            "Coordinates dimension must be 2 or 3, got {dimension}"
+        ))),
+    }
+}
+
+/// Returns the coordinates of all the geometries, interleaved with `dimension`
+/// values per coordinate. Missing Z values are NaN.
+pub fn get_coordinates(wkb: &BinaryChunked, dimension: usize) -> GResult<Vec<f64>> {
+    check_coordinates_dimension(dimension)?;
+    let coords = RefCell::new(Vec::new());
+    for wkb in wkb.into_iter().flatten() {
+        transform_coords(&Geometry::new_from_wkb(wkb)?, &|x, y, z| {
+            coords.borrow_mut().extend(&[x, y, z][..dimension]);
+            Ok((x, y, z))
+        })?;
+    }
+    Ok(coords.into_inner())
+}
+
+/// Replaces the coordinates of all the geometries, given in the order of
+/// `get_coordinates`. Z values are ignored for geometries without Z.
+pub fn set_coordinates(
+    wkb: &BinaryChunked,
+    coords: &[f64],
+    dimension: usize,
+) -> GResult<BinaryChunked> {
+    check_coordinates_dimension(dimension)?;
+    let position = Cell::new(0);
+    let result = wkb.try_apply_nonnull_values_generic(|wkb| {
+        transform_coords(&Geometry::new_from_wkb(wkb)?, &|_, _, z| {
+            let start = position.get();
+            position.set(start + dimension);
+            match coords.get(start..start + dimension) {
+                Some(&[x, y]) => Ok((x, y, z)),
+                Some(&[x, y, z, ..]) => Ok((x, y, z)),
+                // Reported below, once all the geometries are read.
+                _ => Ok((f64::NAN, f64::NAN, z)),
+            }
+        })?
+        .to_ewkb()
+    })?;
+    if position.get() != coords.len() {
+        let (expected, got) = (position.get() / dimension, coords.len() / dimension);
+        let msg = format!("Expected {expected} coordinates, got {got}");
+        return Err(GError::GenericError(msg));
+    }
+    Ok(result)
+}
+
+pub fn to_svg(wkb: &BinaryChunked, params: &ToSvgKwargs) -> GResult<StringChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        svg::to_svg(
+            &Geometry::new_from_wkb(wkb)?,
+            params.view_box,
+            params.flip_y,
+        )
+    })
+}
+
+pub fn to_esrijson(wkb: &BinaryChunked, params: &ToEsriJsonKwargs) -> GResult<StringChunked> {
+    let json = wkb
+        .iter()
+        .map(|wkb| {
+            let Some(wkb) = wkb else { return Ok(None) };
+            esrijson::to_esrijson(&Geometry::new_from_wkb(wkb)?)?
+                .map(|value| esrijson::write(&value, params.indent))
+                .transpose()
+        })
+        .collect::<GResult<Vec<_>>>()?;
+    Ok(StringChunked::from_iter_options(
+        wkb.name().clone(),
+        json.into_iter(),
+    ))
+}
+
+pub fn to_topojson(
+    wkb: &BinaryChunked,
+    object_name: &str,
+    quantization: Option<u32>,
+) -> GResult<String> {
+    let geoms = wkb
+        .into_iter()
+        .map(|wkb| wkb.map(Geometry::new_from_wkb).transpose())
+        .collect::<GResult<Vec<_>>>()?;
+    let topology = topojson::topology(&geoms, object_name, quantization)?;
+    serde_json::to_string(&topology).map_err(|e| GError::GenericError(e.to_string()))
+}
+
+pub fn to_dxf(wkb: &BinaryChunked, layers: Option<&StringChunked>) -> GResult<String> {
+    let geoms = wkb
+        .into_iter()
+        .map(|wkb| wkb.map(Geometry::new_from_wkb).transpose())
+        .collect::<GResult<Vec<_>>>()?;
+    let layers: Vec<Option<&str>> = match layers {
+        Some(layers) => layers.into_iter().collect(),
+        None => vec![None; geoms.len()],
+    };
+    dxf::to_dxf(&geoms, &layers)
+}
+
 pub fn to_python_dict(wkb: &BinaryChunked, py: Python) -> GResult<Vec<Option<PyObject>>> {
     let json = PyModule::import(py, "json").expect("Failed to load json");
     let loads = json.getattr("loads").expect("Failed to get json.loads");
@@ -795,6 +1548,14 @@ pub fn area(wkb: &BinaryChunked) -> GResult<Float64Chunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.area())
 }
 
+pub fn area_geodesic(wkb: &BinaryChunked) -> GResult<Float64Chunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        geodesic::check_srid(&geom)?;
+        geodesic::area(&geom)
+    })
+}
+
 pub fn bounds(wkb: &BinaryChunked) -> GResult<ArrayChunked> {
     let dt = DataType::Array(Box::new(DataType::Float64), 4);
     try_unary_elementwise_values_with_dtype(wkb, dt, |wkb| {
@@ -812,28 +1573,291 @@ pub fn bounds(wkb: &BinaryChunked) -> GResult<ArrayChunked> {
     })
 }
 
-pub fn length(wkb: &BinaryChunked) -> GResult<Float64Chunked> {
-    wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.length())
-}
-
-pub fn distance(a: &BinaryChunked, b: &BinaryChunked) -> GResult<Float64Chunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
-        if a.is_empty()? || b.is_empty()? {
-            Ok(f64::NAN) // Match `hausdorff_distance` and `frechet_distance` behavior
-        } else {
-            a.distance(&b)
+/// Extends `range` with the values of a geometry at position `index` of its
+/// coordinates.
+fn extend_ordinate_range<T: Geom>(
+    geom: &T,
+    dimension: usize,
+    index: usize,
+    range: &mut [f64; 2],
+) -> GResult<()> {
+    match geom.geometry_type()? {
+        _ if geom.is_empty()? => Ok(()),
+        Point | LineString | LinearRing | CircularString => {
+            let coords = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+            for coord in coords.chunks_exact(dimension) {
+                range[0] = range[0].min(coord[index]);
+                range[1] = range[1].max(coord[index]);
+            }
+            Ok(())
         }
-    })
+        Polygon | CurvePolygon => {
+            extend_ordinate_range(&geom.get_exterior_ring()?, dimension, index, range)?;
+            (0..geom.get_num_interior_rings()?).try_for_each(|n| {
+                extend_ordinate_range(&geom.get_interior_ring_n(n)?, dimension, index, range)
+            })
+        }
+        MultiPoint | MultiLineString | MultiCurve | CompoundCurve | MultiPolygon | MultiSurface
+        | GeometryCollection => (0..geom.get_num_geometries()?).try_for_each(|n| {
+            extend_ordinate_range(&geom.get_geometry_n(n)?, dimension, index, range)
+        }),
+    }
 }
 
-pub fn hausdorff_distance(a: &BinaryChunked, b: &BinaryChunked) -> GResult<Float64Chunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
-        a.hausdorff_distance(&b)
-    })
+/// Returns the range of the Z (`m = false`) or M (`m = true`) values of a
+/// geometry, or NaNs if it doesn't have them.
+fn ordinate_range<T: Geom>(geom: &T, m: bool) -> GResult<[f64; 2]> {
+    let (has_z, has_m) = (geom.has_z()?, geom.has_m()?);
+    let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+    let index = match m {
+        false if has_z => 2,
+        true if has_m => dimension - 1,
+        _ => return Ok([f64::NAN, f64::NAN]),
+    };
+    let mut range = [f64::INFINITY, f64::NEG_INFINITY];
+    extend_ordinate_range(geom, dimension, index, &mut range)?;
+    if range[0] > range[1] {
+        return Ok([f64::NAN, f64::NAN]);
+    }
+    Ok(range)
+}
+
+pub fn bounds_3d(wkb: &BinaryChunked) -> GResult<ArrayChunked> {
+    let dt = DataType::Array(Box::new(DataType::Float64), 6);
+    try_unary_elementwise_values_with_dtype(wkb, dt, |wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let bounds = if geom.is_empty()? {
+            [f64::NAN; 6]
+        } else {
+            let [z_min, z_max] = ordinate_range(&geom, false)?;
+            let x_min = geom.get_x_min()?;
+            let y_min = geom.get_y_min()?;
+            let x_max = geom.get_x_max()?;
+            let y_max = geom.get_y_max()?;
+            [x_min, y_min, z_min, x_max, y_max, z_max]
+        };
+        Ok(Box::new(Float64Array::from_slice(bounds)) as Box<dyn Array>)
+    })
+}
+
+pub fn m_range(wkb: &BinaryChunked) -> GResult<ArrayChunked> {
+    let dt = DataType::Array(Box::new(DataType::Float64), 2);
+    try_unary_elementwise_values_with_dtype(wkb, dt, |wkb| {
+        let range = ordinate_range(&Geometry::new_from_wkb(wkb)?, true)?;
+        Ok(Box::new(Float64Array::from_slice(range)) as Box<dyn Array>)
+    })
+}
+
+/// Spreads the bits of a value, so that bit `n` moves to bit `2n`.
+fn spread_bits(value: u32) -> u64 {
+    let mut v = u64::from(value);
+    v = (v | (v << 16)) & 0x0000_FFFF_0000_FFFF;
+    v = (v | (v << 8)) & 0x00FF_00FF_00FF_00FF;
+    v = (v | (v << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+    (v | (v << 1)) & 0x5555_5555_5555_5555
+}
+
+/// Returns the cell containing a value, on a grid of `cells` cells between
+/// `min` and `max`. Values outside of the grid are clamped to it.
+#[allow(clippy::cast_sign_loss, clippy::cast_precision_loss)]
+fn grid_cell(value: f64, min: f64, max: f64, cells: u64) -> u32 {
+    let cell = ((value - min) / (max - min) * cells as f64).floor();
+    // The cell of a zero-width extent is NaN, which is cast to 0.
+    cell.clamp(0.0, (cells - 1) as f64) as u32
+}
+
+pub fn morton_index(wkb: &BinaryChunked, params: &MortonIndexKwargs) -> GResult<UInt64Chunked> {
+    let geoms = wkb
+        .into_iter()
+        .map(|wkb| match wkb.map(Geometry::new_from_wkb).transpose()? {
+            Some(geom) if !geom.is_empty()? => Ok(Some(geom)),
+            _ => Ok(None),
+        })
+        .collect::<GResult<Vec<_>>>()?;
+    let [xmin, ymin, xmax, ymax] = match params.extent {
+        Some(extent) => extent,
+        None => geoms.iter().flatten().try_fold(
+            [
+                f64::INFINITY,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::NEG_INFINITY,
+            ],
+            |[xmin, ymin, xmax, ymax], geom| {
+                GResult::Ok([
+                    xmin.min(geom.get_x_min()?),
+                    ymin.min(geom.get_y_min()?),
+                    xmax.max(geom.get_x_max()?),
+                    ymax.max(geom.get_y_max()?),
+                ])
+            },
+        )?,
+    };
+    let cells = 1u64 << params.level.clamp(1, 32);
+    let index = geoms
+        .iter()
+        .map(|geom| {
+            let Some(geom) = geom else { return Ok(None) };
+            let x = (geom.get_x_min()? + geom.get_x_max()?) / 2.0;
+            let y = (geom.get_y_min()? + geom.get_y_max()?) / 2.0;
+            let (x, y) = (
+                grid_cell(x, xmin, xmax, cells),
+                grid_cell(y, ymin, ymax, cells),
+            );
+            Ok(Some(spread_bits(x) | (spread_bits(y) << 1)))
+        })
+        .collect::<GResult<Vec<_>>>()?;
+    Ok(UInt64Chunked::from_iter_options(
+        wkb.name().clone(),
+        index.into_iter(),
+    ))
+}
+
+pub fn spatial_partition(
+    wkb: &BinaryChunked,
+    params: &SpatialPartitionKwargs,
+) -> GResult<ListChunked> {
+    // Null geometries are `None`, and empty geometries have no envelope.
+    let envelopes = wkb
+        .into_iter()
+        .map(|wkb| {
+            let Some(geom) = wkb.map(Geometry::new_from_wkb).transpose()? else {
+                return Ok(None);
+            };
+            if geom.is_empty()? {
+                return Ok(Some(None));
+            }
+            let envelope = [
+                geom.get_x_min()?,
+                geom.get_y_min()?,
+                geom.get_x_max()?,
+                geom.get_y_max()?,
+            ];
+            Ok(Some(Some(envelope)))
+        })
+        .collect::<GResult<Vec<_>>>()?;
+    let non_empty = envelopes
+        .iter()
+        .flatten()
+        .flatten()
+        .copied()
+        .collect::<Vec<_>>();
+    let extent = params
+        .extent
+        .unwrap_or_else(|| partition::union_bounds(non_empty.iter().copied()));
+    let partitioner = Partitioner::new(params.scheme, extent, &non_empty, params.n);
+    let mut builder = ListPrimitiveChunkedBuilder::<UInt32Type>::new(
+        wkb.name().clone(),
+        envelopes.len(),
+        non_empty.len(),
+        DataType::UInt32,
+    );
+    for envelope in envelopes {
+        match envelope {
+            None => builder.append_null(),
+            Some(None) => builder.append_slice(&[]),
+            Some(Some(envelope)) => builder.append_slice(&partitioner.partitions(envelope)),
+        }
+    }
+    Ok(builder.finish())
+}
+
+pub fn to_h3(wkb: &BinaryChunked, params: &H3Kwargs) -> GResult<UInt64Chunked> {
+    let resolution = h3::resolution(params.resolution)?;
+    try_unary_elementwise(wkb, |wkb| {
+        let Some(wkb) = wkb else { return Ok(None) };
+        let geom = Geometry::new_from_wkb(wkb)?;
+        geodesic::check_srid(&geom)?;
+        h3::point_to_cell(&geom, resolution)
+    })
+}
+
+pub fn h3_to_boundary(cells: &UInt64Chunked) -> GResult<BinaryChunked> {
+    cells.try_apply_nonnull_values_generic(|cell| h3::cell_boundary(cell)?.to_ewkb())
+}
+
+pub fn h3_cover(wkb: &BinaryChunked, params: &H3Kwargs) -> GResult<ListChunked> {
+    let resolution = h3::resolution(params.resolution)?;
+    let mut builder = ListPrimitiveChunkedBuilder::<UInt64Type>::new(
+        wkb.name().clone(),
+        wkb.len(),
+        wkb.len(),
+        DataType::UInt64,
+    );
+    for wkb in wkb {
+        match wkb {
+            None => builder.append_null(),
+            Some(wkb) => {
+                let geom = Geometry::new_from_wkb(wkb)?;
+                geodesic::check_srid(&geom)?;
+                builder.append_slice(&h3::cover(&geom, resolution)?);
+            }
+        }
+    }
+    Ok(builder.finish())
+}
+
+pub fn tile_of(
+    wkb: &BinaryChunked,
+    params: &TileOfKwargs,
+) -> GResult<(UInt8Chunked, UInt32Chunked, UInt32Chunked)> {
+    let tiles = wkb
+        .into_iter()
+        .map(|wkb| {
+            let Some(wkb) = wkb else { return Ok(None) };
+            tiles::tile_of(&Geometry::new_from_wkb(wkb)?, params.zoom)
+        })
+        .try_collect::<Vec<_>>()?;
+    Ok((
+        UInt8Chunked::from_iter_options("z".into(), tiles.iter().map(|t| t.map(|t| t.z))),
+        UInt32Chunked::from_iter_options("x".into(), tiles.iter().map(|t| t.map(|t| t.x))),
+        UInt32Chunked::from_iter_options("y".into(), tiles.iter().map(|t| t.map(|t| t.y))),
+    ))
+}
+
+pub fn length(wkb: &BinaryChunked) -> GResult<Float64Chunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.length())
+}
+
+pub fn length_geodesic(wkb: &BinaryChunked) -> GResult<Float64Chunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        geodesic::check_srid(&geom)?;
+        geodesic::length(&geom)
+    })
+}
+
+pub fn length_3d(wkb: &BinaryChunked) -> GResult<Float64Chunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| measure_3d::length(&Geometry::new_from_wkb(wkb)?))
+}
+
+pub fn distance(a: &BinaryChunked, b: &BinaryChunked) -> GResult<Float64Chunked> {
+    broadcast_try_binary_elementwise_values(a, b, |a, b| {
+        let a = Geometry::new_from_wkb(a)?;
+        let b = Geometry::new_from_wkb(b)?;
+        if a.is_empty()? || b.is_empty()? {
+            Ok(f64::NAN) // Match `hausdorff_distance` and `frechet_distance` behavior
+        } else {
+            a.distance(&b)
+        }
+    })
+}
+
+pub fn distance_3d(a: &BinaryChunked, b: &BinaryChunked) -> GResult<Float64Chunked> {
+    broadcast_try_binary_elementwise_values(a, b, |a, b| {
+        let a = Geometry::new_from_wkb(a)?;
+        let b = Geometry::new_from_wkb(b)?;
+        measure_3d::distance(&a, &b)
+    })
+}
+
+pub fn hausdorff_distance(a: &BinaryChunked, b: &BinaryChunked) -> GResult<Float64Chunked> {
+    broadcast_try_binary_elementwise_values(a, b, |a, b| {
+        let a = Geometry::new_from_wkb(a)?;
+        let b = Geometry::new_from_wkb(b)?;
+        a.hausdorff_distance(&b)
+    })
 }
 
 pub fn hausdorff_distance_densify(
@@ -878,6 +1902,314 @@ pub fn frechet_distance_densify(
     })
 }
 
+/// Returns the coordinates of a point, or `None` if the geometry is empty,
+/// whatever its type.
+fn point_xy<T: Geom>(geom: &T) -> GResult<Option<[f64; 2]>> {
+    match geom.geometry_type()? {
+        _ if geom.is_empty()? => Ok(None),
+        Point => Ok(Some([geom.get_x()?, geom.get_y()?])),
+        geometry_type => Err(GError::GenericError(format!(
+            "Azimuths can only be computed between points, got {geometry_type:?}"
+        ))),
+    }
+}
+
+/// Returns the azimuth from each point to the corresponding point of `b`,
+/// clockwise from the north (positive `y` axis), or NaN for empty or
+/// coincident points.
+pub fn azimuth(a: &BinaryChunked, b: &BinaryChunked, degrees: bool) -> GResult<Float64Chunked> {
+    broadcast_try_binary_elementwise_values(a, b, |a, b| {
+        let a = point_xy(&Geometry::new_from_wkb(a)?)?;
+        let b = point_xy(&Geometry::new_from_wkb(b)?)?;
+        let (Some(a), Some(b)) = (a, b) else {
+            return Ok(f64::NAN);
+        };
+        let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+        if dx == 0.0 && dy == 0.0 {
+            return Ok(f64::NAN);
+        }
+        let azimuth = dx.atan2(dy).rem_euclid(std::f64::consts::TAU);
+        Ok(if degrees {
+            azimuth.to_degrees()
+        } else {
+            azimuth
+        })
+    })
+}
+
+/// Same as `azimuth`, with the forward azimuth of the geodesics between
+/// lon / lat points.
+pub fn azimuth_geodesic(
+    a: &BinaryChunked,
+    b: &BinaryChunked,
+    degrees: bool,
+) -> GResult<Float64Chunked> {
+    broadcast_try_binary_elementwise_values(a, b, |a, b| {
+        let a = Geometry::new_from_wkb(a)?;
+        let b = Geometry::new_from_wkb(b)?;
+        geodesic::check_srid(&a)?;
+        geodesic::check_srid(&b)?;
+        let (Some(a), Some(b)) = (point_xy(&a)?, point_xy(&b)?) else {
+            return Ok(f64::NAN);
+        };
+        let azimuth = geodesic::azimuth(a, b);
+        Ok(if degrees {
+            azimuth
+        } else {
+            azimuth.to_radians()
+        })
+    })
+}
+
+/// Returns, for each geometry, the index and distance of its `k` nearest
+/// geometries in `other`, from the closest to the furthest.
+pub fn knn(wkb: &BinaryChunked, other: &BinaryChunked, k: usize) -> GResult<ListChunked> {
+    let to_geos_err = |e: PolarsError| GError::GenericError(e.to_string());
+    let others = other
+        .into_iter()
+        .map(|v| v.map(Geometry::new_from_wkb).transpose())
+        .collect::<GResult<Vec<_>>>()?;
+    let tree = RTree::new(&others)?;
+    let mut offsets = Vec::with_capacity(wkb.len() + 1);
+    offsets.push(0i64);
+    let mut validity = MutableBitmap::with_capacity(wkb.len());
+    let (mut indices, mut distances) = (Vec::new(), Vec::new());
+    for wkb in wkb {
+        if let Some(wkb) = wkb {
+            let geom = Geometry::new_from_wkb(wkb)?;
+            let distance_to = |index: usize| {
+                let other = others[index]
+                    .as_ref()
+                    .expect("Shouldn't be able to match None");
+                geom.distance(other)
+            };
+            for (index, distance) in tree.nearest(&geom, k, distance_to)? {
+                indices.push(index as u32);
+                distances.push(distance);
+            }
+        }
+        validity.push(wkb.is_some());
+        offsets.push(indices.len() as i64);
+    }
+    let values = StructChunked::from_columns(
+        PlSmallStr::EMPTY,
+        indices.len(),
+        &[
+            UInt32Chunked::from_vec("index".into(), indices).into_column(),
+            Float64Chunked::from_vec("distance".into(), distances).into_column(),
+        ],
+    )
+    .map_err(to_geos_err)?
+    .into_series()
+    .rechunk()
+    .to_arrow(0, CompatLevel::newest());
+    let dtype = LargeListArray::default_datatype(values.dtype().clone());
+    let offsets = Offsets::try_from(offsets).map_err(to_geos_err)?;
+    let array = LargeListArray::new(dtype, offsets.into(), values, validity.into());
+    Series::from_arrow(wkb.name().clone(), array.boxed())
+        .and_then(|s| s.list().cloned())
+        .map_err(to_geos_err)
+}
+
+/// Returns, for each geometry, the number of geometries in `other` that
+/// satisfy a spatial join predicate with it.
+pub fn count_matches(
+    wkb: &BinaryChunked,
+    other: &BinaryChunked,
+    predicate: SpatialJoinPredicate,
+    distance: Option<f64>,
+) -> GResult<UInt32Chunked> {
+    let (predicate, distance, _) = join_predicate(predicate, distance, None)?;
+    let others = other
+        .into_iter()
+        .map(|v| v.map(Geometry::new_from_wkb).transpose())
+        .collect::<GResult<Vec<_>>>()?;
+    let tree = RTree::new(&others)?;
+    let other_geom = |index: usize| {
+        others[index]
+            .as_ref()
+            .expect("Shouldn't be able to match None")
+    };
+    let values = wkb.into_iter().collect::<Vec<_>>();
+    let counts = POOL.install(|| {
+        values
+            .par_iter()
+            .map(|wkb| {
+                let Some(wkb) = wkb else { return Ok(None) };
+                let geom = Geometry::new_from_wkb(wkb)?;
+                let mut candidates = Vec::new();
+                let mut count = 0;
+                match distance {
+                    Some(_) if geom.is_empty()? => {}
+                    Some(distance) => {
+                        tree.query(&search_area(&geom, distance)?, |i| candidates.push(i))?;
+                        for index in candidates {
+                            count += u32::from(geom.distance(other_geom(index))? <= distance);
+                        }
+                    }
+                    None => {
+                        tree.query(&geom, |i| candidates.push(i))?;
+                        let prepared = geom.to_prepared_geom()?;
+                        for index in candidates {
+                            let is_match = predicate(&prepared, other_geom(index));
+                            count += u32::from(matches!(is_match, Ok(true)));
+                        }
+                    }
+                }
+                Ok(Some(count))
+            })
+            .collect::<GResult<Vec<_>>>()
+    })?;
+    Ok(UInt32Chunked::from_iter_options(
+        wkb.name().clone(),
+        counts.into_iter(),
+    ))
+}
+
+/// Returns the indices and distance of all pairs of geometries within
+/// `distance` of each other, with the first index lower than the second.
+pub fn pairs_within(
+    wkb: &BinaryChunked,
+    distance: f64,
+) -> GResult<(UInt32Chunked, UInt32Chunked, Float64Chunked)> {
+    let geoms = wkb
+        .into_iter()
+        .map(|v| v.map(Geometry::new_from_wkb).transpose())
+        .collect::<GResult<Vec<_>>>()?;
+    let tree = RTree::new(&geoms)?;
+    let pairs = POOL.install(|| {
+        geoms
+            .par_iter()
+            .enumerate()
+            .map(|(left_index, geom)| {
+                let mut pairs = Vec::new();
+                let Some(geom) = geom else { return Ok(pairs) };
+                if geom.is_empty()? {
+                    return Ok(pairs);
+                }
+                let mut candidates = Vec::new();
+                tree.query(&search_area(geom, distance)?, |right_index| {
+                    if right_index > left_index {
+                        candidates.push(right_index);
+                    }
+                })?;
+                candidates.sort_unstable();
+                for right_index in candidates {
+                    let other = geoms[right_index]
+                        .as_ref()
+                        .expect("Shouldn't be able to match None");
+                    let pair_distance = geom.distance(other)?;
+                    if pair_distance <= distance {
+                        pairs.push((left_index as u32, right_index as u32, pair_distance));
+                    }
+                }
+                Ok(pairs)
+            })
+            .collect::<GResult<Vec<_>>>()
+    })?;
+    let (mut left, mut right, mut distances) = (Vec::new(), Vec::new(), Vec::new());
+    for (left_index, right_index, pair_distance) in pairs.into_iter().flatten() {
+        left.push(left_index);
+        right.push(right_index);
+        distances.push(pair_distance);
+    }
+    Ok((
+        UInt32Chunked::from_vec("left_index".into(), left),
+        UInt32Chunked::from_vec("right_index".into(), right),
+        Float64Chunked::from_vec("distance".into(), distances),
+    ))
+}
+
+/// Returns, for each geometry, the lowest index of another geometry equal to
+/// it, compared with `equals_exact` if a tolerance is given. Null geometries
+/// are equal to each other, as are empty geometries.
+fn equal_geometries(wkb: &BinaryChunked, tolerance: Option<f64>) -> GResult<Vec<Option<usize>>> {
+    let geoms = wkb
+        .into_iter()
+        .map(|v| v.map(Geometry::new_from_wkb).transpose())
+        .collect::<GResult<Vec<_>>>()?;
+    let tree = RTree::new(&geoms)?;
+    // Null and empty geometries aren't indexed, and are matched with the
+    // first two of their kind instead.
+    let (mut nulls, mut empties) = (Vec::new(), Vec::new());
+    for (index, geom) in geoms.iter().enumerate() {
+        match geom {
+            None => nulls.push(index),
+            Some(geom) if geom.is_empty()? => empties.push(index),
+            Some(_) => {}
+        }
+    }
+    let first_other = |indices: &[usize], index: usize| {
+        indices
+            .iter()
+            .take(2)
+            .copied()
+            .find(|other| *other != index)
+    };
+    POOL.install(|| {
+        geoms
+            .par_iter()
+            .enumerate()
+            .map(|(index, geom)| {
+                let Some(geom) = geom else {
+                    return Ok(first_other(&nulls, index));
+                };
+                if geom.is_empty()? {
+                    return Ok(first_other(&empties, index));
+                }
+                let mut candidates = Vec::new();
+                match tolerance {
+                    Some(tolerance) => {
+                        tree.query(&search_area(geom, tolerance)?, |i| candidates.push(i))?;
+                    }
+                    None => tree.query(geom, |i| candidates.push(i))?,
+                }
+                candidates.sort_unstable();
+                for other_index in candidates {
+                    if other_index == index {
+                        continue;
+                    }
+                    let other = geoms[other_index]
+                        .as_ref()
+                        .expect("Shouldn't be able to match None");
+                    let is_equal = match tolerance {
+                        Some(tolerance) => geom.equals_exact(other, tolerance)?,
+                        None => geom.equals(other)?,
+                    };
+                    if is_equal {
+                        return Ok(Some(other_index));
+                    }
+                }
+                Ok(None)
+            })
+            .collect()
+    })
+}
+
+pub fn is_duplicate_geometry(
+    wkb: &BinaryChunked,
+    tolerance: Option<f64>,
+) -> GResult<BooleanChunked> {
+    let equal = equal_geometries(wkb, tolerance)?;
+    Ok(BooleanChunked::from_iter_values(
+        wkb.name().clone(),
+        equal.iter().map(Option::is_some),
+    ))
+}
+
+/// Keeps the first geometry of each group of equal geometries.
+pub fn unique_geometries(wkb: &BinaryChunked, tolerance: Option<f64>) -> GResult<BinaryChunked> {
+    let equal = equal_geometries(wkb, tolerance)?;
+    Ok(BinaryChunked::from_iter_options(
+        wkb.name().clone(),
+        wkb.into_iter()
+            .zip(equal)
+            .enumerate()
+            .filter(|(index, (_, equal))| equal.is_none_or(|other| other > *index))
+            .map(|(_, (value, _))| value),
+    ))
+}
+
 pub fn minimum_clearance(wkb: &BinaryChunked) -> GResult<Float64Chunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.minimum_clearance())
 }
@@ -890,6 +2222,10 @@ pub fn has_m(wkb: &BinaryChunked) -> GResult<BooleanChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.has_m())
 }
 
+pub fn has_curves(wkb: &BinaryChunked) -> GResult<BooleanChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| curves::has_curves(&Geometry::new_from_wkb(wkb)?))
+}
+
 pub fn is_ccw(wkb: &BinaryChunked) -> GResult<BooleanChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| {
         let geom = Geometry::new_from_wkb(wkb)?;
@@ -932,44 +2268,130 @@ pub fn is_valid_reason(wkb: &BinaryChunked) -> GResult<StringChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.is_valid_reason())
 }
 
+/// Splits a GEOS validity reason such as `Self-intersection[1 2]` into the
+/// reason itself and the coordinates of the location it refers to.
+fn parse_valid_reason(reason: &str) -> (&str, Option<[f64; 2]>) {
+    let Some((reason, location)) = reason.strip_suffix(']').and_then(|r| r.split_once('[')) else {
+        return (reason, None);
+    };
+    let mut coords = location.split_whitespace().map(str::parse::<f64>);
+    match (coords.next(), coords.next()) {
+        (Some(Ok(x)), Some(Ok(y))) => (reason, Some([x, y])),
+        _ => (reason, None),
+    }
+}
+
+pub fn is_valid_detail(
+    wkb: &BinaryChunked,
+) -> GResult<(BooleanChunked, StringChunked, BinaryChunked)> {
+    let mut valid_builder = BooleanChunkedBuilder::new("valid".into(), wkb.len());
+    let mut reason_builder = StringChunkedBuilder::new("reason".into(), wkb.len());
+    let mut location_builder = BinaryChunkedBuilder::new("location".into(), wkb.len());
+    for wkb in wkb {
+        let Some(wkb) = wkb else {
+            valid_builder.append_null();
+            reason_builder.append_null();
+            location_builder.append_null();
+            continue;
+        };
+        let geom = Geometry::new_from_wkb(wkb)?;
+        if geom.is_valid()? {
+            valid_builder.append_value(true);
+            reason_builder.append_null();
+            location_builder.append_null();
+            continue;
+        }
+        let reason = geom.is_valid_reason()?;
+        let (reason, location) = parse_valid_reason(&reason);
+        valid_builder.append_value(false);
+        reason_builder.append_value(reason);
+        match location {
+            Some(coords) => {
+                let mut point =
+                    Geometry::create_point(CoordSeq::new_from_buffer(&coords, 1, false, false)?)?;
+                point.set_srid(geom.get_srid()?);
+                location_builder.append_value(point.to_ewkb()?);
+            }
+            None => location_builder.append_null(),
+        }
+    }
+    Ok((
+        valid_builder.finish(),
+        reason_builder.finish(),
+        location_builder.finish(),
+    ))
+}
+
+pub fn wkb_is_parseable(wkb: &BinaryChunked) -> GResult<BooleanChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| GResult::Ok(Geometry::new_from_wkb(wkb).is_ok()))
+}
+
+pub fn wkb_parse_error(wkb: &BinaryChunked) -> GResult<StringChunked> {
+    try_unary_elementwise(wkb, |wkb| {
+        let error = wkb.and_then(|wkb| Geometry::new_from_wkb(wkb).err());
+        GResult::Ok(error.map(|e| e.to_string()))
+    })
+}
+
+/// Evaluates a spatial predicate between geometries. When one side is a
+/// single geometry broadcast to the other, such as a literal, it is prepared
+/// once, or taken from the cache of prepared geometries.
+fn binary_predicate(
+    a: &BinaryChunked,
+    b: &BinaryChunked,
+    predicate: SpatialJoinPredicate,
+    unprepared: fn(&Geometry, &Geometry) -> GResult<bool>,
+) -> GResult<BooleanChunked> {
+    let scalar = |ca: &BinaryChunked| (ca.len() == 1).then(|| ca.get(0)).flatten();
+    let broadcast = |single: &BinaryChunked, other: &BinaryChunked, predicate| {
+        let (predicate, _, _) = join_predicate(predicate, None, None)?;
+        let wkb = scalar(single).expect("Only scalars are prepared");
+        prepared::with_prepared(wkb, |prepared| {
+            other
+                .try_apply_nonnull_values_generic(|other| {
+                    predicate(prepared, &Geometry::new_from_wkb(other)?)
+                })
+                .map(|ca: BooleanChunked| ca.with_name(a.name().clone()))
+        })
+    };
+    match (scalar(a), scalar(b).and(converse_predicate(predicate))) {
+        (Some(_), _) => broadcast(a, b, predicate),
+        (None, Some(converse)) => broadcast(b, a, converse),
+        (None, None) => broadcast_try_binary_elementwise_values(a, b, |a, b| {
+            let a = Geometry::new_from_wkb(a)?;
+            let b = Geometry::new_from_wkb(b)?;
+            unprepared(&a, &b)
+        }),
+    }
+}
+
 pub fn crosses(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
-        Geometry::crosses(&a, &b)
+    binary_predicate(a, b, SpatialJoinPredicate::Crosses, |a, b| {
+        Geometry::crosses(a, b)
     })
 }
 
 pub fn contains(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
-        Geometry::contains(&a, &b)
+    binary_predicate(a, b, SpatialJoinPredicate::Contains, |a, b| {
+        Geometry::contains(a, b)
     })
 }
 
 pub fn contains_properly(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
-        let prepared = a.to_prepared_geom()?;
-        prepared.contains_properly(&b)
+    binary_predicate(a, b, SpatialJoinPredicate::ContainsProperly, |a, b| {
+        a.to_prepared_geom()?.contains_properly(b)
     })
 }
 
 pub fn covered_by(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
-        Geometry::covered_by(&a, &b)
+    binary_predicate(a, b, SpatialJoinPredicate::CoveredBy, |a, b| {
+        Geometry::covered_by(a, b)
     })
 }
 
 pub fn covers(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
-        Geometry::covers(&a, &b)
+    binary_predicate(a, b, SpatialJoinPredicate::Covers, |a, b| {
+        Geometry::covers(a, b)
     })
 }
 
@@ -990,34 +2412,26 @@ pub fn dwithin(a: &BinaryChunked, b: &BinaryChunked, distance: f64) -> GResult<B
 }
 
 pub fn intersects(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
-        Geometry::intersects(&a, &b)
+    binary_predicate(a, b, SpatialJoinPredicate::Intersects, |a, b| {
+        Geometry::intersects(a, b)
     })
 }
 
 pub fn overlaps(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
-        Geometry::overlaps(&a, &b)
+    binary_predicate(a, b, SpatialJoinPredicate::Overlaps, |a, b| {
+        Geometry::overlaps(a, b)
     })
 }
 
 pub fn touches(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
-        Geometry::touches(&a, &b)
+    binary_predicate(a, b, SpatialJoinPredicate::Touches, |a, b| {
+        Geometry::touches(a, b)
     })
 }
 
 pub fn within(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BooleanChunked> {
-    broadcast_try_binary_elementwise_values(a, b, |a, b| {
-        let a = Geometry::new_from_wkb(a)?;
-        let b = Geometry::new_from_wkb(b)?;
-        Geometry::within(&a, &b)
+    binary_predicate(a, b, SpatialJoinPredicate::Within, |a, b| {
+        Geometry::within(a, b)
     })
 }
 
@@ -1210,28 +2624,42 @@ fn collect_geometry_vec(wkb: &BinaryChunked) -> GResult<Vec<Geometry>> {
         .collect()
 }
 
+/// Returns the SRID shared by all geometries, or 0 if they differ.
+fn common_srid(geoms: &[Geometry]) -> GResult<i32> {
+    let mut srids = geoms.iter().map(Geom::get_srid);
+    let Some(first) = srids.next().transpose()? else {
+        return Ok(0);
+    };
+    for srid in srids {
+        if srid? != first {
+            return Ok(0);
+        }
+    }
+    Ok(first)
+}
+
 pub fn coverage_union_all(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
-    collect_geometry_vec(wkb)
-        .and_then(Geometry::create_geometry_collection)
-        .and_then(|geom| geom.coverage_union())
-        .and_then(|geom| geom.to_ewkb())
-        .map(|res| BinaryChunked::from_slice(wkb.name().clone(), &[res]))
+    aggregate_with(wkb, |geoms| {
+        Geometry::create_geometry_collection(geoms)?.coverage_union()
+    })
 }
 
 pub fn polygonize(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
-    collect_geometry_vec(wkb)
-        .and_then(|vec| Geometry::polygonize(&vec))
-        .and_then(|geom| geom.to_ewkb())
-        .map(|res| BinaryChunked::from_slice(wkb.name().clone(), &[res]))
+    aggregate_with(wkb, |geoms| Geometry::polygonize(&geoms))
 }
 
+/// Aggregates all geometries into one with `func`. Since geometries built by
+/// GEOS from a list of geometries don't have an SRID, the SRID shared by the
+/// aggregated geometries is set on the result.
 fn aggregate_with<F>(wkb: &BinaryChunked, func: F) -> GResult<BinaryChunked>
 where
     F: FnOnce(Vec<Geometry>) -> GResult<Geometry>,
 {
-    collect_geometry_vec(wkb)
-        .and_then(func)
-        .and_then(|geom| geom.to_ewkb())
+    let geoms = collect_geometry_vec(wkb)?;
+    let srid = common_srid(&geoms)?;
+    let mut geom = func(geoms)?;
+    geom.set_srid(srid);
+    geom.to_ewkb()
         .map(|res| BinaryChunked::from_slice(wkb.name().clone(), &[res]))
 }
 
@@ -1259,33 +2687,175 @@ fn collection_supertype(wkb: &BinaryChunked) -> GResult<GeometryTypes> {
     })
 }
 
-pub fn collect(wkb: &BinaryChunked, into: Option<WKBGeometryType>) -> GResult<BinaryChunked> {
-    let into = match into {
-        Some(into) => into.try_into(),
-        None => collection_supertype(wkb),
-    }?;
-    match into {
-        MultiPoint => aggregate_with(wkb, Geometry::create_multipoint),
-        MultiLineString => aggregate_with(wkb, Geometry::create_multiline_string),
-        MultiCurve => aggregate_with(wkb, Geometry::create_multicurve),
-        MultiPolygon => aggregate_with(wkb, Geometry::create_multipolygon),
-        MultiSurface => aggregate_with(wkb, Geometry::create_multisurface),
-        GeometryCollection => aggregate_with(wkb, Geometry::create_geometry_collection),
-        _ => Err(GError::GenericError("type must be a collection".into())),
+/// Rebuilds the coordinate sequence of a point, linestring or linear ring
+/// with the given dimensions. Z and M values are kept when present, filled
+/// with the given values when added, and dropped for `None` dimensions.
+fn coord_seq_with_dimensions<T: Geom>(
+    geom: &T,
+    z: Option<f64>,
+    m: Option<f64>,
+) -> GResult<CoordSeq> {
+    let (geom_z, geom_m) = (geom.has_z()?, geom.has_m()?);
+    let dimension = 2 + usize::from(geom_z) + usize::from(geom_m);
+    let coords = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+    let mut buffer = Vec::with_capacity(coords.len() / dimension * 4);
+    for coord in coords.chunks_exact(dimension) {
+        buffer.extend(&coord[..2]);
+        if let Some(z) = z {
+            buffer.push(if geom_z { coord[2] } else { z });
+        }
+        if let Some(m) = m {
+            buffer.push(if geom_m { coord[dimension - 1] } else { m });
+        }
     }
+    let size = coords.len() / dimension;
+    CoordSeq::new_from_buffer(&buffer, size, z.is_some(), m.is_some())
+}
+
+/// Adds or drops the Z and M dimensions of a geometry, see
+/// `coord_seq_with_dimensions`.
+fn with_dimensions<T: Geom>(geom: &T, z: Option<f64>, m: Option<f64>) -> GResult<Geometry> {
+    if geom.is_empty()? || (geom.has_z()?, geom.has_m()?) == (z.is_some(), m.is_some()) {
+        return Geom::clone(geom);
+    }
+    let parts = || {
+        (0..geom.get_num_geometries()?)
+            .map(|n| with_dimensions(&geom.get_geometry_n(n)?, z, m))
+            .try_collect::<Vec<_>>()
+    };
+    let mut result = match geom.geometry_type()? {
+        Point => Geometry::create_point(coord_seq_with_dimensions(geom, z, m)?),
+        LineString => Geometry::create_line_string(coord_seq_with_dimensions(geom, z, m)?),
+        LinearRing => Geometry::create_linear_ring(coord_seq_with_dimensions(geom, z, m)?),
+        Polygon => {
+            let exterior = with_dimensions(&geom.get_exterior_ring()?, z, m)?;
+            let interiors = (0..geom.get_num_interior_rings()?)
+                .map(|n| with_dimensions(&geom.get_interior_ring_n(n)?, z, m))
+                .try_collect::<Vec<_>>()?;
+            Geometry::create_polygon(exterior, interiors)
+        }
+        MultiPoint => Geometry::create_multipoint(parts()?),
+        MultiLineString => Geometry::create_multiline_string(parts()?),
+        MultiPolygon => Geometry::create_multipolygon(parts()?),
+        GeometryCollection => Geometry::create_geometry_collection(parts()?),
+        // Curved geometries don't expose their coordinate sequences.
+        t => Err(GError::GenericError(format!(
+            "cannot change the dimensions of a {t:?}"
+        ))),
+    }?;
+    result.set_srid(geom.get_srid()?);
+    Ok(result)
+}
+
+/// Gives all geometries the same dimensions, following the policy for
+/// geometries of mixed dimensions. Empty geometries are ignored.
+fn unify_dimensions(geoms: Vec<Geometry>, policy: MixedDimensions) -> GResult<Vec<Geometry>> {
+    let mut dimensions = Vec::with_capacity(geoms.len());
+    for geom in &geoms {
+        if !geom.is_empty()? {
+            dimensions.push((geom.has_z()?, geom.has_m()?));
+        }
+    }
+    let (any_z, any_m) = dimensions
+        .iter()
+        .fold((false, false), |(z, m), (has_z, has_m)| {
+            (z || *has_z, m || *has_m)
+        });
+    let (all_z, all_m) = dimensions
+        .iter()
+        .fold((true, true), |(z, m), (has_z, has_m)| {
+            (z && *has_z, m && *has_m)
+        });
+    if (any_z, any_m) == (all_z, all_m) {
+        return Ok(geoms);
+    }
+    let (has_z, has_m) = match policy {
+        MixedDimensions::Promote => (any_z, any_m),
+        MixedDimensions::Drop => (all_z, all_m),
+        MixedDimensions::Error => {
+            return Err(GError::GenericError(
+                "cannot collect geometries of mixed dimensions".into(),
+            ))
+        }
+    };
+    geoms
+        .iter()
+        .map(|geom| with_dimensions(geom, has_z.then_some(0.0), has_m.then_some(0.0)))
+        .collect()
+}
+
+pub fn collect(
+    wkb: &BinaryChunked,
+    into: Option<WKBGeometryType>,
+    mixed_dimensions: MixedDimensions,
+) -> GResult<BinaryChunked> {
+    let into = match into {
+        Some(into) => into.try_into(),
+        None => collection_supertype(wkb),
+    }?;
+    let create = match into {
+        MultiPoint => Geometry::create_multipoint,
+        MultiLineString => Geometry::create_multiline_string,
+        MultiCurve => Geometry::create_multicurve,
+        MultiPolygon => Geometry::create_multipolygon,
+        MultiSurface => Geometry::create_multisurface,
+        GeometryCollection => Geometry::create_geometry_collection,
+        _ => return Err(GError::GenericError("type must be a collection".into())),
+    };
+    aggregate_with(wkb, |geoms| {
+        create(unify_dimensions(geoms, mixed_dimensions)?)
+    })
+}
+
+fn make_line_coords<T: Geom>(geom: &T, dimension: usize, coords: &mut Vec<f64>) -> GResult<()> {
+    match geom.geometry_type()? {
+        _ if geom.is_empty()? => Ok(()),
+        Point | LineString => {
+            coords.extend(geom.get_coord_seq()?.as_buffer(Some(dimension))?);
+            Ok(())
+        }
+        MultiPoint => (0..geom.get_num_geometries()?)
+            .try_for_each(|n| make_line_coords(&geom.get_geometry_n(n)?, dimension, coords)),
+        _ => Err(GError::GenericError(
+            "make_line only supports Point, MultiPoint and LineString geometries".into(),
+        )),
+    }
+}
+
+/// Connects the coordinates of all geometries, in order, into a single
+/// LineString, which is empty if there are less than two coordinates.
+pub fn make_line(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
+    aggregate_with(wkb, |geoms| {
+        let (mut has_z, mut has_m) = (false, false);
+        for geom in &geoms {
+            has_z |= geom.has_z()?;
+            has_m |= geom.has_m()?;
+        }
+        let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+        let mut coords = Vec::new();
+        for geom in &geoms {
+            make_line_coords(geom, dimension, &mut coords)?;
+        }
+        if coords.len() < 2 * dimension {
+            return Geometry::create_empty_line_string();
+        }
+        let size = coords.len() / dimension;
+        Geometry::create_line_string(CoordSeq::new_from_buffer(&coords, size, has_z, has_m)?)
+    })
+}
+
+pub fn boundary(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let mut boundary = match geom.geometry_type()? {
+            GeometryCollection => Geometry::create_empty_collection(GeometryCollection),
+            _ => geom.boundary(),
+        }?;
+        boundary.set_srid(geom.get_srid()?);
+        boundary.to_ewkb()
+    })
 }
 
-pub fn boundary(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
-    wkb.try_apply_nonnull_values_generic(|wkb| {
-        let geom = Geometry::new_from_wkb(wkb)?;
-        match geom.geometry_type()? {
-            GeometryCollection => Geometry::create_empty_collection(GeometryCollection),
-            _ => geom.boundary(),
-        }?
-        .to_ewkb()
-    })
-}
-
 pub fn buffer(
     wkb: &BinaryChunked,
     distance: &Float64Chunked,
@@ -1299,6 +2869,86 @@ pub fn buffer(
     })
 }
 
+/// Buffers lon / lat geometries by a distance in meters, in an azimuthal
+/// equidistant projection centered on each geometry.
+pub fn buffer_geodesic(
+    wkb: &BinaryChunked,
+    distance: &Float64Chunked,
+    params: &BufferGeodesicKwargs,
+) -> GResult<BinaryChunked> {
+    let buffer_params: BufferParams = (&params.buffer).try_into()?;
+    let wgs84 = "+proj=longlat +datum=WGS84";
+    let wgs84 = Proj::from_proj_string(wgs84).map_err(|e| invalid_crs(wgs84, e))?;
+    broadcast_try_binary_elementwise_values(wkb, distance, |wkb, distance| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        geodesic::check_srid(&geom)?;
+        if geom.is_empty()? {
+            return geom.buffer_with_params(distance, &buffer_params)?.to_ewkb();
+        }
+        let center = geom.get_centroid()?;
+        let (lon, lat) = (center.get_x()?, center.get_y()?);
+        let definition = format!("+proj=aeqd +lat_0={lat} +lon_0={lon} +datum=WGS84");
+        let aeqd = Proj::from_proj_string(&definition).map_err(|e| invalid_crs(&definition, e))?;
+        let local = apply_proj_transform(&wgs84, &aeqd, &geom)?;
+        let buffer = local.buffer_with_params(distance, &buffer_params)?;
+        let buffer = apply_proj_transform(&aeqd, &wgs84, &buffer)?;
+        let mut buffer = antimeridian::apply(buffer, params.antimeridian)?;
+        buffer.set_srid(geom.get_srid()?);
+        buffer.to_ewkb()
+    })
+}
+
+fn buffer_bands(
+    geom: &Geometry,
+    distances: &[f64],
+    params: &BufferParams,
+) -> GResult<Vec<Geometry>> {
+    let mut inner: Option<Geometry> = None;
+    distances
+        .iter()
+        .map(|distance| {
+            let outer = geom.buffer_with_params(*distance, params)?;
+            let band = match &inner {
+                Some(inner) => outer.difference(inner)?,
+                None => outer.clone(),
+            };
+            inner = Some(outer);
+            Ok(band)
+        })
+        .collect()
+}
+
+pub fn buffer_rings(wkb: &BinaryChunked, params: &BufferRingsKwargs) -> GResult<ListChunked> {
+    if !params.distances.is_sorted_by(|a, b| a < b) {
+        return Err(GError::GenericError(
+            "buffer ring distances must be in strictly ascending order".into(),
+        ));
+    }
+    let buffer_params: BufferParams = (&params.buffer).try_into()?;
+    if params.dissolve {
+        let geoms = collect_geometry_vec(wkb)?;
+        let srid = common_srid(&geoms)?;
+        let geom = Geometry::create_geometry_collection(geoms)?;
+        let bands = buffer_bands(&geom, &params.distances, &buffer_params)?
+            .into_iter()
+            .map(|mut band| {
+                band.set_srid(srid);
+                band.to_ewkb()
+            })
+            .collect::<GResult<Vec<_>>>()?;
+        let bands = BinaryChunked::from_slice(wkb.name().clone(), &bands).into_series();
+        return Ok(ListChunked::from_iter([Some(bands)]).with_name(wkb.name().clone()));
+    }
+    // TODO: use try_apply_nonnull_values_generic once pola-rs/polars#22233 is merged
+    let dt = DataType::List(Box::new(DataType::Binary));
+    try_unary_elementwise_values_with_dtype(wkb, dt, |wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let bands = buffer_bands(&geom, &params.distances, &buffer_params)?;
+        let bands = BinaryViewArray::try_arr_from_iter(bands.iter().map(GeometryUtils::to_ewkb))?;
+        Ok(Box::new(bands) as Box<dyn Array>)
+    })
+}
+
 pub fn offset_curve(
     wkb: &BinaryChunked,
     distance: &Float64Chunked,
@@ -1325,12 +2975,16 @@ pub fn get_centroid(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
 pub fn get_center(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| {
         let geom = Geometry::new_from_wkb(wkb)?;
-        if geom.is_empty()? {
-            return Geometry::create_empty_point()?.to_ewkb();
-        }
-        let x = f64::midpoint(geom.get_x_min()?, geom.get_x_max()?);
-        let y = f64::midpoint(geom.get_y_min()?, geom.get_y_max()?);
-        Geometry::create_point(CoordSeq::new_from_buffer(&[x, y], 1, false, false)?)?.to_ewkb()
+        let mut center = match geom.is_empty()? {
+            true => Geometry::create_empty_point()?,
+            false => {
+                let x = f64::midpoint(geom.get_x_min()?, geom.get_x_max()?);
+                let y = f64::midpoint(geom.get_y_min()?, geom.get_y_max()?);
+                Geometry::create_point(CoordSeq::new_from_buffer(&[x, y], 1, false, false)?)?
+            }
+        };
+        center.set_srid(geom.get_srid()?);
+        center.to_ewkb()
     })
 }
 
@@ -1347,6 +3001,76 @@ pub fn clip_by_rect(wkb: &BinaryChunked, rect: &ArrayChunked) -> GResult<BinaryC
     })
 }
 
+/// Depth at which subdivision stops, so that degenerate geometries which
+/// can't be split below the vertex limit don't recurse forever.
+const SUBDIVIDE_MAX_DEPTH: u32 = 50;
+
+fn subdivide_geom<T: Geom>(
+    geom: &T,
+    max_vertices: usize,
+    depth: u32,
+    pieces: &mut Vec<Geometry>,
+) -> GResult<()> {
+    if geom.is_empty()? {
+        return Ok(());
+    }
+    if let MultiPoint | MultiLineString | MultiPolygon | GeometryCollection =
+        geom.geometry_type()?
+    {
+        return (0..geom.get_num_geometries()?).try_for_each(|n| {
+            subdivide_geom(&geom.get_geometry_n(n)?, max_vertices, depth, pieces)
+        });
+    }
+    let (xmin, ymin) = (geom.get_x_min()?, geom.get_y_min()?);
+    let (xmax, ymax) = (geom.get_x_max()?, geom.get_y_max()?);
+    let (width, height) = (xmax - xmin, ymax - ymin);
+    if geom.get_num_coordinates()? <= max_vertices
+        || depth >= SUBDIVIDE_MAX_DEPTH
+        || (width <= 0.0 && height <= 0.0)
+    {
+        pieces.push(Geom::clone(geom)?);
+        return Ok(());
+    }
+    let halves = match width >= height {
+        true => {
+            let x = f64::midpoint(xmin, xmax);
+            [(xmin, ymin, x, ymax), (x, ymin, xmax, ymax)]
+        }
+        false => {
+            let y = f64::midpoint(ymin, ymax);
+            [(xmin, ymin, xmax, y), (xmin, y, xmax, ymax)]
+        }
+    };
+    for (xmin, ymin, xmax, ymax) in halves {
+        let half = geom.clip_by_rect(xmin, ymin, xmax, ymax)?;
+        subdivide_geom(&half, max_vertices, depth + 1, pieces)?;
+    }
+    Ok(())
+}
+
+/// Recursively splits each geometry in halves of its bounding box, until
+/// every piece has at most `max_vertices` vertices.
+pub fn subdivide(wkb: &BinaryChunked, max_vertices: usize) -> GResult<ListChunked> {
+    if max_vertices < 5 {
+        return Err(GError::GenericError(format!(
+            "subdivide max_vertices must be at least 5, got {max_vertices}"
+        )));
+    }
+    // TODO: use try_apply_nonnull_values_generic once pola-rs/polars#22233 is merged
+    let dt = DataType::List(Box::new(DataType::Binary));
+    try_unary_elementwise_values_with_dtype(wkb, dt, |wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let srid = geom.get_srid()?;
+        let mut pieces = Vec::new();
+        subdivide_geom(&geom, max_vertices, 0, &mut pieces)?;
+        let pieces = BinaryViewArray::try_arr_from_iter(pieces.iter_mut().map(|piece| {
+            piece.set_srid(srid);
+            piece.to_ewkb()
+        }))?;
+        Ok(Box::new(pieces) as Box<dyn Array>)
+    })
+}
+
 pub fn convex_hull(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| {
         Geometry::new_from_wkb(wkb)?.convex_hull()?.to_ewkb()
@@ -1365,11 +3089,18 @@ pub fn delaunay_triangulation(
     wkb: &BinaryChunked,
     params: &DelaunayTrianlesKwargs,
 ) -> GResult<BinaryChunked> {
-    collect_geometry_vec(wkb)
-        .and_then(Geometry::create_geometry_collection)
-        .and_then(|geom| geom.delaunay_triangulation(params.tolerance, params.only_edges))
-        .and_then(|geom| geom.to_ewkb())
-        .map(|res| BinaryChunked::from_slice(wkb.name().clone(), &[res]))
+    if params.per_geometry {
+        return wkb.try_apply_nonnull_values_generic(|wkb| {
+            let geom = Geometry::new_from_wkb(wkb)?;
+            let mut triangles = geom.delaunay_triangulation(params.tolerance, params.only_edges)?;
+            triangles.set_srid(geom.get_srid()?);
+            triangles.to_ewkb()
+        });
+    }
+    aggregate_with(wkb, |geoms| {
+        Geometry::create_geometry_collection(geoms)?
+            .delaunay_triangulation(params.tolerance, params.only_edges)
+    })
 }
 
 pub fn densify(wkb: &BinaryChunked, tolerance: &Float64Chunked) -> GResult<BinaryChunked> {
@@ -1378,6 +3109,25 @@ pub fn densify(wkb: &BinaryChunked, tolerance: &Float64Chunked) -> GResult<Binar
     })
 }
 
+pub fn segmentize_geodesic(
+    wkb: &BinaryChunked,
+    max_length: &Float64Chunked,
+    params: &SegmentizeGeodesicKwargs,
+) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise_values(wkb, max_length, |wkb, max_length| {
+        if max_length.is_nan() || max_length <= 0.0 {
+            let msg = "max_length_m must be strictly positive".into();
+            return Err(GError::GenericError(msg));
+        }
+        let geom = Geometry::new_from_wkb(wkb)?;
+        geodesic::check_srid(&geom)?;
+        let result = geodesic::segmentize(&geom, max_length)?;
+        let mut result = antimeridian::apply(result, params.antimeridian)?;
+        result.set_srid(geom.get_srid()?);
+        result.to_ewkb()
+    })
+}
+
 pub fn envelope(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.envelope()?.to_ewkb())
 }
@@ -1394,8 +3144,26 @@ pub fn build_area(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.build_area()?.to_ewkb())
 }
 
-pub fn make_valid(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
-    wkb.try_apply_nonnull_values_generic(|wkb| Geometry::new_from_wkb(wkb)?.make_valid()?.to_ewkb())
+pub fn make_valid(wkb: &BinaryChunked, params: &MakeValidKwargs) -> GResult<BinaryChunked> {
+    if params.method == MakeValidMethod::Linework {
+        if !params.keep_collapsed {
+            return Err(GError::GenericError(
+                "The linework method doesn't support keep_collapsed=False".into(),
+            ));
+        }
+        return wkb.try_apply_nonnull_values_generic(|wkb| {
+            Geometry::new_from_wkb(wkb)?.make_valid()?.to_ewkb()
+        });
+    }
+    let mut make_valid_params = MakeValidParams::new()?;
+    make_valid_params.set_method(params.method.into())?;
+    make_valid_params.set_keep_collapsed(params.keep_collapsed)?;
+    wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let mut valid = geom.make_valid_with_params(&make_valid_params)?;
+        valid.set_srid(geom.get_srid()?);
+        valid.to_ewkb()
+    })
 }
 
 pub fn normalize(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
@@ -1416,6 +3184,33 @@ pub fn point_on_surface(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     })
 }
 
+/// Returns the pole of inaccessibility of each polygon, that is the center of
+/// its maximum inscribed circle, computed up to `tolerance`.
+pub fn polylabel(wkb: &BinaryChunked, tolerance: &Float64Chunked) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise_values(wkb, tolerance, |wkb, tolerance| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let geometry_type = geom.geometry_type()?;
+        if !matches!(geometry_type, Polygon | MultiPolygon) {
+            return Err(GError::GenericError(format!(
+                "polylabel can only be computed for polygons, got {geometry_type:?}"
+            )));
+        }
+        if tolerance.is_nan() || tolerance <= 0.0 {
+            return Err(GError::GenericError(format!(
+                "polylabel tolerance must be positive, got {tolerance}"
+            )));
+        }
+        let mut point = match geom.is_empty()? {
+            true => Geometry::create_empty_point()?,
+            false => geom
+                .maximum_inscribed_circle(tolerance)?
+                .get_start_point()?,
+        };
+        point.set_srid(geom.get_srid()?);
+        point.to_ewkb()
+    })
+}
+
 pub fn remove_repeated_points(
     wkb: &BinaryChunked,
     tolerance: &Float64Chunked,
@@ -1437,6 +3232,81 @@ pub fn simplify(wkb: &BinaryChunked, tolerance: &Float64Chunked) -> GResult<Bina
     })
 }
 
+pub fn simplify_vw(wkb: &BinaryChunked, tolerance: &Float64Chunked) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise_values(wkb, tolerance, |wkb, tolerance| {
+        visvalingam::simplify(&Geometry::new_from_wkb(wkb)?, tolerance)?.to_ewkb()
+    })
+}
+
+pub fn curve_to_linear(wkb: &BinaryChunked, tolerance: &Float64Chunked) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise_values(wkb, tolerance, |wkb, tolerance| {
+        if tolerance.is_nan() || tolerance <= 0.0 {
+            return Err(GError::GenericError(format!(
+                "curve_to_linear tolerance must be positive, got {tolerance}"
+            )));
+        }
+        curves::linearize(&Geometry::new_from_wkb(wkb)?, tolerance)?.to_ewkb()
+    })
+}
+
+pub fn linear_to_curve(wkb: &BinaryChunked, tolerance: &Float64Chunked) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise_values(wkb, tolerance, |wkb, tolerance| {
+        if tolerance.is_nan() || tolerance <= 0.0 {
+            return Err(GError::GenericError(format!(
+                "linear_to_curve tolerance must be positive, got {tolerance}"
+            )));
+        }
+        curves::line_to_curve(&Geometry::new_from_wkb(wkb)?, tolerance)?.to_ewkb()
+    })
+}
+
+/// Simplifies all the polygons of a coverage together, so that the edges
+/// shared by neighbours are simplified identically. Null rows stay null, and
+/// empty polygons are left out of the coverage and returned as is.
+pub fn simplify_coverage(
+    wkb: &BinaryChunked,
+    tolerance: f64,
+    preserve_boundary: bool,
+) -> GResult<BinaryChunked> {
+    let geoms = wkb
+        .into_iter()
+        .flatten()
+        .map(Geometry::new_from_wkb)
+        .try_collect::<Vec<_>>()?;
+    let mut polygons = Vec::with_capacity(geoms.len());
+    for geom in &geoms {
+        let geometry_type = geom.geometry_type()?;
+        if !matches!(geometry_type, Polygon | MultiPolygon) {
+            return Err(GError::GenericError(format!(
+                "simplify_coverage can only be computed for polygons, got {geometry_type:?}"
+            )));
+        }
+        if !geom.is_empty()? {
+            polygons.push(Geom::clone(geom)?);
+        }
+    }
+    let coverage = Geometry::create_geometry_collection(polygons)?
+        .coverage_simplify_vw(tolerance, preserve_boundary)?;
+    let mut n = 0;
+    let mut parts = geoms.iter().map(|geom| {
+        if geom.is_empty()? {
+            return geom.to_ewkb();
+        }
+        let mut part = Geom::clone(&coverage.get_geometry_n(n)?)?;
+        n += 1;
+        part.set_srid(geom.get_srid()?);
+        part.to_ewkb()
+    });
+    let values = wkb
+        .into_iter()
+        .map(|wkb| wkb.and_then(|_| parts.next()).transpose())
+        .try_collect::<Vec<_>>()?;
+    Ok(BinaryChunked::from_iter_options(
+        wkb.name().clone(),
+        values.into_iter(),
+    ))
+}
+
 pub fn topology_preserve_simplify(
     wkb: &BinaryChunked,
     tolerance: &Float64Chunked,
@@ -1448,24 +3318,29 @@ pub fn topology_preserve_simplify(
     })
 }
 
+/// Returns an empty 2D geometry of the given type.
+fn create_empty(geometry_type: GeometryTypes) -> GResult<Geometry> {
+    match geometry_type {
+        Point => Geometry::create_empty_point(),
+        LineString | LinearRing => Geometry::create_empty_line_string(),
+        Polygon => Geometry::create_empty_polygon(),
+        MultiPoint => Geometry::create_empty_collection(MultiPoint),
+        MultiLineString => Geometry::create_empty_collection(MultiLineString),
+        MultiPolygon => Geometry::create_empty_collection(MultiPolygon),
+        GeometryCollection => Geometry::create_empty_collection(GeometryCollection),
+        CircularString => Geometry::create_empty_circular_string(),
+        CompoundCurve => Geometry::create_empty_compound_curve(),
+        CurvePolygon => Geometry::create_empty_curve_polygon(),
+        MultiCurve => Geometry::create_empty_collection(MultiCurve),
+        MultiSurface => Geometry::create_empty_collection(MultiSurface),
+    }
+}
+
 pub fn force_2d(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| {
         let geom = Geometry::new_from_wkb(wkb)?;
         if geom.is_empty()? {
-            let mut res = match geom.geometry_type()? {
-                Point => Geometry::create_empty_point(),
-                LineString | LinearRing => Geometry::create_empty_line_string(),
-                Polygon => Geometry::create_empty_polygon(),
-                MultiPoint => Geometry::create_empty_collection(MultiPoint),
-                MultiLineString => Geometry::create_empty_collection(MultiLineString),
-                MultiPolygon => Geometry::create_empty_collection(MultiPolygon),
-                GeometryCollection => Geometry::create_empty_collection(GeometryCollection),
-                CircularString => Geometry::create_empty_circular_string(),
-                CompoundCurve => Geometry::create_empty_compound_curve(),
-                CurvePolygon => Geometry::create_empty_curve_polygon(),
-                MultiCurve => Geometry::create_empty_collection(MultiCurve),
-                MultiSurface => Geometry::create_empty_collection(MultiSurface),
-            }?;
+            let mut res = create_empty(geom.geometry_type()?)?;
             res.set_srid(geom.get_srid()?);
             res
         } else {
@@ -1483,6 +3358,16 @@ pub fn force_3d(wkb: &BinaryChunked, z: &Float64Chunked) -> GResult<BinaryChunke
     })
 }
 
+pub fn force_4d(
+    wkb: &BinaryChunked,
+    z: &Float64Chunked,
+    m: &Float64Chunked,
+) -> GResult<BinaryChunked> {
+    broadcast_try_ternary_elementwise_values(wkb, z, m, |wkb, z, m| {
+        with_dimensions(&Geometry::new_from_wkb(wkb)?, Some(z), Some(m))?.to_ewkb()
+    })
+}
+
 pub fn minimum_rotated_rectangle(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     wkb.try_apply_nonnull_values_generic(|wkb| {
         Geometry::new_from_wkb(wkb)?
@@ -1697,6 +3582,67 @@ pub fn affine_transform_3d(wkb: &BinaryChunked, matrix: &ArrayChunked) -> GResul
     })
 }
 
+fn mrr_alignment(geom: &Geometry) -> GResult<(f64, f64, f64)> {
+    let mrr = geom.minimum_rotated_rectangle()?;
+    let center = mrr.get_centroid()?;
+    let coords = match mrr.geometry_type()? {
+        Polygon => mrr
+            .get_exterior_ring()?
+            .get_coord_seq()?
+            .as_buffer(Some(2))?,
+        LineString => mrr.get_coord_seq()?.as_buffer(Some(2))?,
+        _ => Vec::new(),
+    };
+    let edge = |n: usize| {
+        (
+            coords[2 * n + 2] - coords[2 * n],
+            coords[2 * n + 3] - coords[2 * n + 1],
+        )
+    };
+    let (dx, dy) = match coords.len() {
+        len if len >= 6 => {
+            let (first, second) = (edge(0), edge(1));
+            if first.0.hypot(first.1) >= second.0.hypot(second.1) {
+                first
+            } else {
+                second
+            }
+        }
+        4 => edge(0),
+        _ => (1.0, 0.0),
+    };
+    // Orientation of the longest edge, folded into [-90, 90)
+    let angle = dy.atan2(dx).to_degrees();
+    let angle = match angle {
+        a if a >= 90.0 => a - 180.0,
+        a if a < -90.0 => a + 180.0,
+        a => a,
+    };
+    Ok((-angle, center.get_x()?, center.get_y()?))
+}
+
+pub fn align_to_mrr(wkb: &BinaryChunked) -> GResult<(BinaryChunked, Float64Chunked)> {
+    let mut geometry_builder = BinaryChunkedBuilder::new("geometry".into(), wkb.len());
+    let mut angle_builder = PrimitiveChunkedBuilder::<Float64Type>::new("angle".into(), wkb.len());
+    for wkb in wkb {
+        let Some(wkb) = wkb else {
+            geometry_builder.append_null();
+            angle_builder.append_null();
+            continue;
+        };
+        let geom = Geometry::new_from_wkb(wkb)?;
+        if geom.is_empty()? {
+            geometry_builder.append_value(wkb);
+            angle_builder.append_value(0.0);
+            continue;
+        }
+        let (angle, x0, y0) = mrr_alignment(&geom)?;
+        geometry_builder.append_value(geom.rotate(angle, x0, y0)?.to_ewkb()?);
+        angle_builder.append_value(angle);
+    }
+    Ok((geometry_builder.finish(), angle_builder.finish()))
+}
+
 pub fn interpolate(wkb: &BinaryChunked, distance: &Float64Chunked) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(wkb, distance, |wkb, distance| {
         Geometry::new_from_wkb(wkb)?
@@ -1716,6 +3662,30 @@ pub fn interpolate_normalized(
     })
 }
 
+/// Interpolates a point at each distance of a list, with null distances
+/// giving null points.
+pub fn interpolate_list(
+    wkb: &BinaryChunked,
+    distances: &ListChunked,
+    normalized: bool,
+) -> GResult<ListChunked> {
+    let dtype = DataType::List(DataType::Binary.into());
+    broadcast_try_binary_elementwise_values_with_dtype(wkb, distances, dtype, |wkb, distances| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let distances = unsafe { distances.as_any().downcast_ref_unchecked::<Float64Array>() };
+        let points = distances.iter().map(|distance| {
+            distance
+                .map(|&distance| match normalized {
+                    true => geom.interpolate_normalized(distance),
+                    false => geom.interpolate(distance),
+                })
+                .map(|point| point?.to_ewkb())
+                .transpose()
+        });
+        Ok(Box::new(BinaryViewArray::try_arr_from_iter(points)?) as Box<dyn Array>)
+    })
+}
+
 pub fn project(a: &BinaryChunked, b: &BinaryChunked) -> GResult<Float64Chunked> {
     broadcast_try_binary_elementwise_values(a, b, |a, b| {
         let a = Geometry::new_from_wkb(a)?;
@@ -1754,6 +3724,206 @@ pub fn line_merge_directed(wkb: &BinaryChunked) -> GResult<BinaryChunked> {
     })
 }
 
+fn linear_coords<T: Geom>(geom: &T, dimension: usize, coords: &mut Vec<Vec<f64>>) -> GResult<()> {
+    match geom.geometry_type()? {
+        _ if geom.is_empty()? => Ok(()),
+        LineString | LinearRing => {
+            coords.push(geom.get_coord_seq()?.as_buffer(Some(dimension))?);
+            Ok(())
+        }
+        Polygon => {
+            linear_coords(&geom.get_exterior_ring()?, dimension, coords)?;
+            (0..geom.get_num_interior_rings()?)
+                .try_for_each(|n| linear_coords(&geom.get_interior_ring_n(n)?, dimension, coords))
+        }
+        MultiLineString | MultiPolygon | GeometryCollection => (0..geom.get_num_geometries()?)
+            .try_for_each(|n| linear_coords(&geom.get_geometry_n(n)?, dimension, coords)),
+        _ => Ok(()),
+    }
+}
+
+fn line_string_from_coords(
+    coords: &[f64],
+    has_z: bool,
+    has_m: bool,
+    srid: i32,
+) -> GResult<Geometry> {
+    let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+    let coord_seq = CoordSeq::new_from_buffer(coords, coords.len() / dimension, has_z, has_m)?;
+    let mut line = Geometry::create_line_string(coord_seq)?;
+    line.set_srid(srid);
+    Ok(line)
+}
+
+pub fn points_along(
+    wkb: &BinaryChunked,
+    interval: Option<f64>,
+    fractions: Option<&[f64]>,
+) -> GResult<ListChunked> {
+    match (interval, fractions) {
+        (Some(interval), None) if interval <= 0.0 => {
+            let msg = "points_along interval must be strictly positive".into();
+            return Err(GError::GenericError(msg));
+        }
+        (Some(_), None) | (None, Some(_)) => {}
+        _ => {
+            let msg = "points_along requires either an interval or fractions".into();
+            return Err(GError::GenericError(msg));
+        }
+    }
+    // TODO: use try_apply_nonnull_values_generic once pola-rs/polars#22233 is merged
+    let dt = DataType::List(Box::new(DataType::Binary));
+    try_unary_elementwise_values_with_dtype(wkb, dt, |wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let srid = geom.get_srid()?;
+        let (has_z, has_m) = (geom.has_z()?, geom.has_m()?);
+        let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+        let mut lines = Vec::new();
+        linear_coords(&geom, dimension, &mut lines)?;
+        let mut points = Vec::new();
+        for line in lines.iter().filter(|line| line.len() >= 2 * dimension) {
+            // Distance along the line of each of its vertices.
+            let mut vertex_distances = vec![0.0];
+            for segment in line.windows(2 * dimension).step_by(dimension) {
+                let (dx, dy) = (
+                    segment[dimension] - segment[0],
+                    segment[dimension + 1] - segment[1],
+                );
+                vertex_distances.push(vertex_distances[vertex_distances.len() - 1] + dx.hypot(dy));
+            }
+            let length = vertex_distances[vertex_distances.len() - 1];
+            let distances = match (interval, fractions) {
+                (Some(interval), _) => (0_u32..)
+                    .map(|n| f64::from(n) * interval)
+                    .take_while(|distance| *distance <= length)
+                    .collect::<Vec<_>>(),
+                (None, fractions) => fractions
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|fraction| fraction.clamp(0.0, 1.0) * length)
+                    .collect(),
+            };
+            for distance in distances {
+                let index = vertex_distances
+                    .partition_point(|d| *d < distance)
+                    .clamp(1, vertex_distances.len() - 1);
+                let (start, end) = (vertex_distances[index - 1], vertex_distances[index]);
+                let ratio = if end > start {
+                    (distance - start) / (end - start)
+                } else {
+                    0.0
+                };
+                let from = &line[(index - 1) * dimension..index * dimension];
+                let to = &line[index * dimension..(index + 1) * dimension];
+                let coords = from
+                    .iter()
+                    .zip(to)
+                    .map(|(a, b)| a + (b - a) * ratio)
+                    .collect::<Vec<_>>();
+                let coord_seq = CoordSeq::new_from_buffer(&coords, 1, has_z, has_m)?;
+                let mut point = Geometry::create_point(coord_seq)?;
+                point.set_srid(srid);
+                points.push(point);
+            }
+        }
+        let points = BinaryViewArray::try_arr_from_iter(points.iter().map(GeometryUtils::to_ewkb))?;
+        Ok(Box::new(points) as Box<dyn Array>)
+    })
+}
+
+pub fn transects(wkb: &BinaryChunked, spacing: f64, length: f64) -> GResult<ListChunked> {
+    if spacing <= 0.0 {
+        return Err(GError::GenericError(
+            "transects spacing must be strictly positive".into(),
+        ));
+    }
+    let half_length = length / 2.0;
+    // TODO: use try_apply_nonnull_values_generic once pola-rs/polars#22233 is merged
+    let dt = DataType::List(Box::new(DataType::Binary));
+    try_unary_elementwise_values_with_dtype(wkb, dt, |wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let srid = geom.get_srid()?;
+        let mut lines = Vec::new();
+        linear_coords(&geom, 2, &mut lines)?;
+        let mut transects = Vec::new();
+        for line in lines {
+            let mut next = 0.0;
+            let mut start = 0.0;
+            for segment in line.windows(4).step_by(2) {
+                let (x0, y0, x1, y1) = (segment[0], segment[1], segment[2], segment[3]);
+                let segment_length = (x1 - x0).hypot(y1 - y0);
+                if segment_length <= 0.0 {
+                    continue;
+                }
+                let (dx, dy) = ((x1 - x0) / segment_length, (y1 - y0) / segment_length);
+                while next <= start + segment_length {
+                    let (x, y) = (x0 + dx * (next - start), y0 + dy * (next - start));
+                    let transect = line_string_from_coords(
+                        &[
+                            x - dy * half_length,
+                            y + dx * half_length,
+                            x + dy * half_length,
+                            y - dx * half_length,
+                        ],
+                        false,
+                        false,
+                        srid,
+                    )?;
+                    transects.push(transect);
+                    next += spacing;
+                }
+                start += segment_length;
+            }
+        }
+        let transects =
+            BinaryViewArray::try_arr_from_iter(transects.iter().map(GeometryUtils::to_ewkb))?;
+        Ok(Box::new(transects) as Box<dyn Array>)
+    })
+}
+
+pub fn segments(wkb: &BinaryChunked) -> GResult<ListChunked> {
+    // TODO: use try_apply_nonnull_values_generic once pola-rs/polars#22233 is merged
+    let dt = DataType::List(Box::new(DataType::Binary));
+    try_unary_elementwise_values_with_dtype(wkb, dt, |wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let srid = geom.get_srid()?;
+        let (has_z, has_m) = (geom.has_z()?, geom.has_m()?);
+        let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+        let mut lines = Vec::new();
+        linear_coords(&geom, dimension, &mut lines)?;
+        let segments = BinaryViewArray::try_arr_from_iter(
+            lines
+                .iter()
+                .flat_map(|line| line.windows(2 * dimension).step_by(dimension))
+                .map(|coords| line_string_from_coords(coords, has_z, has_m, srid)?.to_ewkb()),
+        )?;
+        Ok(Box::new(segments) as Box<dyn Array>)
+    })
+}
+
+pub fn segment_azimuths(wkb: &BinaryChunked) -> GResult<ListChunked> {
+    // TODO: use try_apply_nonnull_values_generic once pola-rs/polars#22233 is merged
+    let dt = DataType::List(Box::new(DataType::Float64));
+    try_unary_elementwise_values_with_dtype(wkb, dt, |wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let mut lines = Vec::new();
+        linear_coords(&geom, 2, &mut lines)?;
+        let azimuths = lines
+            .iter()
+            .flat_map(|line| line.windows(4).step_by(2))
+            .map(|segment| {
+                let (dx, dy) = (segment[2] - segment[0], segment[3] - segment[1]);
+                if dx == 0.0 && dy == 0.0 {
+                    f64::NAN
+                } else {
+                    dx.atan2(dy).to_degrees().rem_euclid(360.0)
+                }
+            })
+            .collect::<Vec<_>>();
+        Ok(Box::new(Float64Array::from_vec(azimuths)) as Box<dyn Array>)
+    })
+}
+
 pub fn shared_paths(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(a, b, |a, b| {
         let a = Geometry::new_from_wkb(a)?;
@@ -1762,6 +3932,112 @@ pub fn shared_paths(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BinaryChunk
     })
 }
 
+/// Cuts the coordinates of a line at the given sorted distances along it,
+/// which must lie strictly between its endpoints.
+fn cut_line(line: &[f64], dimension: usize, cuts: &[f64]) -> Vec<Vec<f64>> {
+    let mut pieces = Vec::new();
+    let mut piece = line[..dimension].to_vec();
+    let mut cuts = cuts.iter().copied().peekable();
+    let mut start = 0.0;
+    for segment in line.windows(2 * dimension).step_by(dimension) {
+        let (from, to) = segment.split_at(dimension);
+        let length = (to[0] - from[0]).hypot(to[1] - from[1]);
+        while let Some(cut) = cuts.next_if(|cut| *cut < start + length) {
+            let ratio = (cut - start) / length;
+            let coord = from
+                .iter()
+                .zip(to)
+                .map(|(a, b)| a + (b - a) * ratio)
+                .collect::<Vec<_>>();
+            if ratio > 0.0 {
+                piece.extend(&coord);
+            }
+            pieces.push(std::mem::replace(&mut piece, coord));
+        }
+        piece.extend(to);
+        start += length;
+    }
+    pieces.push(piece);
+    pieces
+}
+
+fn split_geom<T: Geom>(geom: &T, blade: &Geometry, pieces: &mut Vec<Geometry>) -> GResult<()> {
+    let blade_type = blade.geometry_type()?;
+    match (geom.geometry_type()?, blade_type) {
+        _ if geom.is_empty()? => Ok(()),
+        (MultiLineString | MultiPolygon | GeometryCollection, _) => {
+            let num_geometries = geom.get_num_geometries()?;
+            (0..num_geometries)
+                .try_for_each(|n| split_geom(&geom.get_geometry_n(n)?, blade, pieces))
+        }
+        (LineString, Point | MultiPoint) => {
+            let (has_z, has_m) = (geom.has_z()?, geom.has_m()?);
+            let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+            let length = geom.length()?;
+            let mut cuts = Vec::new();
+            for n in 0..blade.get_num_geometries()? {
+                let point = Geom::clone(&blade.get_geometry_n(n)?)?;
+                if !point.is_empty()? && geom.distance(&point)? <= 0.0 {
+                    cuts.push(geom.project(&point)?);
+                }
+            }
+            cuts.retain(|cut| *cut > 0.0 && *cut < length);
+            cuts.sort_by(f64::total_cmp);
+            cuts.dedup();
+            let coords = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+            for piece in cut_line(&coords, dimension, &cuts) {
+                pieces.push(line_string_from_coords(&piece, has_z, has_m, 0)?);
+            }
+            Ok(())
+        }
+        (LineString, LineString | MultiLineString | Polygon | MultiPolygon) => {
+            let blade = match blade_type {
+                Polygon | MultiPolygon => blade.boundary()?,
+                _ => Geom::clone(blade)?,
+            };
+            let parts = geom.difference(&blade)?;
+            (0..parts.get_num_geometries()?).try_for_each(|n| {
+                let part = parts.get_geometry_n(n)?;
+                if !part.is_empty()? {
+                    pieces.push(Geom::clone(&part)?);
+                }
+                Ok(())
+            })
+        }
+        (Polygon, LineString | MultiLineString) => {
+            // Node the boundary of the polygon with the blade, and keep the
+            // faces that lie inside the polygon.
+            let edges = geom.boundary()?.union(blade)?;
+            let faces = Geometry::polygonize(&[edges])?;
+            (0..faces.get_num_geometries()?).try_for_each(|n| {
+                let face = faces.get_geometry_n(n)?;
+                if geom.contains(&face.point_on_surface()?)? {
+                    pieces.push(Geom::clone(&face)?);
+                }
+                Ok(())
+            })
+        }
+        (geometry_type, blade_type) => Err(GError::GenericError(format!(
+            "Cannot split {geometry_type:?} by {blade_type:?}"
+        ))),
+    }
+}
+
+/// Splits each geometry by a blade, returning the pieces as a geometry
+/// collection. Lines can be split by points, lines or polygon boundaries,
+/// and polygons by lines.
+pub fn split(wkb: &BinaryChunked, blade: &BinaryChunked) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise_values(wkb, blade, |wkb, blade| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        let blade = Geometry::new_from_wkb(blade)?;
+        let mut pieces = Vec::new();
+        split_geom(&geom, &blade, &mut pieces)?;
+        let mut collection = Geometry::create_geometry_collection(pieces)?;
+        collection.set_srid(geom.get_srid()?);
+        collection.to_ewkb()
+    })
+}
+
 pub fn shortest_line(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BinaryChunked> {
     broadcast_try_binary_elementwise_values(a, b, |a, b| {
         let a = Geometry::new_from_wkb(a)?;
@@ -1776,6 +4052,59 @@ pub fn shortest_line(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BinaryChun
     })
 }
 
+/// Returns the vertices of the convex hull of a geometry, which include the
+/// farthest point of the geometry from any other point.
+fn hull_vertices<T: Geom>(geom: &T) -> GResult<Vec<[f64; 2]>> {
+    let vertices = geom.convex_hull()?.extract_unique_points()?;
+    (0..vertices.get_num_geometries()?)
+        .map(|n| {
+            let vertex = vertices.get_geometry_n(n)?;
+            Ok([vertex.get_x()?, vertex.get_y()?])
+        })
+        .collect()
+}
+
+pub fn longest_line(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise_values(a, b, |a, b| {
+        let a = Geometry::new_from_wkb(a)?;
+        let b = Geometry::new_from_wkb(b)?;
+        let mut line = if a.is_empty()? || b.is_empty()? {
+            Geometry::create_empty_line_string()?
+        } else {
+            let (a_vertices, b_vertices) = (hull_vertices(&a)?, hull_vertices(&b)?);
+            let (start, end) = a_vertices
+                .iter()
+                .flat_map(|p| b_vertices.iter().map(move |q| (p, q)))
+                .max_by(|(p0, q0), (p1, q1)| {
+                    let d0 = (q0[0] - p0[0]).hypot(q0[1] - p0[1]);
+                    let d1 = (q1[0] - p1[0]).hypot(q1[1] - p1[1]);
+                    d0.total_cmp(&d1)
+                })
+                .expect("Non-empty geometries should have vertices");
+            let coords = [start[0], start[1], end[0], end[1]];
+            Geometry::create_line_string(CoordSeq::new_from_buffer(&coords, 2, false, false)?)?
+        };
+        line.set_srid(a.get_srid()?);
+        line.to_ewkb()
+    })
+}
+
+pub fn closest_point(a: &BinaryChunked, b: &BinaryChunked) -> GResult<BinaryChunked> {
+    broadcast_try_binary_elementwise_values(a, b, |a, b| {
+        let a = Geometry::new_from_wkb(a)?;
+        let b = Geometry::new_from_wkb(b)?;
+        let mut point = if a.is_empty()? || b.is_empty()? {
+            Geometry::create_empty_point()?
+        } else {
+            let seq = a.nearest_points(&b)?;
+            let coords = [seq.get_x(0)?, seq.get_y(0)?];
+            Geometry::create_point(CoordSeq::new_from_buffer(&coords, 1, false, false)?)?
+        };
+        point.set_srid(a.get_srid()?);
+        point.to_ewkb()
+    })
+}
+
 pub fn snap(
     a: &BinaryChunked,
     b: &BinaryChunked,
@@ -1794,33 +4123,63 @@ pub fn voronoi_polygons(wkb: &BinaryChunked, params: &VoronoiKwargs) -> GResult<
         .as_ref()
         .map(|wkb| Geometry::new_from_wkb(wkb))
         .transpose()?;
-    collect_geometry_vec(wkb)
-        .and_then(Geometry::create_geometry_collection)
-        .and_then(|geom| geom.voronoi(extend_to.as_ref(), params.tolerance, params.only_edges))
-        .and_then(|geom| geom.to_ewkb())
-        .map(|res| BinaryChunked::from_slice(wkb.name().clone(), &[res]))
+    if params.per_geometry {
+        return wkb.try_apply_nonnull_values_generic(|wkb| {
+            let geom = Geometry::new_from_wkb(wkb)?;
+            let mut polygons =
+                geom.voronoi(extend_to.as_ref(), params.tolerance, params.only_edges)?;
+            polygons.set_srid(geom.get_srid()?);
+            polygons.to_ewkb()
+        });
+    }
+    aggregate_with(wkb, |geoms| {
+        Geometry::create_geometry_collection(geoms)?.voronoi(
+            extend_to.as_ref(),
+            params.tolerance,
+            params.only_edges,
+        )
+    })
 }
 
-fn strtree(geoms: &[Option<Geometry>]) -> GResult<STRtree<usize>> {
-    let length = geoms.len();
-    geoms.iter().enumerate().try_fold(
-        STRtree::<usize>::with_capacity(length)?,
-        |mut tree, (index, geom)| {
-            if let Some(geom) = geom {
-                tree.insert(geom, index);
-            }
-            Ok(tree)
-        },
-    )
-}
+type PreparedPredicate = fn(&PreparedGeometry, &Geometry) -> GResult<bool>;
 
-pub fn sjoin(
-    left: &BinaryChunked,
-    right: &BinaryChunked,
+/// Returns the prepared predicate of a spatial join, along with the distance
+/// of the `dwithin` predicate, which is checked separately on the
+/// non-prepared geometries.
+fn join_predicate(
     predicate: SpatialJoinPredicate,
-) -> GResult<(UInt32Chunked, UInt32Chunked)> {
-    let predicate = match predicate {
-        SpatialJoinPredicate::IntersectsBbox => |_: &_, _: &_| Ok(true),
+    distance: Option<f64>,
+    pattern: Option<&str>,
+) -> GResult<(PreparedPredicate, Option<f64>, Option<&str>)> {
+    let distance = match (predicate, distance) {
+        (SpatialJoinPredicate::Dwithin, Some(distance)) => Some(distance),
+        (SpatialJoinPredicate::Dwithin, None) => {
+            return Err(GError::GenericError(
+                "The dwithin predicate requires a distance".into(),
+            ))
+        }
+        _ => None,
+    };
+    let pattern = match (predicate, pattern) {
+        (SpatialJoinPredicate::Relate, Some(pattern)) if is_relate_pattern(pattern) => {
+            Some(pattern)
+        }
+        (SpatialJoinPredicate::Relate, Some(pattern)) => {
+            return Err(GError::GenericError(format!(
+                "Invalid DE-9IM pattern {pattern:?}"
+            )))
+        }
+        (SpatialJoinPredicate::Relate, None) => {
+            return Err(GError::GenericError(
+                "The relate predicate requires a pattern".into(),
+            ))
+        }
+        _ => None,
+    };
+    let predicate: PreparedPredicate = match predicate {
+        SpatialJoinPredicate::IntersectsBbox
+        | SpatialJoinPredicate::Dwithin
+        | SpatialJoinPredicate::Relate => |_: &_, _: &_| Ok(true),
         SpatialJoinPredicate::Intersects => PreparedGeometry::intersects,
         SpatialJoinPredicate::Within => PreparedGeometry::within,
         SpatialJoinPredicate::Contains => PreparedGeometry::contains,
@@ -1831,109 +4190,1027 @@ pub fn sjoin(
         SpatialJoinPredicate::CoveredBy => PreparedGeometry::covered_by,
         SpatialJoinPredicate::ContainsProperly => PreparedGeometry::contains_properly,
     };
-    let left_geoms = left
-        .into_iter()
-        .map(|v| v.map(Geometry::new_from_wkb).transpose())
-        .collect::<GResult<Vec<_>>>()?;
-    let mut spatial_index = strtree(&left_geoms)?;
-    let left_geoms = left_geoms
+    Ok((predicate, distance, pattern))
+}
+
+/// Returns whether a string is a DE-9IM pattern, made of nine `T`, `F`, `*`,
+/// `0`, `1` or `2` characters.
+fn is_relate_pattern(pattern: &str) -> bool {
+    pattern.len() == 9
+        && pattern
+            .bytes()
+            .all(|c| b"TF*012".contains(&c.to_ascii_uppercase()))
+}
+
+/// Returns the DE-9IM pattern holding for `(b, a)` whenever the given one
+/// holds for `(a, b)`, by transposing its matrix.
+fn transpose_pattern(pattern: &str) -> String {
+    let cells = pattern.as_bytes();
+    (0..9)
+        .map(|i| char::from(cells[i % 3 * 3 + i / 3]))
+        .collect()
+}
+
+/// Returns the predicate holding for `(b, a)` whenever the given one holds
+/// for `(a, b)`, if it can be evaluated on prepared geometries.
+fn converse_predicate(predicate: SpatialJoinPredicate) -> Option<SpatialJoinPredicate> {
+    match predicate {
+        SpatialJoinPredicate::Within => Some(SpatialJoinPredicate::Contains),
+        SpatialJoinPredicate::Contains => Some(SpatialJoinPredicate::Within),
+        SpatialJoinPredicate::Covers => Some(SpatialJoinPredicate::CoveredBy),
+        SpatialJoinPredicate::CoveredBy => Some(SpatialJoinPredicate::Covers),
+        SpatialJoinPredicate::ContainsProperly => None,
+        // The pattern is transposed separately.
+        SpatialJoinPredicate::Relate => Some(SpatialJoinPredicate::Relate),
+        symmetric => Some(symmetric),
+    }
+}
+
+/// Returns the envelope of a geometry, expanded by a distance.
+fn search_area(geom: &Geometry, distance: f64) -> GResult<Geometry> {
+    Geometry::create_rectangle(
+        geom.get_x_min()? - distance,
+        geom.get_y_min()? - distance,
+        geom.get_x_max()? + distance,
+        geom.get_y_max()? + distance,
+    )
+}
+
+/// Number of candidate pairs found by a plane sweep which are buffered before
+/// their predicates are evaluated.
+const SWEEP_CHUNK_SIZE: usize = 1 << 16;
+
+/// Returns the envelope of each geometry, expanded by a distance.
+fn expanded_bounds(values: &[Option<&[u8]>], distance: f64) -> GResult<Vec<Option<Bounds>>> {
+    values
         .iter()
-        .map(|v| v.as_ref().map(Geom::to_prepared_geom).transpose())
-        .collect::<GResult<Vec<_>>>()?;
+        .map(|value| {
+            let bounds = value.map(wkb::envelope).transpose()?.flatten();
+            Ok(bounds.map(|[xmin, ymin, xmax, ymax]| {
+                [
+                    xmin - distance,
+                    ymin - distance,
+                    xmax + distance,
+                    ymax + distance,
+                ]
+            }))
+        })
+        .collect()
+}
+
+/// Geometries parsed from WKB on first access, so that indexed geometries
+/// which are never a query candidate aren't parsed at all.
+struct LazyGeometries<'a> {
+    values: Vec<Option<&'a [u8]>>,
+    geoms: Vec<OnceLock<Geometry>>,
+}
+
+impl<'a> LazyGeometries<'a> {
+    fn new(wkb: &'a BinaryChunked) -> Self {
+        let values = wkb.into_iter().collect::<Vec<_>>();
+        let geoms = values.iter().map(|_| OnceLock::new()).collect();
+        Self { values, geoms }
+    }
+
+    fn get(&self, index: usize) -> GResult<&Geometry> {
+        self.geoms[index].get_or_try_init(|| {
+            let value = self.values[index].expect("Shouldn't be able to match None");
+            Geometry::new_from_wkb(value)
+        })
+    }
+}
+
+pub fn sjoin(
+    left: &BinaryChunked,
+    right: &BinaryChunked,
+    params: &SpatialJoinKwargs,
+) -> GResult<(UInt32Chunked, UInt32Chunked, Float64Chunked)> {
+    let SpatialJoinKwargs {
+        predicate,
+        how,
+        distance,
+        include_distance,
+        algorithm,
+        ref pattern,
+    } = *params;
+    // The smaller side is indexed and prepared, and the geometries of the
+    // other side are probed against it. When the right side is indexed, the
+    // converse predicate is evaluated.
+    let converse = converse_predicate(predicate).filter(|_| right.len() < left.len());
+    let index_right = converse.is_some();
+    let (indexed, probed, predicate) = match converse {
+        Some(converse) => (right, left, converse),
+        None => (left, right, predicate),
+    };
+    let bbox_only = predicate == SpatialJoinPredicate::IntersectsBbox;
+    let (predicate, distance, pattern) = join_predicate(predicate, distance, pattern.as_deref())?;
+    let pattern = match index_right {
+        true => pattern.map(transpose_pattern),
+        false => pattern.map(str::to_owned),
+    };
+    let indexed_geoms = LazyGeometries::new(indexed);
+    let probed_wkb = probed.into_iter().collect::<Vec<_>>();
+
+    // Semi and anti joins only need to know whether each left geometry has a
+    // match, so the predicate isn't evaluated again once one was found.
+    let only_matches = matches!(how, SpatialJoinStrategy::Semi | SpatialJoinStrategy::Anti);
+    let matched_left = (0..left.len())
+        .map(|_| AtomicBool::new(false))
+        .collect::<Vec<_>>();
+
+    // Evaluates the predicate between a probed geometry and its candidates.
+    let evaluate = |probed_index: usize,
+                    probed_geom: &Geometry,
+                    candidates: &[usize],
+                    prepared: &mut HashMap<usize, _>,
+                    pairs: &mut Vec<(u32, u32, f64)>|
+     -> GResult<()> {
+        for &indexed_index in candidates {
+            let (left_index, right_index) = match index_right {
+                true => (probed_index, indexed_index),
+                false => (indexed_index, probed_index),
+            };
+            if only_matches && matched_left[left_index].load(AtomicOrdering::Relaxed) {
+                continue;
+            }
+            let indexed_geom = || indexed_geoms.get(indexed_index);
+            let (is_match, pair_distance) = match (distance, pattern.as_deref()) {
+                (Some(distance), _) => {
+                    let pair_distance = indexed_geom()?.distance(probed_geom)?;
+                    (pair_distance <= distance, Some(pair_distance))
+                }
+                (None, _) if bbox_only => (true, None),
+                (None, Some(pattern)) => {
+                    let is_match = indexed_geom()?.relate_pattern(probed_geom, pattern);
+                    (matches!(is_match, Ok(true)), None)
+                }
+                (None, None) => {
+                    let prepared = match prepared.entry(indexed_index) {
+                        Entry::Occupied(entry) => entry.into_mut(),
+                        Entry::Vacant(entry) => entry.insert(indexed_geom()?.to_prepared_geom()?),
+                    };
+                    (matches!(predicate(prepared, probed_geom), Ok(true)), None)
+                }
+            };
+            if is_match && only_matches {
+                matched_left[left_index].store(true, AtomicOrdering::Relaxed);
+            } else if is_match {
+                let pair_distance = match (pair_distance, include_distance) {
+                    (Some(pair_distance), _) => pair_distance,
+                    (None, true) => indexed_geom()?.distance(probed_geom)?,
+                    (None, false) => f64::NAN,
+                };
+                pairs.push((left_index as u32, right_index as u32, pair_distance));
+            }
+        }
+        Ok(())
+    };
+
+    // Prepared geometries build their indexes lazily, so they can't be shared
+    // between threads: each job prepares the indexed geometries it needs, and
+    // collects its pairs in its own buffer.
+    let mut pairs = match algorithm {
+        SpatialJoinAlgorithm::Rtree => {
+            let tree = RTree::from_wkb(&indexed_geoms.values)?;
+            POOL.install(|| {
+                probed_wkb
+                    .par_iter()
+                    .enumerate()
+                    .try_fold(
+                        || (HashMap::new(), Vec::new()),
+                        |(mut prepared, mut pairs), (probed_index, wkb)| -> GResult<_> {
+                            let Some(wkb) = wkb else {
+                                return Ok((prepared, pairs));
+                            };
+                            let probed_geom = Geometry::new_from_wkb(wkb)?;
+                            let expanded;
+                            let query_geom = match distance {
+                                Some(_) if probed_geom.is_empty()? => return Ok((prepared, pairs)),
+                                Some(distance) => {
+                                    expanded = search_area(&probed_geom, distance)?;
+                                    &expanded
+                                }
+                                None => &probed_geom,
+                            };
+                            let mut candidates = Vec::new();
+                            tree.query(query_geom, |i| candidates.push(i))?;
+                            evaluate(
+                                probed_index,
+                                &probed_geom,
+                                &candidates,
+                                &mut prepared,
+                                &mut pairs,
+                            )?;
+                            Ok((prepared, pairs))
+                        },
+                    )
+                    .map(|state| state.map(|(_, pairs)| pairs))
+                    .try_reduce(Vec::new, |mut a, b| {
+                        a.extend(b);
+                        Ok(a)
+                    })
+            })?
+        }
+        SpatialJoinAlgorithm::Sweep => {
+            // Candidate pairs are evaluated by chunks as the sweep finds them,
+            // so that they are never all kept in memory at once.
+            let mut pairs = Vec::new();
+            let mut chunk = Vec::with_capacity(SWEEP_CHUNK_SIZE);
+            let mut evaluate_chunk = |chunk: &mut Vec<(usize, usize)>| -> GResult<()> {
+                chunk.sort_unstable();
+                let groups = chunk.chunk_by(|a, b| a.0 == b.0).collect::<Vec<_>>();
+                let chunk_pairs = POOL.install(|| {
+                    groups
+                        .par_iter()
+                        .try_fold(
+                            || (HashMap::new(), Vec::new()),
+                            |(mut prepared, mut pairs), group| -> GResult<_> {
+                                let probed_index = group[0].0;
+                                let wkb = probed_wkb[probed_index]
+                                    .expect("Shouldn't be able to match None");
+                                let probed_geom = Geometry::new_from_wkb(wkb)?;
+                                let candidates = group.iter().map(|(_, i)| *i).collect::<Vec<_>>();
+                                evaluate(
+                                    probed_index,
+                                    &probed_geom,
+                                    &candidates,
+                                    &mut prepared,
+                                    &mut pairs,
+                                )?;
+                                Ok((prepared, pairs))
+                            },
+                        )
+                        .map(|state| state.map(|(_, pairs)| pairs))
+                        .try_reduce(Vec::new, |mut a, b| {
+                            a.extend(b);
+                            Ok(a)
+                        })
+                })?;
+                pairs.extend(chunk_pairs);
+                chunk.clear();
+                Ok(())
+            };
+            sweep::for_each_intersecting_pair(
+                &expanded_bounds(&indexed_geoms.values, 0.0)?,
+                &expanded_bounds(&probed_wkb, distance.unwrap_or(0.0))?,
+                |indexed_index, probed_index| {
+                    chunk.push((probed_index, indexed_index));
+                    match chunk.len() < SWEEP_CHUNK_SIZE {
+                        true => Ok(()),
+                        false => evaluate_chunk(&mut chunk),
+                    }
+                },
+            )?;
+            evaluate_chunk(&mut chunk)?;
+            pairs
+        }
+    };
+
+    let (left_len, right_len) = (left.len() as u32, right.len() as u32);
+    let rows: Vec<JoinRow> = match how {
+        SpatialJoinStrategy::Inner => {
+            pairs.sort_unstable_by_key(|(l, r, _)| (*l, *r));
+            pairs
+                .into_iter()
+                .map(|(l, r, d)| (Some(l), Some(r), Some(d)))
+                .collect()
+        }
+        SpatialJoinStrategy::Left => outer_join_pairs(pairs, left_len),
+        SpatialJoinStrategy::Right => {
+            let pairs = pairs.into_iter().map(|(l, r, d)| (r, l, d)).collect();
+            outer_join_pairs(pairs, right_len)
+                .into_iter()
+                .map(|(r, l, d)| (l, r, d))
+                .collect()
+        }
+        SpatialJoinStrategy::Full => {
+            let matched_right = pairs.iter().map(|(_, r, _)| *r).collect::<HashSet<_>>();
+            let mut rows = outer_join_pairs(pairs, left_len);
+            rows.extend(
+                (0..right_len)
+                    .filter(|r| !matched_right.contains(r))
+                    .map(|r| (None, Some(r), None)),
+            );
+            rows
+        }
+        SpatialJoinStrategy::Semi | SpatialJoinStrategy::Anti => {
+            let keep_matched = how == SpatialJoinStrategy::Semi;
+            (0..left_len)
+                .filter(|l| matched_left[*l as usize].load(AtomicOrdering::Relaxed) == keep_matched)
+                .map(|l| (Some(l), None, None))
+                .collect()
+        }
+    };
+    let left_index = rows.iter().map(|(l, _, _)| *l);
+    let right_index = rows.iter().map(|(_, r, _)| *r);
+    let distance = rows.iter().map(|(_, _, d)| *d);
+    Ok((
+        UInt32Chunked::from_iter_options("left_index".into(), left_index),
+        UInt32Chunked::from_iter_options("right_index".into(), right_index),
+        Float64Chunked::from_iter_options("distance".into(), distance),
+    ))
+}
+
+/// Left index, right index and distance of a spatial join output row.
+type JoinRow = (Option<u32>, Option<u32>, Option<f64>);
+
+/// Sorts index pairs by their first index, and adds a row without second
+/// index for each of the `len` first indices that doesn't have any pair.
+fn outer_join_pairs(mut pairs: Vec<(u32, u32, f64)>, len: u32) -> Vec<JoinRow> {
+    pairs.sort_unstable_by_key(|(a, b, _)| (*a, *b));
+    let mut pairs = pairs.into_iter().peekable();
+    let mut rows = Vec::with_capacity(len as usize);
+    for index in 0..len {
+        let start = rows.len();
+        while let Some((a, b, d)) = pairs.next_if(|(a, _, _)| *a == index) {
+            rows.push((Some(a), Some(b), Some(d)));
+        }
+        if rows.len() == start {
+            rows.push((Some(index), None, None));
+        }
+    }
+    rows
+}
+
+/// Intersection over union of the areas of both geometries, or `None` if one of
+/// them has no area, in which case the overlap doesn't say anything about the match.
+fn overlap_ratio(a: &Geometry, b: &Geometry) -> GResult<Option<f64>> {
+    let (area_a, area_b) = (a.area()?, b.area()?);
+    if area_a <= 0.0 || area_b <= 0.0 {
+        return Ok(None);
+    }
+    let union_area = a.union(b)?.area()?;
+    Ok(Some(a.intersection(b)?.area()? / union_area))
+}
+
+pub fn conflation_candidates(
+    left: &BinaryChunked,
+    right: &BinaryChunked,
+    max_distance: f64,
+) -> GResult<(UInt32Chunked, UInt32Chunked, Float64Chunked, Float64Chunked)> {
+    let left_geoms = LazyGeometries::new(left);
+    let tree = RTree::from_wkb(&left_geoms.values)?;
 
     let builder_len = core::cmp::max(left.len(), right.len());
     let mut left_index_builder =
         PrimitiveChunkedBuilder::<UInt32Type>::new("left_index".into(), builder_len);
     let mut right_index_builder =
         PrimitiveChunkedBuilder::<UInt32Type>::new("right_index".into(), builder_len);
+    let mut distance_builder =
+        PrimitiveChunkedBuilder::<Float64Type>::new("distance".into(), builder_len);
+    let mut overlap_builder =
+        PrimitiveChunkedBuilder::<Float64Type>::new("overlap".into(), builder_len);
 
     for (right_index, wkb) in right.into_iter().enumerate() {
-        if wkb.is_none() {
+        let Some(wkb) = wkb else {
+            continue;
+        };
+        let right_geom = Geometry::new_from_wkb(wkb)?;
+        if right_geom.is_empty()? {
             continue;
         }
-        let right_geom = Geometry::new_from_wkb(wkb.unwrap())?;
-        spatial_index.query(&right_geom, |left_index| {
-            let left_geom = left_geoms[*left_index]
-                .as_ref()
-                .expect("Shouldn't be able to match None");
-            if matches!(predicate(left_geom, &right_geom), Ok(true)) {
-                left_index_builder.append_value(*left_index as u32);
+        let mut candidates = Vec::new();
+        tree.query(&search_area(&right_geom, max_distance)?, |left_index| {
+            candidates.push(left_index);
+        })?;
+        for left_index in candidates {
+            let left_geom = left_geoms.get(left_index)?;
+            let distance = left_geom.distance(&right_geom)?;
+            if distance <= max_distance {
+                left_index_builder.append_value(left_index as u32);
                 right_index_builder.append_value(right_index as u32);
+                distance_builder.append_value(distance);
+                overlap_builder.append_option(overlap_ratio(left_geom, &right_geom)?);
             }
-        });
+        }
+    }
+    Ok((
+        left_index_builder.finish(),
+        right_index_builder.finish(),
+        distance_builder.finish(),
+        overlap_builder.finish(),
+    ))
+}
+
+pub fn greedy_match(
+    left: &UInt32Chunked,
+    right: &UInt32Chunked,
+    score: &Float64Chunked,
+) -> BooleanChunked {
+    let mut candidates = left
+        .into_iter()
+        .zip(right)
+        .zip(score)
+        .enumerate()
+        .filter_map(|(row, ((l, r), s))| Some((row, l?, r?, s?)))
+        .filter(|(.., s)| !s.is_nan())
+        .collect::<Vec<_>>();
+    // Ties are broken by index so that the result doesn't depend on the row order.
+    candidates.sort_by(|a, b| b.3.total_cmp(&a.3).then((a.1, a.2).cmp(&(b.1, b.2))));
+
+    let mut matched_left = HashSet::new();
+    let mut matched_right = HashSet::new();
+    let mut mask = vec![false; left.len()];
+    for (row, l, r, _) in candidates {
+        if matched_left.contains(&l) || matched_right.contains(&r) {
+            continue;
+        }
+        matched_left.insert(l);
+        matched_right.insert(r);
+        mask[row] = true;
+    }
+    BooleanChunked::from_slice(left.name().clone(), &mask)
+}
+
+/// Radius of the sphere used by the Web Mercator projection (EPSG:3857).
+const WEB_MERCATOR_RADIUS: f64 = 6_378_137.0;
+
+/// Transforms between EPSG:4326 and EPSG:3857 with the closed-form spherical
+/// Mercator formulas, which is much faster than a generic transformation.
+fn web_mercator_transform(geom: &Geometry, to_web_mercator: bool) -> GResult<Geometry> {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+    transform_coords(geom, &|x, y, z| {
+        Ok(match to_web_mercator {
+            true => (
+                WEB_MERCATOR_RADIUS * x.to_radians(),
+                WEB_MERCATOR_RADIUS * (FRAC_PI_4 + y.to_radians() / 2.0).tan().ln(),
+                z,
+            ),
+            false => (
+                (x / WEB_MERCATOR_RADIUS).to_degrees(),
+                (2.0 * (y / WEB_MERCATOR_RADIUS).exp().atan() - FRAC_PI_2).to_degrees(),
+                z,
+            ),
+        })
+    })
+}
+
+/// Transforms the coordinate sequence of a point, linestring or linear ring,
+/// working on its coordinate buffer directly. M values are kept as is.
+fn transform_coord_seq<T, F>(geom: &T, f: &F) -> GResult<CoordSeq>
+where
+    T: Geom,
+    F: Fn(f64, f64, f64) -> GResult<(f64, f64, f64)>,
+{
+    let (has_z, has_m) = (geom.has_z()?, geom.has_m()?);
+    let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+    let mut coords = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+    for coord in coords.chunks_exact_mut(dimension) {
+        let z = if has_z { coord[2] } else { f64::NAN };
+        let (x, y, z) = f(coord[0], coord[1], z)?;
+        coord[0] = x;
+        coord[1] = y;
+        if has_z {
+            coord[2] = z;
+        }
     }
-    Ok((left_index_builder.finish(), right_index_builder.finish()))
+    CoordSeq::new_from_buffer(&coords, coords.len() / dimension, has_z, has_m)
 }
 
-fn apply_proj_transform(src: &Proj, dst: &Proj, geom: &Geometry) -> GResult<Geometry> {
+/// Transforms the coordinates of a geometry, like `transform_xyz`, but by
+/// rebuilding it from transformed coordinate buffers, which avoids calling
+/// back into Rust from GEOS for every coordinate. Missing Z values are NaN.
+fn transform_coords<T, F>(geom: &T, f: &F) -> GResult<Geometry>
+where
+    T: Geom,
+    F: Fn(f64, f64, f64) -> GResult<(f64, f64, f64)>,
+{
+    if geom.is_empty()? {
+        return Geom::clone(geom);
+    }
+    let parts = || {
+        (0..geom.get_num_geometries()?)
+            .map(|n| transform_coords(&geom.get_geometry_n(n)?, f))
+            .try_collect::<Vec<_>>()
+    };
+    let mut transformed = match geom.geometry_type()? {
+        Point => Geometry::create_point(transform_coord_seq(geom, f)?),
+        LineString => Geometry::create_line_string(transform_coord_seq(geom, f)?),
+        LinearRing => Geometry::create_linear_ring(transform_coord_seq(geom, f)?),
+        Polygon => {
+            let exterior = transform_coords(&geom.get_exterior_ring()?, f)?;
+            let interiors = (0..geom.get_num_interior_rings()?)
+                .map(|n| transform_coords(&geom.get_interior_ring_n(n)?, f))
+                .try_collect::<Vec<_>>()?;
+            Geometry::create_polygon(exterior, interiors)
+        }
+        MultiPoint => Geometry::create_multipoint(parts()?),
+        MultiLineString => Geometry::create_multiline_string(parts()?),
+        MultiPolygon => Geometry::create_multipolygon(parts()?),
+        GeometryCollection => Geometry::create_geometry_collection(parts()?),
+        // Curved geometries don't expose their coordinate sequences.
+        _ => geom.transform_xyz(f),
+    }?;
+    transformed.set_srid(geom.get_srid()?);
+    Ok(transformed)
+}
+
+/// Transforms a coordinate between two projections. Angles are in degrees
+/// and heights are kept in meters. Missing Z values stay missing.
+fn proj_transform_point(
+    src: &Proj,
+    dst: &Proj,
+    x: f64,
+    y: f64,
+    z: f64,
+) -> GResult<(f64, f64, f64)> {
     use proj4rs::adaptors::{transform_xy, transform_xyz};
-    geom.transform_xyz(|x, y, z| {
-        let has_z = !z.is_nan();
-        let mut new_x: f64;
-        let mut new_y: f64;
-        let mut new_z: f64;
-
-        if src.is_latlong() {
-            new_x = x.to_radians();
-            new_y = y.to_radians();
-            new_z = z.to_radians();
-        } else {
-            new_x = x;
-            new_y = y;
-            new_z = z;
+    let (x, y) = match src.is_latlong() {
+        true => (x.to_radians(), y.to_radians()),
+        false => (x, y),
+    };
+    let (x, y, z) = match z.is_nan() {
+        true => {
+            let (x, y) =
+                transform_xy(src, dst, x, y).map_err(|e| GError::GenericError(e.to_string()))?;
+            (x, y, z)
         }
-        if has_z {
-            (new_x, new_y, new_z) = transform_xyz(src, dst, new_x, new_y, new_z)
-                .map_err(|e| GError::GenericError(e.to_string()))?;
-        } else {
-            (new_x, new_y) = transform_xy(src, dst, new_x, new_y)
-                .map_err(|e| GError::GenericError(e.to_string()))?;
+        false => {
+            transform_xyz(src, dst, x, y, z).map_err(|e| GError::GenericError(e.to_string()))?
         }
-        if dst.is_latlong() {
-            new_x = new_x.to_degrees();
-            new_y = new_y.to_degrees();
-            new_z = new_z.to_degrees();
+    };
+    Ok(match dst.is_latlong() {
+        true => (x.to_degrees(), y.to_degrees(), z),
+        false => (x, y, z),
+    })
+}
+
+fn apply_proj_transform(src: &Proj, dst: &Proj, geom: &Geometry) -> GResult<Geometry> {
+    transform_coords(geom, &|x, y, z| proj_transform_point(src, dst, x, y, z))
+}
+
+/// Geographic WGS 84 coordinates, through which transformations between CRS
+/// with a vertical datum go.
+static WGS84_PROJ: LazyLock<Proj> = LazyLock::new(|| {
+    Proj::from_proj_string("+proj=longlat +datum=WGS84").expect("WGS 84 is a valid CRS")
+});
+
+/// Transforms a geometry between two CRS. When either CRS has orthometric
+/// heights or datum shift grids, coordinates go through WGS 84, where the
+/// grids are applied and the geoid heights are added to or removed from the
+/// Z values.
+fn apply_crs_transform(src: &Crs, dst: &Crs, geom: &Geometry) -> GResult<Geometry> {
+    if src.geoid.is_none() && dst.geoid.is_none() && src.datum.is_none() && dst.datum.is_none() {
+        return apply_proj_transform(&src.proj, &dst.proj, geom);
+    }
+    transform_coords(geom, &|x, y, z| {
+        let (lon, lat, mut h) = match &src.datum {
+            Some(datum) => {
+                let (lon, lat, h) = proj_transform_point(&src.proj, &datum.geographic, x, y, z)?;
+                let (lon, lat) = datum.shift(lon, lat, false)?;
+                (lon, lat, h)
+            }
+            None => proj_transform_point(&src.proj, &WGS84_PROJ, x, y, z)?,
+        };
+        if !h.is_nan() {
+            if let Some(geoid) = &src.geoid {
+                h += geoid.height(lon, lat)?;
+            }
+            if let Some(geoid) = &dst.geoid {
+                h -= geoid.height(lon, lat)?;
+            }
         }
-        Ok((new_x, new_y, new_z))
+        match &dst.datum {
+            Some(datum) => {
+                let (lon, lat) = datum.shift(lon, lat, true)?;
+                proj_transform_point(&datum.geographic, &dst.proj, lon, lat, h)
+            }
+            None => proj_transform_point(&WGS84_PROJ, &dst.proj, lon, lat, h),
+        }
+    })
+}
+
+/// Returns whether no datum shift grids are registered for either SRID, in
+/// which case transformations between them can be left to libproj.
+#[cfg(feature = "proj")]
+fn has_no_datum_grids(src: i64, dst: i64) -> bool {
+    [src, dst].into_iter().all(|srid| {
+        i32::try_from(srid)
+            .ok()
+            .and_then(crs::get_datum_grids)
+            .is_none()
     })
 }
-struct ProjCache(HashMap<u16, Proj>);
+
+/// Returns the EPSG code of the WGS 84 UTM zone containing a longitude and
+/// latitude, with the exceptions around Norway and Svalbard.
+fn utm_epsg_code(lon: f64, lat: f64) -> i32 {
+    let lon = (lon + 180.0).rem_euclid(360.0) - 180.0;
+    let zone = match (lon, lat) {
+        (3.0..12.0, 56.0..64.0) => 32,
+        (0.0..9.0, 72.0..=84.0) => 31,
+        (9.0..21.0, 72.0..=84.0) => 33,
+        (21.0..33.0, 72.0..=84.0) => 35,
+        (33.0..42.0, 72.0..=84.0) => 37,
+        _ => (((lon + 180.0) / 6.0).floor() as i32 + 1).clamp(1, 60),
+    };
+    if lat >= 0.0 {
+        32600 + zone
+    } else {
+        32700 + zone
+    }
+}
+
+pub fn utm_zone(wkb: &BinaryChunked) -> GResult<Int32Chunked> {
+    let mut cache = ProjCache::new();
+    #[cfg(feature = "proj")]
+    let mut libproj_cache = libproj::TransformCache::default();
+    try_unary_elementwise(wkb, |wkb| {
+        let Some(wkb) = wkb else { return Ok(None) };
+        let geom = Geometry::new_from_wkb(wkb)?;
+        if geom.is_empty()? {
+            return Ok(None);
+        }
+        let x = (geom.get_x_min()? + geom.get_x_max()?) / 2.0;
+        let y = (geom.get_y_min()? + geom.get_y_max()?) / 2.0;
+        let (lon, lat) = match geom.get_srid()? {
+            0 | 4326 => (x, y),
+            srid => {
+                let center =
+                    Geometry::create_point(CoordSeq::new_from_buffer(&[x, y], 1, false, false)?)?;
+                #[cfg(feature = "proj")]
+                if let Some(proj) = has_no_datum_grids(srid.into(), 4326)
+                    .then(|| libproj_cache.get(srid.into(), 4326))
+                    .flatten()
+                {
+                    let center = libproj::transform(proj, &center)?;
+                    return Ok(Some(utm_epsg_code(center.get_x()?, center.get_y()?)));
+                }
+                let center =
+                    apply_crs_transform(&cache.get(srid.into())?, &cache.get(4326)?, &center)?;
+                (center.get_x()?, center.get_y()?)
+            }
+        };
+        Ok(Some(utm_epsg_code(lon, lat)))
+    })
+}
+
+/// A CRS, along with the geoid of its vertical datum when its heights are
+/// orthometric rather than ellipsoidal, and the datum shift grids registered
+/// for its SRID.
+#[derive(Clone)]
+struct Crs {
+    proj: Proj,
+    geoid: Option<Arc<Geoid>>,
+    datum: Option<Arc<DatumShift>>,
+}
+
+/// Datum shift grids, which transform the geographic coordinates of a CRS to
+/// WGS 84 in place of the parameters of its datum.
+struct DatumShift {
+    /// Geographic coordinates on the datum of the CRS.
+    geographic: Proj,
+    grids: Vec<Arc<Grid>>,
+}
+
+impl DatumShift {
+    /// Returns the datum shift grids registered for an SRID, if any.
+    fn new(srid: i32) -> GResult<Option<Self>> {
+        let Some(paths) = crs::get_datum_grids(srid) else {
+            return Ok(None);
+        };
+        let projstring = crs::get_proj_string(srid)
+            .ok_or_else(|| GError::GenericError(format!("Unknown SRID: {srid}")))?;
+        let datum = projstring
+            .split_whitespace()
+            .filter(|param| {
+                let key = param.trim_start_matches('+').split('=').next();
+                key.is_some_and(|key| DATUM_PARAMS.contains(&key))
+            })
+            .collect::<Vec<_>>();
+        let geographic = format!("+proj=longlat {}", datum.join(" "));
+        Ok(Some(Self {
+            geographic: Proj::from_proj_string(&geographic)
+                .map_err(|e| invalid_crs(&geographic, e))?,
+            grids: paths
+                .iter()
+                .map(|path| Grid::load(path))
+                .collect::<GResult<_>>()?,
+        }))
+    }
+
+    /// Shifts geographic coordinates, in degrees, with the first grid covering
+    /// them.
+    fn shift(&self, lon: f64, lat: f64, inverse: bool) -> GResult<(f64, f64)> {
+        self.grids
+            .iter()
+            .find_map(|grid| grid.apply(lon, lat, inverse))
+            .ok_or_else(|| {
+                let msg = format!("Point ({lon} {lat}) is outside of the datum shift grids");
+                GError::GenericError(msg)
+            })
+    }
+}
+
+struct ProjCache(HashMap<i64, Crs>);
 
 impl ProjCache {
     fn new() -> Self {
-        Self(HashMap::<u16, Proj>::new())
+        Self(HashMap::<i64, Crs>::new())
     }
 
-    fn get(&mut self, srid: u16) -> Result<Proj, ProjError> {
-        Ok(match self.0.entry(srid) {
-            std::collections::hash_map::Entry::Occupied(proj) => proj.into_mut(),
-            std::collections::hash_map::Entry::Vacant(e) => e.insert(Proj::from_epsg_code(srid)?),
+    /// Returns a registered CRS, or the CRS of an EPSG or ESRI code.
+    fn get(&mut self, srid: i64) -> GResult<Crs> {
+        if let Some(crs) = self.0.get(&srid) {
+            return Ok(crs.clone());
+        }
+        let mut crs = match srid.try_into().ok().and_then(crs::get_registered_crs) {
+            Some(definition) => crs_from_definition(&definition)?,
+            None => srid
+                .try_into()
+                .ok()
+                .and_then(|srid| Proj::from_epsg_code(srid).ok())
+                .or_else(|| esri_proj(srid.try_into().ok()?).ok())
+                .map(|proj| Crs {
+                    proj,
+                    geoid: None,
+                    datum: None,
+                })
+                .ok_or_else(|| {
+                    GError::GenericError(format!(
+                        "Unknown SRID: {srid}, register its definition with `register_crs`"
+                    ))
+                })?,
+        };
+        if let Ok(srid) = srid.try_into() {
+            crs.datum = DatumShift::new(srid)?.map(Arc::new);
         }
-        .clone())
+        self.0.insert(srid, crs.clone());
+        Ok(crs)
     }
 }
 
-pub fn to_srid(wkb: &BinaryChunked, srid: &Int64Chunked) -> GResult<BinaryChunked> {
-    let mut cache = ProjCache::new();
+fn invalid_crs(definition: &str, error: impl std::fmt::Display) -> GError {
+    GError::GenericError(format!("Invalid CRS {definition:?}: {error}"))
+}
 
-    broadcast_try_binary_elementwise_values(wkb, srid, |wkb, dest_srid| {
-        let geom = Geometry::new_from_wkb(wkb)?;
-        let geom_srid: i64 = geom.get_srid()?.into();
+/// Builds the projection of an ESRI CRS code.
+fn esri_proj(code: i32) -> GResult<Proj> {
+    let definition = esri::from_code(code)
+        .ok_or_else(|| GError::GenericError(format!("Unknown ESRI code: {code}")))?;
+    Proj::from_proj_string(definition.proj4).map_err(|e| invalid_crs(definition.proj4, e))
+}
+
+/// Builds a projection from a PROJ string, a WKT CRS or an `EPSG:<code>` or
+/// `ESRI:<code>` authority code.
+fn proj_from_definition(definition: &str) -> GResult<Proj> {
+    let definition = definition.trim();
+    if definition.starts_with('+') {
+        return Proj::from_proj_string(definition).map_err(|e| invalid_crs(definition, e));
+    }
+    if let Some((authority, code)) = definition.split_once(':') {
+        if authority.eq_ignore_ascii_case("EPSG") {
+            let code = code.parse().map_err(|e| invalid_crs(definition, e))?;
+            return Proj::from_epsg_code(code).map_err(|e| invalid_crs(definition, e));
+        }
+        if authority.eq_ignore_ascii_case("ESRI") {
+            return esri_proj(code.parse().map_err(|e| invalid_crs(definition, e))?);
+        }
+    }
+    let projstring =
+        proj4wkt::wkt_to_projstring(definition).map_err(|e| invalid_crs(definition, e))?;
+    Proj::from_proj_string(&projstring).map_err(|e| invalid_crs(definition, e))
+}
+
+/// Builds a CRS from a definition accepted by `proj_from_definition`. Geoid
+/// grids are given by the `+geoidgrids` parameter of PROJ strings, or by the
+/// `PROJ4_GRIDS` extension of the vertical CRS of WKT compound CRS.
+fn crs_from_definition(definition: &str) -> GResult<Crs> {
+    let definition = definition.trim();
+    let (definition, grids) = match crs::split_compound_wkt(definition) {
+        Some((horizontal, grids)) => (horizontal.to_owned(), grids),
+        None if definition.starts_with('+') => {
+            let mut grids = None;
+            let params = definition
+                .split_whitespace()
+                .filter(|param| match param.strip_prefix("+geoidgrids=") {
+                    Some(value) => {
+                        grids = Some(value);
+                        false
+                    }
+                    None => true,
+                })
+                .collect::<Vec<_>>();
+            (params.join(" "), grids)
+        }
+        None => (definition.to_owned(), None),
+    };
+    Ok(Crs {
+        proj: proj_from_definition(&definition)?,
+        geoid: grids.map(Geoid::load).transpose()?.map(Arc::new),
+        datum: None,
+    })
+}
+
+/// Returns the EPSG or ESRI code of a CRS definition, or registers it if it
+/// doesn't have one. Codes of other authorities could clash with EPSG codes,
+/// so their definitions are registered as well.
+fn srid_from_definition(definition: &str) -> i32 {
+    let definition = definition.trim();
+    let code = crs::get_crs_authority(definition).and_then(|(authority, code)| {
+        let code = code.parse().ok()?;
+        match authority {
+            "EPSG" => Some(code),
+            "ESRI" => esri::from_code(code).map(|_| code),
+            _ => None,
+        }
+    });
+    code.unwrap_or_else(|| crs::register_definition(definition.to_owned()))
+}
+
+/// Returns the outline of the envelope of a geometry, with `densify_points`
+/// points added along each edge.
+fn densified_envelope(geom: &Geometry, densify_points: u32) -> GResult<Geometry> {
+    let (x_min, y_min) = (geom.get_x_min()?, geom.get_y_min()?);
+    let (x_max, y_max) = (geom.get_x_max()?, geom.get_y_max()?);
+    let corners = [
+        (x_min, y_min),
+        (x_max, y_min),
+        (x_max, y_max),
+        (x_min, y_max),
+        (x_min, y_min),
+    ];
+    let steps = densify_points + 1;
+    let mut coords = Vec::with_capacity(2 * (4 * steps as usize + 1));
+    for edge in corners.windows(2) {
+        let [(x0, y0), (x1, y1)] = [edge[0], edge[1]];
+        for step in 0..steps {
+            let t = f64::from(step) / f64::from(steps);
+            coords.extend([x0 + (x1 - x0) * t, y0 + (y1 - y0) * t]);
+        }
+    }
+    coords.extend([x_min, y_min]);
+    let coords = CoordSeq::new_from_buffer(&coords, coords.len() / 2, false, false)?;
+    let mut envelope = Geometry::create_line_string(coords)?;
+    envelope.set_srid(geom.get_srid()?);
+    Ok(envelope)
+}
 
-        if geom_srid == dest_srid || geom.is_empty()? {
-            return Ok(wkb.into());
+/// Transforms the bounds of each geometry into a new CRS. Only the points of
+/// the densified envelopes are transformed, which is much cheaper than
+/// transforming whole geometries.
+pub fn bounds_to_srid(
+    wkb: &BinaryChunked,
+    srid: &Int64Chunked,
+    params: &BoundsToSridKwargs,
+) -> GResult<ArrayChunked> {
+    let envelopes: BinaryChunked = wkb.try_apply_nonnull_values_generic(|wkb| {
+        let geom = Geometry::new_from_wkb(wkb)?;
+        match geom.is_empty()? {
+            true => Ok(wkb.to_vec()),
+            false => densified_envelope(&geom, params.densify_points)?.to_ewkb(),
         }
+    })?;
+    let to_srid_params = ToSridKwargs {
+        pipeline: None,
+        antimeridian: Antimeridian::Ignore,
+    };
+    bounds(&to_srid(&envelopes, srid, &to_srid_params)?)
+}
 
-        let Ok(Ok(proj_src)) = geom_srid.try_into().map(|srid| cache.get(srid)) else {
-            return Err(GError::GenericError(format!("Unknown SRID: {geom_srid}")));
+pub fn transform(wkb: &BinaryChunked, params: &CrsTransformKwargs) -> GResult<BinaryChunked> {
+    let apply = |transform: &dyn Fn(&Geometry) -> GResult<Geometry>| {
+        let srid = srid_from_definition(&params.to_crs);
+        wkb.try_apply_nonnull_values_generic(|wkb| {
+            let geom = Geometry::new_from_wkb(wkb)?;
+            let mut transformed = match geom.is_empty()? {
+                true => geom,
+                false => transform(&geom)?,
+            };
+            transformed.set_srid(srid);
+            transformed.to_ewkb()
+        })
+    };
+    #[cfg(feature = "proj")]
+    if let Some(proj) = libproj::from_definitions(&params.from_crs, &params.to_crs) {
+        return apply(&|geom| libproj::transform(&proj, geom));
+    }
+    let crs_src = crs_from_definition(&params.from_crs)?;
+    let crs_dst = crs_from_definition(&params.to_crs)?;
+    apply(&|geom| apply_crs_transform(&crs_src, &crs_dst, geom))
+}
+
+pub fn to_srid(
+    wkb: &BinaryChunked,
+    srid: &Int64Chunked,
+    params: &ToSridKwargs,
+) -> GResult<BinaryChunked> {
+    // Antimeridian crossings only make sense in geographic coordinates.
+    let fix_antimeridian = |srid: i64| {
+        !matches!(params.antimeridian, Antimeridian::Ignore)
+            && i32::try_from(srid)
+                .ok()
+                .and_then(crs::get_crs_info)
+                .is_some_and(|info| info.is_geographic)
+    };
+    let finish = |transformed: Geometry, srid: i64, fix_antimeridian: bool| {
+        let mut transformed = match fix_antimeridian {
+            true => antimeridian::apply(transformed, params.antimeridian)?,
+            false => transformed,
         };
+        transformed.set_srid(srid as _);
+        transformed.to_ewkb()
+    };
+
+    if let Some(pipeline) = &params.pipeline {
+        let pipeline = Pipeline::new(pipeline)?;
+        return broadcast_try_binary_elementwise_values(wkb, srid, |wkb, dest_srid| {
+            let geom = Geometry::new_from_wkb(wkb)?;
+            let transformed = match geom.is_empty()? {
+                true => geom,
+                false => geom.transform_xyz(|x, y, z| pipeline.apply(x, y, z))?,
+            };
+            finish(transformed, dest_srid, fix_antimeridian(dest_srid))
+        });
+    }
 
-        let Ok(Ok(proj_dst)) = dest_srid.try_into().map(|srid| cache.get(srid)) else {
-            return Err(GError::GenericError(format!("Unknown SRID: {dest_srid}")));
+    // Rows are grouped by source and destination SRID, so that each
+    // transformation is built once and applied to its rows in parallel.
+    let (geoms, srids) = (
+        wkb.iter().collect::<Vec<_>>(),
+        srid.iter().collect::<Vec<_>>(),
+    );
+    let len = match (geoms.len(), srids.len()) {
+        (a, b) if a == b || b == 1 => a,
+        (1, b) => b,
+        (a, b) => {
+            let msg = format!("Cannot broadcast {b} SRIDs to {a} geometries");
+            return Err(GError::GenericError(msg));
+        }
+    };
+    let broadcast = |values_len: usize, row: usize| if values_len == 1 { 0 } else { row };
+    let mut output: Vec<Option<Vec<u8>>> = vec![None; len];
+    let mut groups = HashMap::<(i64, i64), Vec<(usize, &[u8])>>::new();
+    for (row, output) in output.iter_mut().enumerate() {
+        let geom = geoms[broadcast(geoms.len(), row)];
+        let (Some(wkb), Some(dest_srid)) = (geom, srids[broadcast(srids.len(), row)]) else {
+            continue;
         };
+        let geom_srid = WKBHeader::try_from(wkb)?.srid.into();
+        match geom_srid == dest_srid {
+            true => *output = Some(wkb.to_vec()),
+            false => groups
+                .entry((geom_srid, dest_srid))
+                .or_default()
+                .push((row, wkb)),
+        }
+    }
 
-        let mut transformed = apply_proj_transform(&proj_src, &proj_dst, &geom)?;
-        transformed.set_srid(dest_srid as _);
-        transformed.to_ewkb()
-    })
+    let mut cache = ProjCache::new();
+    #[cfg(feature = "proj")]
+    let mut libproj_cache = libproj::TransformCache::default();
+    let web_mercator_fast_path =
+        crs::get_registered_crs(4326).is_none() && crs::get_registered_crs(3857).is_none();
+
+    for ((geom_srid, dest_srid), rows) in groups {
+        let fix_antimeridian = fix_antimeridian(dest_srid);
+        let transform_row = |wkb: &[u8], transform: &dyn Fn(&Geometry) -> GResult<Geometry>| {
+            let geom = Geometry::new_from_wkb(wkb)?;
+            match geom.is_empty()? {
+                true => Ok(wkb.to_vec()),
+                false => finish(transform(&geom)?, dest_srid, fix_antimeridian),
+            }
+        };
+
+        let transformed = if web_mercator_fast_path
+            && matches!((geom_srid, dest_srid), (4326, 3857) | (3857, 4326))
+        {
+            let to_web_mercator = dest_srid == 3857;
+            POOL.install(|| {
+                rows.par_iter()
+                    .map(|(_, wkb)| {
+                        transform_row(wkb, &|geom| web_mercator_transform(geom, to_web_mercator))
+                    })
+                    .collect::<GResult<Vec<_>>>()
+            })?
+        } else {
+            // libproj transformations aren't thread-safe, so they run sequentially.
+            // Datum shift grids registered for either SRID take precedence.
+            #[cfg(feature = "proj")]
+            if let Some(proj) = has_no_datum_grids(geom_srid, dest_srid)
+                .then(|| libproj_cache.get(geom_srid, dest_srid))
+                .flatten()
+            {
+                for (row, wkb) in rows {
+                    output[row] = Some(transform_row(wkb, &|geom| libproj::transform(proj, geom))?);
+                }
+                continue;
+            }
+            let (crs_src, crs_dst) = (cache.get(geom_srid)?, cache.get(dest_srid)?);
+            POOL.install(|| {
+                rows.par_iter()
+                    .map(|(_, wkb)| {
+                        transform_row(wkb, &|geom| apply_crs_transform(&crs_src, &crs_dst, geom))
+                    })
+                    .collect::<GResult<Vec<_>>>()
+            })?
+        };
+        for ((row, _), transformed) in rows.iter().zip(transformed) {
+            output[*row] = Some(transformed);
+        }
+    }
+    Ok(BinaryChunked::from_iter_options(
+        wkb.name().clone(),
+        output.into_iter(),
+    ))
 }
@@ -0,0 +1,521 @@
+use geos::{CoordSeq, Error as GError, GResult, Geom, Geometry, GeometryTypes};
+use polars::prelude::*;
+use polars_arrow::array::{Array, FixedSizeListArray, Float64Array, StructArray};
+use polars_arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_arrow::datatypes::Field as ArrowField;
+use polars_arrow::offset::{Offsets, OffsetsBuffer};
+
+use crate::{args::GeoArrowDimensions, wkb::WKBGeometryType};
+
+fn invalid(msg: &str) -> GError {
+    GError::GenericError(format!("Invalid GeoArrow array: {msg}"))
+}
+
+fn unsupported(geometry_type: WKBGeometryType) -> GError {
+    GError::GenericError(format!(
+        "GeoArrow native encoding doesn't support geometries of type {geometry_type:?}"
+    ))
+}
+
+/// Returns the number of list levels wrapping the coordinates of a GeoArrow
+/// native geometry type.
+fn nesting_depth(geometry_type: WKBGeometryType) -> GResult<usize> {
+    use WKBGeometryType::*;
+    match geometry_type {
+        Point => Ok(0),
+        LineString | MultiPoint => Ok(1),
+        Polygon | MultiLineString => Ok(2),
+        MultiPolygon => Ok(3),
+        other => Err(unsupported(other)),
+    }
+}
+
+impl GeoArrowDimensions {
+    fn names(self) -> &'static [&'static str] {
+        match self {
+            Self::Xy => &["x", "y"],
+            Self::Xyz => &["x", "y", "z"],
+            Self::Xym => &["x", "y", "m"],
+            Self::Xyzm => &["x", "y", "z", "m"],
+        }
+    }
+}
+
+fn wrap_dtype(geometry_type: WKBGeometryType, coords: DataType) -> GResult<DataType> {
+    let depth = nesting_depth(geometry_type)?;
+    Ok((0..depth).fold(coords, |dtype, _| DataType::List(dtype.into())))
+}
+
+/// Returns the dtype of a GeoArrow native geometry type with separated coordinates.
+pub fn dtype(geometry_type: WKBGeometryType, dimensions: GeoArrowDimensions) -> GResult<DataType> {
+    let fields = dimensions
+        .names()
+        .iter()
+        .map(|name| Field::new((*name).into(), DataType::Float64))
+        .collect();
+    wrap_dtype(geometry_type, DataType::Struct(fields))
+}
+
+/// Returns the dtype a GeoArrow native array should be cast to before being decoded,
+/// with `Float64` coordinates, either separated in a struct or interleaved in an array.
+pub fn cast_dtype(dtype: &DataType, geometry_type: WKBGeometryType) -> GResult<DataType> {
+    let mut coords = dtype;
+    for _ in 0..nesting_depth(geometry_type)? {
+        match coords {
+            DataType::List(inner) => coords = inner,
+            other => return Err(invalid(&format!("expected a list, got {other}"))),
+        }
+    }
+    let coords = match coords {
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|field| Field::new(field.name().clone(), DataType::Float64))
+                .collect(),
+        ),
+        DataType::Array(_, size) => DataType::Array(DataType::Float64.into(), *size),
+        other => {
+            let msg = format!("expected a struct or an array of coordinates, got {other}");
+            return Err(invalid(&msg));
+        }
+    };
+    wrap_dtype(geometry_type, coords)
+}
+
+fn geometry_type_error(geometry_type: GeometryTypes, target: WKBGeometryType) -> GError {
+    GError::GenericError(format!(
+        "Cannot encode a {geometry_type:?} geometry as a GeoArrow {target:?}"
+    ))
+}
+
+/// Builds a GeoArrow native array with separated coordinates.
+///
+/// Single-part geometries are accepted for the corresponding multi-part types,
+/// and empty points are encoded with NaN coordinates.
+pub struct Encoder {
+    geometry_type: WKBGeometryType,
+    dimensions: GeoArrowDimensions,
+    columns: Vec<Vec<f64>>,
+    levels: Vec<Offsets<i64>>,
+    validity: MutableBitmap,
+}
+
+impl Encoder {
+    pub fn new(geometry_type: WKBGeometryType, dimensions: GeoArrowDimensions) -> GResult<Self> {
+        Ok(Self {
+            geometry_type,
+            dimensions,
+            columns: vec![vec![]; dimensions.names().len()],
+            levels: (0..nesting_depth(geometry_type)?)
+                .map(|_| Offsets::new())
+                .collect(),
+            validity: MutableBitmap::new(),
+        })
+    }
+
+    fn push_offset(&mut self, level: usize, length: usize) -> GResult<()> {
+        self.levels[level]
+            .try_push(length)
+            .map_err(|e| GError::GenericError(e.to_string()))
+    }
+
+    /// Appends the coordinates of a geometry, filling missing dimensions with NaN,
+    /// and returns how many were appended.
+    fn push_coords<T: Geom>(&mut self, geom: &T) -> GResult<usize> {
+        if geom.is_empty()? {
+            return Ok(0);
+        }
+        let has_z = geom.has_z()?;
+        let has_m = geom.has_m()?;
+        let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+        let buffer = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+        for coords in buffer.chunks_exact(dimension) {
+            for (column, name) in self.columns.iter_mut().zip(self.dimensions.names()) {
+                column.push(match *name {
+                    "x" => coords[0],
+                    "y" => coords[1],
+                    "z" if has_z => coords[2],
+                    "m" if has_m => coords[dimension - 1],
+                    _ => f64::NAN,
+                });
+            }
+        }
+        Ok(buffer.len() / dimension)
+    }
+
+    fn push_nan_coords(&mut self) {
+        for column in &mut self.columns {
+            column.push(f64::NAN);
+        }
+    }
+
+    fn push_point<T: Geom>(&mut self, geom: &T) -> GResult<()> {
+        if self.push_coords(geom)? == 0 {
+            self.push_nan_coords();
+        }
+        Ok(())
+    }
+
+    fn push_rings<T: Geom>(&mut self, geom: &T, level: usize) -> GResult<usize> {
+        if geom.is_empty()? {
+            return Ok(0);
+        }
+        let num_interiors = geom.get_num_interior_rings()?;
+        let size = self.push_coords(&geom.get_exterior_ring()?)?;
+        self.push_offset(level, size)?;
+        for n in 0..num_interiors {
+            let size = self.push_coords(&geom.get_interior_ring_n(n)?)?;
+            self.push_offset(level, size)?;
+        }
+        Ok(num_interiors + 1)
+    }
+
+    fn push_part<T: Geom>(&mut self, part: &T) -> GResult<()> {
+        use WKBGeometryType::*;
+        match self.geometry_type {
+            MultiPoint => self.push_point(part),
+            MultiLineString => {
+                let size = self.push_coords(part)?;
+                self.push_offset(1, size)
+            }
+            _ => {
+                let size = self.push_rings(part, 2)?;
+                self.push_offset(1, size)
+            }
+        }
+    }
+
+    pub fn push<T: Geom>(&mut self, geom: &T) -> GResult<()> {
+        use GeometryTypes as G;
+        use WKBGeometryType as W;
+        let geometry_type = geom.geometry_type()?;
+        match (self.geometry_type, geometry_type) {
+            (W::Point, G::Point) => self.push_point(geom)?,
+            (W::LineString, G::LineString | G::LinearRing) => {
+                let size = self.push_coords(geom)?;
+                self.push_offset(0, size)?;
+            }
+            (W::Polygon, G::Polygon) => {
+                let size = self.push_rings(geom, 1)?;
+                self.push_offset(0, size)?;
+            }
+            (W::MultiPoint, G::Point)
+            | (W::MultiLineString, G::LineString | G::LinearRing)
+            | (W::MultiPolygon, G::Polygon) => {
+                let size = match geom.is_empty()? {
+                    true => 0,
+                    false => {
+                        self.push_part(geom)?;
+                        1
+                    }
+                };
+                self.push_offset(0, size)?;
+            }
+            (W::MultiPoint, G::MultiPoint)
+            | (W::MultiLineString, G::MultiLineString)
+            | (W::MultiPolygon, G::MultiPolygon) => {
+                let size = geom.get_num_geometries()?;
+                for n in 0..size {
+                    self.push_part(&geom.get_geometry_n(n)?)?;
+                }
+                self.push_offset(0, size)?;
+            }
+            (target, _) => return Err(geometry_type_error(geometry_type, target)),
+        }
+        self.validity.push(true);
+        Ok(())
+    }
+
+    pub fn push_null(&mut self) -> GResult<()> {
+        match self.levels.is_empty() {
+            true => self.push_nan_coords(),
+            false => self.push_offset(0, 0)?,
+        }
+        self.validity.push(false);
+        Ok(())
+    }
+
+    pub fn finish(self, name: PlSmallStr) -> PolarsResult<Series> {
+        let fields = self
+            .dimensions
+            .names()
+            .iter()
+            .map(|name| ArrowField::new((*name).into(), ArrowDataType::Float64, false))
+            .collect();
+        let length = self.columns[0].len();
+        let values = self
+            .columns
+            .into_iter()
+            .map(|column| Float64Array::from_vec(column).boxed())
+            .collect();
+        let mut validity: Option<Bitmap> = self.validity.into();
+        let struct_validity = match self.levels.is_empty() {
+            true => validity.take(),
+            false => None,
+        };
+        let dtype = ArrowDataType::Struct(fields);
+        let mut array = StructArray::new(dtype, length, values, struct_validity).boxed();
+        for (level, offsets) in self.levels.into_iter().enumerate().rev() {
+            let dtype = LargeListArray::default_datatype(array.dtype().clone());
+            let validity = if level == 0 { validity.take() } else { None };
+            array = LargeListArray::new(dtype, offsets.into(), array, validity).boxed();
+        }
+        Series::from_arrow(name, array)
+    }
+
+    /// Consumes the encoder into interleaved coordinates and the offsets of each
+    /// nesting level, innermost first, as in shapely ragged arrays.
+    pub fn finish_ragged(self) -> (Vec<f64>, Vec<Vec<i64>>) {
+        let columns = &self.columns;
+        let coords = (0..columns[0].len())
+            .flat_map(|n| columns.iter().map(move |column| column[n]))
+            .collect();
+        let offsets = self
+            .levels
+            .into_iter()
+            .rev()
+            .map(Offsets::into_inner)
+            .collect();
+        (coords, offsets)
+    }
+}
+
+/// Returns the GeoArrow native type all geometries can be encoded as, and whether
+/// they have Z coordinates, or `None` if they don't share a single type and
+/// dimension or if any of them has M values. Mixed dimensions are left to WKB,
+/// as encoding them as XYZ would give NaN Z values to the 2D geometries.
+///
+/// Single-part geometries are promoted to their multi-part type if any geometry
+/// of the same family is multi-part.
+pub fn common_type(geoms: &[Option<Geometry>]) -> GResult<Option<(WKBGeometryType, bool)>> {
+    use GeometryTypes as G;
+    use WKBGeometryType as W;
+    let mut family = None;
+    let mut multi = false;
+    let mut has_z = None;
+    for geom in geoms.iter().flatten() {
+        let (geom_family, geom_multi) = match geom.geometry_type()? {
+            G::Point => (0, false),
+            G::MultiPoint => (0, true),
+            G::LineString | G::LinearRing => (1, false),
+            G::MultiLineString => (1, true),
+            G::Polygon => (2, false),
+            G::MultiPolygon => (2, true),
+            _ => return Ok(None),
+        };
+        if geom.has_m()? || family.is_some_and(|family| family != geom_family) {
+            return Ok(None);
+        }
+        // Empty geometries have no coordinates, and fit any dimension.
+        if !geom.is_empty()? {
+            let geom_has_z = geom.has_z()?;
+            if has_z.is_some_and(|has_z| has_z != geom_has_z) {
+                return Ok(None);
+            }
+            has_z = Some(geom_has_z);
+        }
+        family = Some(geom_family);
+        multi |= geom_multi;
+    }
+    let geometry_type = match (family.unwrap_or(0), multi) {
+        (0, false) => W::Point,
+        (0, true) => W::MultiPoint,
+        (1, false) => W::LineString,
+        (1, true) => W::MultiLineString,
+        (_, false) => W::Polygon,
+        (_, true) => W::MultiPolygon,
+    };
+    Ok(Some((geometry_type, has_z.unwrap_or(false))))
+}
+
+fn float_values(array: &dyn Array) -> GResult<&[f64]> {
+    array
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .map(|array| array.values().as_slice())
+        .ok_or_else(|| invalid("coordinates must be Float64"))
+}
+
+enum CoordValues<'a> {
+    Separated(Vec<&'a [f64]>),
+    Interleaved(&'a [f64]),
+}
+
+struct Coords<'a> {
+    values: CoordValues<'a>,
+    has_z: bool,
+    has_m: bool,
+}
+
+impl<'a> Coords<'a> {
+    fn new(array: &'a dyn Array) -> GResult<Self> {
+        if let Some(array) = array.as_any().downcast_ref::<StructArray>() {
+            let column = |name: &str| {
+                array
+                    .fields()
+                    .iter()
+                    .position(|field| field.name.as_str() == name)
+                    .map(|n| float_values(array.values()[n].as_ref()))
+                    .transpose()
+            };
+            let x = column("x")?.ok_or_else(|| invalid("missing x field"))?;
+            let y = column("y")?.ok_or_else(|| invalid("missing y field"))?;
+            let (z, m) = (column("z")?, column("m")?);
+            let values = [Some(x), Some(y), z, m].into_iter().flatten().collect();
+            return Ok(Self {
+                values: CoordValues::Separated(values),
+                has_z: z.is_some(),
+                has_m: m.is_some(),
+            });
+        }
+        if let Some(array) = array.as_any().downcast_ref::<FixedSizeListArray>() {
+            let (has_z, has_m) = match array.size() {
+                2 => (false, false),
+                3 => (true, false),
+                4 => (true, true),
+                _ => {
+                    return Err(invalid(
+                        "interleaved coordinates must have 2, 3 or 4 values",
+                    ))
+                }
+            };
+            return Ok(Self {
+                values: CoordValues::Interleaved(float_values(array.values().as_ref())?),
+                has_z,
+                has_m,
+            });
+        }
+        Err(invalid("expected a struct or an array of coordinates"))
+    }
+
+    fn dimension(&self) -> usize {
+        2 + usize::from(self.has_z) + usize::from(self.has_m)
+    }
+
+    fn coord_seq(&self, start: usize, end: usize) -> GResult<CoordSeq> {
+        let dimension = self.dimension();
+        let buffer = match &self.values {
+            CoordValues::Interleaved(values) => values
+                .get(start * dimension..end * dimension)
+                .ok_or_else(|| invalid("coordinates out of bounds"))?
+                .to_vec(),
+            CoordValues::Separated(columns) => {
+                if columns.iter().any(|column| column.len() < end) {
+                    return Err(invalid("coordinates out of bounds"));
+                }
+                (start..end)
+                    .flat_map(|i| columns.iter().map(move |column| column[i]))
+                    .collect()
+            }
+        };
+        CoordSeq::new_from_buffer(&buffer, end - start, self.has_z, self.has_m)
+    }
+
+    fn is_nan(&self, n: usize) -> bool {
+        match &self.values {
+            CoordValues::Interleaved(values) => values
+                .get(n * self.dimension()..n * self.dimension() + 2)
+                .is_some_and(|xy| xy.iter().all(|v| v.is_nan())),
+            CoordValues::Separated(columns) => columns[..2]
+                .iter()
+                .all(|column| column.get(n).is_some_and(|v| v.is_nan())),
+        }
+    }
+}
+
+/// Reads geometries from a GeoArrow native array, with either separated or
+/// interleaved coordinates.
+pub struct Decoder<'a> {
+    array: &'a dyn Array,
+    geometry_type: WKBGeometryType,
+    levels: Vec<&'a OffsetsBuffer<i64>>,
+    coords: Coords<'a>,
+}
+
+impl<'a> Decoder<'a> {
+    pub fn new(array: &'a dyn Array, geometry_type: WKBGeometryType) -> GResult<Self> {
+        let mut levels = vec![];
+        let mut values = array;
+        for _ in 0..nesting_depth(geometry_type)? {
+            let list = values
+                .as_any()
+                .downcast_ref::<LargeListArray>()
+                .ok_or_else(|| invalid("expected a list"))?;
+            levels.push(list.offsets());
+            values = list.values().as_ref();
+        }
+        Ok(Self {
+            array,
+            geometry_type,
+            levels,
+            coords: Coords::new(values)?,
+        })
+    }
+
+    fn range(&self, level: usize, n: usize) -> GResult<(usize, usize)> {
+        let offsets = self.levels[level];
+        match n < offsets.len_proxy() {
+            true => Ok(offsets.start_end(n)),
+            false => Err(invalid("offsets out of bounds")),
+        }
+    }
+
+    fn point(&self, n: usize) -> GResult<Geometry> {
+        match self.coords.is_nan(n) {
+            true => Geometry::create_empty_point(),
+            false => Geometry::create_point(self.coords.coord_seq(n, n + 1)?),
+        }
+    }
+
+    fn line_string(&self, level: usize, n: usize) -> GResult<Geometry> {
+        match self.range(level, n)? {
+            (start, end) if start == end => Geometry::create_empty_line_string(),
+            (start, end) => Geometry::create_line_string(self.coords.coord_seq(start, end)?),
+        }
+    }
+
+    fn polygon(&self, level: usize, n: usize) -> GResult<Geometry> {
+        let (start, end) = self.range(level, n)?;
+        if start == end {
+            return Geometry::create_empty_polygon();
+        }
+        let mut rings = (start..end)
+            .map(|ring| {
+                let (start, end) = self.range(level + 1, ring)?;
+                Geometry::create_linear_ring(self.coords.coord_seq(start, end)?)
+            })
+            .try_collect::<Vec<_>>()?;
+        let exterior = rings.remove(0);
+        Geometry::create_polygon(exterior, rings)
+    }
+
+    /// Returns the geometry at index `n`, or `None` if it is null.
+    pub fn get(&self, n: usize) -> GResult<Option<Geometry>> {
+        use WKBGeometryType::*;
+        if !self.array.is_valid(n) {
+            return Ok(None);
+        }
+        let geom = match self.geometry_type {
+            Point => self.point(n),
+            LineString => self.line_string(0, n),
+            Polygon => self.polygon(0, n),
+            MultiPoint => {
+                let (start, end) = self.range(0, n)?;
+                let points = (start..end).map(|n| self.point(n)).try_collect()?;
+                Geometry::create_multipoint(points)
+            }
+            MultiLineString => {
+                let (start, end) = self.range(0, n)?;
+                let lines = (start..end).map(|n| self.line_string(1, n)).try_collect()?;
+                Geometry::create_multiline_string(lines)
+            }
+            MultiPolygon => {
+                let (start, end) = self.range(0, n)?;
+                let polygons = (start..end).map(|n| self.polygon(1, n)).try_collect()?;
+                Geometry::create_multipolygon(polygons)
+            }
+            other => return Err(unsupported(other)),
+        }?;
+        Ok(Some(geom))
+    }
+}
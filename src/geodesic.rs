@@ -0,0 +1,170 @@
+use std::sync::LazyLock;
+
+use geographiclib_rs::{DirectGeodesic, Geodesic, InverseGeodesic, PolygonArea, Winding};
+use geos::{CoordSeq, Error as GError, GResult, Geom, Geometry, GeometryTypes::*};
+
+use crate::curves;
+
+/// The WGS84 ellipsoid, used for geometries in EPSG:4326.
+static WGS84: LazyLock<Geodesic> = LazyLock::new(Geodesic::wgs84);
+
+/// Checks that a geometry has longitude / latitude coordinates, meaning its
+/// SRID is either EPSG:4326 or unset.
+pub fn check_srid<T: Geom>(geom: &T) -> GResult<()> {
+    match geom.get_srid()? {
+        0 | 4326 => Ok(()),
+        srid => Err(GError::GenericError(format!(
+            "Geodesic functions require EPSG:4326 geometries, got SRID {srid}"
+        ))),
+    }
+}
+
+/// Maximum deviation, in degrees, of the segments replacing circular arcs,
+/// which is about a centimeter.
+const CURVE_TOLERANCE: f64 = 1e-7;
+
+fn unsupported(geometry_type: impl std::fmt::Debug) -> GError {
+    let msg = format!("Geodesic functions don't support geometries of type {geometry_type:?}");
+    GError::GenericError(msg)
+}
+
+fn xy_buffer<T: Geom>(geom: &T) -> GResult<Vec<f64>> {
+    if geom.is_empty()? {
+        return Ok(vec![]);
+    }
+    geom.get_coord_seq()?.as_buffer(Some(2))
+}
+
+fn line_length(coords: &[f64]) -> f64 {
+    coords
+        .windows(4)
+        .step_by(2)
+        .map(|w| InverseGeodesic::<f64>::inverse(&*WGS84, w[1], w[0], w[3], w[2]))
+        .sum()
+}
+
+fn ring_area(coords: &[f64]) -> f64 {
+    let mut polygon = PolygonArea::new(&WGS84, Winding::CounterClockwise);
+    // The last point of a ring repeats the first one.
+    for point in coords.chunks_exact(2).skip(1) {
+        polygon.add_point(point[1], point[0]);
+    }
+    let (_, area, _) = polygon.compute(true);
+    area.abs()
+}
+
+/// Returns the ellipsoidal area of a geometry in square meters.
+pub fn area<T: Geom>(geom: &T) -> GResult<f64> {
+    match geom.geometry_type()? {
+        Point | LineString | LinearRing | MultiPoint | MultiLineString => Ok(0.0),
+        Polygon if geom.is_empty()? => Ok(0.0),
+        Polygon => {
+            let mut area = ring_area(&xy_buffer(&geom.get_exterior_ring()?)?);
+            for n in 0..geom.get_num_interior_rings()? {
+                area -= ring_area(&xy_buffer(&geom.get_interior_ring_n(n)?)?);
+            }
+            Ok(area)
+        }
+        MultiPolygon | GeometryCollection => (0..geom.get_num_geometries()?)
+            .map(|n| area(&geom.get_geometry_n(n)?))
+            .sum(),
+        CircularString | CompoundCurve | CurvePolygon | MultiCurve | MultiSurface => {
+            area(&curves::linearize(geom, CURVE_TOLERANCE)?)
+        }
+        other => Err(unsupported(other)),
+    }
+}
+
+/// Returns the ellipsoidal length of a geometry in meters. As with `length`,
+/// the length of polygons is the length of their rings.
+pub fn length<T: Geom>(geom: &T) -> GResult<f64> {
+    match geom.geometry_type()? {
+        Point | MultiPoint => Ok(0.0),
+        LineString | LinearRing => Ok(line_length(&xy_buffer(geom)?)),
+        Polygon if geom.is_empty()? => Ok(0.0),
+        Polygon => {
+            let mut length = line_length(&xy_buffer(&geom.get_exterior_ring()?)?);
+            for n in 0..geom.get_num_interior_rings()? {
+                length += line_length(&xy_buffer(&geom.get_interior_ring_n(n)?)?);
+            }
+            Ok(length)
+        }
+        MultiLineString | MultiPolygon | GeometryCollection => (0..geom.get_num_geometries()?)
+            .map(|n| length(&geom.get_geometry_n(n)?))
+            .sum(),
+        CircularString | CompoundCurve | CurvePolygon | MultiCurve | MultiSurface => {
+            length(&curves::linearize(geom, CURVE_TOLERANCE)?)
+        }
+        other => Err(unsupported(other)),
+    }
+}
+
+/// Returns the forward azimuth of the geodesic from `a` to `b`, in degrees
+/// clockwise from the north in the range [0, 360), or NaN if they coincide.
+pub fn azimuth(a: [f64; 2], b: [f64; 2]) -> f64 {
+    let (distance, azimuth, _, _): (f64, f64, f64, f64) = WGS84.inverse(a[1], a[0], b[1], b[0]);
+    if distance == 0.0 {
+        f64::NAN
+    } else {
+        azimuth.rem_euclid(360.0)
+    }
+}
+
+/// Densifies a coordinate sequence so that no segment is longer than
+/// `max_length` meters, with the new points placed along the geodesics.
+/// Z and M values are interpolated linearly.
+#[allow(clippy::cast_sign_loss, clippy::cast_precision_loss)]
+fn segmentize_coords<T: Geom>(geom: &T, max_length: f64) -> GResult<CoordSeq> {
+    let (has_z, has_m) = (geom.has_z()?, geom.has_m()?);
+    let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+    let coords = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+    let mut output = Vec::with_capacity(coords.len());
+    for segment in coords.windows(2 * dimension).step_by(dimension) {
+        let (start, end) = segment.split_at(dimension);
+        output.extend_from_slice(start);
+        let (length, azimuth, _, _): (f64, f64, f64, f64) =
+            WGS84.inverse(start[1], start[0], end[1], end[0]);
+        let segments = (length / max_length).ceil() as usize;
+        for i in 1..segments {
+            let fraction = i as f64 / segments as f64;
+            let (lat, lon): (f64, f64) =
+                WGS84.direct(start[1], start[0], azimuth, length * fraction);
+            output.extend([lon, lat]);
+            output.extend((2..dimension).map(|d| start[d] + (end[d] - start[d]) * fraction));
+        }
+    }
+    output.extend_from_slice(&coords[coords.len().saturating_sub(dimension)..]);
+    CoordSeq::new_from_buffer(&output, output.len() / dimension, has_z, has_m)
+}
+
+/// Returns a geometry with its segments split along the geodesics, so that
+/// none is longer than `max_length` meters. Curved geometries are linearized.
+pub fn segmentize<T: Geom>(geom: &T, max_length: f64) -> GResult<Geometry> {
+    if geom.is_empty()? {
+        return Geom::clone(geom);
+    }
+    let parts = || {
+        (0..geom.get_num_geometries()?)
+            .map(|n| segmentize(&geom.get_geometry_n(n)?, max_length))
+            .try_collect::<Vec<_>>()
+    };
+    match geom.geometry_type()? {
+        Point | MultiPoint => Geom::clone(geom),
+        LineString => Geometry::create_line_string(segmentize_coords(geom, max_length)?),
+        LinearRing => Geometry::create_linear_ring(segmentize_coords(geom, max_length)?),
+        Polygon => {
+            let exterior = segmentize(&geom.get_exterior_ring()?, max_length)?;
+            let interiors = (0..geom.get_num_interior_rings()?)
+                .map(|n| segmentize(&geom.get_interior_ring_n(n)?, max_length))
+                .try_collect::<Vec<_>>()?;
+            Geometry::create_polygon(exterior, interiors)
+        }
+        MultiLineString => Geometry::create_multiline_string(parts()?),
+        MultiPolygon => Geometry::create_multipolygon(parts()?),
+        GeometryCollection => Geometry::create_geometry_collection(parts()?),
+        CircularString | CompoundCurve | CurvePolygon | MultiCurve | MultiSurface => {
+            segmentize(&curves::linearize(geom, CURVE_TOLERANCE)?, max_length)
+        }
+        other => Err(unsupported(other)),
+    }
+}
@@ -0,0 +1,138 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, Mutex, PoisonError},
+};
+
+use geos::{Error as GError, GResult};
+use scroll::{Endian, Pread};
+
+const HEADER_SIZE: usize = 40;
+/// Value of the nodes without data in GTX grids.
+const NO_DATA: f32 = -88.8888;
+
+/// Grids loaded from files, by path.
+static GRIDS: LazyLock<Mutex<HashMap<String, Arc<Grid>>>> = LazyLock::new(Default::default);
+
+fn invalid() -> GError {
+    GError::GenericError("Invalid GTX grid file".into())
+}
+
+/// A GTX vertical shift grid, such as the `egm96_15.gtx` geoid model. Nodes
+/// are ordered from south to north, then from west to east.
+pub struct Grid {
+    south: f64,
+    west: f64,
+    lat_inc: f64,
+    lon_inc: f64,
+    rows: usize,
+    columns: usize,
+    /// Height of the geoid above the ellipsoid at each node, in meters.
+    heights: Vec<f32>,
+}
+
+impl Grid {
+    pub fn parse(data: &[u8]) -> GResult<Self> {
+        let float = |offset: usize| -> GResult<f64> {
+            data.pread_with::<f64>(offset, Endian::Big)
+                .map_err(|_| invalid())
+        };
+        let int = |offset: usize| -> GResult<usize> {
+            let value = data
+                .pread_with::<i32>(offset, Endian::Big)
+                .map_err(|_| invalid())?;
+            usize::try_from(value).map_err(|_| invalid())
+        };
+        let (rows, columns) = (int(32)?, int(36)?);
+        if rows < 2 || columns < 2 {
+            return Err(invalid());
+        }
+        let heights = (0..rows * columns)
+            .map(|n| {
+                data.pread_with::<f32>(HEADER_SIZE + 4 * n, Endian::Big)
+                    .map_err(|_| invalid())
+            })
+            .try_collect::<Vec<_>>()?;
+        Ok(Self {
+            south: float(0)?,
+            west: float(8)?,
+            lat_inc: float(16)?,
+            lon_inc: float(24)?,
+            rows,
+            columns,
+            heights,
+        })
+    }
+
+    /// Loads a grid file, or returns it from the cache if it was already loaded.
+    pub fn load(path: &str) -> GResult<Arc<Self>> {
+        let mut grids = GRIDS.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(grid) = grids.get(path) {
+            return Ok(grid.clone());
+        }
+        let data = std::fs::read(path)
+            .map_err(|e| GError::GenericError(format!("Couldn't read grid {path:?}: {e}")))?;
+        let grid = Arc::new(Self::parse(&data)?);
+        grids.insert(path.to_owned(), grid.clone());
+        Ok(grid)
+    }
+
+    /// Returns the bilinear interpolation of the heights of the nodes
+    /// surrounding a longitude and latitude in degrees, or `None` if the point
+    /// is outside of the grid or next to a node without data.
+    #[allow(clippy::cast_sign_loss, clippy::cast_precision_loss)]
+    fn height(&self, lon: f64, lat: f64) -> Option<f64> {
+        // Grids may use longitudes from 0 to 360.
+        let lon = self.west + (lon - self.west).rem_euclid(360.0);
+        let x = (lon - self.west) / self.lon_inc;
+        let y = (lat - self.south) / self.lat_inc;
+        if !(0.0..=(self.columns - 1) as f64).contains(&x)
+            || !(0.0..=(self.rows - 1) as f64).contains(&y)
+        {
+            return None;
+        }
+        // Points on the north and east edges are interpolated from the last cell.
+        let column = (x.floor() as usize).min(self.columns - 2);
+        let row = (y.floor() as usize).min(self.rows - 2);
+        let (fx, fy) = (x - column as f64, y - row as f64);
+        let node = |row: usize, column: usize| self.heights[row * self.columns + column];
+        let nodes = [
+            node(row, column),
+            node(row, column + 1),
+            node(row + 1, column),
+            node(row + 1, column + 1),
+        ];
+        if nodes.contains(&NO_DATA) {
+            return None;
+        }
+        let [a, b, c, d] = nodes.map(f64::from);
+        Some((a * (1.0 - fx) + b * fx) * (1.0 - fy) + (c * (1.0 - fx) + d * fx) * fy)
+    }
+}
+
+/// A geoid model made of one or more grids, as given to the `+geoidgrids`
+/// parameter of a CRS or the `+grids` parameter of a `vgridshift` step.
+pub struct Geoid(Vec<Arc<Grid>>);
+
+impl Geoid {
+    /// Loads a comma separated list of grids. Grids prefixed by `@` are
+    /// optional, and skipped if they can't be loaded.
+    pub fn load(grids: &str) -> GResult<Self> {
+        grids
+            .split(',')
+            .filter_map(|path| match path.strip_prefix('@') {
+                Some(path) => Grid::load(path).ok().map(Ok),
+                None => Some(Grid::load(path)),
+            })
+            .try_collect::<Vec<_>>()
+            .map(Self)
+    }
+
+    /// Returns the height of the geoid above the ellipsoid, in meters, with
+    /// the first grid covering a longitude and latitude in degrees.
+    pub fn height(&self, lon: f64, lat: f64) -> GResult<f64> {
+        self.0
+            .iter()
+            .find_map(|grid| grid.height(lon, lat))
+            .ok_or_else(|| GError::GenericError("Point outside of the geoid grids".into()))
+    }
+}
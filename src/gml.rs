@@ -0,0 +1,285 @@
+use std::fmt::Write;
+
+use geos::{CoordSeq, Error as GError, GResult, Geom, Geometry, GeometryTypes::*};
+use roxmltree::{Document, Node};
+
+const GML_NAMESPACE: &str = "http://www.opengis.net/gml/3.2";
+
+const GEOMETRY_TAGS: [&str; 11] = [
+    "Point",
+    "LineString",
+    "LinearRing",
+    "Polygon",
+    "Envelope",
+    "MultiPoint",
+    "MultiCurve",
+    "MultiLineString",
+    "MultiSurface",
+    "MultiPolygon",
+    "MultiGeometry",
+];
+
+fn invalid(msg: &str) -> GError {
+    GError::GenericError(format!("Invalid GML: {msg}"))
+}
+
+fn write_positions<T: Geom>(geom: &T, tag: &str, out: &mut String) -> GResult<()> {
+    let has_z = geom.has_z()?;
+    let dimension = 2 + usize::from(has_z) + usize::from(geom.has_m()?);
+    let _ = write!(out, "<gml:{tag}>");
+    if !geom.is_empty()? {
+        let buffer = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+        for (i, coords) in buffer.chunks_exact(dimension).enumerate() {
+            if i > 0 {
+                out.push(' ');
+            }
+            let _ = write!(out, "{} {}", coords[0], coords[1]);
+            if has_z {
+                let _ = write!(out, " {}", coords[2]);
+            }
+        }
+    }
+    let _ = write!(out, "</gml:{tag}>");
+    Ok(())
+}
+
+fn write_linear_ring<T: Geom>(ring: &T, boundary: &str, out: &mut String) -> GResult<()> {
+    let _ = write!(out, "<gml:{boundary}><gml:LinearRing>");
+    write_positions(ring, "posList", out)?;
+    let _ = write!(out, "</gml:LinearRing></gml:{boundary}>");
+    Ok(())
+}
+
+fn write_geometry<T: Geom>(geom: &T, attributes: &str, out: &mut String) -> GResult<()> {
+    let (tag, member) = match geom.geometry_type()? {
+        Point => ("Point", ""),
+        LineString => ("LineString", ""),
+        LinearRing => ("LinearRing", ""),
+        Polygon => ("Polygon", ""),
+        MultiPoint => ("MultiPoint", "pointMember"),
+        MultiLineString => ("MultiCurve", "curveMember"),
+        MultiPolygon => ("MultiSurface", "surfaceMember"),
+        GeometryCollection => ("MultiGeometry", "geometryMember"),
+        other => {
+            let msg = format!("GML doesn't support geometries of type {other:?}");
+            return Err(GError::GenericError(msg));
+        }
+    };
+    let _ = write!(out, "<gml:{tag}{attributes}>");
+    match tag {
+        "Point" => write_positions(geom, "pos", out)?,
+        "LineString" | "LinearRing" => write_positions(geom, "posList", out)?,
+        "Polygon" => {
+            if !geom.is_empty()? {
+                write_linear_ring(&geom.get_exterior_ring()?, "exterior", out)?;
+                for n in 0..geom.get_num_interior_rings()? {
+                    write_linear_ring(&geom.get_interior_ring_n(n)?, "interior", out)?;
+                }
+            }
+        }
+        _ => {
+            for n in 0..geom.get_num_geometries()? {
+                let _ = write!(out, "<gml:{member}>");
+                write_geometry(&geom.get_geometry_n(n)?, "", out)?;
+                let _ = write!(out, "</gml:{member}>");
+            }
+        }
+    }
+    let _ = write!(out, "</gml:{tag}>");
+    Ok(())
+}
+
+/// Serializes a geometry as a GML 3.2 geometry element.
+pub fn to_gml<T: Geom>(geom: &T) -> GResult<String> {
+    let mut attributes = format!(" xmlns:gml=\"{GML_NAMESPACE}\"");
+    match geom.get_srid()? {
+        0 => {}
+        srid => {
+            let _ = write!(attributes, " srsName=\"EPSG:{srid}\"");
+        }
+    }
+    if geom.has_z()? {
+        attributes.push_str(" srsDimension=\"3\"");
+    }
+    let mut out = String::new();
+    write_geometry(geom, &attributes, &mut out)?;
+    Ok(out)
+}
+
+fn is_geometry(node: &Node) -> bool {
+    node.is_element() && GEOMETRY_TAGS.contains(&node.tag_name().name())
+}
+
+fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    names: &'static [&'static str],
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |n| n.is_element() && names.contains(&n.tag_name().name()))
+}
+
+fn parse_values(text: &str) -> GResult<Vec<f64>> {
+    text.split_whitespace()
+        .map(|value| {
+            value
+                .parse::<f64>()
+                .map_err(|_| invalid(&format!("invalid coordinate value {value}")))
+        })
+        .try_collect()
+}
+
+/// Returns the `srsDimension` declared on a node or its ancestors, 2 by default.
+fn srs_dimension(node: Node) -> GResult<usize> {
+    match node.ancestors().find_map(|n| n.attribute("srsDimension")) {
+        None => Ok(2),
+        Some(dimension) => match dimension.parse::<usize>() {
+            Ok(dimension @ 2..) => Ok(dimension),
+            _ => Err(invalid(&format!("invalid srsDimension {dimension}"))),
+        },
+    }
+}
+
+/// Reads the positions of a node from its `posList`, `pos` or GML 2 `coordinates`
+/// children, as tuples of two or three values.
+fn parse_positions(node: Node) -> GResult<Vec<Vec<f64>>> {
+    if let Some(pos_list) = children(node, &["posList"]).next() {
+        let dimension = srs_dimension(pos_list)?;
+        let values = parse_values(pos_list.text().unwrap_or_default())?;
+        if values.len() % dimension != 0 {
+            return Err(invalid("posList length isn't a multiple of its dimension"));
+        }
+        return Ok(values
+            .chunks_exact(dimension)
+            .map(<[f64]>::to_vec)
+            .collect());
+    }
+    if let Some(coordinates) = children(node, &["coordinates"]).next() {
+        return coordinates
+            .text()
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(|tuple| parse_values(&tuple.replace(',', " ")))
+            .try_collect();
+    }
+    children(node, &["pos"])
+        .map(|pos| parse_values(pos.text().unwrap_or_default()))
+        .filter(|values| !matches!(values, Ok(v) if v.is_empty()))
+        .try_collect()
+}
+
+fn coord_seq(positions: &[Vec<f64>]) -> GResult<Option<CoordSeq>> {
+    if positions.is_empty() {
+        return Ok(None);
+    }
+    if positions.iter().any(|position| position.len() < 2) {
+        return Err(invalid("positions must have at least two values"));
+    }
+    let has_z = positions.iter().any(|position| position.len() > 2);
+    let mut buffer = Vec::with_capacity(positions.len() * (2 + usize::from(has_z)));
+    for position in positions {
+        buffer.extend_from_slice(&position[..2]);
+        if has_z {
+            buffer.push(position.get(2).copied().unwrap_or(f64::NAN));
+        }
+    }
+    CoordSeq::new_from_buffer(&buffer, positions.len(), has_z, false).map(Some)
+}
+
+fn parse_linear_ring(node: Node) -> GResult<Geometry> {
+    let coords = coord_seq(&parse_positions(node)?)?.ok_or_else(|| invalid("empty LinearRing"))?;
+    Geometry::create_linear_ring(coords)
+}
+
+fn parse_envelope(node: Node) -> GResult<Geometry> {
+    let corner = |name: &'static [&'static str]| -> GResult<Option<Vec<f64>>> {
+        children(node, name)
+            .next()
+            .map(|n| parse_values(n.text().unwrap_or_default()))
+            .transpose()
+    };
+    match (corner(&["lowerCorner"])?, corner(&["upperCorner"])?) {
+        (Some(lower), Some(upper)) if lower.len() >= 2 && upper.len() >= 2 => {
+            Geometry::create_rectangle(lower[0], lower[1], upper[0], upper[1])
+        }
+        _ => Geometry::create_empty_polygon(),
+    }
+}
+
+fn parse_geometry(node: Node) -> GResult<Geometry> {
+    match node.tag_name().name() {
+        "Point" => match coord_seq(&parse_positions(node)?)? {
+            Some(coords) => Geometry::create_point(coords),
+            None => Geometry::create_empty_point(),
+        },
+        "LineString" => match coord_seq(&parse_positions(node)?)? {
+            Some(coords) => Geometry::create_line_string(coords),
+            None => Geometry::create_empty_line_string(),
+        },
+        "LinearRing" => parse_linear_ring(node),
+        "Polygon" => {
+            let Some(exterior) = children(node, &["exterior", "outerBoundaryIs"])
+                .flat_map(|boundary| children(boundary, &["LinearRing"]))
+                .next()
+            else {
+                return Geometry::create_empty_polygon();
+            };
+            let interiors = children(node, &["interior", "innerBoundaryIs"])
+                .flat_map(|boundary| children(boundary, &["LinearRing"]))
+                .map(parse_linear_ring)
+                .try_collect::<Vec<_>>()?;
+            Geometry::create_polygon(parse_linear_ring(exterior)?, interiors)
+        }
+        "Envelope" => parse_envelope(node),
+        name => {
+            // Members are wrapped in `*Member` (one geometry) or `*Members`
+            // (any number of geometries) elements.
+            let geoms = node
+                .children()
+                .filter(Node::is_element)
+                .flat_map(|member| member.children().filter(is_geometry))
+                .map(parse_geometry)
+                .try_collect::<Vec<_>>()?;
+            match name {
+                "MultiPoint" => Geometry::create_multipoint(geoms),
+                "MultiCurve" | "MultiLineString" => Geometry::create_multiline_string(geoms),
+                "MultiSurface" | "MultiPolygon" => Geometry::create_multipolygon(geoms),
+                "MultiGeometry" => Geometry::create_geometry_collection(geoms),
+                other => Err(invalid(&format!("unsupported geometry element {other}"))),
+            }
+        }
+    }
+}
+
+/// Extracts the EPSG code of a `srsName`, such as `EPSG:4326`,
+/// `urn:ogc:def:crs:EPSG::4326` or `http://www.opengis.net/def/crs/EPSG/0/4326`.
+fn parse_srs_name(srs_name: &str) -> Option<i32> {
+    if !srs_name.to_ascii_uppercase().contains("EPSG") {
+        return None;
+    }
+    srs_name
+        .rsplit(|c: char| !c.is_ascii_digit())
+        .next()
+        .and_then(|code| code.parse().ok())
+}
+
+/// Parses the first geometry element of a GML document or fragment.
+///
+/// The SRID is read from the closest `srsName` attribute. Coordinates are kept
+/// in the order they are written, regardless of the axis order of the CRS.
+pub fn from_gml(gml: &str) -> GResult<Geometry> {
+    let doc = Document::parse(gml).map_err(|e| invalid(&e.to_string()))?;
+    let node = doc
+        .root_element()
+        .descendants()
+        .find(is_geometry)
+        .ok_or_else(|| invalid("no geometry element found"))?;
+    let mut geom = parse_geometry(node)?;
+    if let Some(srid) = node
+        .ancestors()
+        .find_map(|n| n.attribute("srsName"))
+        .and_then(parse_srs_name)
+    {
+        geom.set_srid(srid);
+    }
+    Ok(geom)
+}
@@ -0,0 +1,215 @@
+use geos::{CoordSeq, Error as GError, GResult, Geom, Geometry};
+use polars::{error::to_compute_err, prelude::*};
+use pyo3::prelude::*;
+use pyo3_polars::{error::PyPolarsErr, PyDataFrame};
+use roxmltree::{Document, Node};
+
+use crate::functions::GeometryUtils;
+
+fn invalid(msg: &str) -> GError {
+    GError::GenericError(format!("Invalid GPX: {msg}"))
+}
+
+fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+fn child_text<'a>(node: Node<'a, '_>, name: &'static str) -> Option<&'a str> {
+    children(node, name)
+        .next()
+        .and_then(|n| n.text())
+        .map(str::trim)
+}
+
+/// Returns the number of days between the Unix epoch and a date of the proleptic
+/// Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Parses an ISO 8601 timestamp, as written in GPX `time` elements, into
+/// microseconds since the Unix epoch. Timestamps without an offset are UTC.
+pub fn parse_time(text: &str) -> Option<i64> {
+    let (date, time) = text.split_once('T')?;
+    let mut date = date.splitn(3, '-').map(str::parse::<i64>);
+    let year = date.next()?.ok()?;
+    let month = date.next()?.ok()?;
+    let day = date.next()?.ok()?;
+    let (time, offset) = match time.find(['Z', 'z', '+', '-']) {
+        Some(i) => time.split_at(i),
+        None => (time, ""),
+    };
+    let offset = match offset {
+        "" | "Z" | "z" => 0,
+        _ => {
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let offset = &offset[1..];
+            let (hours, minutes) = offset
+                .split_once(':')
+                .unwrap_or_else(|| offset.split_at(offset.len().min(2)));
+            let minutes = match minutes {
+                "" => 0,
+                minutes => minutes.parse::<i64>().ok()?,
+            };
+            sign * (hours.parse::<i64>().ok()? * 3_600 + minutes * 60)
+        }
+    };
+    let mut time = time.splitn(3, ':');
+    let hours = time.next()?.parse::<i64>().ok()?;
+    let minutes = time.next()?.parse::<i64>().ok()?;
+    let seconds = time.next()?.parse::<f64>().ok()?;
+    let days = days_from_civil(year, month, day);
+    let whole_seconds = days * 86_400 + hours * 3_600 + minutes * 60 - offset;
+    Some(whole_seconds * 1_000_000 + (seconds * 1e6).round() as i64)
+}
+
+struct Point {
+    lon: f64,
+    lat: f64,
+    elevation: Option<f64>,
+    time: Option<i64>,
+}
+
+fn parse_point(node: Node) -> GResult<Point> {
+    let coordinate = |name: &str| {
+        node.attribute(name)
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .ok_or_else(|| invalid(&format!("missing or invalid {name} attribute")))
+    };
+    Ok(Point {
+        lon: coordinate("lon")?,
+        lat: coordinate("lat")?,
+        elevation: child_text(node, "ele").and_then(|ele| ele.parse().ok()),
+        time: child_text(node, "time").and_then(parse_time),
+    })
+}
+
+fn to_ewkb(mut geom: Geometry) -> GResult<Vec<u8>> {
+    geom.set_srid(4326);
+    geom.to_ewkb()
+}
+
+#[derive(Default)]
+struct PointColumns {
+    geometry: Vec<Vec<u8>>,
+    kind: Vec<&'static str>,
+    name: Vec<Option<String>>,
+    track_id: Vec<Option<u32>>,
+    segment_id: Vec<Option<u32>>,
+    elevation: Vec<Option<f64>>,
+    time: Vec<Option<i64>>,
+}
+
+impl PointColumns {
+    fn push(
+        &mut self,
+        point: &Point,
+        kind: &'static str,
+        name: Option<&str>,
+        ids: (Option<u32>, Option<u32>),
+    ) -> GResult<()> {
+        let coords = CoordSeq::new_from_buffer(&[point.lon, point.lat], 1, false, false)?;
+        self.geometry
+            .push(to_ewkb(Geometry::create_point(coords)?)?);
+        self.kind.push(kind);
+        self.name.push(name.map(str::to_owned));
+        self.track_id.push(ids.0);
+        self.segment_id.push(ids.1);
+        self.elevation.push(point.elevation);
+        self.time.push(point.time);
+        Ok(())
+    }
+}
+
+fn read_points(root: Node) -> GResult<DataFrame> {
+    let mut columns = PointColumns::default();
+    for waypoint in children(root, "wpt") {
+        let name = child_text(waypoint, "name");
+        columns.push(&parse_point(waypoint)?, "waypoint", name, (None, None))?;
+    }
+    for (track_id, track) in children(root, "trk").enumerate() {
+        let name = child_text(track, "name");
+        for (segment_id, segment) in children(track, "trkseg").enumerate() {
+            let ids = (Some(track_id as u32), Some(segment_id as u32));
+            for point in children(segment, "trkpt") {
+                columns.push(&parse_point(point)?, "trackpoint", name, ids)?;
+            }
+        }
+    }
+    let time = Int64Chunked::from_iter_options("time".into(), columns.time.into_iter())
+        .into_datetime(TimeUnit::Microseconds, Some("UTC".into()));
+    DataFrame::new(vec![
+        BinaryChunked::from_slice("geometry".into(), &columns.geometry).into_column(),
+        Column::new("type".into(), columns.kind),
+        Column::new("name".into(), columns.name),
+        Column::new("track_id".into(), columns.track_id),
+        Column::new("segment_id".into(), columns.segment_id),
+        Column::new("elevation".into(), columns.elevation),
+        time.into_column(),
+    ])
+    .map_err(|e| GError::GenericError(e.to_string()))
+}
+
+fn read_tracks(root: Node) -> GResult<DataFrame> {
+    let mut geometry = vec![];
+    let mut names = vec![];
+    let mut start_times = vec![];
+    let mut end_times = vec![];
+    for track in children(root, "trk") {
+        let mut lines = vec![];
+        let mut times = vec![];
+        for segment in children(track, "trkseg") {
+            let points = children(segment, "trkpt")
+                .map(parse_point)
+                .try_collect::<Vec<_>>()?;
+            times.extend(points.iter().filter_map(|point| point.time));
+            if points.len() < 2 {
+                continue;
+            }
+            let buffer = points
+                .iter()
+                .flat_map(|point| [point.lon, point.lat])
+                .collect::<Vec<_>>();
+            let coords = CoordSeq::new_from_buffer(&buffer, points.len(), false, false)?;
+            lines.push(Geometry::create_line_string(coords)?);
+        }
+        geometry.push(to_ewkb(Geometry::create_multiline_string(lines)?)?);
+        names.push(child_text(track, "name").map(str::to_owned));
+        start_times.push(times.iter().min().copied());
+        end_times.push(times.iter().max().copied());
+    }
+    let datetime = |name: &str, values: Vec<Option<i64>>| {
+        Int64Chunked::from_iter_options(name.into(), values.into_iter())
+            .into_datetime(TimeUnit::Microseconds, Some("UTC".into()))
+            .into_column()
+    };
+    DataFrame::new(vec![
+        BinaryChunked::from_slice("geometry".into(), &geometry).into_column(),
+        Column::new("name".into(), names),
+        datetime("start_time", start_times),
+        datetime("end_time", end_times),
+    ])
+    .map_err(|e| GError::GenericError(e.to_string()))
+}
+
+/// Reads the waypoints and track points of a GPX document, one row per point,
+/// or its tracks as `MultiLineString` geometries, one row per track.
+#[pyfunction]
+pub fn read_gpx(contents: &str, tracks: bool) -> Result<PyDataFrame, PyPolarsErr> {
+    let doc = Document::parse(contents).map_err(|e| to_compute_err(invalid(&e.to_string())))?;
+    let root = doc.root_element();
+    let df = match tracks {
+        true => read_tracks(root),
+        false => read_points(root),
+    };
+    df.map(PyDataFrame).map_err(|e| to_compute_err(e).into())
+}
@@ -0,0 +1,91 @@
+use geo_types::{Coord, LineString as GeoLineString, Polygon as GeoPolygon};
+use geos::{CoordSeq, Error as GError, GResult, Geom, Geometry, GeometryTypes::*};
+use h3o::{
+    geom::{ContainmentMode, PolyfillConfig, ToCells},
+    CellIndex, LatLng, Resolution,
+};
+
+fn h3_error(err: impl std::fmt::Display) -> GError {
+    GError::GenericError(format!("H3 error: {err}"))
+}
+
+pub fn resolution(resolution: u8) -> GResult<Resolution> {
+    Resolution::try_from(resolution).map_err(h3_error)
+}
+
+/// Returns the cell containing a point, or `None` if it is empty.
+pub fn point_to_cell<T: Geom>(geom: &T, resolution: Resolution) -> GResult<Option<u64>> {
+    match geom.geometry_type()? {
+        Point if geom.is_empty()? => Ok(None),
+        Point => {
+            let point = LatLng::new(geom.get_y()?, geom.get_x()?).map_err(h3_error)?;
+            Ok(Some(point.to_cell(resolution).into()))
+        }
+        geometry_type => Err(GError::GenericError(format!(
+            "H3 cells can only be computed for points, got {geometry_type:?}"
+        ))),
+    }
+}
+
+/// Returns the polygon of a cell, in EPSG:4326. Vertices of cells crossing
+/// the antimeridian aren't unwrapped.
+pub fn cell_boundary(cell: u64) -> GResult<Geometry> {
+    let cell = CellIndex::try_from(cell).map_err(h3_error)?;
+    let mut coords = cell
+        .boundary()
+        .iter()
+        .flat_map(|vertex| [vertex.lng(), vertex.lat()])
+        .collect::<Vec<_>>();
+    coords.extend([coords[0], coords[1]]);
+    let ring = CoordSeq::new_from_buffer(&coords, coords.len() / 2, false, false)?;
+    let mut polygon = Geometry::create_polygon(Geometry::create_linear_ring(ring)?, vec![])?;
+    polygon.set_srid(4326);
+    Ok(polygon)
+}
+
+fn to_geo_ring<T: Geom>(ring: &T) -> GResult<GeoLineString<f64>> {
+    let coords = ring.get_coord_seq()?.as_buffer(Some(2))?;
+    Ok(coords
+        .chunks_exact(2)
+        .map(|coord| Coord {
+            x: coord[0],
+            y: coord[1],
+        })
+        .collect())
+}
+
+fn to_geo_polygon<T: Geom>(polygon: &T) -> GResult<GeoPolygon<f64>> {
+    let exterior = to_geo_ring(&polygon.get_exterior_ring()?)?;
+    let interiors = (0..polygon.get_num_interior_rings()?)
+        .map(|n| to_geo_ring(&polygon.get_interior_ring_n(n)?))
+        .try_collect::<Vec<_>>()?;
+    Ok(GeoPolygon::new(exterior, interiors))
+}
+
+fn add_cover_cells<T: Geom>(geom: &T, config: PolyfillConfig, cells: &mut Vec<u64>) -> GResult<()> {
+    match geom.geometry_type()? {
+        _ if geom.is_empty()? => Ok(()),
+        Polygon => {
+            let polygon =
+                h3o::geom::Polygon::from_degrees(to_geo_polygon(geom)?).map_err(h3_error)?;
+            cells.extend(polygon.to_cells(config).map(u64::from));
+            Ok(())
+        }
+        MultiPolygon => (0..geom.get_num_geometries()?)
+            .try_for_each(|n| add_cover_cells(&geom.get_geometry_n(n)?, config, cells)),
+        geometry_type => Err(GError::GenericError(format!(
+            "H3 covers can only be computed for polygons, got {geometry_type:?}"
+        ))),
+    }
+}
+
+/// Returns the sorted cells covering a polygon or multipolygon, that is all
+/// the cells intersecting it.
+pub fn cover<T: Geom>(geom: &T, resolution: Resolution) -> GResult<Vec<u64>> {
+    let config = PolyfillConfig::new(resolution).containment_mode(ContainmentMode::Covers);
+    let mut cells = Vec::new();
+    add_cover_cells(geom, config, &mut cells)?;
+    cells.sort_unstable();
+    cells.dedup();
+    Ok(cells)
+}
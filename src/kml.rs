@@ -0,0 +1,193 @@
+use std::fmt::Write;
+
+use geos::{CoordSeq, Error as GError, GResult, Geom, Geometry, GeometryTypes::*};
+use roxmltree::{Document, Node};
+
+const GEOMETRY_TAGS: [&str; 5] = [
+    "Point",
+    "LineString",
+    "LinearRing",
+    "Polygon",
+    "MultiGeometry",
+];
+
+fn invalid(msg: &str) -> GError {
+    GError::GenericError(format!("Invalid KML: {msg}"))
+}
+
+fn write_coordinates<T: Geom>(geom: &T, out: &mut String) -> GResult<()> {
+    let has_z = geom.has_z()?;
+    let dimension = 2 + usize::from(has_z) + usize::from(geom.has_m()?);
+    out.push_str("<coordinates>");
+    if !geom.is_empty()? {
+        let buffer = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+        for (i, coords) in buffer.chunks_exact(dimension).enumerate() {
+            if i > 0 {
+                out.push(' ');
+            }
+            let _ = write!(out, "{},{}", coords[0], coords[1]);
+            if has_z {
+                let _ = write!(out, ",{}", coords[2]);
+            }
+        }
+    }
+    out.push_str("</coordinates>");
+    Ok(())
+}
+
+fn write_linear_ring<T: Geom>(ring: &T, boundary: &str, out: &mut String) -> GResult<()> {
+    let _ = write!(out, "<{boundary}><LinearRing>");
+    write_coordinates(ring, out)?;
+    let _ = write!(out, "</LinearRing></{boundary}>");
+    Ok(())
+}
+
+fn write_geometry<T: Geom>(geom: &T, out: &mut String) -> GResult<()> {
+    let tag = match geom.geometry_type()? {
+        Point => "Point",
+        LineString => "LineString",
+        LinearRing => "LinearRing",
+        Polygon => "Polygon",
+        MultiPoint | MultiLineString | MultiPolygon | GeometryCollection => "MultiGeometry",
+        other => {
+            let msg = format!("KML doesn't support geometries of type {other:?}");
+            return Err(GError::GenericError(msg));
+        }
+    };
+    let _ = write!(out, "<{tag}>");
+    match tag {
+        "Polygon" => {
+            if !geom.is_empty()? {
+                write_linear_ring(&geom.get_exterior_ring()?, "outerBoundaryIs", out)?;
+                for n in 0..geom.get_num_interior_rings()? {
+                    write_linear_ring(&geom.get_interior_ring_n(n)?, "innerBoundaryIs", out)?;
+                }
+            }
+        }
+        "MultiGeometry" => {
+            for n in 0..geom.get_num_geometries()? {
+                write_geometry(&geom.get_geometry_n(n)?, out)?;
+            }
+        }
+        _ => write_coordinates(geom, out)?,
+    }
+    let _ = write!(out, "</{tag}>");
+    Ok(())
+}
+
+/// Serializes a geometry as a KML geometry element.
+pub fn to_kml<T: Geom>(geom: &T) -> GResult<String> {
+    let mut out = String::new();
+    write_geometry(geom, &mut out)?;
+    Ok(out)
+}
+
+fn is_geometry(node: &Node) -> bool {
+    node.is_element() && GEOMETRY_TAGS.contains(&node.tag_name().name())
+}
+
+fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+fn parse_coordinates(node: Node) -> GResult<Option<CoordSeq>> {
+    let text = children(node, "coordinates")
+        .next()
+        .and_then(|n| n.text())
+        .unwrap_or_default();
+    let tuples = text
+        .split_whitespace()
+        .map(|tuple| {
+            tuple
+                .split(',')
+                .map(|value| value.parse::<f64>())
+                .try_collect::<Vec<_>>()
+                .map_err(|_| invalid(&format!("invalid coordinates {tuple}")))
+        })
+        .try_collect::<Vec<_>>()?;
+    if tuples.is_empty() {
+        return Ok(None);
+    }
+    if tuples.iter().any(|tuple| tuple.len() < 2) {
+        return Err(invalid("coordinates must have at least two values"));
+    }
+    let has_z = tuples.iter().any(|tuple| tuple.len() > 2);
+    let mut buffer = Vec::with_capacity(tuples.len() * (2 + usize::from(has_z)));
+    for tuple in &tuples {
+        buffer.extend_from_slice(&tuple[..2]);
+        if has_z {
+            buffer.push(tuple.get(2).copied().unwrap_or(f64::NAN));
+        }
+    }
+    CoordSeq::new_from_buffer(&buffer, tuples.len(), has_z, false).map(Some)
+}
+
+fn parse_linear_ring(node: Node) -> GResult<Geometry> {
+    let coords = parse_coordinates(node)?.ok_or_else(|| invalid("empty LinearRing"))?;
+    Geometry::create_linear_ring(coords)
+}
+
+fn parse_geometry(node: Node) -> GResult<Geometry> {
+    match node.tag_name().name() {
+        "Point" => match parse_coordinates(node)? {
+            Some(coords) => Geometry::create_point(coords),
+            None => Geometry::create_empty_point(),
+        },
+        "LineString" => match parse_coordinates(node)? {
+            Some(coords) => Geometry::create_line_string(coords),
+            None => Geometry::create_empty_line_string(),
+        },
+        "LinearRing" => parse_linear_ring(node),
+        "Polygon" => {
+            let Some(exterior) = children(node, "outerBoundaryIs")
+                .flat_map(|boundary| children(boundary, "LinearRing"))
+                .next()
+            else {
+                return Geometry::create_empty_polygon();
+            };
+            let interiors = children(node, "innerBoundaryIs")
+                .flat_map(|boundary| children(boundary, "LinearRing"))
+                .map(parse_linear_ring)
+                .try_collect::<Vec<_>>()?;
+            Geometry::create_polygon(parse_linear_ring(exterior)?, interiors)
+        }
+        "MultiGeometry" => {
+            let geoms = node
+                .children()
+                .filter(is_geometry)
+                .map(parse_geometry)
+                .try_collect::<Vec<_>>()?;
+            let types = geoms
+                .iter()
+                .map(Geom::geometry_type)
+                .try_collect::<Vec<_>>()?;
+            match types.first() {
+                Some(&first) if types.iter().all(|&t| t == first) => match first {
+                    Point => Geometry::create_multipoint(geoms),
+                    LineString => Geometry::create_multiline_string(geoms),
+                    Polygon => Geometry::create_multipolygon(geoms),
+                    _ => Geometry::create_geometry_collection(geoms),
+                },
+                _ => Geometry::create_geometry_collection(geoms),
+            }
+        }
+        other => Err(invalid(&format!("unsupported geometry element {other}"))),
+    }
+}
+
+/// Parses the first geometry element of a KML document or fragment, in EPSG:4326.
+pub fn from_kml(kml: &str) -> GResult<Geometry> {
+    let doc = Document::parse(kml).map_err(|e| invalid(&e.to_string()))?;
+    let node = doc
+        .root_element()
+        .descendants()
+        .find(is_geometry)
+        .ok_or_else(|| invalid("no geometry element found"))?;
+    let mut geom = parse_geometry(node)?;
+    geom.set_srid(4326);
+    Ok(geom)
+}
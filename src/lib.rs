@@ -1,5 +1,6 @@
 #![feature(iterator_try_collect)]
 #![feature(iterator_try_reduce)]
+#![feature(once_cell_try)]
 #![feature(downcast_unchecked)]
 #![deny(clippy::all)]
 #![deny(clippy::pedantic)]
@@ -14,11 +15,38 @@
 
 use pyo3::prelude::*;
 
+mod antimeridian;
 mod args;
 mod arity;
 mod crs;
+mod curves;
+mod dxf;
+mod esri;
+mod esrijson;
 mod expressions;
+mod flatgeobuf;
 mod functions;
+mod geoarrow;
+mod geodesic;
+mod geoid;
+mod gml;
+mod gpx;
+mod h3;
+mod kml;
+#[cfg(feature = "proj")]
+mod libproj;
+mod measure_3d;
+mod ntv2;
+mod partition;
+mod pipeline;
+mod prepared;
+mod rtree;
+mod spatialite;
+mod svg;
+mod sweep;
+mod tiles;
+mod topojson;
+mod visvalingam;
 mod wkb;
 
 #[pymodule]
@@ -26,6 +54,19 @@ fn _lib(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
     m.add_function(wrap_pyfunction!(crs::get_crs_authority, m)?)?;
     m.add_function(wrap_pyfunction!(crs::get_crs_from_code, m)?)?;
+    m.add_function(wrap_pyfunction!(crs::get_srid_authority, m)?)?;
+    m.add_function(wrap_pyfunction!(crs::register_crs, m)?)?;
+    m.add_function(wrap_pyfunction!(crs::get_registered_crs, m)?)?;
+    m.add_function(wrap_pyfunction!(crs::register_datum_grids, m)?)?;
     m.add_function(wrap_pyfunction!(expressions::to_python_dict, m)?)?;
+    m.add_function(wrap_pyfunction!(expressions::to_topojson, m)?)?;
+    m.add_function(wrap_pyfunction!(expressions::to_dxf, m)?)?;
+    m.add_function(wrap_pyfunction!(expressions::to_ragged_array, m)?)?;
+    m.add_function(wrap_pyfunction!(expressions::from_ragged_array, m)?)?;
+    m.add_function(wrap_pyfunction!(expressions::get_coordinates, m)?)?;
+    m.add_function(wrap_pyfunction!(expressions::set_coordinates, m)?)?;
+    m.add_function(wrap_pyfunction!(flatgeobuf::flatgeobuf_offsets, m)?)?;
+    m.add_function(wrap_pyfunction!(flatgeobuf::read_flatgeobuf, m)?)?;
+    m.add_function(wrap_pyfunction!(gpx::read_gpx, m)?)?;
     Ok(())
 }
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use geos::{Error as GError, GResult, Geom, Geometry};
+use proj::Proj;
+
+use crate::crs;
+
+/// Returns the definitions libproj may know an SRID by. SRIDs that are neither
+/// registered nor in the built-in EPSG and ESRI tables are tried as both EPSG
+/// and ESRI codes, as the built-in ESRI table only covers a few common CRS.
+fn definitions(srid: i64) -> Vec<String> {
+    let Ok(srid) = srid.try_into() else {
+        return vec![format!("EPSG:{srid}")];
+    };
+    if let Some(definition) = crs::get_registered_crs(srid) {
+        return vec![definition];
+    }
+    match crs::get_proj_string(srid) {
+        Some(_) => vec![format!("{}:{srid}", crs::get_srid_authority(srid))],
+        None => vec![format!("EPSG:{srid}"), format!("ESRI:{srid}")],
+    }
+}
+
+/// Transformations between pairs of SRIDs created by libproj, which picks the
+/// most accurate datum transformation available. Pairs of CRS that libproj
+/// can't handle are cached as `None`.
+#[derive(Default)]
+pub struct TransformCache(HashMap<(i64, i64), Option<Proj>>);
+
+impl TransformCache {
+    pub fn get(&mut self, from: i64, to: i64) -> Option<&Proj> {
+        self.0
+            .entry((from, to))
+            .or_insert_with(|| {
+                let (from, to) = (definitions(from), definitions(to));
+                from.iter()
+                    .flat_map(|from| to.iter().map(move |to| (from, to)))
+                    .find_map(|(from, to)| Proj::new_known_crs(from, to, None).ok())
+            })
+            .as_ref()
+    }
+}
+
+/// Returns the transformation between two CRS definitions, or `None` if
+/// libproj can't handle one of them.
+pub fn from_definitions(from: &str, to: &str) -> Option<Proj> {
+    Proj::new_known_crs(from.trim(), to.trim(), None).ok()
+}
+
+/// Transforms the coordinates of a geometry, in the traditional GIS order
+/// (longitude first). Z values are transformed along, so that heights follow
+/// vertical datum shifts, while missing Z values stay missing.
+pub fn transform(proj: &Proj, geom: &Geometry) -> GResult<Geometry> {
+    let error = |e: proj::ProjError| GError::GenericError(e.to_string());
+    geom.transform_xyz(|x, y, z| match z.is_nan() {
+        true => {
+            let (x, y) = proj.convert((x, y)).map_err(error)?;
+            Ok((x, y, z))
+        }
+        false => proj.convert((x, y, z)).map_err(error),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use geos::{Geom, Geometry};
+
+    use super::{transform, TransformCache};
+
+    #[test]
+    fn transform_keeps_missing_z() {
+        let mut cache = TransformCache::default();
+        let proj = cache.get(4326, 4978).expect("libproj knows both CRS");
+        let geom = Geometry::new_from_wkt("POINT (0 0)").unwrap();
+        let result = transform(proj, &geom).unwrap();
+        assert!(!result.has_z().unwrap());
+        assert!((result.get_x().unwrap() - 6_378_137.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn transform_z() {
+        let mut cache = TransformCache::default();
+        let proj = cache.get(4326, 4978).expect("libproj knows both CRS");
+        let geom = Geometry::new_from_wkt("POINT Z (90 0 100)").unwrap();
+        let result = transform(proj, &geom).unwrap();
+        assert!(result.get_x().unwrap().abs() < 1e-6);
+        assert!((result.get_y().unwrap() - 6_378_237.0).abs() < 1e-6);
+        assert!(result.get_z().unwrap().abs() < 1e-6);
+    }
+}
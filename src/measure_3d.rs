@@ -0,0 +1,247 @@
+use geos::{Error as GError, GResult, Geom, GeometryTypes::*};
+
+type Coord = [f64; 3];
+
+fn unsupported(geometry_type: impl std::fmt::Debug) -> GError {
+    let msg = format!("3D measures don't support geometries of type {geometry_type:?}");
+    GError::GenericError(msg)
+}
+
+/// Returns the coordinates of a point, linestring or linear ring, with Z
+/// values of 0 for geometries without Z.
+fn coords<T: Geom>(geom: &T) -> GResult<Vec<Coord>> {
+    let (has_z, has_m) = (geom.has_z()?, geom.has_m()?);
+    let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+    let coords = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+    Ok(coords
+        .chunks_exact(dimension)
+        .map(|c| [c[0], c[1], if has_z { c[2] } else { 0.0 }])
+        .collect())
+}
+
+fn sub(a: Coord, b: Coord) -> Coord {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: Coord, b: Coord) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn norm(a: Coord) -> f64 {
+    dot(a, a).sqrt()
+}
+
+fn line_length(coords: &[Coord]) -> f64 {
+    coords.windows(2).map(|w| norm(sub(w[1], w[0]))).sum()
+}
+
+/// Returns the 3D length of a geometry. As with `length`, the length of
+/// polygons is the length of their rings.
+pub fn length<T: Geom>(geom: &T) -> GResult<f64> {
+    match geom.geometry_type()? {
+        _ if geom.is_empty()? => Ok(0.0),
+        Point | MultiPoint => Ok(0.0),
+        LineString | LinearRing => Ok(line_length(&coords(geom)?)),
+        Polygon => {
+            let mut length = line_length(&coords(&geom.get_exterior_ring()?)?);
+            for n in 0..geom.get_num_interior_rings()? {
+                length += line_length(&coords(&geom.get_interior_ring_n(n)?)?);
+            }
+            Ok(length)
+        }
+        MultiLineString | MultiPolygon | GeometryCollection => (0..geom.get_num_geometries()?)
+            .map(|n| length(&geom.get_geometry_n(n)?))
+            .sum(),
+        other => Err(unsupported(other)),
+    }
+}
+
+fn cross(a: Coord, b: Coord) -> Coord {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn scale(a: Coord, factor: f64) -> Coord {
+    [a[0] * factor, a[1] * factor, a[2] * factor]
+}
+
+/// The inside of a polygon, lying in the plane of its exterior ring.
+struct Surface {
+    rings: Vec<Vec<Coord>>,
+    origin: Coord,
+    normal: Coord,
+}
+
+impl Surface {
+    /// Returns the surface of a polygon, or `None` if its exterior ring
+    /// doesn't span a plane.
+    fn new(rings: Vec<Vec<Coord>>) -> Option<Self> {
+        // The vector area of the ring, which also holds for concave rings.
+        let exterior = rings.first()?;
+        let origin = exterior[0];
+        let normal = exterior.windows(2).fold([0.0; 3], |normal, w| {
+            let [x, y, z] = cross(sub(w[0], origin), sub(w[1], origin));
+            [normal[0] + x, normal[1] + y, normal[2] + z]
+        });
+        let length = norm(normal);
+        (length > 0.0).then(|| Self {
+            origin,
+            normal: scale(normal, 1.0 / length),
+            rings,
+        })
+    }
+
+    /// Returns whether a point of the plane lies inside the polygon, following
+    /// the even-odd rule once projected along the main axis of the normal.
+    #[allow(clippy::many_single_char_names)]
+    fn contains(&self, point: Coord) -> bool {
+        let axis = (0..3)
+            .max_by(|&i, &j| self.normal[i].abs().total_cmp(&self.normal[j].abs()))
+            .unwrap_or(2);
+        let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+        let mut inside = false;
+        for ring in &self.rings {
+            for w in ring.windows(2) {
+                let (a, b) = (w[0], w[1]);
+                if (a[v] > point[v]) != (b[v] > point[v])
+                    && point[u] < a[u] + (point[v] - a[v]) * (b[u] - a[u]) / (b[v] - a[v])
+                {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+
+    /// Returns the distance between a segment and the inside of the polygon,
+    /// or infinity if it is reached through the boundary. Either the segment
+    /// crosses the inside, or one of its end points is the closest to it.
+    #[allow(clippy::many_single_char_names)]
+    fn segment_distance(&self, [p, q]: [Coord; 2]) -> f64 {
+        let (dp, dq) = (
+            dot(sub(p, self.origin), self.normal),
+            dot(sub(q, self.origin), self.normal),
+        );
+        if (dp <= 0.0) != (dq <= 0.0) || dp == 0.0 {
+            let t = if dp == 0.0 { 0.0 } else { dp / (dp - dq) };
+            if self.contains([
+                p[0] + (q[0] - p[0]) * t,
+                p[1] + (q[1] - p[1]) * t,
+                p[2] + (q[2] - p[2]) * t,
+            ]) {
+                return 0.0;
+            }
+        }
+        [(p, dp), (q, dq)]
+            .into_iter()
+            .filter(|&(c, d)| self.contains(sub(c, scale(self.normal, d))))
+            .map(|(_, d)| d.abs())
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+/// Collects the segments of a geometry, points being degenerate segments
+/// and polygons contributing the segments of their rings, along with the
+/// surfaces of its polygons.
+fn parts<T: Geom>(
+    geom: &T,
+    segments: &mut Vec<[Coord; 2]>,
+    surfaces: &mut Vec<Surface>,
+) -> GResult<()> {
+    match geom.geometry_type()? {
+        _ if geom.is_empty()? => Ok(()),
+        Point => {
+            let point = coords(geom)?[0];
+            segments.push([point, point]);
+            Ok(())
+        }
+        LineString | LinearRing => {
+            segments.extend(coords(geom)?.windows(2).map(|w| [w[0], w[1]]));
+            Ok(())
+        }
+        Polygon => {
+            let mut rings = vec![coords(&geom.get_exterior_ring()?)?];
+            for n in 0..geom.get_num_interior_rings()? {
+                rings.push(coords(&geom.get_interior_ring_n(n)?)?);
+            }
+            for ring in &rings {
+                segments.extend(ring.windows(2).map(|w| [w[0], w[1]]));
+            }
+            surfaces.extend(Surface::new(rings));
+            Ok(())
+        }
+        MultiPoint | MultiLineString | MultiPolygon | GeometryCollection => {
+            for n in 0..geom.get_num_geometries()? {
+                parts(&geom.get_geometry_n(n)?, segments, surfaces)?;
+            }
+            Ok(())
+        }
+        other => Err(unsupported(other)),
+    }
+}
+
+/// Returns the distance between two segments, following the closest points
+/// computation of Ericson's "Real-Time Collision Detection".
+#[allow(clippy::many_single_char_names, clippy::similar_names)]
+fn segment_distance([p1, q1]: [Coord; 2], [p2, q2]: [Coord; 2]) -> f64 {
+    let (d1, d2, r) = (sub(q1, p1), sub(q2, p2), sub(p1, p2));
+    let (a, e, f) = (dot(d1, d1), dot(d2, d2), dot(d2, r));
+    let (s, t) = if a <= 0.0 && e <= 0.0 {
+        (0.0, 0.0)
+    } else if a <= 0.0 {
+        (0.0, (f / e).clamp(0.0, 1.0))
+    } else {
+        let c = dot(d1, r);
+        if e <= 0.0 {
+            ((-c / a).clamp(0.0, 1.0), 0.0)
+        } else {
+            let b = dot(d1, d2);
+            let denom = a * e - b * b;
+            let s = if denom > 0.0 {
+                ((b * f - c * e) / denom).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let t = (b * s + f) / e;
+            if t < 0.0 {
+                ((-c / a).clamp(0.0, 1.0), 0.0)
+            } else if t > 1.0 {
+                (((b - c) / a).clamp(0.0, 1.0), 1.0)
+            } else {
+                (s, t)
+            }
+        }
+    };
+    let c1 = [p1[0] + d1[0] * s, p1[1] + d1[1] * s, p1[2] + d1[2] * s];
+    let c2 = [p2[0] + d2[0] * t, p2[1] + d2[1] * t, p2[2] + d2[2] * t];
+    norm(sub(c1, c2))
+}
+
+/// Returns the 3D distance between two geometries, or NaN if one of them is
+/// empty. Missing Z values are 0, and polygons are the planar surfaces
+/// bounded by their rings.
+pub fn distance<T: Geom, U: Geom>(a: &T, b: &U) -> GResult<f64> {
+    let (mut a_segments, mut a_surfaces) = (Vec::new(), Vec::new());
+    let (mut b_segments, mut b_surfaces) = (Vec::new(), Vec::new());
+    parts(a, &mut a_segments, &mut a_surfaces)?;
+    parts(b, &mut b_segments, &mut b_surfaces)?;
+    if a_segments.is_empty() || b_segments.is_empty() {
+        return Ok(f64::NAN);
+    }
+    let between_segments = a_segments
+        .iter()
+        .flat_map(|a| b_segments.iter().map(|b| segment_distance(*a, *b)));
+    let to_surfaces = |segments: &[[Coord; 2]], surfaces: &[Surface]| {
+        segments
+            .iter()
+            .flat_map(|segment| surfaces.iter().map(|s| s.segment_distance(*segment)))
+            .fold(f64::INFINITY, f64::min)
+    };
+    Ok(between_segments
+        .fold(f64::INFINITY, f64::min)
+        .min(to_surfaces(&a_segments, &b_surfaces))
+        .min(to_surfaces(&b_segments, &a_surfaces)))
+}
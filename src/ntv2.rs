@@ -0,0 +1,263 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, Mutex, PoisonError},
+};
+
+use geos::{Error as GError, GResult};
+use scroll::{Endian, Pread};
+
+const RECORD: usize = 16;
+const HEADER_RECORDS: usize = 11;
+const MAX_ITERATIONS: usize = 10;
+
+/// Grids loaded from files, by path.
+static GRIDS: LazyLock<Mutex<HashMap<String, Arc<Grid>>>> = LazyLock::new(Default::default);
+
+fn invalid() -> GError {
+    GError::GenericError("Invalid NTv2 grid file".into())
+}
+
+/// A NTv2 sub-grid. Coordinates are in arc-seconds, with longitudes positive
+/// west, and nodes are ordered from south to north, then from east to west.
+struct SubGrid {
+    parent: String,
+    name: String,
+    south: f64,
+    north: f64,
+    east: f64,
+    west: f64,
+    lat_inc: f64,
+    lon_inc: f64,
+    columns: usize,
+    /// Latitude and longitude shifts of each node, in arc-seconds.
+    shifts: Vec<(f64, f64)>,
+}
+
+impl SubGrid {
+    fn contains(&self, lat: f64, lon: f64) -> bool {
+        (self.south..=self.north).contains(&lat) && (self.east..=self.west).contains(&lon)
+    }
+
+    /// Returns the bilinear interpolation of the shifts of the surrounding nodes.
+    #[allow(clippy::cast_sign_loss, clippy::cast_precision_loss)]
+    fn shift(&self, lat: f64, lon: f64) -> (f64, f64) {
+        let rows = self.shifts.len() / self.columns;
+        let x = (lon - self.east) / self.lon_inc;
+        let y = (lat - self.south) / self.lat_inc;
+        // Points on the north and west edges are interpolated from the last cell.
+        let column = (x.floor() as usize).min(self.columns.saturating_sub(2));
+        let row = (y.floor() as usize).min(rows.saturating_sub(2));
+        let (fx, fy) = (x - column as f64, y - row as f64);
+        let node = |row: usize, column: usize| self.shifts[row * self.columns + column];
+        let (a, b) = (node(row, column), node(row, column + 1));
+        let (c, d) = (node(row + 1, column), node(row + 1, column + 1));
+        let interpolate = |a: f64, b: f64, c: f64, d: f64| {
+            (a * (1.0 - fx) + b * fx) * (1.0 - fy) + (c * (1.0 - fx) + d * fx) * fy
+        };
+        (
+            interpolate(a.0, b.0, c.0, d.0),
+            interpolate(a.1, b.1, c.1, d.1),
+        )
+    }
+}
+
+/// A NTv2 datum shift grid, such as the Canadian `NTV2_0.GSB` or the British
+/// `OSTN15_NTv2_OSGBtoETRS.gsb`, or a NADCON grid such as `conus`, which is
+/// read as a single NTv2 sub-grid.
+pub struct Grid(Vec<SubGrid>);
+
+impl Grid {
+    #[allow(clippy::cast_sign_loss)]
+    pub fn parse(data: &[u8]) -> GResult<Self> {
+        let endian = match data.pread_with::<i32>(8, Endian::Little) {
+            Ok(11) => Endian::Little,
+            Ok(_) => Endian::Big,
+            Err(_) => return Err(invalid()),
+        };
+        let int = |offset: usize| -> GResult<usize> {
+            let value = data
+                .pread_with::<i32>(offset + 8, endian)
+                .map_err(|_| invalid())?;
+            usize::try_from(value).map_err(|_| invalid())
+        };
+        let float = |offset: usize| -> GResult<f64> {
+            data.pread_with::<f64>(offset + 8, endian)
+                .map_err(|_| invalid())
+        };
+        let text = |offset: usize| -> GResult<String> {
+            let bytes = data.get(offset + 8..offset + RECORD).ok_or_else(invalid)?;
+            Ok(String::from_utf8_lossy(bytes)
+                .trim_end_matches(char::from(0))
+                .trim()
+                .to_owned())
+        };
+        if !text(3 * RECORD)?.eq_ignore_ascii_case("SECONDS") {
+            return Err(GError::GenericError(
+                "Only NTv2 grids in seconds are supported".into(),
+            ));
+        }
+        let mut offset = int(0)? * RECORD;
+        let subgrids = (0..int(2 * RECORD)?)
+            .map(|_| {
+                let count = int(offset + 10 * RECORD)?;
+                let mut subgrid = SubGrid {
+                    name: text(offset)?,
+                    parent: text(offset + RECORD)?,
+                    south: float(offset + 4 * RECORD)?,
+                    north: float(offset + 5 * RECORD)?,
+                    east: float(offset + 6 * RECORD)?,
+                    west: float(offset + 7 * RECORD)?,
+                    lat_inc: float(offset + 8 * RECORD)?,
+                    lon_inc: float(offset + 9 * RECORD)?,
+                    columns: 0,
+                    shifts: Vec::with_capacity(count),
+                };
+                subgrid.columns =
+                    ((subgrid.west - subgrid.east) / subgrid.lon_inc).round() as usize + 1;
+                offset += HEADER_RECORDS * RECORD;
+                for _ in 0..count {
+                    let lat = data
+                        .pread_with::<f32>(offset, endian)
+                        .map_err(|_| invalid())?;
+                    let lon = data
+                        .pread_with::<f32>(offset + 4, endian)
+                        .map_err(|_| invalid())?;
+                    subgrid.shifts.push((f64::from(lat), f64::from(lon)));
+                    offset += RECORD;
+                }
+                if subgrid.columns < 2
+                    || count % subgrid.columns != 0
+                    || count < 2 * subgrid.columns
+                {
+                    return Err(invalid());
+                }
+                Ok(subgrid)
+            })
+            .try_collect::<Vec<_>>()?;
+        Ok(Self(subgrids))
+    }
+
+    /// Parses a NADCON grid, given as its latitude (`.las`) and longitude
+    /// (`.los`) shift files. Records are `4 * (NC + 1)` bytes long, and the
+    /// rows of nodes follow the header, from south to north and from west to
+    /// east, with shifts in arc-seconds and longitude shifts positive west.
+    #[allow(clippy::cast_sign_loss, clippy::cast_precision_loss)]
+    pub fn parse_nadcon(las: &[u8], los: &[u8]) -> GResult<Self> {
+        let invalid = || GError::GenericError("Invalid NADCON grid file".into());
+        let int = |data: &[u8], offset: usize| -> GResult<usize> {
+            let value = data
+                .pread_with::<i32>(offset, Endian::Little)
+                .map_err(|_| invalid())?;
+            usize::try_from(value).map_err(|_| invalid())
+        };
+        let float = |data: &[u8], offset: usize| -> GResult<f64> {
+            data.pread_with::<f32>(offset, Endian::Little)
+                .map(f64::from)
+                .map_err(|_| invalid())
+        };
+        let (columns, rows) = (int(las, 64)?, int(las, 68)?);
+        if columns < 2 || rows < 2 || (int(los, 64)?, int(los, 68)?) != (columns, rows) {
+            return Err(invalid());
+        }
+        // Bounds are in degrees with longitudes positive east in the header.
+        let (x_min, dx, y_min, dy) = (
+            float(las, 76)?,
+            float(las, 80)?,
+            float(las, 84)?,
+            float(las, 88)?,
+        );
+        let record = 4 * (columns + 1);
+        let mut shifts = Vec::with_capacity(columns * rows);
+        for row in 0..rows {
+            let start = (row + 1) * record + 4;
+            // Nodes of NTv2 sub-grids are ordered from east to west.
+            for column in (0..columns).rev() {
+                let offset = start + 4 * column;
+                shifts.push((float(las, offset)?, float(los, offset)?));
+            }
+        }
+        let x_max = x_min + dx * (columns - 1) as f64;
+        Ok(Self(vec![SubGrid {
+            parent: "NONE".into(),
+            name: "NADCON".into(),
+            south: y_min * 3600.0,
+            north: (y_min + dy * (rows - 1) as f64) * 3600.0,
+            east: -x_max * 3600.0,
+            west: -x_min * 3600.0,
+            lat_inc: dy * 3600.0,
+            lon_inc: dx * 3600.0,
+            columns,
+            shifts,
+        }]))
+    }
+
+    /// Loads a NTv2 grid file, or a NADCON grid from the path of either its
+    /// `.las` or `.los` file, or returns it from the cache if it was already
+    /// loaded.
+    pub fn load(path: &str) -> GResult<Arc<Self>> {
+        let mut grids = GRIDS.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(grid) = grids.get(path) {
+            return Ok(grid.clone());
+        }
+        let read = |path: &str| {
+            std::fs::read(path)
+                .map_err(|e| GError::GenericError(format!("Couldn't read grid {path:?}: {e}")))
+        };
+        let nadcon = [".las", ".los"]
+            .into_iter()
+            .find_map(|extension| path.strip_suffix(extension));
+        let grid = match nadcon {
+            Some(stem) => Self::parse_nadcon(
+                &read(&format!("{stem}.las"))?,
+                &read(&format!("{stem}.los"))?,
+            )?,
+            None => Self::parse(&read(path)?)?,
+        };
+        let grid = Arc::new(grid);
+        grids.insert(path.to_owned(), grid.clone());
+        Ok(grid)
+    }
+
+    /// Returns the most detailed sub-grid containing a point, by descending
+    /// into the child grids of the containing parent grids.
+    fn find(&self, lat: f64, lon: f64) -> Option<&SubGrid> {
+        let mut found = self
+            .0
+            .iter()
+            .find(|g| g.parent.eq_ignore_ascii_case("NONE") && g.contains(lat, lon))?;
+        while let Some(child) = self
+            .0
+            .iter()
+            .find(|g| g.parent == found.name && g.contains(lat, lon))
+        {
+            found = child;
+        }
+        Some(found)
+    }
+
+    /// Returns the shifted longitude and latitude, in degrees, or `None` if
+    /// the point is outside of the grid.
+    fn forward(&self, lon: f64, lat: f64) -> Option<(f64, f64)> {
+        let (lat_s, lon_s) = (lat * 3600.0, -lon * 3600.0);
+        let (dlat, dlon) = self.find(lat_s, lon_s)?.shift(lat_s, lon_s);
+        Some((lon - dlon / 3600.0, lat + dlat / 3600.0))
+    }
+
+    /// Applies the grid shift to a longitude and latitude in degrees. The
+    /// inverse shift is computed iteratively.
+    pub fn apply(&self, lon: f64, lat: f64, inverse: bool) -> Option<(f64, f64)> {
+        if !inverse {
+            return self.forward(lon, lat);
+        }
+        let (mut x, mut y) = (lon, lat);
+        for _ in 0..MAX_ITERATIONS {
+            let (fx, fy) = self.forward(x, y)?;
+            let (dx, dy) = (fx - lon, fy - lat);
+            (x, y) = (x - dx, y - dy);
+            if dx.abs() < 1e-12 && dy.abs() < 1e-12 {
+                break;
+            }
+        }
+        Some((x, y))
+    }
+}
@@ -0,0 +1,194 @@
+use crate::args::PartitionScheme;
+
+/// Bounds as `[xmin, ymin, xmax, ymax]`.
+pub type Bounds = [f64; 4];
+
+/// Returns the bounds covering all the given bounds.
+pub fn union_bounds(bounds: impl IntoIterator<Item = Bounds>) -> Bounds {
+    bounds.into_iter().fold(
+        [
+            f64::INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NEG_INFINITY,
+        ],
+        |[xmin, ymin, xmax, ymax], [x0, y0, x1, y1]| {
+            [xmin.min(x0), ymin.min(y0), xmax.max(x1), ymax.max(y1)]
+        },
+    )
+}
+
+/// Returns the range of cells overlapped by an interval, on a grid of `count`
+/// cells between `min` and `max`. Intervals outside of the grid are clamped
+/// to it.
+#[allow(clippy::cast_sign_loss, clippy::cast_precision_loss)]
+fn cell_range(start: f64, end: f64, min: f64, max: f64, count: usize) -> (usize, usize) {
+    let cell = |value: f64| {
+        let cell = ((value - min) / (max - min) * count as f64).floor();
+        // The cell of a zero-width grid is NaN, which is cast to 0.
+        cell.max(0.0).min((count - 1) as f64) as usize
+    };
+    (cell(start), cell(end))
+}
+
+/// Returns whether an interval overlaps a cell, which excludes its upper bound
+/// unless it is the last cell of the extent.
+fn overlaps(start: f64, end: f64, cell_min: f64, cell_max: f64, is_last: bool) -> bool {
+    end >= cell_min && (start < cell_max || (is_last && start <= cell_max))
+}
+
+struct Leaf {
+    bounds: Bounds,
+    centers: Vec<(f64, f64)>,
+    splittable: bool,
+}
+
+impl Leaf {
+    /// Splits the leaf in two at the median center along its longest side,
+    /// or gives it back marked as unsplittable if all centers are equal.
+    fn split(mut self) -> Result<(Self, Self), Self> {
+        let [xmin, ymin, xmax, ymax] = self.bounds;
+        let axis = usize::from(ymax - ymin > xmax - xmin);
+        let coord = |center: &(f64, f64)| if axis == 0 { center.0 } else { center.1 };
+        self.centers.sort_by(|a, b| coord(a).total_cmp(&coord(b)));
+        let median = coord(&self.centers[self.centers.len() / 2]);
+        let mut index = self.centers.partition_point(|c| coord(c) < median);
+        if index == 0 {
+            // The lower half only holds centers equal to the median, so the
+            // split moves up to the next center.
+            index = self.centers.partition_point(|c| coord(c) <= median);
+        }
+        if index == self.centers.len() {
+            self.splittable = false;
+            return Err(self);
+        }
+        // Centers may be outside of a user given extent.
+        let split = coord(&self.centers[index])
+            .max(self.bounds[axis])
+            .min(self.bounds[axis + 2]);
+        let high = self.centers.split_off(index);
+        let (mut low_bounds, mut high_bounds) = (self.bounds, self.bounds);
+        low_bounds[axis + 2] = split;
+        high_bounds[axis] = split;
+        Ok((
+            Self {
+                bounds: low_bounds,
+                centers: self.centers,
+                splittable: true,
+            },
+            Self {
+                bounds: high_bounds,
+                centers: high,
+                splittable: true,
+            },
+        ))
+    }
+}
+
+/// Assigns bounds to the partitions they overlap, among partitions tiling an
+/// extent.
+pub enum Partitioner {
+    /// A regular grid of `columns` by `rows` cells, numbered row by row.
+    Grid {
+        extent: Bounds,
+        columns: usize,
+        rows: usize,
+    },
+    /// The leaves of a K-D-B tree, which splits the extent at the median of
+    /// the geometry centers, so that partitions hold similar numbers of
+    /// geometries.
+    Kdb { extent: Bounds, leaves: Vec<Bounds> },
+}
+
+impl Partitioner {
+    /// Creates a partitioner of at least `n` partitions, or at most `n`
+    /// partitions for the K-D-B tree scheme.
+    #[allow(clippy::cast_sign_loss, clippy::cast_precision_loss)]
+    pub fn new(scheme: PartitionScheme, extent: Bounds, envelopes: &[Bounds], n: usize) -> Self {
+        let n = n.max(1);
+        match scheme {
+            PartitionScheme::Grid => {
+                let columns = (n as f64).sqrt().ceil() as usize;
+                let rows = n.div_ceil(columns);
+                Partitioner::Grid {
+                    extent,
+                    columns,
+                    rows,
+                }
+            }
+            PartitionScheme::Kdb => {
+                let centers = envelopes
+                    .iter()
+                    .map(|[xmin, ymin, xmax, ymax]| ((xmin + xmax) / 2.0, (ymin + ymax) / 2.0))
+                    .collect();
+                let mut leaves = vec![Leaf {
+                    bounds: extent,
+                    centers,
+                    splittable: true,
+                }];
+                while leaves.len() < n {
+                    // The most populated leaf is split first, the first one among ties.
+                    let Some(index) = leaves
+                        .iter()
+                        .enumerate()
+                        .rev()
+                        .filter(|(_, leaf)| leaf.splittable && leaf.centers.len() > 1)
+                        .max_by_key(|(_, leaf)| leaf.centers.len())
+                        .map(|(index, _)| index)
+                    else {
+                        break;
+                    };
+                    // Children replace their parent, so that partitions stay ordered.
+                    match leaves.remove(index).split() {
+                        Ok((low, high)) => {
+                            leaves.insert(index, high);
+                            leaves.insert(index, low);
+                        }
+                        Err(leaf) => leaves.insert(index, leaf),
+                    }
+                }
+                Partitioner::Kdb {
+                    extent,
+                    leaves: leaves.into_iter().map(|leaf| leaf.bounds).collect(),
+                }
+            }
+        }
+    }
+
+    /// Returns the identifiers of the partitions overlapped by some bounds.
+    pub fn partitions(&self, [xmin, ymin, xmax, ymax]: Bounds) -> Vec<u32> {
+        match self {
+            Partitioner::Grid {
+                extent,
+                columns,
+                rows,
+            } => {
+                let (x0, x1) = cell_range(xmin, xmax, extent[0], extent[2], *columns);
+                let (y0, y1) = cell_range(ymin, ymax, extent[1], extent[3], *rows);
+                (y0..=y1)
+                    .flat_map(|row| (x0..=x1).map(move |column| (row * columns + column) as u32))
+                    .collect()
+            }
+            Partitioner::Kdb { extent, leaves } => {
+                let clamp = |value: f64, min: f64, max: f64| value.max(min).min(max);
+                let (x0, x1) = (
+                    clamp(xmin, extent[0], extent[2]),
+                    clamp(xmax, extent[0], extent[2]),
+                );
+                let (y0, y1) = (
+                    clamp(ymin, extent[1], extent[3]),
+                    clamp(ymax, extent[1], extent[3]),
+                );
+                leaves
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, leaf)| {
+                        overlaps(x0, x1, leaf[0], leaf[2], leaf[2] >= extent[2])
+                            && overlaps(y0, y1, leaf[1], leaf[3], leaf[3] >= extent[3])
+                    })
+                    .map(|(index, _)| index as u32)
+                    .collect()
+            }
+        }
+    }
+}
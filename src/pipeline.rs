@@ -0,0 +1,340 @@
+use std::{f64::consts::PI, fmt::Write, sync::Arc};
+
+use geos::{Error as GError, GResult};
+use proj4rs::{adaptors::transform_xyz, Proj};
+
+use crate::{geoid::Geoid, ntv2::Grid};
+
+const ARCSECOND: f64 = PI / 648_000.0;
+pub const DATUM_PARAMS: [&str; 12] = [
+    "ellps", "datum", "a", "b", "rf", "f", "es", "e", "R", "towgs84", "nadgrids", "pm",
+];
+
+/// Parameters accepted by the projection steps, besides the datum ones.
+const PROJECTION_PARAMS: [&str; 24] = [
+    "lat_0", "lon_0", "lat_1", "lat_2", "lat_ts", "k", "k_0", "x_0", "y_0", "units", "to_meter",
+    "zone", "south", "axis", "alpha", "gamma", "lonc", "no_uoff", "no_off", "h", "sweep", "R_A",
+    "approx", "over",
+];
+
+/// Returns the parameters accepted by an operation, besides `+proj`, `+inv`
+/// and the datum ones.
+fn operation_params(name: &str) -> &'static [&'static str] {
+    match name {
+        "noop" | "cart" => &[],
+        "unitconvert" => &["xy_in", "xy_out", "z_in", "z_out"],
+        "axisswap" => &["order"],
+        "hgridshift" => &["grids"],
+        "vgridshift" => &["grids", "multiplier"],
+        "helmert" => &["x", "y", "z", "rx", "ry", "rz", "s", "convention"],
+        _ => &PROJECTION_PARAMS,
+    }
+}
+
+fn invalid(msg: impl std::fmt::Display) -> GError {
+    GError::GenericError(format!("Invalid PROJ pipeline: {msg}"))
+}
+
+struct Params<'a>(Vec<(&'a str, Option<&'a str>)>);
+
+impl<'a> Params<'a> {
+    fn parse(tokens: &[&'a str]) -> GResult<Self> {
+        tokens
+            .iter()
+            .map(|token| {
+                let param = token
+                    .strip_prefix('+')
+                    .ok_or_else(|| invalid(format!("unexpected token {token:?}")))?;
+                Ok(match param.split_once('=') {
+                    Some((key, value)) => (key, Some(value)),
+                    None => (param, None),
+                })
+            })
+            .try_collect::<Vec<_>>()
+            .map(Self)
+    }
+
+    /// Fails on the first parameter which is neither in `known`, nor `+proj`,
+    /// `+inv`, `+no_defs` or a datum parameter.
+    fn check_keys(&self, known: &[&str]) -> GResult<()> {
+        let is_known = |key: &str| {
+            ["proj", "inv", "no_defs"].contains(&key)
+                || DATUM_PARAMS.contains(&key)
+                || known.contains(&key)
+        };
+        match self.0.iter().find(|(key, _)| !is_known(key)) {
+            Some((key, _)) => Err(invalid(format!("unknown parameter +{key}"))),
+            None => Ok(()),
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&'a str> {
+        self.0.iter().find(|(k, _)| *k == key).and_then(|(_, v)| *v)
+    }
+
+    fn has(&self, key: &str) -> bool {
+        self.0.iter().any(|(k, _)| *k == key)
+    }
+
+    fn f64(&self, key: &str) -> GResult<f64> {
+        self.get(key).map_or(Ok(0.0), |value| {
+            value
+                .parse()
+                .map_err(|_| invalid(format!("invalid value for +{key}: {value:?}")))
+        })
+    }
+
+    fn to_proj_string(&self, name: &str, keys: Option<&[&str]>) -> String {
+        let mut out = format!("+proj={name}");
+        for (key, value) in &self.0 {
+            if *key == "proj" || *key == "inv" || keys.is_some_and(|keys| !keys.contains(key)) {
+                continue;
+            }
+            let _ = match value {
+                Some(value) => write!(out, " +{key}={value}"),
+                None => write!(out, " +{key}"),
+            };
+        }
+        out
+    }
+}
+
+fn unit_factor(unit: Option<&str>) -> GResult<f64> {
+    match unit {
+        None | Some("m" | "rad") => Ok(1.0),
+        Some("deg") => Ok(PI / 180.0),
+        Some("km") => Ok(1000.0),
+        Some("ft") => Ok(0.3048),
+        Some("us-ft") => Ok(1200.0 / 3937.0),
+        Some(unit) => Err(invalid(format!("unsupported unit {unit:?}"))),
+    }
+}
+
+enum Operation {
+    /// Projects geodetic coordinates, in radians, with `to`.
+    Projection {
+        from: Proj,
+        to: Proj,
+    },
+    UnitConvert {
+        xy: f64,
+        z: f64,
+    },
+    AxisSwap {
+        order: Vec<i8>,
+    },
+    /// Shifts geodetic coordinates, in radians, with the first grid covering
+    /// them.
+    GridShift {
+        grids: Vec<Arc<Grid>>,
+    },
+    /// Adds the geoid height, scaled by `multiplier`, to the heights of
+    /// geodetic coordinates in radians.
+    VerticalGridShift {
+        geoid: Geoid,
+        multiplier: f64,
+    },
+    /// Bursa-Wolf transformation of geocentric coordinates, with the rotations
+    /// in radians using the position vector convention.
+    Helmert {
+        translation: [f64; 3],
+        rotation: [f64; 3],
+        scale: f64,
+    },
+    Noop,
+}
+
+struct Step {
+    operation: Operation,
+    inverse: bool,
+}
+
+impl Step {
+    fn parse(params: &Params) -> GResult<Self> {
+        let inverse = params.has("inv");
+        let name = params
+            .get("proj")
+            .ok_or_else(|| invalid("step without +proj"))?;
+        // As with PROJ, the datum parameters are parsed for all operations, so
+        // that invalid values are reported even where they aren't used.
+        let from = params.to_proj_string("longlat", Some(DATUM_PARAMS.as_slice()));
+        let from = Proj::from_proj_string(&from).map_err(invalid)?;
+        let operation = match name {
+            "noop" => Operation::Noop,
+            "unitconvert" => Operation::UnitConvert {
+                xy: unit_factor(params.get("xy_in"))? / unit_factor(params.get("xy_out"))?,
+                z: unit_factor(params.get("z_in"))? / unit_factor(params.get("z_out"))?,
+            },
+            "axisswap" => {
+                let order = params
+                    .get("order")
+                    .ok_or_else(|| invalid("axisswap without +order"))?
+                    .split(',')
+                    .map(|axis| match axis.parse::<i8>() {
+                        Ok(axis @ (-3..=-1 | 1..=3)) => Ok(axis),
+                        _ => Err(invalid(format!("invalid axisswap axis {axis:?}"))),
+                    })
+                    .try_collect::<Vec<_>>()?;
+                Operation::AxisSwap { order }
+            }
+            "hgridshift" => {
+                let grids = params
+                    .get("grids")
+                    .ok_or_else(|| invalid("hgridshift without +grids"))?
+                    .split(',')
+                    // Grids prefixed by `@` are optional, and skipped if they can't be loaded.
+                    .filter_map(|path| match path.strip_prefix('@') {
+                        Some(path) => Grid::load(path).ok().map(Ok),
+                        None => Some(Grid::load(path)),
+                    })
+                    .try_collect::<Vec<_>>()?;
+                Operation::GridShift { grids }
+            }
+            "vgridshift" => {
+                let grids = params
+                    .get("grids")
+                    .ok_or_else(|| invalid("vgridshift without +grids"))?;
+                // As with PROJ, the forward direction converts ellipsoidal heights
+                // to heights above the geoid.
+                let multiplier = match params.has("multiplier") {
+                    true => params.f64("multiplier")?,
+                    false => -1.0,
+                };
+                Operation::VerticalGridShift {
+                    geoid: Geoid::load(grids)?,
+                    multiplier,
+                }
+            }
+            "helmert" => {
+                let [rx, ry, rz] = [params.f64("rx")?, params.f64("ry")?, params.f64("rz")?];
+                let sign = match params.get("convention") {
+                    Some("position_vector") => 1.0,
+                    Some("coordinate_frame") => -1.0,
+                    None if rx == 0.0 && ry == 0.0 && rz == 0.0 => 1.0,
+                    _ => return Err(invalid("helmert rotations require a valid +convention")),
+                };
+                Operation::Helmert {
+                    translation: [params.f64("x")?, params.f64("y")?, params.f64("z")?],
+                    rotation: [rx, ry, rz].map(|angle| sign * angle * ARCSECOND),
+                    scale: 1.0 + params.f64("s")? * 1e-6,
+                }
+            }
+            name => {
+                let name = if name == "cart" { "geocent" } else { name };
+                let to = params.to_proj_string(name, None);
+                Operation::Projection {
+                    from,
+                    to: Proj::from_proj_string(&to).map_err(invalid)?,
+                }
+            }
+        };
+        Ok(Self { operation, inverse })
+    }
+
+    fn apply(&self, [x, y, z]: [f64; 3]) -> GResult<[f64; 3]> {
+        Ok(match &self.operation {
+            Operation::Noop => [x, y, z],
+            Operation::Projection { from, to } => {
+                let (from, to) = if self.inverse { (to, from) } else { (from, to) };
+                let (x, y, z) = transform_xyz(from, to, x, y, z).map_err(invalid)?;
+                [x, y, z]
+            }
+            Operation::UnitConvert { xy, z: z_factor } => match self.inverse {
+                false => [x * xy, y * xy, z * z_factor],
+                true => [x / xy, y / xy, z / z_factor],
+            },
+            Operation::GridShift { grids } => {
+                let (lon, lat) = grids
+                    .iter()
+                    .find_map(|grid| grid.apply(x.to_degrees(), y.to_degrees(), self.inverse))
+                    .ok_or_else(|| invalid("point outside of the hgridshift grids"))?;
+                [lon.to_radians(), lat.to_radians(), z]
+            }
+            Operation::VerticalGridShift { geoid, multiplier } => {
+                let shift = multiplier * geoid.height(x.to_degrees(), y.to_degrees())?;
+                match self.inverse {
+                    false => [x, y, z + shift],
+                    true => [x, y, z - shift],
+                }
+            }
+            Operation::AxisSwap { order } => {
+                let input = [x, y, z];
+                let mut output = input;
+                for (i, &axis) in order.iter().enumerate() {
+                    let j = usize::from(axis.unsigned_abs()) - 1;
+                    let sign = f64::from(axis.signum());
+                    match self.inverse {
+                        false => output[i] = sign * input[j],
+                        true => output[j] = sign * input[i],
+                    }
+                }
+                output
+            }
+            Operation::Helmert {
+                translation: [tx, ty, tz],
+                rotation: [rx, ry, rz],
+                scale,
+            } => match self.inverse {
+                false => [
+                    tx + scale * (x - rz * y + ry * z),
+                    ty + scale * (rz * x + y - rx * z),
+                    tz + scale * (-ry * x + rx * y + z),
+                ],
+                // The rotation matrix is inverted by transposing it, which holds
+                // for the small angles of datum transformations.
+                true => {
+                    let [x, y, z] = [(x - tx) / scale, (y - ty) / scale, (z - tz) / scale];
+                    [
+                        x + rz * y - ry * z,
+                        -rz * x + y + rx * z,
+                        ry * x - rx * y + z,
+                    ]
+                }
+            },
+        })
+    }
+}
+
+/// A PROJ transformation pipeline, such as
+/// `+proj=pipeline +step +proj=unitconvert +xy_in=deg +xy_out=rad +step +proj=merc`.
+///
+/// Projection steps are delegated to `proj4rs`, and the `unitconvert`,
+/// `axisswap`, `helmert`, `hgridshift` (with NTv2 grids), `vgridshift` (with
+/// GTX grids), `cart` and `noop` operations are supported, along with `+inv`
+/// steps. Parameters given before the first step apply to all steps, and
+/// unknown parameters are rejected.
+pub struct Pipeline(Vec<Step>);
+
+impl Pipeline {
+    pub fn new(definition: &str) -> GResult<Self> {
+        let tokens = definition.split_whitespace().collect::<Vec<_>>();
+        let mut steps = tokens.split(|token| *token == "+step");
+        let globals = Params::parse(steps.next().unwrap_or_default())?;
+        if globals.get("proj") != Some("pipeline") {
+            return Err(invalid("expected +proj=pipeline"));
+        }
+        globals.check_keys(&PROJECTION_PARAMS)?;
+        let globals = globals.0.into_iter().filter(|(key, _)| *key != "proj");
+        let globals = globals.collect::<Vec<_>>();
+        steps
+            .map(|tokens| {
+                let mut params = Params::parse(tokens)?;
+                params.check_keys(params.get("proj").map_or(&[][..], operation_params))?;
+                params.0.extend(globals.iter().copied());
+                Step::parse(&params)
+            })
+            .try_collect::<Vec<_>>()
+            .map(Self)
+    }
+
+    /// Transforms a coordinate through all the steps. Missing Z values are
+    /// taken as zero, and stay missing.
+    pub fn apply(&self, x: f64, y: f64, z: f64) -> GResult<(f64, f64, f64)> {
+        let mut coords = [x, y, if z.is_nan() { 0.0 } else { z }];
+        for step in &self.0 {
+            coords = step.apply(coords)?;
+        }
+        let [x, y, new_z] = coords;
+        Ok((x, y, if z.is_nan() { z } else { new_z }))
+    }
+}
@@ -0,0 +1,62 @@
+use std::{cell::RefCell, mem::ManuallyDrop, rc::Rc};
+
+use geos::{GResult, Geom, Geometry, PreparedGeometry};
+
+/// Number of prepared geometries kept in the cache of each thread.
+const CAPACITY: usize = 16;
+
+thread_local! {
+    /// Prepared geometries by WKB, from the most to the least recently used.
+    /// Prepared geometries build their indexes lazily and can't be shared
+    /// between threads, so each thread keeps its own cache, without locking.
+    /// It isn't dropped when the thread exits, as the GEOS context of the
+    /// thread may already be gone by then.
+    static CACHE: RefCell<ManuallyDrop<Vec<(Vec<u8>, Rc<Entry>)>>> =
+        const { RefCell::new(ManuallyDrop::new(Vec::new())) };
+}
+
+/// A prepared geometry, along with the geometry it borrows.
+struct Entry {
+    // Declared first, so that it is dropped before the geometry.
+    prepared: PreparedGeometry<'static>,
+    _geom: Box<Geometry>,
+}
+
+impl Entry {
+    fn new(wkb: &[u8]) -> GResult<Self> {
+        let geom = Box::new(Geometry::new_from_wkb(wkb)?);
+        // SAFETY: the geometry is boxed, so it doesn't move with the entry,
+        // and it is never mutated nor dropped before the prepared geometry.
+        let borrowed: &'static Geometry = unsafe { &*std::ptr::from_ref(geom.as_ref()) };
+        Ok(Self {
+            prepared: borrowed.to_prepared_geom()?,
+            _geom: geom,
+        })
+    }
+}
+
+/// Calls `f` with the prepared geometry of a WKB, which is only prepared if
+/// it isn't among the most recently used ones of the current thread.
+pub fn with_prepared<R>(wkb: &[u8], f: impl FnOnce(&PreparedGeometry) -> GResult<R>) -> GResult<R> {
+    let cached = CACHE.with_borrow_mut(|cache| {
+        let position = cache.iter().position(|(key, _)| key.as_slice() == wkb)?;
+        let entry = cache.remove(position);
+        let prepared = entry.1.clone();
+        cache.insert(0, entry);
+        Some(prepared)
+    });
+    let entry = match cached {
+        Some(entry) => entry,
+        None => {
+            let entry = Rc::new(Entry::new(wkb)?);
+            CACHE.with_borrow_mut(|cache| {
+                cache.insert(0, (wkb.to_vec(), entry.clone()));
+                cache.truncate(CAPACITY);
+            });
+            entry
+        }
+    };
+    // The cache isn't borrowed while `f` runs, and the entry is kept alive by
+    // its reference count even if it gets evicted meanwhile.
+    f(&entry.prepared)
+}
@@ -0,0 +1,268 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use geos::{GResult, Geom, Geometry};
+
+use crate::wkb;
+
+const NODE_CAPACITY: usize = 10;
+
+#[derive(Clone, Copy)]
+struct Envelope {
+    xmin: f64,
+    ymin: f64,
+    xmax: f64,
+    ymax: f64,
+}
+
+impl From<[f64; 4]> for Envelope {
+    fn from([xmin, ymin, xmax, ymax]: [f64; 4]) -> Self {
+        Self {
+            xmin,
+            ymin,
+            xmax,
+            ymax,
+        }
+    }
+}
+
+impl Envelope {
+    fn of<T: Geom>(geom: &T) -> GResult<Self> {
+        Ok(Self {
+            xmin: geom.get_x_min()?,
+            ymin: geom.get_y_min()?,
+            xmax: geom.get_x_max()?,
+            ymax: geom.get_y_max()?,
+        })
+    }
+
+    fn union(self, other: Self) -> Self {
+        Self {
+            xmin: self.xmin.min(other.xmin),
+            ymin: self.ymin.min(other.ymin),
+            xmax: self.xmax.max(other.xmax),
+            ymax: self.ymax.max(other.ymax),
+        }
+    }
+
+    fn center(self) -> (f64, f64) {
+        ((self.xmin + self.xmax) / 2.0, (self.ymin + self.ymax) / 2.0)
+    }
+
+    fn intersects(self, other: Self) -> bool {
+        self.xmin <= other.xmax
+            && other.xmin <= self.xmax
+            && self.ymin <= other.ymax
+            && other.ymin <= self.ymax
+    }
+
+    /// Returns the minimum distance between two envelopes, which is a lower
+    /// bound of the distance between the geometries they contain.
+    fn distance(self, other: Self) -> f64 {
+        let dx = (other.xmin - self.xmax)
+            .max(self.xmin - other.xmax)
+            .max(0.0);
+        let dy = (other.ymin - self.ymax)
+            .max(self.ymin - other.ymax)
+            .max(0.0);
+        dx.hypot(dy)
+    }
+}
+
+enum Node {
+    Leaf(Envelope, usize),
+    Branch(Envelope, Vec<Node>),
+}
+
+impl Node {
+    fn envelope(&self) -> Envelope {
+        match self {
+            Node::Leaf(envelope, _) | Node::Branch(envelope, _) => *envelope,
+        }
+    }
+
+    fn branch(children: Vec<Node>) -> Self {
+        let envelope = children
+            .iter()
+            .map(Node::envelope)
+            .reduce(Envelope::union)
+            .expect("Branches shouldn't be empty");
+        Node::Branch(envelope, children)
+    }
+}
+
+/// Splits off the first `size` nodes of a vector.
+fn take_front(nodes: &mut Vec<Node>, size: usize) -> Vec<Node> {
+    let rest = nodes.split_off(size.min(nodes.len()));
+    std::mem::replace(nodes, rest)
+}
+
+/// Packs nodes into parent nodes with the Sort-Tile-Recursive algorithm,
+/// until a single root remains.
+#[allow(clippy::cast_sign_loss, clippy::cast_precision_loss)]
+fn pack(mut nodes: Vec<Node>) -> Option<Node> {
+    while nodes.len() > 1 {
+        let parents_count = nodes.len().div_ceil(NODE_CAPACITY);
+        let slice_size = NODE_CAPACITY * (parents_count as f64).sqrt().ceil() as usize;
+        nodes.sort_by(|a, b| a.envelope().center().0.total_cmp(&b.envelope().center().0));
+        let mut parents = Vec::with_capacity(parents_count);
+        while !nodes.is_empty() {
+            let mut slice = take_front(&mut nodes, slice_size);
+            slice.sort_by(|a, b| a.envelope().center().1.total_cmp(&b.envelope().center().1));
+            while !slice.is_empty() {
+                parents.push(Node::branch(take_front(&mut slice, NODE_CAPACITY)));
+            }
+        }
+        nodes = parents;
+    }
+    nodes.pop()
+}
+
+enum Item<'a> {
+    Node(&'a Node),
+    /// A geometry whose exact distance is known.
+    Geometry(usize),
+}
+
+/// A node or geometry to visit, ordered so that the closest one, with exact
+/// distances first, is at the top of the `BinaryHeap`.
+struct Candidate<'a> {
+    distance: f64,
+    item: Item<'a>,
+}
+
+impl Candidate<'_> {
+    fn key(&self) -> (usize, usize) {
+        match self.item {
+            Item::Geometry(index) => (0, index),
+            Item::Node(Node::Leaf(_, index)) => (1, *index),
+            Item::Node(Node::Branch(..)) => (2, 0),
+        }
+    }
+}
+
+impl Ord for Candidate<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .total_cmp(&self.distance)
+            .then_with(|| other.key().cmp(&self.key()))
+    }
+}
+
+impl PartialOrd for Candidate<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Candidate<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate<'_> {}
+
+/// A packed R-tree of geometries, built in bulk from all their envelopes.
+/// Unlike the GEOS `STRtree`, it can be queried from several threads at once.
+pub struct RTree {
+    root: Option<Node>,
+}
+
+impl RTree {
+    /// Builds the tree of the non-null and non-empty geometries of a slice.
+    pub fn new(geoms: &[Option<Geometry>]) -> GResult<Self> {
+        let mut leaves = Vec::with_capacity(geoms.len());
+        for (index, geom) in geoms.iter().enumerate() {
+            let Some(geom) = geom else { continue };
+            if !geom.is_empty()? {
+                leaves.push(Node::Leaf(Envelope::of(geom)?, index));
+            }
+        }
+        Ok(Self { root: pack(leaves) })
+    }
+
+    /// Builds the tree of the non-null and non-empty geometries of a slice of
+    /// WKB, whose envelopes are read without parsing the whole geometries.
+    pub fn from_wkb(values: &[Option<&[u8]>]) -> GResult<Self> {
+        let mut leaves = Vec::with_capacity(values.len());
+        for (index, value) in values.iter().enumerate() {
+            let Some(value) = value else { continue };
+            let envelope = wkb::envelope(value)?.map(Envelope::from);
+            if let Some(envelope) = envelope {
+                leaves.push(Node::Leaf(envelope, index));
+            }
+        }
+        Ok(Self { root: pack(leaves) })
+    }
+
+    /// Calls `callback` with the index of each geometry whose envelope
+    /// intersects the envelope of a geometry.
+    pub fn query<T: Geom>(&self, geom: &T, mut callback: impl FnMut(usize)) -> GResult<()> {
+        let Some(root) = &self.root else {
+            return Ok(());
+        };
+        if geom.is_empty()? {
+            return Ok(());
+        }
+        let envelope = Envelope::of(geom)?;
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            if !node.envelope().intersects(envelope) {
+                continue;
+            }
+            match node {
+                Node::Leaf(_, index) => callback(*index),
+                Node::Branch(_, children) => stack.extend(children),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the index and distance of the `k` geometries closest to a
+    /// geometry, from the closest to the furthest, given the distance to the
+    /// geometry of an index. Neighbors are found incrementally with a
+    /// best-first traversal of the tree.
+    pub fn nearest<T: Geom>(
+        &self,
+        geom: &T,
+        k: usize,
+        mut distance_to: impl FnMut(usize) -> GResult<f64>,
+    ) -> GResult<Vec<(usize, f64)>> {
+        let mut result = Vec::with_capacity(k);
+        let Some(root) = &self.root else {
+            return Ok(result);
+        };
+        if k == 0 || geom.is_empty()? {
+            return Ok(result);
+        }
+        let envelope = Envelope::of(geom)?;
+        let mut heap = BinaryHeap::from([Candidate {
+            distance: root.envelope().distance(envelope),
+            item: Item::Node(root),
+        }]);
+        while let Some(Candidate { distance, item }) = heap.pop() {
+            match item {
+                Item::Geometry(index) => {
+                    result.push((index, distance));
+                    if result.len() == k {
+                        break;
+                    }
+                }
+                Item::Node(Node::Leaf(_, index)) => {
+                    heap.push(Candidate {
+                        distance: distance_to(*index)?,
+                        item: Item::Geometry(*index),
+                    });
+                }
+                Item::Node(Node::Branch(_, children)) => {
+                    heap.extend(children.iter().map(|child| Candidate {
+                        distance: child.envelope().distance(envelope),
+                        item: Item::Node(child),
+                    }));
+                }
+            }
+        }
+        Ok(result)
+    }
+}
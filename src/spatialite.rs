@@ -0,0 +1,261 @@
+use geos::{CoordSeq, Error as GError, GResult, Geom, Geometry, GeometryTypes::*};
+use scroll::{Endian, IOread};
+
+const MBR_END: u8 = 0x7C;
+const ENTITY: u8 = 0x69;
+const END: u8 = 0xFE;
+
+fn invalid() -> GError {
+    GError::GenericError("Invalid SpatiaLite geometry blob".into())
+}
+
+/// Splits a SpatiaLite class type into its base type (1 to 7), its
+/// dimensions and whether its coordinates are compressed.
+fn split_class_type(class_type: u32) -> GResult<(u32, bool, bool, bool)> {
+    let compressed = class_type >= 1_000_000;
+    let class_type = class_type % 1_000_000;
+    let (has_z, has_m) = match class_type / 1000 {
+        0 => (false, false),
+        1 => (true, false),
+        2 => (false, true),
+        3 => (true, true),
+        _ => return Err(invalid()),
+    };
+    match class_type % 1000 {
+        base @ (2 | 3) => Ok((base, has_z, has_m, compressed)),
+        base @ 1..=7 if !compressed => Ok((base, has_z, has_m, compressed)),
+        _ => Err(GError::GenericError(format!(
+            "Invalid SpatiaLite geometry type: {class_type}"
+        ))),
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    endian: Endian,
+}
+
+impl Reader<'_> {
+    fn u8(&mut self) -> GResult<u8> {
+        self.data.ioread::<u8>().map_err(|_| invalid())
+    }
+
+    fn u32(&mut self) -> GResult<u32> {
+        self.data
+            .ioread_with::<u32>(self.endian)
+            .map_err(|_| invalid())
+    }
+
+    fn f32(&mut self) -> GResult<f32> {
+        self.data
+            .ioread_with::<f32>(self.endian)
+            .map_err(|_| invalid())
+    }
+
+    fn f64(&mut self) -> GResult<f64> {
+        self.data
+            .ioread_with::<f64>(self.endian)
+            .map_err(|_| invalid())
+    }
+
+    /// Reads `size` vertices. Compressed sequences store their first and last
+    /// vertices as doubles, and the others as float deltas from the previous
+    /// vertex, except for M values which are always doubles.
+    fn coord_seq(
+        &mut self,
+        size: usize,
+        has_z: bool,
+        has_m: bool,
+        compressed: bool,
+    ) -> GResult<CoordSeq> {
+        let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+        let mut buffer = Vec::with_capacity(size.min(self.data.len()) * dimension);
+        for i in 0..size {
+            if !compressed || i == 0 || i == size - 1 {
+                for _ in 0..dimension {
+                    buffer.push(self.f64()?);
+                }
+                continue;
+            }
+            let previous = buffer.len() - dimension;
+            for d in 0..dimension {
+                let value = if has_m && d == dimension - 1 {
+                    self.f64()?
+                } else {
+                    buffer[previous + d] + f64::from(self.f32()?)
+                };
+                buffer.push(value);
+            }
+        }
+        CoordSeq::new_from_buffer(&buffer, size, has_z, has_m)
+    }
+
+    fn line_string(&mut self, has_z: bool, has_m: bool, compressed: bool) -> GResult<Geometry> {
+        match self.u32()? as usize {
+            0 => Geometry::create_empty_line_string(),
+            size => Geometry::create_line_string(self.coord_seq(size, has_z, has_m, compressed)?),
+        }
+    }
+
+    fn polygon(&mut self, has_z: bool, has_m: bool, compressed: bool) -> GResult<Geometry> {
+        let num_rings = self.u32()?;
+        if num_rings == 0 {
+            return Geometry::create_empty_polygon();
+        }
+        let mut rings = (0..num_rings)
+            .map(|_| {
+                let size = self.u32()? as usize;
+                Geometry::create_linear_ring(self.coord_seq(size, has_z, has_m, compressed)?)
+            })
+            .try_collect::<Vec<_>>()?;
+        let exterior = rings.remove(0);
+        Geometry::create_polygon(exterior, rings)
+    }
+
+    fn geometry(&mut self, class_type: u32) -> GResult<Geometry> {
+        let (base, has_z, has_m, compressed) = split_class_type(class_type)?;
+        match base {
+            1 => Geometry::create_point(self.coord_seq(1, has_z, has_m, false)?),
+            2 => self.line_string(has_z, has_m, compressed),
+            3 => self.polygon(has_z, has_m, compressed),
+            _ => {
+                let geoms = (0..self.u32()?)
+                    .map(|_| match self.u8()? {
+                        ENTITY => {
+                            let class_type = self.u32()?;
+                            self.geometry(class_type)
+                        }
+                        _ => Err(invalid()),
+                    })
+                    .try_collect::<Vec<_>>()?;
+                match base {
+                    4 => Geometry::create_multipoint(geoms),
+                    5 => Geometry::create_multiline_string(geoms),
+                    6 => Geometry::create_multipolygon(geoms),
+                    _ => Geometry::create_geometry_collection(geoms),
+                }
+            }
+        }
+    }
+}
+
+/// Parses a geometry from the SpatiaLite internal BLOB format.
+pub fn from_spatialite(blob: &[u8]) -> GResult<Geometry> {
+    let [0x00, byte_order @ (0x00 | 0x01), ..] = *blob else {
+        return Err(invalid());
+    };
+    if blob.len() < 44 || blob[38] != MBR_END || blob[blob.len() - 1] != END {
+        return Err(invalid());
+    }
+    let endian = Endian::from(byte_order == 0x01);
+    let srid = (&blob[2..6])
+        .ioread_with::<i32>(endian)
+        .map_err(|_| invalid())?;
+    let mut reader = Reader {
+        data: &blob[39..blob.len() - 1],
+        endian,
+    };
+    let class_type = reader.u32()?;
+    let mut geom = reader.geometry(class_type)?;
+    if srid > 0 {
+        geom.set_srid(srid);
+    }
+    Ok(geom)
+}
+
+fn class_type<T: Geom>(geom: &T, has_z: bool, has_m: bool) -> GResult<u32> {
+    let base = match geom.geometry_type()? {
+        Point => 1,
+        LineString | LinearRing => 2,
+        Polygon => 3,
+        MultiPoint => 4,
+        MultiLineString => 5,
+        MultiPolygon => 6,
+        GeometryCollection => 7,
+        other => {
+            let msg = format!("SpatiaLite doesn't support geometries of type {other:?}");
+            return Err(GError::GenericError(msg));
+        }
+    };
+    Ok(base + 1000 * (u32::from(has_z) + 2 * u32::from(has_m)))
+}
+
+fn write_coords<T: Geom>(geom: &T, dimension: usize, blob: &mut Vec<u8>) -> GResult<()> {
+    if geom.is_empty()? {
+        return Ok(());
+    }
+    for value in geom.get_coord_seq()?.as_buffer(Some(dimension))? {
+        blob.extend(value.to_le_bytes());
+    }
+    Ok(())
+}
+
+fn write_geometry<T: Geom>(geom: &T, has_z: bool, has_m: bool, blob: &mut Vec<u8>) -> GResult<()> {
+    let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+    match geom.geometry_type()? {
+        Point if geom.is_empty()? => {
+            for _ in 0..dimension {
+                blob.extend(f64::NAN.to_le_bytes());
+            }
+        }
+        Point => write_coords(geom, dimension, blob)?,
+        LineString | LinearRing => {
+            blob.extend((geom.get_num_points()? as u32).to_le_bytes());
+            write_coords(geom, dimension, blob)?;
+        }
+        Polygon => {
+            if geom.is_empty()? {
+                blob.extend(0u32.to_le_bytes());
+                return Ok(());
+            }
+            let num_interiors = geom.get_num_interior_rings()?;
+            blob.extend((num_interiors as u32 + 1).to_le_bytes());
+            write_geometry(&geom.get_exterior_ring()?, has_z, has_m, blob)?;
+            for n in 0..num_interiors {
+                write_geometry(&geom.get_interior_ring_n(n)?, has_z, has_m, blob)?;
+            }
+        }
+        _ => {
+            let num_geometries = geom.get_num_geometries()?;
+            blob.extend((num_geometries as u32).to_le_bytes());
+            for n in 0..num_geometries {
+                let part = geom.get_geometry_n(n)?;
+                if part.geometry_type()?.is_collection() {
+                    return Err(GError::GenericError(
+                        "SpatiaLite doesn't support nested geometry collections".into(),
+                    ));
+                }
+                blob.push(ENTITY);
+                blob.extend(class_type(&part, has_z, has_m)?.to_le_bytes());
+                write_geometry(&part, has_z, has_m, blob)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Serializes a geometry to the little endian SpatiaLite internal BLOB format,
+/// without coordinates compression.
+pub fn to_spatialite<T: Geom>(geom: &T) -> GResult<Vec<u8>> {
+    let has_z = geom.has_z()?;
+    let has_m = geom.has_m()?;
+    let mbr = match geom.is_empty()? {
+        true => [0.0; 4],
+        false => [
+            geom.get_x_min()?,
+            geom.get_y_min()?,
+            geom.get_x_max()?,
+            geom.get_y_max()?,
+        ],
+    };
+    let mut blob = vec![0x00, 0x01];
+    blob.extend(geom.get_srid()?.to_le_bytes());
+    for value in mbr {
+        blob.extend(value.to_le_bytes());
+    }
+    blob.push(MBR_END);
+    blob.extend(class_type(geom, has_z, has_m)?.to_le_bytes());
+    write_geometry(geom, has_z, has_m, &mut blob)?;
+    blob.push(END);
+    Ok(blob)
+}
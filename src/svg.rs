@@ -0,0 +1,156 @@
+use std::fmt::Write;
+
+use geos::{Error as GError, GResult, Geom, GeometryTypes::*};
+
+struct Style {
+    flip_y: bool,
+    radius: f64,
+}
+
+impl Style {
+    fn y(&self, y: f64) -> f64 {
+        // Subtracting from zero avoids writing negative zeros.
+        if self.flip_y {
+            0.0 - y
+        } else {
+            y
+        }
+    }
+}
+
+fn write_path_data<T: Geom>(
+    geom: &T,
+    closed: bool,
+    style: &Style,
+    out: &mut String,
+) -> GResult<()> {
+    if geom.is_empty()? {
+        return Ok(());
+    }
+    let dimension = 2 + usize::from(geom.has_z()?) + usize::from(geom.has_m()?);
+    let buffer = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+    for (i, coords) in buffer.chunks_exact(dimension).enumerate() {
+        if !out.is_empty() {
+            out.push(' ');
+        }
+        let command = if i == 0 { 'M' } else { 'L' };
+        let _ = write!(out, "{command}{} {}", coords[0], style.y(coords[1]));
+    }
+    if closed {
+        out.push_str(" Z");
+    }
+    Ok(())
+}
+
+fn write_polygon_data<T: Geom>(geom: &T, style: &Style, out: &mut String) -> GResult<()> {
+    if geom.is_empty()? {
+        return Ok(());
+    }
+    write_path_data(&geom.get_exterior_ring()?, true, style, out)?;
+    for n in 0..geom.get_num_interior_rings()? {
+        write_path_data(&geom.get_interior_ring_n(n)?, true, style, out)?;
+    }
+    Ok(())
+}
+
+fn write_element<T: Geom>(geom: &T, style: &Style, out: &mut String) -> GResult<()> {
+    let mut data = String::new();
+    let attributes = match geom.geometry_type()? {
+        Point => {
+            if !geom.is_empty()? {
+                let (x, y) = (geom.get_x()?, style.y(geom.get_y()?));
+                let _ = write!(out, r#"<circle cx="{x}" cy="{y}" r="{}"/>"#, style.radius);
+            }
+            return Ok(());
+        }
+        LineString | LinearRing => {
+            write_path_data(geom, false, style, &mut data)?;
+            r#"fill="none""#
+        }
+        MultiLineString => {
+            for n in 0..geom.get_num_geometries()? {
+                write_path_data(&geom.get_geometry_n(n)?, false, style, &mut data)?;
+            }
+            r#"fill="none""#
+        }
+        Polygon => {
+            write_polygon_data(geom, style, &mut data)?;
+            r#"fill-rule="evenodd""#
+        }
+        MultiPolygon => {
+            for n in 0..geom.get_num_geometries()? {
+                write_polygon_data(&geom.get_geometry_n(n)?, style, &mut data)?;
+            }
+            r#"fill-rule="evenodd""#
+        }
+        MultiPoint | GeometryCollection => {
+            out.push_str("<g>");
+            for n in 0..geom.get_num_geometries()? {
+                write_element(&geom.get_geometry_n(n)?, style, out)?;
+            }
+            out.push_str("</g>");
+            return Ok(());
+        }
+        other => {
+            let msg = format!("SVG output doesn't support geometries of type {other:?}");
+            return Err(GError::GenericError(msg));
+        }
+    };
+    if !data.is_empty() {
+        let _ = write!(out, r#"<path d="{data}" {attributes}/>"#);
+    }
+    Ok(())
+}
+
+/// Renders a geometry as SVG elements: `circle` for points, and `path` for
+/// lines and polygons.
+///
+/// If `view_box` is true, the elements are wrapped in an `svg` element whose
+/// `viewBox` fits the geometry bounds, with a stroke width and point radius
+/// proportional to its size. If `flip_y` is true, y coordinates are negated so
+/// that the geometry isn't drawn upside down.
+pub fn to_svg<T: Geom>(geom: &T, view_box: bool, flip_y: bool) -> GResult<String> {
+    let mut out = String::new();
+    if !view_box {
+        let style = Style {
+            flip_y,
+            radius: 1.0,
+        };
+        write_element(geom, &style, &mut out)?;
+        return Ok(out);
+    }
+    let (x_min, y_min, x_max, y_max) = match geom.is_empty()? {
+        true => (0.0, 0.0, 0.0, 0.0),
+        false => (
+            geom.get_x_min()?,
+            geom.get_y_min()?,
+            geom.get_x_max()?,
+            geom.get_y_max()?,
+        ),
+    };
+    let (y_min, y_max) = match flip_y {
+        true => (0.0 - y_max, 0.0 - y_min),
+        false => (y_min, y_max),
+    };
+    let size = match (x_max - x_min).max(y_max - y_min) {
+        size if size > 0.0 => size,
+        _ => 1.0,
+    };
+    let margin = size * 0.05;
+    let style = Style {
+        flip_y,
+        radius: size * 0.02,
+    };
+    let _ = write!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" fill="currentColor" fill-opacity="0.5" stroke="currentColor" stroke-width="{}">"#,
+        x_min - margin,
+        y_min - margin,
+        x_max - x_min + 2.0 * margin,
+        y_max - y_min + 2.0 * margin,
+        size * 0.01,
+    );
+    write_element(geom, &style, &mut out)?;
+    out.push_str("</svg>");
+    Ok(out)
+}
@@ -0,0 +1,45 @@
+use crate::partition::Bounds;
+
+/// Calls `f` with each pair of indices of the left and right bounds which
+/// intersect, stopping at the first error.
+///
+/// Bounds of both sides are sorted by `xmin` and swept along the x axis, while
+/// keeping the bounds of each side that may still be crossed by the sweep
+/// line. Unlike an R-tree, only the sorted bounds are kept in memory, and
+/// pairs are passed to `f` as soon as they are found.
+pub fn for_each_intersecting_pair<E>(
+    left: &[Option<Bounds>],
+    right: &[Option<Bounds>],
+    mut f: impl FnMut(usize, usize) -> Result<(), E>,
+) -> Result<(), E> {
+    let mut events = left
+        .iter()
+        .enumerate()
+        .filter_map(|(index, bounds)| Some((bounds.as_ref()?, false, index)))
+        .chain(
+            right
+                .iter()
+                .enumerate()
+                .filter_map(|(index, bounds)| Some((bounds.as_ref()?, true, index))),
+        )
+        .collect::<Vec<_>>();
+    events.sort_by(|a, b| a.0[0].total_cmp(&b.0[0]));
+
+    let mut active: [Vec<(&Bounds, usize)>; 2] = [Vec::new(), Vec::new()];
+    for (bounds, is_right, index) in events {
+        let [xmin, ymin, _, ymax] = *bounds;
+        let others = &mut active[usize::from(!is_right)];
+        // Bounds ending before the sweep line can't intersect anything else.
+        others.retain(|(other, _)| other[2] >= xmin);
+        for (other, other_index) in &*others {
+            if other[1] <= ymax && ymin <= other[3] {
+                match is_right {
+                    true => f(*other_index, index)?,
+                    false => f(index, *other_index)?,
+                }
+            }
+        }
+        active[usize::from(is_right)].push((bounds, index));
+    }
+    Ok(())
+}
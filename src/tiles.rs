@@ -0,0 +1,130 @@
+use std::f64::consts::PI;
+
+use geos::{Error as GError, GResult, Geom, Geometry, GeometryTypes::Point};
+
+/// Half the width of the Web Mercator (EPSG:3857) extent, in meters.
+const ORIGIN_SHIFT: f64 = PI * 6_378_137.0;
+
+/// Highest zoom level, so that quadkeys and tile coordinates stay reasonable.
+pub const MAX_ZOOM: u8 = 30;
+
+fn invalid_tile(msg: impl std::fmt::Display) -> GError {
+    GError::GenericError(format!("Invalid tile: {msg}"))
+}
+
+/// An XYZ tile, with the origin at the top left corner of the Web Mercator
+/// extent, as used by web maps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tile {
+    pub z: u8,
+    pub x: u32,
+    pub y: u32,
+}
+
+impl Tile {
+    pub fn new(z: u8, x: u32, y: u32) -> GResult<Self> {
+        if z > MAX_ZOOM {
+            return Err(invalid_tile(format!(
+                "zoom must be at most {MAX_ZOOM}, got {z}"
+            )));
+        }
+        if u64::from(x) >> z != 0 || u64::from(y) >> z != 0 {
+            return Err(invalid_tile(format!("{z}/{x}/{y} is outside of the grid")));
+        }
+        Ok(Self { z, x, y })
+    }
+
+    /// Parses a Bing Maps quadkey, whose digits give the quadrant of each
+    /// zoom level.
+    pub fn from_quadkey(quadkey: &str) -> GResult<Self> {
+        let z = u8::try_from(quadkey.len())
+            .ok()
+            .filter(|z| *z <= MAX_ZOOM)
+            .ok_or_else(|| invalid_tile(format!("quadkey {quadkey:?} is too long")))?;
+        let (mut x, mut y) = (0, 0);
+        for digit in quadkey.bytes() {
+            let quadrant = match digit {
+                b'0'..=b'3' => u32::from(digit - b'0'),
+                _ => return Err(invalid_tile(format!("invalid quadkey {quadkey:?}"))),
+            };
+            x = (x << 1) | (quadrant & 1);
+            y = (y << 1) | (quadrant >> 1);
+        }
+        Ok(Self { z, x, y })
+    }
+
+    /// Returns the tile containing a Web Mercator coordinate, which is
+    /// clamped to the extent of the grid.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn containing(x: f64, y: f64, z: u8) -> Self {
+        let n = f64::from(1u32 << z);
+        let cell = |value: f64| (value * n).floor().clamp(0.0, n - 1.0) as u32;
+        Self {
+            z,
+            x: cell((x + ORIGIN_SHIFT) / (2.0 * ORIGIN_SHIFT)),
+            y: cell((ORIGIN_SHIFT - y) / (2.0 * ORIGIN_SHIFT)),
+        }
+    }
+
+    /// Returns the bounds of the tile as `[xmin, ymin, xmax, ymax]`, in
+    /// EPSG:3857 or EPSG:4326.
+    pub fn bounds(self, srid: i32) -> GResult<[f64; 4]> {
+        let size = 2.0 * ORIGIN_SHIFT / f64::from(1u32 << self.z);
+        let xmin = -ORIGIN_SHIFT + f64::from(self.x) * size;
+        let ymax = ORIGIN_SHIFT - f64::from(self.y) * size;
+        let (xmax, ymin) = (xmin + size, ymax - size);
+        match srid {
+            3857 => Ok([xmin, ymin, xmax, ymax]),
+            4326 => {
+                let lon = |x: f64| x / ORIGIN_SHIFT * 180.0;
+                let lat = |y: f64| (y / ORIGIN_SHIFT * PI).sinh().atan().to_degrees();
+                Ok([lon(xmin), lat(ymin), lon(xmax), lat(ymax)])
+            }
+            srid => Err(GError::GenericError(format!(
+                "Tiles can only be created in EPSG:3857 or EPSG:4326, got SRID {srid}"
+            ))),
+        }
+    }
+
+    /// Returns the polygon of the tile, in EPSG:3857 or EPSG:4326.
+    pub fn to_polygon(self, srid: i32) -> GResult<Geometry> {
+        let [xmin, ymin, xmax, ymax] = self.bounds(srid)?;
+        let mut polygon = Geometry::create_rectangle(xmin, ymin, xmax, ymax)?;
+        polygon.set_srid(srid);
+        Ok(polygon)
+    }
+}
+
+/// Returns the tile containing a point, or `None` if it is empty. Points
+/// must be in EPSG:3857 or EPSG:4326, which is assumed for points without
+/// SRID.
+pub fn tile_of<T: Geom>(geom: &T, z: u8) -> GResult<Option<Tile>> {
+    if z > MAX_ZOOM {
+        return Err(invalid_tile(format!(
+            "zoom must be at most {MAX_ZOOM}, got {z}"
+        )));
+    }
+    let geometry_type = geom.geometry_type()?;
+    if geometry_type != Point {
+        return Err(GError::GenericError(format!(
+            "Tiles can only be computed for points, got {geometry_type:?}"
+        )));
+    }
+    if geom.is_empty()? {
+        return Ok(None);
+    }
+    let (x, y) = (geom.get_x()?, geom.get_y()?);
+    let (x, y) = match geom.get_srid()? {
+        3857 => (x, y),
+        0 | 4326 => (
+            x / 180.0 * ORIGIN_SHIFT,
+            (PI / 4.0 + y.to_radians() / 2.0).tan().ln() / PI * ORIGIN_SHIFT,
+        ),
+        srid => {
+            return Err(GError::GenericError(format!(
+                "Tiles can only be computed for EPSG:3857 or EPSG:4326 points, got SRID {srid}"
+            )))
+        }
+    };
+    Ok(Some(Tile::containing(x, y, z)))
+}
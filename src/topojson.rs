@@ -0,0 +1,433 @@
+use std::collections::{HashMap, HashSet};
+
+use geos::{
+    CoordSeq, Error as GError, GResult, Geom, Geometry, GeometryTypes::*, STRtree, SpatialIndex,
+};
+use serde_json::{json, Map, Value};
+
+type Coord = [f64; 2];
+type Key = [u64; 2];
+
+fn key(coord: &Coord) -> Key {
+    // Adding zero turns negative zeros into positive ones.
+    [(coord[0] + 0.0).to_bits(), (coord[1] + 0.0).to_bits()]
+}
+
+fn coords<T: Geom>(geom: &T) -> GResult<Vec<Coord>> {
+    if geom.is_empty()? {
+        return Ok(vec![]);
+    }
+    let dimension = 2 + usize::from(geom.has_z()?) + usize::from(geom.has_m()?);
+    let buffer = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+    Ok(buffer
+        .chunks_exact(dimension)
+        .map(|c| [c[0], c[1]])
+        .collect())
+}
+
+fn line_string(coords: &[Coord]) -> GResult<Geometry> {
+    let buffer = coords.concat();
+    Geometry::create_line_string(CoordSeq::new_from_buffer(
+        &buffer,
+        coords.len(),
+        false,
+        false,
+    )?)
+}
+
+struct Line {
+    coords: Vec<Coord>,
+    closed: bool,
+}
+
+/// Geometry whose linework is replaced by indices into the list of lines.
+enum Object {
+    Null,
+    Point(Option<Coord>),
+    MultiPoint(Vec<Coord>),
+    LineString(usize),
+    MultiLineString(Vec<usize>),
+    Polygon(Vec<usize>),
+    MultiPolygon(Vec<Vec<usize>>),
+    GeometryCollection(Vec<Object>),
+}
+
+fn add_line<T: Geom>(geom: &T, closed: bool, lines: &mut Vec<Line>) -> GResult<usize> {
+    let mut coords = coords(geom)?;
+    coords.dedup_by_key(|coord| key(coord));
+    lines.push(Line { coords, closed });
+    Ok(lines.len() - 1)
+}
+
+fn add_polygon<T: Geom>(geom: &T, lines: &mut Vec<Line>) -> GResult<Vec<usize>> {
+    if geom.is_empty()? {
+        return Ok(vec![]);
+    }
+    let mut rings = vec![add_line(&geom.get_exterior_ring()?, true, lines)?];
+    for n in 0..geom.get_num_interior_rings()? {
+        rings.push(add_line(&geom.get_interior_ring_n(n)?, true, lines)?);
+    }
+    Ok(rings)
+}
+
+fn add_object<T: Geom>(geom: &T, lines: &mut Vec<Line>) -> GResult<Object> {
+    let parts = 0..geom.get_num_geometries()?;
+    Ok(match geom.geometry_type()? {
+        Point => Object::Point(coords(geom)?.first().copied()),
+        MultiPoint => {
+            let mut points = vec![];
+            for n in parts {
+                points.extend(coords(&geom.get_geometry_n(n)?)?);
+            }
+            Object::MultiPoint(points)
+        }
+        LineString => Object::LineString(add_line(geom, false, lines)?),
+        LinearRing => Object::LineString(add_line(geom, true, lines)?),
+        MultiLineString => Object::MultiLineString(
+            parts
+                .map(|n| add_line(&geom.get_geometry_n(n)?, false, lines))
+                .try_collect()?,
+        ),
+        Polygon => Object::Polygon(add_polygon(geom, lines)?),
+        MultiPolygon => Object::MultiPolygon(
+            parts
+                .map(|n| add_polygon(&geom.get_geometry_n(n)?, lines))
+                .try_collect()?,
+        ),
+        GeometryCollection => Object::GeometryCollection(
+            parts
+                .map(|n| add_object(&geom.get_geometry_n(n)?, lines))
+                .try_collect()?,
+        ),
+        other => {
+            let msg = format!("TopoJSON doesn't support geometries of type {other:?}");
+            return Err(GError::GenericError(msg));
+        }
+    })
+}
+
+/// Nodes all lines together, and snaps each line to the nodes lying on it, so
+/// that intersections and overlaps are represented by shared vertices.
+fn insert_nodes(lines: &mut [Line], tolerance: f64) -> GResult<()> {
+    let geoms = lines
+        .iter()
+        .map(|line| match line.coords.len() {
+            0 | 1 => Ok(None),
+            _ => line_string(&line.coords).map(Some),
+        })
+        .try_collect::<Vec<_>>()?;
+    let parts = geoms
+        .iter()
+        .flatten()
+        .map(Geom::clone)
+        .try_collect::<Vec<_>>()?;
+    if parts.is_empty() {
+        return Ok(());
+    }
+    let noded = Geometry::create_multiline_string(parts)?.node()?;
+    let edges = (0..noded.get_num_geometries()?)
+        .map(|n| Geom::clone(&noded.get_geometry_n(n)?))
+        .try_collect::<Vec<_>>()?;
+    let mut tree = STRtree::<usize>::with_capacity(edges.len())?;
+    for (index, edge) in edges.iter().enumerate() {
+        tree.insert(edge, index);
+    }
+    for (line, geom) in lines.iter_mut().zip(&geoms) {
+        let Some(geom) = geom else {
+            continue;
+        };
+        let own = line.coords.iter().map(key).collect::<HashSet<_>>();
+        let mut candidates = vec![];
+        tree.query(geom, |index| candidates.push(*index));
+        let mut nodes = vec![];
+        for index in candidates {
+            for coord in coords(&edges[index])? {
+                if !own.contains(&key(&coord)) {
+                    nodes.push(Geometry::create_point(CoordSeq::new_from_buffer(
+                        &coord, 1, false, false,
+                    )?)?);
+                }
+            }
+        }
+        if nodes.is_empty() {
+            continue;
+        }
+        let snapped = geom.snap(&Geometry::create_multipoint(nodes)?, tolerance)?;
+        line.coords = coords(&snapped)?;
+        line.coords.dedup_by_key(|coord| key(coord));
+    }
+    Ok(())
+}
+
+/// Returns the vertices where lines meet or diverge, as well as the endpoints of
+/// open lines.
+fn junctions(lines: &[Line]) -> HashSet<Key> {
+    let mut neighbours = HashMap::<Key, HashSet<Key>>::new();
+    for line in lines {
+        for segment in line.coords.windows(2) {
+            let (a, b) = (key(&segment[0]), key(&segment[1]));
+            neighbours.entry(a).or_default().insert(b);
+            neighbours.entry(b).or_default().insert(a);
+        }
+    }
+    let mut junctions = neighbours
+        .into_iter()
+        .filter(|(_, neighbours)| neighbours.len() != 2)
+        .map(|(key, _)| key)
+        .collect::<HashSet<_>>();
+    for line in lines.iter().filter(|line| !line.closed) {
+        junctions.extend(line.coords.first().map(key));
+        junctions.extend(line.coords.last().map(key));
+    }
+    junctions
+}
+
+/// Splits a line into arcs at junctions. Closed rings are first rotated to start at a
+/// junction, or at their smallest vertex if they don't have any, so that identical
+/// rings produce identical arcs.
+fn split(line: &Line, junctions: &HashSet<Key>) -> Vec<Vec<Coord>> {
+    let coords = &line.coords;
+    if coords.len() < 2 {
+        return vec![];
+    }
+    let rotated;
+    let coords = if line.closed && coords.len() > 2 {
+        let n = coords.len() - 1;
+        let start = (0..n)
+            .find(|&i| junctions.contains(&key(&coords[i])))
+            .or_else(|| {
+                (0..n).min_by(|&a, &b| {
+                    let (a, b) = (coords[a], coords[b]);
+                    a[0].total_cmp(&b[0]).then(a[1].total_cmp(&b[1]))
+                })
+            })
+            .unwrap_or(0);
+        rotated = coords[start..n]
+            .iter()
+            .chain(&coords[..=start])
+            .copied()
+            .collect::<Vec<_>>();
+        &rotated
+    } else {
+        coords
+    };
+    let mut arcs = vec![];
+    let mut current = vec![coords[0]];
+    for (i, coord) in coords.iter().enumerate().skip(1) {
+        current.push(*coord);
+        if i < coords.len() - 1 && junctions.contains(&key(coord)) {
+            arcs.push(std::mem::replace(&mut current, vec![*coord]));
+        }
+    }
+    arcs.push(current);
+    arcs
+}
+
+#[derive(Default)]
+struct Arcs {
+    arcs: Vec<Vec<Coord>>,
+    index: HashMap<Vec<Key>, i64>,
+}
+
+impl Arcs {
+    /// Returns the index of an arc, or its one's complement if the arc was
+    /// already added in the opposite direction.
+    fn get_or_insert(&mut self, arc: Vec<Coord>) -> i64 {
+        let keys = arc.iter().map(key).collect::<Vec<_>>();
+        if let Some(&index) = self.index.get(&keys) {
+            return index;
+        }
+        let reversed = keys.iter().rev().copied().collect::<Vec<_>>();
+        if let Some(&index) = self.index.get(&reversed) {
+            return !index;
+        }
+        let index = self.arcs.len() as i64;
+        self.index.insert(keys, index);
+        self.arcs.push(arc);
+        index
+    }
+}
+
+struct Transform {
+    translate: Coord,
+    scale: Coord,
+}
+
+impl Transform {
+    fn new(bbox: [f64; 4], quantization: u32) -> Self {
+        let scale = |min: f64, max: f64| match max - min {
+            extent if extent > 0.0 => extent / f64::from(quantization - 1),
+            _ => 1.0,
+        };
+        Self {
+            translate: [bbox[0], bbox[1]],
+            scale: [scale(bbox[0], bbox[2]), scale(bbox[1], bbox[3])],
+        }
+    }
+
+    fn quantize(&self, coord: &Coord) -> [i64; 2] {
+        [
+            ((coord[0] - self.translate[0]) / self.scale[0]).round() as i64,
+            ((coord[1] - self.translate[1]) / self.scale[1]).round() as i64,
+        ]
+    }
+
+    /// Quantizes and delta-encodes an arc, keeping at least two positions.
+    fn encode_arc(&self, arc: &[Coord]) -> Value {
+        let mut positions = vec![];
+        let mut previous = [0, 0];
+        for coord in arc {
+            let position = self.quantize(coord);
+            let delta = [position[0] - previous[0], position[1] - previous[1]];
+            if positions.is_empty() || delta != [0, 0] {
+                positions.push(json!(delta));
+                previous = position;
+            }
+        }
+        if positions.len() == 1 {
+            positions.push(json!([0, 0]));
+        }
+        Value::Array(positions)
+    }
+}
+
+fn encode_object(
+    object: &Object,
+    line_arcs: &[Vec<i64>],
+    position: &dyn Fn(&Coord) -> Value,
+) -> Value {
+    let rings = |rings: &[usize]| rings.iter().map(|&i| &line_arcs[i]).collect::<Vec<_>>();
+    match object {
+        Object::Null | Object::Point(None) => json!({ "type": null }),
+        Object::Point(Some(coord)) => json!({ "type": "Point", "coordinates": position(coord) }),
+        Object::MultiPoint(coords) => json!({
+            "type": "MultiPoint",
+            "coordinates": coords.iter().map(position).collect::<Vec<_>>(),
+        }),
+        Object::LineString(line) => json!({ "type": "LineString", "arcs": line_arcs[*line] }),
+        Object::MultiLineString(lines) => json!({
+            "type": "MultiLineString",
+            "arcs": rings(lines),
+        }),
+        Object::Polygon(polygon) => json!({ "type": "Polygon", "arcs": rings(polygon) }),
+        Object::MultiPolygon(polygons) => json!({
+            "type": "MultiPolygon",
+            "arcs": polygons.iter().map(|polygon| rings(polygon)).collect::<Vec<_>>(),
+        }),
+        Object::GeometryCollection(objects) => json!({
+            "type": "GeometryCollection",
+            "geometries": objects
+                .iter()
+                .map(|object| encode_object(object, line_arcs, position))
+                .collect::<Vec<_>>(),
+        }),
+    }
+}
+
+fn visit_points(object: &Object, f: &mut dyn FnMut(&Coord)) {
+    match object {
+        Object::Point(Some(coord)) => f(coord),
+        Object::MultiPoint(coords) => coords.iter().for_each(f),
+        Object::GeometryCollection(objects) => {
+            for object in objects {
+                visit_points(object, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Builds a TopoJSON topology with a single `GeometryCollection` object, in
+/// which adjacent geometries share the arcs of their common boundaries.
+///
+/// If `quantization` is not `None`, positions are quantized to integers on a grid
+/// of `quantization` by `quantization` cells spanning the topology bounding box.
+pub fn topology(
+    geoms: &[Option<Geometry>],
+    object_name: &str,
+    quantization: Option<u32>,
+) -> GResult<Value> {
+    if matches!(quantization, Some(0 | 1)) {
+        return Err(GError::GenericError(
+            "quantization must be greater than 1".into(),
+        ));
+    }
+    let mut lines = vec![];
+    let objects = geoms
+        .iter()
+        .map(|geom| match geom {
+            Some(geom) => add_object(geom, &mut lines),
+            None => Ok(Object::Null),
+        })
+        .try_collect::<Vec<_>>()?;
+
+    let mut bbox: Option<[f64; 4]> = None;
+    let mut extend = |coord: &Coord| {
+        let [x, y] = *coord;
+        bbox = Some(match bbox {
+            None => [x, y, x, y],
+            Some(b) => [b[0].min(x), b[1].min(y), b[2].max(x), b[3].max(y)],
+        });
+    };
+    lines
+        .iter()
+        .flat_map(|line| &line.coords)
+        .for_each(&mut extend);
+    objects
+        .iter()
+        .for_each(|object| visit_points(object, &mut extend));
+
+    if let Some(b) = bbox {
+        let tolerance = (b[2] - b[0]).max(b[3] - b[1]) * 1e-12;
+        insert_nodes(&mut lines, tolerance)?;
+    }
+    let junctions = junctions(&lines);
+    let mut arcs = Arcs::default();
+    let line_arcs = lines
+        .iter()
+        .map(|line| {
+            split(line, &junctions)
+                .into_iter()
+                .map(|arc| arcs.get_or_insert(arc))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let transform = bbox
+        .zip(quantization)
+        .map(|(bbox, quantization)| Transform::new(bbox, quantization));
+    let position = |coord: &Coord| match &transform {
+        Some(transform) => json!(transform.quantize(coord)),
+        None => json!(coord),
+    };
+    let geometries = objects
+        .iter()
+        .map(|object| encode_object(object, &line_arcs, &position))
+        .collect::<Vec<_>>();
+    let encoded_arcs = arcs
+        .arcs
+        .iter()
+        .map(|arc| match &transform {
+            Some(transform) => transform.encode_arc(arc),
+            None => json!(arc),
+        })
+        .collect::<Vec<_>>();
+
+    let mut topology = Map::new();
+    topology.insert("type".into(), "Topology".into());
+    if let Some(bbox) = bbox {
+        topology.insert("bbox".into(), json!(bbox));
+    }
+    if let Some(transform) = &transform {
+        topology.insert(
+            "transform".into(),
+            json!({ "scale": transform.scale, "translate": transform.translate }),
+        );
+    }
+    topology.insert(
+        "objects".into(),
+        json!({ object_name: { "type": "GeometryCollection", "geometries": geometries } }),
+    );
+    topology.insert("arcs".into(), Value::Array(encoded_arcs));
+    Ok(Value::Object(topology))
+}
@@ -0,0 +1,133 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use geos::{CoordSeq, Error as GError, GResult, Geom, Geometry, GeometryTypes::*};
+
+/// A vertex to remove, ordered so that the one forming the smallest triangle
+/// with its neighbours is at the top of the `BinaryHeap`.
+struct Candidate {
+    area: f64,
+    index: usize,
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .total_cmp(&self.area)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+fn triangle_area(a: &[f64], b: &[f64], c: &[f64]) -> f64 {
+    ((b[0] - a[0]) * (c[1] - a[1]) - (c[0] - a[0]) * (b[1] - a[1])).abs() / 2.0
+}
+
+/// Simplifies the coordinates of a line by repeatedly removing the vertex
+/// forming the smallest triangle with its neighbours, while that area is
+/// below `tolerance`. Endpoints are kept, as well as at least `min_points`
+/// vertices.
+fn simplify_coords(
+    coords: &[f64],
+    dimension: usize,
+    tolerance: f64,
+    min_points: usize,
+) -> Vec<f64> {
+    let len = coords.len() / dimension;
+    let coord = |i: usize| &coords[i * dimension..(i + 1) * dimension];
+    let mut prev = (0..len).map(|i| i.saturating_sub(1)).collect::<Vec<_>>();
+    let mut next = (1..=len).collect::<Vec<_>>();
+    let mut areas = vec![f64::INFINITY; len];
+    let mut heap = BinaryHeap::with_capacity(len);
+    for i in 1..len.saturating_sub(1) {
+        areas[i] = triangle_area(coord(i - 1), coord(i), coord(i + 1));
+        heap.push(Candidate {
+            area: areas[i],
+            index: i,
+        });
+    }
+    let mut remaining = len;
+    while let Some(Candidate { area, index }) = heap.pop() {
+        if area >= tolerance || remaining <= min_points {
+            break;
+        }
+        // Areas are pushed again when a neighbour is removed, so that
+        // outdated candidates are skipped.
+        if area.to_bits() != areas[index].to_bits() {
+            continue;
+        }
+        let (p, n) = (prev[index], next[index]);
+        next[p] = n;
+        prev[n] = p;
+        areas[index] = f64::NAN;
+        remaining -= 1;
+        for i in [p, n].into_iter().filter(|i| *i != 0 && *i != len - 1) {
+            areas[i] = triangle_area(coord(prev[i]), coord(i), coord(next[i]));
+            heap.push(Candidate {
+                area: areas[i],
+                index: i,
+            });
+        }
+    }
+    let mut simplified = Vec::with_capacity(remaining * dimension);
+    let mut i = 0;
+    while i < len {
+        simplified.extend(coord(i));
+        i = next[i];
+    }
+    simplified
+}
+
+fn simplify_coord_seq<T: Geom>(geom: &T, tolerance: f64, min_points: usize) -> GResult<CoordSeq> {
+    let (has_z, has_m) = (geom.has_z()?, geom.has_m()?);
+    let dimension = 2 + usize::from(has_z) + usize::from(has_m);
+    let coords = geom.get_coord_seq()?.as_buffer(Some(dimension))?;
+    let coords = simplify_coords(&coords, dimension, tolerance, min_points);
+    CoordSeq::new_from_buffer(&coords, coords.len() / dimension, has_z, has_m)
+}
+
+/// Simplifies a geometry with the Visvalingam–Whyatt algorithm. Lines keep
+/// at least 2 vertices and rings 4, but the topology isn't preserved.
+pub fn simplify<T: Geom>(geom: &T, tolerance: f64) -> GResult<Geometry> {
+    if geom.is_empty()? {
+        return Geom::clone(geom);
+    }
+    let parts = || {
+        (0..geom.get_num_geometries()?)
+            .map(|n| simplify(&geom.get_geometry_n(n)?, tolerance))
+            .try_collect::<Vec<_>>()
+    };
+    let mut simplified = match geom.geometry_type()? {
+        Point | MultiPoint => Geom::clone(geom),
+        LineString => Geometry::create_line_string(simplify_coord_seq(geom, tolerance, 2)?),
+        LinearRing => Geometry::create_linear_ring(simplify_coord_seq(geom, tolerance, 4)?),
+        Polygon => {
+            let exterior = simplify(&geom.get_exterior_ring()?, tolerance)?;
+            let interiors = (0..geom.get_num_interior_rings()?)
+                .map(|n| simplify(&geom.get_interior_ring_n(n)?, tolerance))
+                .try_collect::<Vec<_>>()?;
+            Geometry::create_polygon(exterior, interiors)
+        }
+        MultiLineString => Geometry::create_multiline_string(parts()?),
+        MultiPolygon => Geometry::create_multipolygon(parts()?),
+        GeometryCollection => Geometry::create_geometry_collection(parts()?),
+        geometry_type => Err(GError::GenericError(format!(
+            "Visvalingam simplification isn't supported for {geometry_type:?}"
+        ))),
+    }?;
+    simplified.set_srid(geom.get_srid()?);
+    Ok(simplified)
+}
@@ -1,4 +1,4 @@
-use geos::GeometryTypes;
+use geos::{Geom, Geometry, GeometryTypes};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use scroll::{Endian, IOread};
 use serde::{Deserialize, Serialize};
@@ -44,6 +44,210 @@ impl TryFrom<&[u8]> for WKBHeader {
     }
 }
 
+/// Returns the envelope of a WKB or EWKB geometry as `[xmin, ymin, xmax, ymax]`,
+/// or `None` if it is empty. It is read from the coordinates without building
+/// a GEOS geometry, except for curved geometries, whose envelope may extend
+/// beyond their control points.
+pub fn envelope(wkb: &[u8]) -> Result<Option<[f64; 4]>, geos::Error> {
+    fn read_points(
+        wkb: &mut &[u8],
+        endian: Endian,
+        dims: usize,
+        count: u32,
+        bounds: &mut [f64; 4],
+    ) -> Result<(), io::Error> {
+        for _ in 0..count {
+            let x = wkb.ioread_with::<f64>(endian)?;
+            let y = wkb.ioread_with::<f64>(endian)?;
+            for _ in 2..dims {
+                wkb.ioread_with::<f64>(endian)?;
+            }
+            // Empty points are written with NaN coordinates.
+            if !x.is_nan() && !y.is_nan() {
+                *bounds = [
+                    bounds[0].min(x),
+                    bounds[1].min(y),
+                    bounds[2].max(x),
+                    bounds[3].max(y),
+                ];
+            }
+        }
+        Ok(())
+    }
+
+    fn read_geometry(wkb: &mut &[u8], bounds: &mut [f64; 4]) -> Result<(), io::Error> {
+        let endian = Endian::from(wkb.ioread::<u8>()? != 0);
+        let type_id = wkb.ioread_with::<u32>(endian)?;
+        if type_id & 0x2000_0000 != 0 {
+            wkb.ioread_with::<i32>(endian)?;
+        }
+        // Dimensions are either given by EWKB flags, or by ISO type codes.
+        let iso_dims = (type_id & 0xFFFF) / 1000;
+        let has_z = type_id & 0x8000_0000 != 0 || matches!(iso_dims, 1 | 3);
+        let has_m = type_id & 0x4000_0000 != 0 || matches!(iso_dims, 2 | 3);
+        let dims = 2 + usize::from(has_z) + usize::from(has_m);
+        match (type_id & 0xFFFF) % 1000 {
+            1 => read_points(wkb, endian, dims, 1, bounds),
+            2 => {
+                let count = wkb.ioread_with::<u32>(endian)?;
+                read_points(wkb, endian, dims, count, bounds)
+            }
+            3 | 17 => {
+                for _ in 0..wkb.ioread_with::<u32>(endian)? {
+                    let count = wkb.ioread_with::<u32>(endian)?;
+                    read_points(wkb, endian, dims, count, bounds)?;
+                }
+                Ok(())
+            }
+            4..=7 | 15 | 16 => {
+                for _ in 0..wkb.ioread_with::<u32>(endian)? {
+                    read_geometry(wkb, bounds)?;
+                }
+                Ok(())
+            }
+            _ => Err(io::Error::from(io::ErrorKind::Unsupported)),
+        }
+    }
+
+    let mut bounds = [
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NEG_INFINITY,
+    ];
+    let mut reader = wkb;
+    if read_geometry(&mut reader, &mut bounds).is_ok() {
+        return Ok((bounds[0] <= bounds[2]).then_some(bounds));
+    }
+    // Invalid WKB gets its error from GEOS.
+    let geom = Geometry::new_from_wkb(wkb)?;
+    if geom.is_empty()? {
+        return Ok(None);
+    }
+    Ok(Some([
+        geom.get_x_min()?,
+        geom.get_y_min()?,
+        geom.get_x_max()?,
+        geom.get_y_max()?,
+    ]))
+}
+
+/// Converts an EWKB geometry, as written by GEOS, to ISO WKB: Z and M flags are
+/// replaced by ISO type codes, and the SRID is dropped.
+pub fn to_iso(ewkb: &[u8]) -> Result<Vec<u8>, geos::Error> {
+    fn copy_points(
+        wkb: &mut &[u8],
+        iso: &mut Vec<u8>,
+        dims: usize,
+        count: usize,
+    ) -> Result<(), io::Error> {
+        let size = count * dims * 8;
+        if wkb.len() < size {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
+        iso.extend_from_slice(&wkb[..size]);
+        *wkb = &wkb[size..];
+        Ok(())
+    }
+
+    fn copy_count(wkb: &mut &[u8], iso: &mut Vec<u8>, endian: Endian) -> Result<usize, io::Error> {
+        let count = wkb.ioread_with::<u32>(endian)?;
+        iso.extend_from_slice(&match endian {
+            Endian::Little => count.to_le_bytes(),
+            Endian::Big => count.to_be_bytes(),
+        });
+        Ok(count as usize)
+    }
+
+    fn copy_geometry(wkb: &mut &[u8], iso: &mut Vec<u8>) -> Result<(), io::Error> {
+        let byte_order = wkb.ioread::<u8>()?;
+        let endian = Endian::from(byte_order != 0);
+        let type_id = wkb.ioread_with::<u32>(endian)?;
+        if type_id & 0x2000_0000 != 0 {
+            wkb.ioread_with::<i32>(endian)?;
+        }
+        let iso_dims = (type_id & 0xFFFF) / 1000;
+        let has_z = type_id & 0x8000_0000 != 0 || matches!(iso_dims, 1 | 3);
+        let has_m = type_id & 0x4000_0000 != 0 || matches!(iso_dims, 2 | 3);
+        let kind = (type_id & 0xFFFF) % 1000;
+        let iso_type = kind + 1000 * u32::from(has_z) + 2000 * u32::from(has_m);
+        iso.push(byte_order);
+        iso.extend_from_slice(&match endian {
+            Endian::Little => iso_type.to_le_bytes(),
+            Endian::Big => iso_type.to_be_bytes(),
+        });
+        let dims = 2 + usize::from(has_z) + usize::from(has_m);
+        match kind {
+            1 => copy_points(wkb, iso, dims, 1),
+            2 | 8 => {
+                let count = copy_count(wkb, iso, endian)?;
+                copy_points(wkb, iso, dims, count)
+            }
+            3 | 17 => {
+                for _ in 0..copy_count(wkb, iso, endian)? {
+                    let count = copy_count(wkb, iso, endian)?;
+                    copy_points(wkb, iso, dims, count)?;
+                }
+                Ok(())
+            }
+            4..=7 | 9..=12 | 15 | 16 => {
+                for _ in 0..copy_count(wkb, iso, endian)? {
+                    copy_geometry(wkb, iso)?;
+                }
+                Ok(())
+            }
+            _ => Err(io::Error::from(io::ErrorKind::Unsupported)),
+        }
+    }
+
+    let mut reader = ewkb;
+    let mut iso = Vec::with_capacity(ewkb.len());
+    copy_geometry(&mut reader, &mut iso)
+        .map_err(|e| geos::Error::GenericError(format!("Invalid WKB: {e}")))?;
+    Ok(iso)
+}
+
+/// Header of a GeoPackage geometry blob, which precedes a standard WKB geometry.
+pub struct GpkgHeader {
+    pub srid: i32,
+    pub size: usize,
+}
+
+impl TryFrom<&[u8]> for GpkgHeader {
+    type Error = geos::Error;
+
+    fn try_from(blob: &[u8]) -> Result<Self, Self::Error> {
+        let invalid = || geos::Error::GenericError("Invalid GeoPackage geometry header".into());
+
+        let [b'G', b'P', 0, flags, ..] = *blob else {
+            return Err(invalid());
+        };
+        if flags & 0b0010_0000 != 0 {
+            return Err(geos::Error::GenericError(
+                "Extended GeoPackage geometries are not supported".into(),
+            ));
+        }
+        let endian = Endian::from(flags & 0b0000_0001 != 0);
+        let srid = blob
+            .get(4..)
+            .ok_or_else(invalid)?
+            .ioread_with::<i32>(endian)
+            .map_err(|_| invalid())?;
+        let envelope_size = match (flags >> 1) & 0b111 {
+            0 => 0,
+            1 => 32,
+            2 | 3 => 48,
+            4 => 64,
+            _ => return Err(invalid()),
+        };
+        let size = 8 + envelope_size;
+        if blob.len() < size {
+            return Err(invalid());
+        }
+        Ok(Self { srid, size })
+    }
+}
+
 #[derive(Clone, Copy, Debug, IntoPrimitive, TryFromPrimitive, Serialize, Deserialize)]
 #[repr(u32)]
 pub enum WKBGeometryType {
@@ -0,0 +1,68 @@
+import polars as pl
+import pytest
+
+import polars_st as st
+
+
+def frame(geometries: list[str | None], id_name: str) -> st.GeoDataFrame:
+    return st.GeoDataFrame({"geometry": geometries, id_name: list(range(len(geometries)))})
+
+
+def match_ids(left: st.GeoDataFrame, right: st.GeoDataFrame, **kwargs) -> list[tuple[int, int]]:
+    result = left.st.conflate(right, **kwargs)
+    return sorted(result.select("left_id", "right_id").iter_rows())
+
+
+def test_conflate_one_to_one():
+    left = frame(["POINT (0 0)", "POINT (3 0)"], "left_id")
+    right = frame(["POINT (1 0)", "POINT (0.5 0)"], "right_id")
+    assert match_ids(left, right, max_distance=5) == [(0, 1), (1, 0)]
+
+
+def test_conflate_min_score():
+    left = frame(["POINT (0 0)", "POINT (3 0)"], "left_id")
+    right = frame(["POINT (1 0)", "POINT (0.5 0)"], "right_id")
+    assert match_ids(left, right, max_distance=5, min_score=0.7) == [(0, 1)]
+
+
+def test_conflate_ties():
+    one = frame(["POINT (0 0)"], "left_id")
+    two = frame(["POINT (1 0)", "POINT (-1 0)"], "right_id")
+    assert match_ids(one, two, max_distance=2) == [(0, 0)]
+    one, two = one.rename({"left_id": "right_id"}), two.rename({"right_id": "left_id"})
+    assert match_ids(two, one, max_distance=2) == [(0, 0)]
+
+
+def test_conflate_similarity():
+    left = frame(["POINT (0 0)"], "left_id").with_columns(name=pl.lit("a"))
+    right = frame(["POINT (1 0)", "POINT (-1 0)"], "right_id").with_columns(
+        name=pl.Series(["b", "a"]),
+    )
+    similarity = pl.col("name") == pl.col("name_right")
+    result = left.st.conflate(right, max_distance=2, similarity=similarity)
+    assert result.select("left_id", "right_id", "match_score").rows() == [(0, 1, 0.75)]
+
+
+@pytest.mark.parametrize(
+    ("left", "right", "score"),
+    [
+        pytest.param("POINT (0 0)", "POINT (1 0)", 0.5, id="points"),
+        pytest.param(
+            "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))",
+            "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))",
+            1.0,
+            id="polygons",
+        ),
+    ],
+)
+def test_conflate_overlap_score(left: str, right: str, score: float):
+    result = frame([left], "left_id").st.conflate(frame([right], "right_id"), max_distance=2)
+    assert result["match_score"].to_list() == [score]
+
+
+def test_conflate_null_and_empty():
+    left = frame([None, "POINT EMPTY", "POINT (0 0)"], "left_id")
+    right = frame(["POINT (1 0)", None, "POINT EMPTY"], "right_id")
+    assert match_ids(left, right, max_distance=2) == [(2, 0)]
+    assert match_ids(left.clear(), right, max_distance=2) == []
+    assert match_ids(left, right.clear(), max_distance=2) == []
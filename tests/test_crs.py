@@ -0,0 +1,160 @@
+import json
+
+import polars as pl
+import pytest
+
+import polars_st as st
+from polars_st.utils.geoarrow import from_geoarrow_table
+
+LOCAL_CRS = "+proj=tmerc +lat_0=0 +lon_0=3 +k=1 +x_0=500000 +y_0=0 +ellps=GRS80 +units=m"
+
+
+def test_register_crs():
+    srid = st.register_crs(LOCAL_CRS)
+    assert srid >= 900_000
+    assert st.register_crs(LOCAL_CRS) == srid
+    gdf = st.GeoDataFrame(["SRID=4326;POINT (3 0)"])
+    result = gdf.select(st.to_srid(srid=srid).st.to_ewkt(rounding_precision=3)).item()
+    assert result == f"SRID={srid};POINT (500000 0)"
+
+
+def test_register_crs_srid_from_definition():
+    # FNV-1a hash of the definition, which doesn't depend on the order of registration.
+    definition = "+proj=tmerc +lat_0=0 +lon_0=9 +k=1 +x_0=500000 +y_0=0 +ellps=GRS80 +units=m"
+    fnv = 0xCBF29CE484222325
+    for byte in definition.encode():
+        fnv = ((fnv ^ byte) * 0x100000001B3) % 2**64
+    assert st.register_crs(definition) == 900_000 + fnv % 100_000
+
+
+def test_register_crs_existing_srid():
+    for srid in [4326, 102003]:
+        with pytest.raises(ValueError, match="overwrite"):
+            st.register_crs(LOCAL_CRS, srid)
+    first, second = "+proj=longlat +ellps=GRS80", "+proj=longlat +ellps=intl"
+    assert st.register_crs(first, 800_001) == 800_001
+    assert st.register_crs(first, 800_001) == 800_001
+    with pytest.raises(ValueError, match="overwrite"):
+        st.register_crs(second, 800_001)
+    assert st.register_crs(second, 800_001, overwrite=True) == 800_001
+
+
+def test_transform_other_authority():
+    wkt = (
+        'PROJCS["RGF93 / CC46",GEOGCS["RGF93",DATUM["Reseau_Geodesique_Francais_1993",'
+        'SPHEROID["GRS 1980",6378137,298.257222101]],PRIMEM["Greenwich",0],'
+        'UNIT["degree",0.0174532925199433]],PROJECTION["Transverse_Mercator"],'
+        'PARAMETER["latitude_of_origin",0],PARAMETER["central_meridian",3],'
+        'PARAMETER["scale_factor",1],PARAMETER["false_easting",500000],'
+        'PARAMETER["false_northing",0],UNIT["metre",1],AUTHORITY["IGNF","2154"]]'
+    )
+    gdf = st.GeoDataFrame(["SRID=4326;POINT (3 0)"])
+    result = gdf.select(st.transform(from_crs="EPSG:4326", to_crs=wkt))
+    assert result.select(st.srid()).item() == st.register_crs(wkt) != 2154
+    assert result.select(st.x(), st.y()).row(0) == pytest.approx((500000, 0), abs=1e-6)
+
+
+def test_registered_crs_arrow_roundtrip():
+    srid = st.register_crs(LOCAL_CRS)
+    gdf = st.GeoDataFrame({"geometry": ["POINT (1 2)"]}).with_columns(st.set_srid(srid=srid))
+    table = gdf.st.to_arrow()
+    metadata = table.schema.field("geometry").metadata[b"ARROW:extension:metadata"]
+    assert LOCAL_CRS.encode() in metadata
+    result = from_geoarrow_table(table)
+    assert result.select(st.srid()).item() == srid
+
+
+def test_arrow_metadata_crs_through_operations():
+    import pyarrow as pa
+
+    # A CRS which only exists in the field metadata of the table.
+    definition = "+proj=tmerc +lat_0=0 +lon_0=27 +k=1 +x_0=500000 +y_0=0 +ellps=intl +units=m"
+    metadata = {
+        b"ARROW:extension:name": b"geoarrow.wkb",
+        b"ARROW:extension:metadata": json.dumps({"crs": definition}).encode(),
+    }
+    wkb = st.GeoSeries(["POINT (1 2)"]).st.to_wkb().to_arrow()
+    field = pa.field("geometry", pa.binary(), metadata=metadata)
+    table = pa.Table.from_arrays([wkb.cast(pa.binary())], schema=pa.schema([field]))
+
+    gdf = st.GeoDataFrame(from_geoarrow_table(table))
+    result = gdf.select(st.buffer(distance=1).st.centroid())
+    assert result.select(st.srid()).item() == st.register_crs(definition)
+    exported = result.st.to_arrow().schema.field("geometry").metadata
+    assert json.loads(exported[b"ARROW:extension:metadata"])["crs"] == definition
+
+
+def test_srid_propagation():
+    gdf = st.GeoDataFrame([
+        "SRID=3857;POLYGON ((0 0, 0 1, 1 1, 1 0, 0 0))",
+        "SRID=3857;POLYGON ((1 0, 1 1, 2 1, 2 0, 1 0))",
+        "SRID=3857;GEOMETRYCOLLECTION EMPTY",
+    ])
+    for expr in [
+        st.buffer(distance=1),
+        st.boundary(),
+        st.centroid(),
+        st.center(),
+        st.geom().st.intersection(st.geom()),
+    ]:
+        assert gdf.select(expr.st.srid()).to_series().to_list() == [3857] * 3
+    for expr in [st.collect(), st.polygonize(), st.coverage_union_all(), st.union_all()]:
+        assert gdf.head(2).select(expr.st.srid()).item() == 3857
+    mixed = pl.concat([gdf, st.GeoDataFrame(["POINT (0 0)"])])
+    assert mixed.select(st.collect().st.srid()).item() == 0
+
+
+def test_crs_info():
+    gdf = st.GeoDataFrame([
+        "SRID=4326;POINT (0 0)",
+        "SRID=3857;POINT (0 0)",
+        "SRID=2263;POINT (0 0)",
+        "POINT (0 0)",
+    ])
+    result = gdf.select(st.crs_info()).unnest("geometry")
+    assert result["authority"].to_list() == ["EPSG", "EPSG", "EPSG", None]
+    assert result["name"].to_list()[:2] == ["WGS 84", "WGS 84 / Pseudo-Mercator"]
+    assert result["units"].to_list() == ["degree", "m", "us-ft", None]
+    assert result["is_geographic"].to_list() == [True, False, False, None]
+    assert result["axis_order"].to_list()[1:] == ["east,north", "east,north", None]
+
+
+def test_crs_info_registered():
+    srid = st.register_crs(LOCAL_CRS)
+    gdf = st.GeoDataFrame(["POINT (0 0)"]).with_columns(st.set_srid(srid=srid))
+    result = gdf.select(st.crs_info()).item()
+    assert result == {
+        "authority": None,
+        "name": None,
+        "units": "m",
+        "is_geographic": False,
+        "axis_order": "east,north",
+    }
+
+
+def test_esri_codes():
+    gdf = st.GeoDataFrame(["SRID=4326;POINT (-96 37.5)"])
+    for expr in [st.to_srid(srid=102003), st.transform("EPSG:4326", "ESRI:102003")]:
+        result = gdf.select(expr)
+        assert result.select(st.srid()).item() == 102003
+        assert result.select(st.x(), st.y()).row(0) == pytest.approx((0, 0), abs=1e-6)
+
+
+def test_esri_crs_info():
+    gdf = st.GeoDataFrame(["POINT (0 0)"]).with_columns(st.set_srid(srid=102003))
+    result = gdf.select(st.crs_info()).item()
+    assert result == {
+        "authority": "ESRI",
+        "name": "USA_Contiguous_Albers_Equal_Area_Conic",
+        "units": "m",
+        "is_geographic": False,
+        "axis_order": "east,north",
+    }
+
+
+def test_esri_arrow_roundtrip():
+    gdf = st.GeoDataFrame({"geometry": ["POINT (1 2)"]}).with_columns(st.set_srid(srid=102003))
+    table = gdf.st.to_arrow()
+    metadata = table.schema.field("geometry").metadata[b"ARROW:extension:metadata"]
+    assert b"ESRI:102003" in metadata
+    assert from_geoarrow_table(table).select(st.srid()).item() == 102003
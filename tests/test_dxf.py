@@ -0,0 +1,36 @@
+from pathlib import Path
+
+import polars as pl
+import pytest
+
+import polars_st as st
+
+
+def entities(dxf: str) -> list[tuple[str, str]]:
+    lines = dxf.splitlines()
+    return [(code.strip(), value) for code, value in zip(lines[::2], lines[1::2], strict=True)]
+
+
+def test_write_dxf_polygon():
+    gdf = st.GeoDataFrame({"geometry": ["POLYGON ((0 0, 1 0, 1 1, 0 0))"], "layer": ["Parcels"]})
+    groups = entities(gdf.st.write_dxf(layer_name="layer"))
+    assert groups[:4] == [("0", "SECTION"), ("2", "ENTITIES"), ("0", "POLYLINE"), ("8", "Parcels")]
+    assert ("70", "1") in groups
+    assert [value for code, value in groups if code == "0"].count("VERTEX") == 3
+    assert groups[-2:] == [("0", "ENDSEC"), ("0", "EOF")]
+
+
+def test_write_dxf_default_layer(tmp_path: Path):
+    gdf = st.GeoDataFrame({"geometry": ["LINESTRING Z (0 0 1, 1 1 2)", None]})
+    path = tmp_path / "test.dxf"
+    gdf.st.write_dxf(path)
+    groups = entities(path.read_text())
+    assert {value for code, value in groups if code == "8"} == {"0"}
+    assert ("70", "8") in groups
+    assert ("30", "2") in groups
+
+
+def test_write_dxf_unsupported():
+    gdf = st.GeoDataFrame({"geometry": ["CIRCULARSTRING (0 0, 1 1, 2 0)"]})
+    with pytest.raises(pl.exceptions.ComputeError):
+        gdf.st.write_dxf()
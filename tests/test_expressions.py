@@ -1,9 +1,13 @@
 # ruff: noqa: E501
 
+import math
+import struct
 import warnings
 from collections.abc import Callable
+from concurrent.futures import ThreadPoolExecutor
 from dataclasses import dataclass, field
-from typing import Any, Concatenate, ParamSpec
+from pathlib import Path
+from typing import Any, Concatenate, Literal, ParamSpec
 
 import numpy as np
 import polars as pl
@@ -92,8 +96,22 @@ functions = [
     Function(Geo.dimensions, pl.Int32()),
     Function(Geo.coordinate_dimension, pl.UInt32()),
     Function(Geo.srid, pl.Int32()),
+    Function(
+        Geo.crs_info,
+        pl.Struct({
+            "authority": pl.String(),
+            "name": pl.String(),
+            "units": pl.String(),
+            "is_geographic": pl.Boolean(),
+            "axis_order": pl.String(),
+        }),
+    ),
     Function(Geo.set_srid, pl.Binary(), {"srid": 3857}),
     Function(Geo.to_srid, pl.Binary(), {"srid": 3857}),
+    Function(Geo.transform_with, pl.Binary(), {"func": lambda coords: coords}),
+    Function(Geo.bounds_to_srid, pl.Array(pl.Float64, 4), {"srid": 3857}),
+    Function(Geo.utm_zone, pl.Int32()),
+    Function(Geo.to_utm, pl.Binary()),
     Function(Geo.x, pl.Float64()),
     Function(Geo.y, pl.Float64()),
     Function(Geo.z, pl.Float64()),
@@ -106,6 +124,19 @@ functions = [
     Function(Geo.get_interior_ring, pl.Binary(), {"index": 0}),
     Function(Geo.get_geometry, pl.Binary(), {"index": 0}),
     Function(Geo.parts, pl.List(pl.Binary())),
+    Function(
+        Geo.dump_points,
+        pl.List(
+            pl.Struct({
+                "point": pl.Binary(),
+                "part_index": pl.UInt32(),
+                "ring_index": pl.UInt32(),
+                "vertex_index": pl.UInt32(),
+            })
+        ),
+    ),
+    Function(Geo.collection_extract, pl.Binary(), {"type": "polygon"}),
+    Function(Geo.unnest_collection, pl.Binary()),
     Function(Geo.interior_rings, pl.List(pl.Binary())),
     Function(Geo.precision, pl.Float64()),
     Function(Geo.set_precision, pl.Binary(), {"grid_size": 1.0, "mode": "valid_output"}),
@@ -114,20 +145,51 @@ functions = [
     Function(Geo.to_wkt, pl.String()),
     Function(Geo.to_ewkt, pl.String()),
     Function(Geo.to_wkb, pl.Binary()),
+    Function(Geo.to_ewkb_hex, pl.String()),
     Function(Geo.to_geojson, pl.String()),
+    Function(Geo.to_esrijson, pl.String()),
+    Function(Geo.to_kml, pl.String()),
+    Function(Geo.to_gml, pl.String()),
+    Function(Geo.to_gpkg, pl.Binary()),
+    Function(Geo.to_spatialite, pl.Binary()),
+    Function(Geo.to_svg, pl.String()),
     Function(Geo.to_dict, pl.Object()),
     Function(Geo.to_shapely, pl.Object()),
     Function(Geo.area, pl.Float64()),
+    Function(Geo.area_geodesic, pl.Float64()),
     Function(Geo.bounds, pl.Array(pl.Float64, 4)),
+    Function(Geo.bounds_3d, pl.Array(pl.Float64, 6)),
+    Function(Geo.m_range, pl.Array(pl.Float64, 2)),
+    Function(Geo.morton_index, pl.UInt64()),
+    Function(Geo.morton_index, pl.UInt64(), {"extent": (0, 0, 1, 1), "level": 32}),
+    Function(Geo.spatial_partition, pl.List(pl.UInt32()), {"scheme": "grid"}),
+    Function(Geo.spatial_partition, pl.List(pl.UInt32()), {"scheme": "kdb"}),
     Function(Geo.length, pl.Float64()),
+    Function(Geo.length_geodesic, pl.Float64()),
+    Function(Geo.length_3d, pl.Float64()),
     Function(Geo.distance, pl.Float64(), {"other": dummy_point}),
+    Function(Geo.distance_3d, pl.Float64(), {"other": dummy_point}),
     Function(Geo.hausdorff_distance, pl.Float64(), {"other": dummy_point, "densify": None}),
     Function(Geo.hausdorff_distance, pl.Float64(), {"other": dummy_point, "densify": 0.5}),
     Function(Geo.frechet_distance, pl.Float64(), {"other": dummy_point, "densify": None}),
     Function(Geo.frechet_distance, pl.Float64(), {"other": dummy_point, "densify": 0.5}),
+    Function(Geo.azimuth, pl.Float64(), {"other": dummy_point}),
+    Function(Geo.azimuth_geodesic, pl.Float64(), {"other": dummy_point}),
+    Function(
+        Geo.knn,
+        pl.List(pl.Struct({"index": pl.UInt32(), "distance": pl.Float64()})),
+        {"other": dummy_point, "k": 2},
+    ),
+    Function(Geo.count_matches, pl.UInt32(), {"other": dummy_point}),
+    Function(
+        Geo.count_matches,
+        pl.UInt32(),
+        {"other": dummy_point, "predicate": "dwithin", "distance": 1.0},
+    ),
     Function(Geo.minimum_clearance, pl.Float64()),
     Function(Geo.has_z, pl.Boolean()),
     Function(Geo.has_m, pl.Boolean()),
+    Function(Geo.has_curves, pl.Boolean()),
     Function(Geo.is_ccw, pl.Boolean()),
     Function(Geo.is_closed, pl.Boolean()),
     Function(Geo.is_empty, pl.Boolean()),
@@ -135,6 +197,12 @@ functions = [
     Function(Geo.is_simple, pl.Boolean()),
     Function(Geo.is_valid, pl.Boolean()),
     Function(Geo.is_valid_reason, pl.String()),
+    Function(
+        Geo.is_valid_detail,
+        pl.Struct({"valid": pl.Boolean(), "reason": pl.String(), "location": pl.Binary()}),
+    ),
+    Function(Geo.wkb_is_parseable, pl.Boolean()),
+    Function(Geo.wkb_parse_error, pl.String()),
     Function(Geo.crosses, pl.Boolean(), {"other": dummy_point}),
     Function(Geo.contains, pl.Boolean(), {"other": dummy_point}),
     Function(Geo.contains_properly, pl.Boolean(), {"other": dummy_point}),
@@ -166,6 +234,8 @@ functions = [
     Function(Geo.boundary, pl.Binary()),
     Function(Geo.coverage_union, pl.Binary()),
     Function(Geo.buffer, pl.Binary(), {"distance": 1.0}),
+    Function(Geo.buffer_geodesic, pl.Binary(), {"distance_m": 1.0}),
+    Function(Geo.buffer_rings, pl.List(pl.Binary()), {"distances": [1.0, 2.0]}),
     Function(Geo.offset_curve, pl.Binary(), {"distance": 1.0}),
     Function(Geo.centroid, pl.Binary()),
     Function(Geo.center, pl.Binary()),
@@ -173,6 +243,9 @@ functions = [
     Function(Geo.concave_hull, pl.Binary()),
     Function(Geo.convex_hull, pl.Binary()),
     Function(Geo.segmentize, pl.Binary(), {"max_segment_length": 1.0}),
+    Function(Geo.segmentize_geodesic, pl.Binary(), {"max_length_m": 1000.0}),
+    Function(Geo.curve_to_linear, pl.Binary(), {"tolerance": 0.1}),
+    Function(Geo.linear_to_curve, pl.Binary(), {"tolerance": 0.1}),
     Function(Geo.envelope, pl.Binary()),
     Function(Geo.extract_unique_points, pl.Binary()),
     Function(Geo.build_area, pl.Binary()),
@@ -181,16 +254,22 @@ functions = [
     Function(Geo.node, pl.Binary()),
     Function(Geo.point_on_surface, pl.Binary()),
     Function(Geo.remove_repeated_points, pl.Binary()),
+    Function(Geo.snap_to_grid, pl.Binary(), {"size_x": 1.0}),
+    Function(Geo.snap_to_grid, pl.Binary(), {"size_x": 10.0}),
+    Function(Geo.polylabel, pl.Binary()),
+    Function(Geo.subdivide, pl.List(pl.Binary())),
     Function(Geo.reverse, pl.Binary()),
     Function(Geo.snap, pl.Binary(), {"other": dummy_point, "tolerance": 1.0}),
     Function(Geo.simplify, pl.Binary(), {"tolerance": 1.0, "preserve_topology": False}),
     Function(Geo.simplify, pl.Binary(), {"tolerance": 1.0, "preserve_topology": True}),
+    Function(Geo.simplify_coverage, pl.Binary(), {"tolerance": 1.0}),
     Function(Geo.flip_coordinates, pl.Binary()),
     Function(Geo.minimum_rotated_rectangle, pl.Binary()),
     Function(Geo.translate, pl.Binary()),
     Function(Geo.rotate, pl.Binary(), {"angle": 90}),
     Function(Geo.scale, pl.Binary()),
     Function(Geo.skew, pl.Binary()),
+    Function(Geo.align_to_mrr, pl.Struct({"geometry": pl.Binary(), "angle": pl.Float64()})),
     Function(Geo.interpolate, pl.Binary(), {"distance": 1.0, "normalized": False}),
     Function(Geo.interpolate, pl.Binary(), {"distance": 1.0, "normalized": True}),
     Function(Geo.project, pl.Float64(), {"other": dummy_point, "normalized": False}),
@@ -198,7 +277,14 @@ functions = [
     Function(Geo.line_merge, pl.Binary(), {"directed": True}),
     Function(Geo.line_merge, pl.Binary(), {"directed": False}),
     Function(Geo.shared_paths, pl.Binary(), {"other": dummy_line}),
+    Function(Geo.points_along, pl.List(pl.Binary()), {"interval": 0.5}),
+    Function(Geo.points_along, pl.List(pl.Binary()), {"fractions": [0.0, 0.5]}),
+    Function(Geo.transects, pl.List(pl.Binary()), {"spacing": 0.5, "length": 1.0}),
+    Function(Geo.segments, pl.List(pl.Binary())),
+    Function(Geo.segment_azimuths, pl.List(pl.Float64())),
     Function(Geo.shortest_line, pl.Binary(), {"other": dummy_point}),
+    Function(Geo.longest_line, pl.Binary(), {"other": dummy_point}),
+    Function(Geo.closest_point, pl.Binary(), {"other": dummy_point}),
     Function(Geo.count_coordinates, pl.UInt32()),
     Function(Geo.coordinates, pl.List(pl.List(pl.Float64))),
 ]
@@ -221,6 +307,7 @@ aggregates = [
     Aggregate(Geo.union_all, pl.Binary(), collection_empty.item()),
     Aggregate(Geo.coverage_union_all, pl.Binary(), collection_empty.item()),
     Aggregate(Geo.collect, pl.Binary(), collection_empty.item()),
+    Aggregate(Geo.make_line, pl.Binary(), line_empty.item()),
 ]
 
 
@@ -248,7 +335,7 @@ def test_functions_none_frame(frame: pl.DataFrame, func: Function):
 def test_functions_empty_frame_agg(frame: pl.DataFrame, func: Function):
     """Functions should work on empty frames in aggregation context."""
     # Should file a bug report in polars for that (cannot concatenate empty list of arrays)
-    if func.call == Geo.bounds:
+    if func.call in {Geo.bounds, Geo.bounds_3d, Geo.m_range, Geo.bounds_to_srid}:
         return
     result = frame.group_by(0).agg(func()).drop("literal")
     assert result.schema == pl.Schema([("geometry", pl.List(func.dtype))])
@@ -372,7 +459,16 @@ def test_functions_all_types_frame(frame: pl.DataFrame, func: Function):
     }:
         error = "Geometry must be a collection"
 
-    if func.call == Geo.to_srid:
+    if func.call == Geo.polylabel and geom_type not in {"Polygon", "MultiPolygon"}:
+        error = "polylabel can only be computed for polygons"
+
+    if func.call == Geo.simplify_coverage and geom_type not in {"Polygon", "MultiPolygon"}:
+        error = "simplify_coverage can only be computed for polygons"
+
+    if func.call in {Geo.azimuth, Geo.azimuth_geodesic} and geom_type != "Point" and not geom_empty:
+        error = "Azimuths can only be computed between points"
+
+    if func.call in {Geo.to_srid, Geo.to_utm, Geo.bounds_to_srid}:
         frame = frame.select(st.geom().st.set_srid(4326))
 
     if error is not None:
@@ -385,3 +481,1653 @@ def test_functions_all_types_frame(frame: pl.DataFrame, func: Function):
         result = frame.select(func())
 
     assert result.schema == pl.Schema([("geometry", func.dtype)])
+
+
+def test_from_wkb_on_error():
+    wkb = st.GeoSeries(["POINT (1 2)", "POLYGON ((0 0, 1 1, 1 0, 0 1, 0 0))"]).st.to_wkb()
+    # A well-formed polygon blob whose ring isn't closed.
+    unclosed = struct.pack("<bIII6d", 1, 3, 1, 3, 0, 0, 1, 0, 0, 1)
+    df = pl.concat([wkb, pl.Series([b"corrupt", unclosed])]).to_frame("geometry")
+    with pytest.raises(pl.exceptions.ComputeError):
+        df.select(st.from_wkb("geometry"))
+    result = df.select(st.from_wkb("geometry", on_error="null")).to_series()
+    assert result.st.is_valid().to_list() == [True, False, None, None]
+    result = df.select(st.from_wkb("geometry", on_error="repair")).to_series()
+    assert result.st.is_valid().to_list() == [True, True, None, None]
+    assert result.st.geometry_type().to_list() == ["Point", "MultiPolygon", None, None]
+
+
+def test_to_wkb_flavor():
+    gdf = st.GeoDataFrame({"geometry": ["POINT Z (1 2 3)", "MULTIPOINT M ((1 2 4))"]})
+    iso = gdf.select(st.to_wkb(output_dimension=4, byte_order=1, flavor="iso")).to_series()
+    types = [int.from_bytes(wkb[1:5], "little") for wkb in iso]
+    assert types == [1001, 2004]
+    assert int.from_bytes(iso[1][10:14], "little") == 2001
+    extended = gdf.select(st.to_wkb(output_dimension=4, byte_order=1)).to_series()
+    assert int.from_bytes(extended[0][1:5], "little") == 0x8000_0001
+    result = iso.to_frame("geometry").select(st.from_wkb("geometry").st.to_wkt())
+    assert result.to_series().to_list() == ["POINT Z (1 2 3)", "MULTIPOINT M ((1 2 4))"]
+    with pytest.raises(pl.exceptions.ComputeError, match="ISO WKB"):
+        gdf.select(st.to_wkb(include_srid=True, flavor="iso"))
+
+
+def test_wkb_parse_checks():
+    wkb = st.GeoSeries(["POINT (1 2)", None]).st.to_wkb()
+    df = pl.concat([wkb, pl.Series([b"corrupt"])]).to_frame("geometry")
+    assert df.select(st.wkb_is_parseable()).to_series().to_list() == [True, None, False]
+    errors = df.select(st.wkb_parse_error()).to_series().to_list()
+    assert errors[:2] == [None, None]
+    assert isinstance(errors[2], str)
+
+
+def test_transform():
+    gdf = st.GeoDataFrame(["SRID=4326;POINT (2 1)", "SRID=4326;POINT EMPTY"])
+    expected = gdf.select(st.to_srid(srid=3857).st.to_ewkt(rounding_precision=3))
+    for from_crs in ["EPSG:4326", "+proj=longlat +datum=WGS84 +no_defs"]:
+        result = gdf.select(st.transform(from_crs=from_crs, to_crs="EPSG:3857"))
+        assert result.select(st.to_ewkt(rounding_precision=3)).equals(expected)
+    to_crs = "+proj=merc +datum=WGS84"
+    result = gdf.select(st.transform(from_crs="EPSG:4326", to_crs=to_crs))
+    assert result.select(st.srid()).to_series().to_list() == [st.register_crs(to_crs)] * 2
+    with pytest.raises(pl.exceptions.ComputeError, match="Invalid CRS"):
+        gdf.select(st.transform(from_crs="EPSG:4326", to_crs="not a crs"))
+
+
+def test_to_srid_web_mercator():
+    gdf = st.GeoDataFrame(["SRID=4326;LINESTRING Z (2.35 48.85 10, -180 -85 0)"])
+    result = gdf.select(st.to_srid(srid=3857))
+    coords = result.select(st.coordinates().list.explode()).to_series().to_list()
+    assert coords[0] == pytest.approx([261600.803, 6249447.753, 10])
+    assert coords[1] == pytest.approx([-20037508.343, -19971868.880, 0])
+    roundtrip = result.select(st.to_srid(srid=4326).st.to_ewkt(rounding_precision=9)).item()
+    assert roundtrip == "SRID=4326;LINESTRING Z (2.35 48.85 10, -180 -85 0)"
+
+
+def test_to_srid_mixed_srids():
+    gdf = st.GeoDataFrame([
+        "SRID=3857;POINT (261600.803 6249447.753)",
+        "SRID=32631;MULTIPOINT ((452314.891 5410984.888), (500000 0))",
+        None,
+        "SRID=4326;LINESTRING (2.35 48.85, 3 0)",
+        "SRID=32631;POLYGON ((500000 0, 452314.891 5410984.888, 500000 10, 500000 0))",
+    ])
+    result = gdf.select(st.to_srid(srid=4326).st.to_ewkt(rounding_precision=6)).to_series()
+    assert result.to_list() == [
+        "SRID=4326;POINT (2.35 48.85)",
+        "SRID=4326;MULTIPOINT ((2.35 48.85), (3 0))",
+        None,
+        "SRID=4326;LINESTRING (2.35 48.85, 3 0)",
+        "SRID=4326;POLYGON ((3 0, 2.35 48.85, 3 0.00009, 3 0))",
+    ]
+
+
+def test_bounds_to_srid():
+    gdf = st.GeoDataFrame([
+        "SRID=4326;POLYGON ((0 40, 6 40, 6 50, 0 50, 0 40))",
+        "SRID=4326;POINT (2.35 48.85)",
+        "SRID=4326;POINT EMPTY",
+    ])
+    result = gdf.select(st.bounds_to_srid(srid=3857)).to_series()
+    expected = gdf.select(st.to_srid(srid=3857).st.bounds()).to_series()
+    assert result[0].to_list() == pytest.approx(expected[0].to_list())
+    assert result[1].to_list() == pytest.approx([261600.803, 6249447.753] * 2)
+    assert np.isnan(result[2].to_numpy()).all()
+
+
+def test_bounds_to_srid_densify():
+    gdf = st.GeoDataFrame(["SRID=4326;POLYGON ((0 40, 6 40, 6 50, 0 50, 0 40))"])
+    # The northern edge curves in UTM, with its maximum on the central meridian.
+    corners = gdf.select(st.bounds_to_srid(srid=32631, densify_points=0)).item()
+    densified = gdf.select(st.bounds_to_srid(srid=32631)).item()
+    central = gdf.select(st.segmentize(0.01).st.to_srid(32631).st.bounds()).item()
+    assert densified[3] > corners[3]
+    assert densified[3] == pytest.approx(central[3])
+
+
+def test_transform_with():
+    gdf = st.GeoDataFrame([
+        "SRID=3857;POINT (1 2)",
+        None,
+        "LINESTRING Z (0 0 1, 1 1 2)",
+        "POLYGON ((0 0, 1 0, 1 1, 0 0), (0.2 0.1, 0.8 0.1, 0.8 0.7, 0.2 0.1))",
+        "GEOMETRYCOLLECTION (POINT EMPTY, MULTIPOINT ((3 4)))",
+    ])
+    calls = []
+
+    def swap(coords: np.ndarray) -> np.ndarray:
+        calls.append(coords.shape)
+        return coords[:, ::-1]
+
+    result = gdf.select(st.transform_with(func=swap).st.to_ewkt()).to_series().to_list()
+    assert calls == [(12, 2)]
+    assert result == [
+        "SRID=3857;POINT (2 1)",
+        None,
+        "LINESTRING Z (0 0 1, 1 1 2)",
+        "POLYGON ((0 0, 0 1, 1 1, 0 0), (0.1 0.2, 0.1 0.8, 0.7 0.8, 0.1 0.2))",
+        "GEOMETRYCOLLECTION (POINT EMPTY, MULTIPOINT ((4 3)))",
+    ]
+
+    result = gdf.select(st.transform_with(func=lambda c: c + [0, 0, 10], include_z=True))
+    assert result.select(st.to_wkt()).to_series().to_list()[:3] == [
+        "POINT (1 2)",
+        None,
+        "LINESTRING Z (0 0 11, 1 1 12)",
+    ]
+
+    with pytest.raises((ValueError, pl.exceptions.ComputeError), match="Expected coordinates"):
+        gdf.select(st.transform_with(func=lambda c: c[1:]))
+
+
+def test_to_srid_pipeline():
+    gdf = st.GeoDataFrame(["SRID=4326;POINT (2 1)", "SRID=4326;POINT EMPTY"])
+    pipeline = (
+        "+proj=pipeline +a=6378137 +b=6378137"
+        " +step +proj=unitconvert +xy_in=deg +xy_out=rad"
+        " +step +proj=merc"
+    )
+    expected = gdf.select(st.to_srid(srid=3857).st.to_ewkt(rounding_precision=3))
+    result = gdf.select(st.to_srid(srid=3857, pipeline=pipeline).st.to_ewkt(rounding_precision=3))
+    assert result.equals(expected)
+
+    inverse = f"{pipeline} +step +inv +proj=merc +step +inv +proj=unitconvert +xy_in=deg"
+    result = gdf.select(st.to_srid(srid=4326, pipeline=inverse).st.to_ewkt(rounding_precision=6))
+    assert result.to_series().to_list() == ["SRID=4326;POINT (2 1)", "SRID=4326;POINT EMPTY"]
+
+    with pytest.raises(pl.exceptions.ComputeError, match="Invalid PROJ pipeline"):
+        gdf.select(st.to_srid(srid=3857, pipeline="+proj=merc"))
+    with pytest.raises(pl.exceptions.ComputeError, match=r"unknown parameter \+xy_ou"):
+        gdf.select(st.to_srid(srid=3857, pipeline=pipeline.replace("xy_out", "xy_ou")))
+    typo = pipeline.replace("+a=6378137 +b=6378137", "+ellps=GRS8O")
+    with pytest.raises(pl.exceptions.ComputeError, match="Invalid PROJ pipeline"):
+        gdf.select(st.to_srid(srid=3857, pipeline=typo))
+
+
+def write_ntv2_grid(path: Path, lat_shift: float, lon_shift: float):
+    def record(key: str, fmt: str, value: object) -> bytes:
+        return key.ljust(8).encode() + struct.pack(fmt, value)
+
+    # A single sub-grid from 0 to 1 degree north and 0 to 2 degrees east, with
+    # longitudes positive west and constant shifts in arc-seconds.
+    header = [("NUM_OREC", "<i4x", 11), ("NUM_SREC", "<i4x", 11), ("NUM_FILE", "<i4x", 1)]
+    header += [("GS_TYPE", "8s", b"SECONDS ")] + [(key, "8s", b"") for key in "ABCDEFG"]
+    subgrid = [("SUB_NAME", "8s", b"TEST"), ("PARENT", "8s", b"NONE"), ("CREATED", "8s", b"")]
+    subgrid += [("UPDATED", "8s", b""), ("S_LAT", "<d", 0.0), ("N_LAT", "<d", 3600.0)]
+    subgrid += [("E_LONG", "<d", -7200.0), ("W_LONG", "<d", 0.0), ("LAT_INC", "<d", 3600.0)]
+    subgrid += [("LONG_INC", "<d", 3600.0), ("GS_COUNT", "<i4x", 6)]
+    data = b"".join(record(*r) for r in header + subgrid)
+    data += struct.pack("<4f", lat_shift, lon_shift, 0, 0) * 6
+    path.write_bytes(data)
+
+
+def write_gtx_grid(path: Path, heights: list[float]):
+    # A single row of nodes from 0 to 2 degrees east, repeated from 0 to 1 degree north.
+    header = struct.pack(">4d2i", 0.0, 0.0, 1.0, 1.0, 2, len(heights))
+    path.write_bytes(header + struct.pack(f">{2 * len(heights)}f", *heights, *heights))
+
+
+def test_to_srid_geoid(tmp_path: Path):
+    grid = tmp_path / "geoid.gtx"
+    write_gtx_grid(grid, [40, 50, 60])
+    srid = st.register_crs(f"+proj=longlat +datum=WGS84 +geoidgrids={grid}")
+    gdf = st.GeoDataFrame(["SRID=4326;POINT Z (1.5 0.5 100)", "SRID=4326;POINT (1.5 0.5)"])
+    result = gdf.select(st.to_srid(srid=srid).st.to_wkt(rounding_precision=6))
+    assert result.to_series().to_list() == ["POINT Z (1.5 0.5 45)", "POINT (1.5 0.5)"]
+
+    result = gdf.with_columns(st.set_srid(srid=srid)).select(st.to_srid(srid=32631))
+    assert result.select(st.z()).to_series()[0] == pytest.approx(155)
+
+    outside = st.GeoDataFrame(["SRID=4326;POINT Z (5 0.5 100)"])
+    with pytest.raises(pl.exceptions.ComputeError, match="outside of the geoid grids"):
+        outside.select(st.to_srid(srid=srid))
+
+
+def test_to_srid_compound_wkt(tmp_path: Path):
+    grid = tmp_path / "geoid.gtx"
+    write_gtx_grid(grid, [40, 40, 40])
+    wkt = (
+        'COMPD_CS["WGS 84 + geoid height",'
+        'GEOGCS["WGS 84",DATUM["WGS_1984",SPHEROID["WGS 84",6378137,298.257223563]],'
+        'PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]],'
+        f'VERT_CS["geoid height",VERT_DATUM["geoid",2005,EXTENSION["PROJ4_GRIDS","{grid}"]],'
+        'UNIT["metre",1]]]'
+    )
+    srid = st.register_crs(wkt)
+    gdf = st.GeoDataFrame(["SRID=4326;POINT Z (1 0.5 100)"])
+    result = gdf.select(st.to_srid(srid=srid).st.to_wkt(rounding_precision=6)).item()
+    assert result == "POINT Z (1 0.5 60)"
+
+
+def test_to_srid_keeps_heights():
+    gdf = st.GeoDataFrame(["SRID=4326;POINT Z (3 0 100)"])
+    result = gdf.select(st.to_srid(srid=32631).st.to_wkt(rounding_precision=3)).item()
+    assert result == "POINT Z (500000 0 100)"
+
+
+def test_to_srid_vgridshift(tmp_path: Path):
+    grid = tmp_path / "geoid.gtx"
+    write_gtx_grid(grid, [40, 50, 60])
+    gdf = st.GeoDataFrame(["SRID=4326;POINT Z (0.5 0.5 100)"])
+    pipeline = (
+        "+proj=pipeline +step +proj=unitconvert +xy_in=deg +xy_out=rad"
+        f" +step +proj=vgridshift +grids={grid}"
+        " +step +inv +proj=unitconvert +xy_in=deg +xy_out=rad"
+    )
+    result = gdf.select(st.to_srid(srid=4326, pipeline=pipeline).st.to_wkt(rounding_precision=6))
+    assert result.item() == "POINT Z (0.5 0.5 55)"
+
+    inverse = pipeline.replace("+proj=vgridshift", "+inv +proj=vgridshift +multiplier=2")
+    result = gdf.select(st.to_srid(srid=4326, pipeline=inverse).st.to_wkt(rounding_precision=6))
+    assert result.item() == "POINT Z (0.5 0.5 10)"
+
+
+def test_to_srid_hgridshift(tmp_path: Path):
+    grid = tmp_path / "test.gsb"
+    write_ntv2_grid(grid, 1.8, -3.6)
+    gdf = st.GeoDataFrame(["SRID=4326;POINT (1 0.5)"])
+    pipeline = (
+        "+proj=pipeline +step +proj=unitconvert +xy_in=deg +xy_out=rad"
+        f" +step +proj=hgridshift +grids=@{tmp_path / 'missing.gsb'},{grid}"
+        " +step +inv +proj=unitconvert +xy_in=deg +xy_out=rad"
+    )
+    result = gdf.select(st.to_srid(srid=4326, pipeline=pipeline).st.to_wkt(rounding_precision=6))
+    assert result.item() == "POINT (1.001 0.5005)"
+
+    inverse = pipeline.replace("+proj=hgridshift", "+inv +proj=hgridshift")
+    result = gdf.select(st.to_srid(srid=4326, pipeline=inverse).st.to_wkt(rounding_precision=6))
+    assert result.item() == "POINT (0.999 0.4995)"
+
+    gdf = st.GeoDataFrame(["SRID=4326;POINT (3 0.5)"])
+    with pytest.raises(pl.exceptions.ComputeError, match="outside of the hgridshift grids"):
+        gdf.select(st.to_srid(srid=4326, pipeline=pipeline))
+
+
+def write_nadcon_grid(path: Path, lat_shift: float, lon_shift: float):
+    # A grid from 0 to 1 degree north and 0 to 23 degrees east, with constant shifts in
+    # arc-seconds. Records are long enough for the header, as in the published grids.
+    columns, rows = 24, 2
+    for extension, shift in [(".las", lat_shift), (".los", lon_shift)]:
+        header = struct.pack("<56s8s3i5f", b"TEST", b"NADCON", columns, rows, 1, 0, 1, 0, 1, 0)
+        record = 4 * (columns + 1)
+        row = struct.pack(f"<i{columns}f", 0, *[shift] * columns)
+        path.with_suffix(extension).write_bytes(header.ljust(record, b"\0") + row * rows)
+
+
+@pytest.mark.parametrize("grid_name", ["test.gsb", "test.las"])
+def test_to_srid_datum_grids(tmp_path: Path, grid_name: str):
+    grid = tmp_path / grid_name
+    match grid.suffix:
+        case ".gsb":
+            write_ntv2_grid(grid, 1.8, -3.6)
+        case _:
+            write_nadcon_grid(grid, 1.8, -3.6)
+    srid = st.register_crs("+proj=longlat +ellps=clrk66 +towgs84=-8,160,176")
+    st.register_datum_grids(srid, grid)
+    gdf = st.GeoDataFrame([f"SRID={srid};POINT (1 0.5)"])
+    result = gdf.select(st.to_srid(srid=4326).st.to_wkt(rounding_precision=6))
+    assert result.item() == "POINT (1.001 0.5005)"
+
+    gdf = st.GeoDataFrame(["SRID=4326;POINT (1 0.5)"])
+    result = gdf.select(st.to_srid(srid=srid).st.to_wkt(rounding_precision=6))
+    assert result.item() == "POINT (0.999 0.4995)"
+
+    st.register_datum_grids(srid, [])
+    result = gdf.select(st.to_srid(srid=srid).st.to_wkt(rounding_precision=6))
+    assert result.item() != "POINT (0.999 0.4995)"
+
+    st.register_datum_grids(srid, [grid])
+    gdf = st.GeoDataFrame(["SRID=4326;POINT (1 5)"])
+    with pytest.raises(pl.exceptions.ComputeError, match="outside of the datum shift grids"):
+        gdf.select(st.to_srid(srid=srid))
+
+
+def test_geodesic_measures():
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))",
+        "SRID=4326;LINESTRING (0 0, 1 0)",
+        "MULTIPOINT ((0 0), (1 1))",
+    ])
+    result = gdf.select(st.area_geodesic(), st.length_geodesic().alias("length"))
+    assert result["geometry"].to_list() == pytest.approx([1.23088e10, 0, 0], rel=1e-4)
+    assert result["length"].to_list() == pytest.approx([443770.917, 111319.491, 0])
+
+    gdf = st.GeoDataFrame(["SRID=3857;POINT (0 0)"])
+    with pytest.raises(pl.exceptions.ComputeError, match="require EPSG:4326"):
+        gdf.select(st.area_geodesic())
+
+
+def test_geodesic_curves():
+    gdf = st.GeoDataFrame([
+        "CIRCULARSTRING (0 0, 1 1, 2 0)",
+        "CURVEPOLYGON (CIRCULARSTRING (0 0, 2 0, 0 0))",
+    ])
+    linear = gdf.select(st.curve_to_linear(tolerance=1e-7))
+    measures = [st.length_geodesic().alias("length"), st.area_geodesic().alias("area")]
+    result = gdf.select(measures).rows()
+    expected = linear.select(measures).rows()
+    assert result == [pytest.approx(row) for row in expected]
+    assert result[1][1] > 0
+    segmentized = gdf.select(st.segmentize_geodesic(max_length_m=10_000))
+    assert segmentized.select(st.geometry_type()).to_series().to_list() == [
+        "LineString", "Polygon",
+    ]
+
+
+def test_segmentize_geodesic():
+    gdf = st.GeoDataFrame(["SRID=4326;LINESTRING Z (-74 40.6 0, 2.5 49 10)"])
+    result = gdf.select(st.segmentize_geodesic(max_length_m=100_000))
+    assert result.select(st.srid()).item() == 4326
+    assert result.select(st.count_points()).item() == 60
+    assert result.select(st.length_geodesic()).item() == pytest.approx(
+        gdf.select(st.length_geodesic()).item()
+    )
+    # Vertices follow the geodesic, which reaches higher latitudes than the endpoints.
+    coords = result.select(st.geom().st.coordinates(output_dimension=3)).item().to_list()
+    assert max(lat for _, lat, _ in coords) > 51
+    assert coords[30][2] == pytest.approx(10 * 30 / 59)
+
+    with pytest.raises(pl.exceptions.ComputeError, match="strictly positive"):
+        gdf.select(st.segmentize_geodesic(max_length_m=0))
+
+
+def test_buffer_geodesic():
+    gdf = st.GeoDataFrame(["SRID=4326;POINT (2.35 48.85)", "LINESTRING (-0.1 51.5, 2.35 48.85)"])
+    result = gdf.select(st.buffer_geodesic(distance_m=1000, quad_segs=64))
+    assert result.select(st.srid()).to_series().to_list() == [4326, 0]
+    area = result.select(st.area_geodesic()).to_series()
+    assert area[0] == pytest.approx(np.pi * 1000**2, rel=1e-3)
+    length = gdf.select(st.length_geodesic()).item(1, 0)
+    assert area[1] == pytest.approx(2000 * length + np.pi * 1000**2, rel=1e-2)
+    # Every vertex of the point buffer is 1 km away from the point.
+    coords = result.select(st.coordinates()).item(0, 0).to_list()
+    radii = st.GeoSeries([f"LINESTRING (2.35 48.85, {x} {y})" for x, y in coords])
+    assert radii.st.length_geodesic().to_list() == pytest.approx([1000] * len(coords))
+
+    with pytest.raises(pl.exceptions.ComputeError, match="require EPSG:4326"):
+        gdf.select(st.set_srid(srid=3857).st.buffer_geodesic(100))
+
+
+def test_utm_zone():
+    gdf = st.GeoDataFrame([
+        "SRID=4326;POINT (2.35 48.85)",
+        "POINT (-70.6 -33.4)",
+        "POINT (5 60)",
+        "POINT (15 78)",
+        "POINT (180 0)",
+        "SRID=3857;POINT (261600 6250000)",
+        "POINT EMPTY",
+    ])
+    result = gdf.select(st.utm_zone()).to_series().to_list()
+    assert result == [32631, 32719, 32632, 32633, 32601, 32631, None]
+
+
+def test_to_utm():
+    gdf = st.GeoDataFrame(["SRID=4326;POINT (3 0)", "SRID=4326;POINT (9 -1)"])
+    result = gdf.select(st.to_utm().st.to_ewkt(rounding_precision=0)).to_series().to_list()
+    assert result == ["SRID=32631;POINT (500000 0)", "SRID=32732;POINT (500000 9889470)"]
+
+    gdf = st.GeoDataFrame(["SRID=4326;POINT (2 1)", "SRID=4326;POINT (7 1)"])
+    assert gdf.select(st.to_utm().st.srid()).to_series().to_list() == [32631, 32632]
+    result = gdf.select(st.to_utm(by="frame").st.srid()).to_series().to_list()
+    assert result == [32631, 32631]
+
+
+def test_to_srid_antimeridian():
+    srid = st.register_crs("+proj=merc +lon_0=180 +datum=WGS84 +units=m")
+    gdf = st.GeoDataFrame([
+        "LINESTRING (-111319.49 0, 111319.49 0)",
+        "POLYGON ((-111319.49 0, 111319.49 0, 111319.49 100000, -111319.49 100000, -111319.49 0))",
+    ]).with_columns(st.set_srid(srid=srid))
+
+    def transform(antimeridian: Literal["ignore", "shift", "split"]) -> pl.Series:
+        expr = st.to_srid(srid=4326, antimeridian=antimeridian)
+        return gdf.select(expr).to_series()
+
+    assert transform("ignore").st.to_wkt(rounding_precision=3)[0] == "LINESTRING (179 0, -179 0)"
+    assert transform("shift").st.to_wkt(rounding_precision=3)[0] == "LINESTRING (179 0, 181 0)"
+    split = transform("split")
+    assert split.st.geometry_type().to_list() == ["MultiLineString", "MultiPolygon"]
+    assert split.st.length_geodesic()[0] == pytest.approx(2 * 111319.49, rel=1e-6)
+    assert split.st.bounds()[1].to_list() == pytest.approx([-180, 0, 180, 0.904], abs=1e-3)
+
+
+def test_to_srid_antimeridian_projected_destination():
+    gdf = st.GeoDataFrame(["SRID=4326;LINESTRING (-100 0, 100 0)"])
+    ignored = gdf.select(st.to_srid(srid=3857).st.to_ewkt(rounding_precision=3))
+    for antimeridian in ["shift", "split"]:
+        result = gdf.select(st.to_srid(srid=3857, antimeridian=antimeridian).st.to_ewkt(rounding_precision=3))
+        assert result.equals(ignored)
+
+
+def test_to_srid_antimeridian_pole():
+    # A square around the north pole in the Arctic polar stereographic projection.
+    gdf = st.GeoDataFrame([
+        "SRID=3995;POLYGON ((-1e6 -1e6, 1e6 -1e6, 1e6 1e6, -1e6 1e6, -1e6 -1e6))",
+    ])
+    for antimeridian in ["shift", "split"]:
+        result = gdf.select(st.to_srid(srid=4326, antimeridian=antimeridian))
+        assert result.select(st.is_valid()).item()
+        assert result.select(st.bounds()).item()[3] == pytest.approx(90)
+    bounds = gdf.select(st.to_srid(srid=4326, antimeridian="split").st.bounds()).item()
+    assert bounds == pytest.approx([-180, 77.04, 180, 90], abs=0.01)
+
+
+def test_buffer_geodesic_antimeridian():
+    gdf = st.GeoDataFrame(["POINT (180 0)"])
+    result = gdf.select(st.buffer_geodesic(distance_m=1000))
+    assert result.select(st.geometry_type()).item() == "MultiPolygon"
+    assert result.select(st.area_geodesic()).item() == pytest.approx(np.pi * 1000**2, rel=1e-2)
+
+
+def test_knn():
+    rng = np.random.default_rng(0)
+    points, queries = rng.uniform(0, 100, (500, 2)), rng.uniform(0, 100, (50, 2))
+    others = st.GeoSeries([f"POINT ({x} {y})" for x, y in points])
+    gdf = st.GeoDataFrame([f"POINT ({x} {y})" for x, y in queries])
+    result = gdf.select(st.geom().st.knn(others, k=5)).to_series()
+    distances = np.hypot(*(queries[:, None] - points[None]).transpose(2, 0, 1))
+    expected = np.argsort(distances, axis=1)[:, :5]
+    assert result.list.eval(pl.element().struct["index"]).to_list() == expected.tolist()
+    expected_distances = np.take_along_axis(distances, expected, axis=1)
+    result_distances = result.list.eval(pl.element().struct["distance"]).to_list()
+    assert np.allclose(result_distances, expected_distances)
+
+
+def test_knn_nulls_and_empty():
+    others = st.GeoSeries(["POINT (1 0)", None, "POINT EMPTY", "POINT (3 0)"])
+    gdf = st.GeoDataFrame(["POINT (0 0)", None, "POINT EMPTY"])
+    result = gdf.select(st.geom().st.knn(others, k=5)).to_series().to_list()
+    assert result == [
+        [{"index": 0, "distance": 1.0}, {"index": 3, "distance": 3.0}],
+        None,
+        [],
+    ]
+
+
+def test_morton_index():
+    gdf = st.GeoDataFrame(["POINT (0 0)", "POINT (3 1)", "POINT (10 -5)", None, "POINT EMPTY"])
+    result = gdf.select(st.morton_index(extent=(0, 0, 4, 4), level=2)).to_series()
+    assert result.to_list() == [0, 7, 5, None, None]
+    # Without extent, the grid covers the total bounds of the geometries.
+    result = gdf.head(2).select(st.morton_index(level=1)).to_series()
+    assert result.to_list() == [0, 3]
+    with pytest.raises(ValueError, match="level"):
+        gdf.select(st.morton_index(level=33))
+
+
+def test_spatial_partition_grid():
+    gdf = st.GeoDataFrame(["POINT (0 0)", "POINT (5 5)", "LINESTRING (1 1, 6 1)", None])
+    result = gdf.select(st.spatial_partition(n=9, extent=(0, 0, 6, 6))).to_series()
+    assert result.to_list() == [[0], [8], [0, 1, 2], None]
+
+
+def test_spatial_partition_kdb():
+    gdf = st.GeoDataFrame([f"POINT ({x} 0)" for x in range(8)])
+    result = gdf.select(st.spatial_partition(scheme="kdb", n=4)).to_series()
+    assert result.to_list() == [[0], [0], [1], [1], [2], [2], [3], [3]]
+    # Partitions are only split while they hold distinct centers.
+    gdf = st.GeoDataFrame(["POINT (0 0)"] * 3)
+    result = gdf.select(st.spatial_partition(scheme="kdb", n=4)).to_series()
+    assert result.to_list() == [[0], [0], [0]]
+
+
+def test_count_matches():
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+        "POLYGON ((10 10, 12 10, 12 12, 10 12, 10 10))",
+        None,
+        "POLYGON EMPTY",
+    ])
+    others = st.GeoSeries(["POINT (1 1)", "POINT (2 1)", None, "POINT (5 5)", "POINT EMPTY"])
+    result = gdf.select(
+        intersects=st.geom().st.count_matches(others),
+        contains_properly=st.geom().st.count_matches(others, "contains_properly"),
+        dwithin=st.geom().st.count_matches(others, "dwithin", distance=8),
+    )
+    assert result.to_dict(as_series=False) == {
+        "intersects": [2, 0, None, 0],
+        "contains_properly": [1, 0, None, 0],
+        "dwithin": [3, 1, None, 0],
+    }
+
+
+def test_count_matches_requires_distance():
+    with pytest.raises(ValueError, match="distance"):
+        st.geom().st.count_matches(st.geom(), "dwithin")
+
+
+def test_pairs_within():
+    rng = np.random.default_rng(0)
+    points = rng.uniform(0, 100, (300, 2))
+    gdf = st.GeoDataFrame([f"POINT ({x} {y})" for x, y in points])
+    result = gdf.select(st.geom().st.pairs_within(5)).unnest("geometry")
+    distances = np.hypot(*(points[:, None] - points[None]).transpose(2, 0, 1))
+    expected = np.argwhere(np.triu(distances <= 5, k=1))
+    assert result.select("left_index", "right_index").rows() == [tuple(p) for p in expected]
+    assert np.allclose(result["distance"], distances[expected[:, 0], expected[:, 1]])
+
+
+def test_pairs_within_nulls_and_empty():
+    gdf = st.GeoDataFrame(["POINT (0 0)", None, "POINT EMPTY", "POINT (1 0)", "POINT (3 0)"])
+    result = gdf.select(st.geom().st.pairs_within(2)).unnest("geometry")
+    assert result.rows() == [(0, 3, 1.0), (3, 4, 2.0)]
+
+
+def test_is_duplicate_geometry():
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+        "POINT (0 0)",
+        "POLYGON ((1 0, 1 1, 0 0, 1 0))",
+        "POINT (0.01 0)",
+        None,
+        "POINT EMPTY",
+        "LINESTRING EMPTY",
+        None,
+    ])
+    result = gdf.select(
+        exact=st.geom().st.is_duplicate_geometry(),
+        tolerance=st.geom().st.is_duplicate_geometry(tolerance=0.1),
+    )
+    assert result.to_dict(as_series=False) == {
+        "exact": [True, False, True, False, True, True, True, True],
+        "tolerance": [False, True, False, True, True, True, True, True],
+    }
+
+
+def test_unique_geometries():
+    gdf = st.GeoDataFrame([
+        "LINESTRING (0 0, 1 1)",
+        None,
+        "POINT (0 0)",
+        "LINESTRING (1 1, 0 0)",
+        None,
+        "POINT (0 0)",
+    ])
+    result = gdf.select(st.geom().st.unique_geometries().st.to_wkt()).to_series()
+    assert result.to_list() == ["LINESTRING (0 0, 1 1)", None, "POINT (0 0)"]
+
+
+@pytest.mark.parametrize(
+    "predicate",
+    [
+        "crosses",
+        "contains",
+        "contains_properly",
+        "covered_by",
+        "covers",
+        "intersects",
+        "overlaps",
+        "touches",
+        "within",
+    ],
+)
+def test_predicates_with_literal(predicate: str):
+    gdf = st.GeoDataFrame([
+        "POINT (1 1)",
+        "POINT (0 0)",
+        "LINESTRING (-1 1, 3 1)",
+        "POLYGON ((1 1, 3 1, 3 3, 1 3, 1 1))",
+        "POLYGON ((0.5 0.5, 1 0.5, 1 1, 0.5 0.5))",
+        None,
+    ])
+    square = st.from_wkt(pl.lit("POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))"))
+    squares = gdf.select(st.from_wkt(pl.repeat("POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))", 6)))
+    gdf = gdf.with_columns(square=squares.to_series())
+    # The literal is prepared on the first iteration, then taken from the cache.
+    for _ in range(2):
+        result = gdf.select(
+            left=getattr(st.geom().st, predicate)(square),
+            right=getattr(square.st, predicate)(st.geom()),
+        )
+        expected = gdf.select(
+            left=getattr(st.geom().st, predicate)(st.geom("square")),
+            right=getattr(st.geom("square").st, predicate)(st.geom()),
+        )
+        assert result.equals(expected)
+
+
+def test_predicates_with_literal_across_chunks_and_threads():
+    points = [f"POINT ({i % 40 / 10} 1)" for i in range(400)]
+    chunks = [st.GeoDataFrame(points[i : i + 10]) for i in range(0, 400, 10)]
+    gdf = pl.concat(chunks, rechunk=False)
+    assert gdf.n_chunks() == 40
+    square = st.from_wkt(pl.lit("POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))"))
+    expected = [i % 40 <= 20 for i in range(400)]
+
+    def run(_: int) -> list[bool | None]:
+        return gdf.lazy().select(st.geom().st.intersects(square)).collect().to_series().to_list()
+
+    with ThreadPoolExecutor(max_workers=4) as executor:
+        assert all(result == expected for result in executor.map(run, range(16)))
+
+
+def test_linestring_from_coordinates():
+    points = st.GeoDataFrame({
+        "track": [0, 0, 1, 1, 1],
+        "geometry": [
+            "POINT Z (0 0 1)",
+            "POINT Z (1 1 2)",
+            "POINT Z (5 5 0)",
+            "POINT Z (6 5 0)",
+            "POINT Z (6 6 0)",
+        ],
+    })
+    tracks = points.group_by("track", maintain_order=True).agg(st.coordinates().explode())
+    assert tracks.schema["geometry"] == pl.List(pl.Array(pl.Float64, 3))
+    result = tracks.select(st.linestring("geometry").st.to_wkt())
+    assert result.to_series().to_list() == [
+        "LINESTRING Z (0 0 1, 1 1 2)",
+        "LINESTRING Z (5 5 0, 6 5 0, 6 6 0)",
+    ]
+    roundtrip = result.select(st.linestring(st.from_wkt("geometry").st.coordinates()))
+    assert roundtrip.select(st.to_wkt()).equals(result)
+
+
+def test_polygon_from_rings():
+    df = pl.DataFrame({
+        "exterior": [
+            [[0, 0], [0, 4], [4, 4], [4, 0], [0, 0]],
+            [[0, 0], [1, 0], [1, 1], [0, 0]],
+            None,
+        ],
+        "holes": [[[[1, 1], [1, 2], [2, 2], [1, 1]]], None, None],
+    })
+    result = df.select(st.polygon("exterior", holes="holes").st.to_wkt())
+    assert result.to_series().to_list() == [
+        "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 1 2, 2 2, 1 1))",
+        "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+        None,
+    ]
+    unclosed = pl.DataFrame({"exterior": [[[0, 0], [1, 0], [1, 1], [0, 1]]], "holes": [None]})
+    with pytest.raises(pl.exceptions.ComputeError, match="first and last coordinates"):
+        unclosed.select(st.polygon("exterior", holes="holes"))
+    with pytest.raises(pl.exceptions.ComputeError):
+        pl.DataFrame({"coords": [unclosed["exterior"].to_list()]}).select(st.polygon("coords"))
+
+
+def test_polygon_orientation():
+    ring = [[0, 0], [0, 1], [1, 1], [0, 0]]
+    df = pl.DataFrame({"coords": [[ring]], "exterior": [ring], "holes": [None]})
+    kept = df.select(st.polygon("coords").st.to_wkt()).item()
+    oriented = df.select(st.polygon("exterior", holes="holes").st.to_wkt()).item()
+    assert kept == "POLYGON ((0 0, 0 1, 1 1, 0 0))"
+    assert oriented == "POLYGON ((0 0, 1 1, 0 1, 0 0))"
+
+
+def test_from_bounds():
+    gdf = st.GeoDataFrame(["POLYGON ((0 0, 4 1, 2 3, 0 0))", "LINESTRING (-1 -2, 5 6)"])
+    names = ["xmin", "ymin", "xmax", "ymax"]
+    bounds = gdf.select(st.bounds().arr.to_struct(names)).unnest("geometry")
+    result = bounds.select(geometry=st.from_bounds(*names, srid=pl.lit(3857)))
+    assert result.st.to_ewkt().to_series().to_list() == [
+        "SRID=3857;POLYGON ((0 0, 4 0, 4 3, 0 3, 0 0))",
+        "SRID=3857;POLYGON ((-1 -2, 5 -2, 5 6, -1 6, -1 -2))",
+    ]
+    assert result.select(st.bounds()).equals(gdf.select(st.bounds()))
+
+
+def test_make_grid():
+    gdf = st.make_grid((0, 0, 2.5, 1), cell_size=1, srid=3857)
+    assert gdf.columns == ["row", "col", "geometry"]
+    assert gdf.select("row", "col").rows() == [(0, 0), (0, 1), (0, 2)]
+    assert gdf.select(st.to_ewkt()).to_series().to_list()[-1] == (
+        "SRID=3857;POLYGON ((2 0, 3 0, 3 1, 2 1, 2 0))"
+    )
+    gdf = st.make_grid((0, 0, 4, 3), nx=2, ny=3)
+    assert gdf.select(st.area()).to_series().to_list() == [2.0] * 6
+    assert gdf.select(st.total_bounds()).item().to_list() == [0.0, 0.0, 4.0, 3.0]
+    with pytest.raises(ValueError, match="cell_size"):
+        st.make_grid((0, 0, 1, 1))
+    with pytest.raises(ValueError, match="cell_size"):
+        st.make_grid((0, 0, 1, 1), cell_size=1, nx=2, ny=2)
+
+
+@pytest.mark.parametrize("orientation", ["flat", "pointy"])
+def test_make_hex_grid(orientation: str):
+    bounds = (0, 0, 10, 10)
+    gdf = st.make_hex_grid(bounds, 2, orientation=orientation, srid=3857)  # type: ignore[arg-type]
+    assert gdf.height == 14
+    assert gdf.select(st.srid().unique()).item() == 3857
+    assert gdf.select(st.area()).to_series().to_list() == pytest.approx([6 * 3**0.5] * 14)
+    box = st.rectangle(0.0, 0.0, 10.0, 10.0).st.set_srid(3857)
+    covered = gdf.select(st.geom().st.intersection(box).st.area().sum()).item()
+    assert covered == pytest.approx(100)
+    with pytest.raises(ValueError, match="size"):
+        st.make_hex_grid(bounds, 0)
+
+
+def test_points_along():
+    gdf = st.GeoDataFrame([
+        "SRID=3857;LINESTRING Z (0 0 0, 4 0 4, 4 3 7)",
+        "MULTILINESTRING ((0 0, 1 0), (5 5, 5 7))",
+        "LINESTRING EMPTY",
+    ])
+    result = gdf.select(st.points_along(interval=2).list.eval(pl.element().st.to_ewkt()))
+    assert result.to_series().to_list() == [
+        [
+            "SRID=3857;POINT Z (0 0 0)",
+            "SRID=3857;POINT Z (2 0 2)",
+            "SRID=3857;POINT Z (4 0 4)",
+            "SRID=3857;POINT Z (4 2 6)",
+        ],
+        ["POINT (0 0)", "POINT (5 5)", "POINT (5 7)"],
+        [],
+    ]
+    result = gdf.select(st.points_along(fractions=[1, 0.5]).list.eval(pl.element().st.to_wkt()))
+    assert result.to_series().to_list() == [
+        ["POINT Z (4 3 7)", "POINT Z (3.5 0 3.5)"],
+        ["POINT (1 0)", "POINT (0.5 0)", "POINT (5 7)", "POINT (5 6)"],
+        [],
+    ]
+    with pytest.raises(ValueError, match="interval"):
+        gdf.select(st.points_along())
+
+
+def test_from_coords_structs():
+    df = pl.DataFrame({
+        "coords": [
+            [{"x": 0, "y": 1}, {"x": 2, "y": 3}],
+            [{"x": 4, "y": 5}],
+            [],
+            None,
+        ],
+    })
+    result = df.select(st.multipoint("coords").st.to_wkt())
+    assert result.to_series().to_list() == [
+        "MULTIPOINT ((0 1), (2 3))",
+        "MULTIPOINT ((4 5))",
+        "MULTIPOINT EMPTY",
+        None,
+    ]
+    result = df.head(1).select(st.from_coords("coords").st.to_wkt())
+    assert result.item() == "LINESTRING (0 1, 2 3)"
+    points = pl.DataFrame({"coords": [{"x": 0.0, "y": 1.0, "z": 2.0}]})
+    assert points.select(st.point("coords").st.to_wkt()).item() == "POINT Z (0 1 2)"
+
+
+def test_make_line():
+    gdf = st.GeoDataFrame({
+        "track": [0, 0, 1, 0, 1, 2],
+        "time": [2, 0, 1, 1, 0, 0],
+        "geometry": [
+            "POINT (2 2)",
+            "POINT (0 0)",
+            "MULTIPOINT ((5 5), (6 6))",
+            "LINESTRING (1 1, 1.5 1.5)",
+            "POINT EMPTY",
+            "POINT (9 9)",
+        ],
+    })
+    result = gdf.group_by("track", maintain_order=True).agg(
+        st.make_line(order_by="time").st.to_wkt(),
+    )
+    assert result.rows() == [
+        (0, "LINESTRING (0 0, 1 1, 1.5 1.5, 2 2)"),
+        (1, "LINESTRING (5 5, 6 6)"),
+        (2, "LINESTRING EMPTY"),
+    ]
+    assert gdf.select(st.make_line().st.to_wkt()).item() == (
+        "LINESTRING (2 2, 0 0, 5 5, 6 6, 1 1, 1.5 1.5, 9 9)"
+    )
+    with pytest.raises(pl.exceptions.ComputeError, match="make_line"):
+        gdf.select(st.geom().st.buffer(1).st.make_line())
+
+
+def test_collect_mixed_dimensions():
+    gdf = st.GeoDataFrame([
+        "POINT Z (0 1 2)",
+        "POINT M (3 4 5)",
+        "POINT ZM (6 7 8 9)",
+        None,
+    ])
+    result = gdf.select(st.collect().st.to_wkt())
+    assert result.item() == "MULTIPOINT ZM ((0 1 2 0), (3 4 0 5), (6 7 8 9))"
+    result = gdf.select(st.collect(mixed_dimensions="drop").st.to_wkt())
+    assert result.item() == "MULTIPOINT ((0 1), (3 4), (6 7))"
+    with pytest.raises(pl.exceptions.ComputeError, match="mixed dimensions"):
+        gdf.select(st.collect(mixed_dimensions="error"))
+    uniform = gdf.head(1).select(st.collect(mixed_dimensions="error").st.to_wkt())
+    assert uniform.item() == "MULTIPOINT Z ((0 1 2))"
+
+
+def test_h3():
+    gdf = st.GeoDataFrame([
+        "POINT (-122.0553238 37.3615593)",
+        "POINT EMPTY",
+        None,
+    ])
+    cells = gdf.select(st.to_h3(resolution=7)).to_series()
+    assert cells.dtype == pl.UInt64
+    assert cells.to_list() == [0x87283472BFFFFFF, None, None]
+    boundaries = cells.to_frame().select(st.h3_to_boundary("geometry"))
+    assert boundaries.select(st.geom().st.srid()).to_series().to_list() == [4326, None, None]
+    centroids = boundaries.select(st.centroid().st.to_h3(7))
+    assert centroids.to_series().equals(cells)
+    covers = boundaries.select(st.h3_cover(resolution=7)).to_series()
+    assert covers.dtype == pl.List(pl.UInt64)
+    assert 0x87283472BFFFFFF in covers[0]
+    assert covers[1:].to_list() == [None, None]
+    with pytest.raises(pl.exceptions.ComputeError, match="points"):
+        boundaries.select(st.to_h3(resolution=7))
+    with pytest.raises(pl.exceptions.ComputeError, match="polygons"):
+        gdf.select(st.h3_cover(resolution=7))
+    with pytest.raises(ValueError, match="resolution"):
+        gdf.select(st.to_h3(resolution=16))
+
+
+def test_tiles():
+    gdf = st.GeoDataFrame(
+        ["POINT (2.3522 48.8566)", "SRID=3857;POINT (261846 6250564)", "POINT EMPTY", None]
+    )
+    tiles = gdf.select(st.tile_of(zoom=12)).unnest("geometry")
+    assert tiles.rows() == [
+        (12, 2074, 1409),
+        (12, 2074, 1409),
+        (None, None, None),
+        (None, None, None),
+    ]
+    polygons = tiles.select(st.from_tile("z", "x", "y", srid=4326))
+    assert polygons.select(st.geom().st.srid()).to_series().to_list() == [4326, 4326, None, None]
+    tile = polygons.head(1).rename({"geometry": "tile"})
+    assert gdf.head(1).hstack(tile).select(st.geom().st.within(st.geom("tile"))).item()
+    centers = polygons.select(st.centroid().st.tile_of(12).struct.field("x"))
+    assert centers.to_series().to_list() == [2074, 2074, None, None]
+    quadkeys = pl.DataFrame({"quadkey": ["", "0", "3", "120", None]})
+    result = quadkeys.select(st.from_quadkey("quadkey").st.bounds().arr.get(0))
+    assert result.to_series().to_list() == [
+        pytest.approx(-20037508.342789244),
+        pytest.approx(-20037508.342789244),
+        pytest.approx(0.0),
+        pytest.approx(0.0),
+        None,
+    ]
+    with pytest.raises(pl.exceptions.ComputeError, match="outside of the grid"):
+        pl.select(st.from_tile(1, 2, 0))
+    with pytest.raises(pl.exceptions.ComputeError, match="quadkey"):
+        pl.select(st.from_quadkey(pl.lit("124")))
+    with pytest.raises(ValueError, match="zoom"):
+        gdf.select(st.tile_of(zoom=31))
+
+
+def test_circle_ellipse():
+    df = pl.DataFrame({
+        "center": [[0.0, 0.0], [10.0, 5.0], None],
+        "radius": [1.0, 2.0, 1.0],
+    })
+    circles = df.select(st.circle("center", "radius", quad_segs=16))
+    assert circles.select(st.area()).to_series().to_list() == [
+        pytest.approx(math.pi, rel=1e-2),
+        pytest.approx(4 * math.pi, rel=1e-2),
+        None,
+    ]
+    bounds = circles.select(st.bounds().arr.eval(pl.element().round(6))).to_series()
+    assert bounds.to_list() == [[-1, -1, 1, 1], [8, 3, 12, 7], None]
+    ellipses = df.select(st.ellipse("center", 3, "radius", rotation=90, quad_segs=16))
+    assert ellipses.select(st.area()).to_series().to_list() == [
+        pytest.approx(3 * math.pi, rel=1e-2),
+        pytest.approx(6 * math.pi, rel=1e-2),
+        None,
+    ]
+    bounds = ellipses.select(st.bounds().arr.eval(pl.element().round(6))).to_series()
+    assert bounds.to_list() == [[-1, -3, 1, 3], [8, 2, 12, 8], None]
+
+
+def test_interpolate_list():
+    gdf = st.GeoDataFrame({
+        "geometry": ["LINESTRING (0 0, 2 0, 2 3)", "LINESTRING (0 0, 0 4)", None],
+        "stations": [[0.0, 1.0, None, 5.0], [0.5], [1.0]],
+    })
+    result = gdf.select(st.interpolate(distance="stations"))
+    assert result.schema["geometry"] == pl.List(pl.Binary)
+    points = result.explode("geometry").select(st.to_wkt()).to_series()
+    assert points.to_list() == [
+        "POINT (0 0)",
+        "POINT (1 0)",
+        None,
+        "POINT (2 3)",
+        "POINT (0 0.5)",
+        None,
+    ]
+    result = gdf.select(st.interpolate(distance=pl.lit([0.5]), normalized=True))
+    points = result.explode("geometry").select(st.to_wkt()).to_series()
+    assert points.to_list() == ["POINT (2 0.5)", "POINT (0 2)", None]
+
+
+def test_polylabel():
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 6 0, 0 8, 0 0))",
+        "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 1, 0 0)), ((10 0, 14 0, 14 4, 10 4, 10 0)))",
+        "POLYGON EMPTY",
+        None,
+    ]).select(st.set_srid(srid=3857))
+    result = gdf.select(st.polylabel(tolerance=0.01))
+    assert result.select(st.x()).to_series().to_list()[:2] == [
+        pytest.approx(2, abs=0.01),
+        pytest.approx(12, abs=0.01),
+    ]
+    assert result.select(st.y()).to_series().to_list()[:2] == [
+        pytest.approx(2, abs=0.01),
+        pytest.approx(2, abs=0.01),
+    ]
+    assert result.select(st.is_empty()).to_series().to_list() == [False, False, True, None]
+    assert result.select(st.srid()).to_series().to_list() == [3857, 3857, 3857, None]
+    with pytest.raises(pl.exceptions.ComputeError, match="polygons"):
+        point_2d.select(st.polylabel())
+    with pytest.raises(pl.exceptions.ComputeError, match="positive"):
+        poly_2d.select(st.polylabel(tolerance=0))
+
+
+def test_subdivide():
+    ring = [(math.cos(a / 50 * math.pi), math.sin(a / 50 * math.pi)) for a in range(100)]
+    wkt = "POLYGON ((" + ", ".join(f"{x} {y}" for x, y in [*ring, ring[0]]) + "))"
+    gdf = st.GeoDataFrame([wkt, "POINT (1 2)", "POLYGON EMPTY", None])
+    gdf = gdf.select(st.set_srid(srid=3857))
+    result = gdf.select(st.subdivide(max_vertices=10))
+    assert result.schema["geometry"] == pl.List(pl.Binary)
+    assert result.select(pl.col("geometry").list.len()).to_series().to_list()[1:] == [1, 0, None]
+    pieces = result.head(1).explode("geometry")
+    assert len(pieces) > 10
+    assert pieces.select(st.count_coordinates().max()).item() <= 10
+    assert pieces.select(st.srid().unique()).to_series().to_list() == [3857]
+    assert pieces.select(st.area().sum()).item() == pytest.approx(
+        gdf.head(1).select(st.area()).item()
+    )
+    with pytest.raises(pl.exceptions.ComputeError, match="at least 5"):
+        gdf.select(st.subdivide(max_vertices=4))
+
+
+def test_split():
+    gdf = st.GeoDataFrame({
+        "geometry": [
+            "LINESTRING (0 0, 2 0, 2 2)",
+            "LINESTRING (0 0, 4 0)",
+            "LINESTRING (0 0, 4 0)",
+            "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+            "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+            None,
+        ],
+        "blade": [
+            "MULTIPOINT ((1 0), (2 0), (2 1), (5 5))",
+            "LINESTRING (1 -1, 1 1)",
+            "POLYGON ((1 -1, 3 -1, 3 1, 1 1, 1 -1))",
+            "LINESTRING (1 -1, 1 3)",
+            "LINESTRING (5 -1, 5 3)",
+            "POINT (0 0)",
+        ],
+    }).with_columns(st.from_wkt("blade"))
+    result = gdf.select(st.geom().st.split("blade"))
+    assert result.select(st.geometry_type()).to_series().to_list()[:5] == [
+        "GeometryCollection"
+    ] * 5
+    parts = result.select(st.parts()).to_series()
+    lines = [sorted(p.st.to_wkt().to_list()) for p in parts[:3]]
+    assert lines == [
+        [
+            "LINESTRING (0 0, 1 0)",
+            "LINESTRING (1 0, 2 0)",
+            "LINESTRING (2 0, 2 1)",
+            "LINESTRING (2 1, 2 2)",
+        ],
+        ["LINESTRING (0 0, 1 0)", "LINESTRING (1 0, 4 0)"],
+        ["LINESTRING (0 0, 1 0)", "LINESTRING (1 0, 3 0)", "LINESTRING (3 0, 4 0)"],
+    ]
+    areas = [p.st.area().to_list() for p in parts[3:5]]
+    assert areas == [[2.0, 2.0], [4.0]]
+    assert parts[5] is None
+    with pytest.raises(pl.exceptions.ComputeError, match="Cannot split"):
+        poly_2d.select(st.geom().st.split(dummy_point))
+
+
+def test_snap_to_grid():
+    gdf = st.GeoDataFrame([
+        "LINESTRING (0.2 0.3, 0.9 1.2, 0.7 0.8, 2.6 2.4)",
+        "POINT Z (1.26 -0.74 3.3)",
+        None,
+    ]).select(st.set_srid(srid=3857))
+    result = gdf.select(st.snap_to_grid(size_x=0.5, size_y=0, origin=(0.25, 0)))
+    assert result.select(st.to_wkt()).to_series().to_list() == [
+        "LINESTRING (0.25 0.3, 0.75 1.2, 0.75 0.8, 2.75 2.4)",
+        "POINT Z (1.25 -0.74 3.3)",
+        None,
+    ]
+    assert result.select(st.srid()).to_series().to_list() == [3857, 3857, None]
+    result = gdf.select(st.snap_to_grid(size_x=1))
+    assert result.select(st.to_wkt()).to_series().to_list() == [
+        "LINESTRING (0 0, 1 1, 3 2)",
+        "POINT Z (1 -1 3.3)",
+        None,
+    ]
+    collapsed = st.GeoDataFrame([
+        "POLYGON ((0 0, 0.4 0, 0.4 0.4, 0 0.4, 0 0))",
+        "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 1.2 1, 1.2 1.2, 1 1))",
+        "MULTIPOLYGON (((0 0, 0.4 0, 0 0.4, 0 0)), ((2 2, 4 2, 2 4, 2 2)))",
+    ])
+    result = collapsed.select(st.snap_to_grid(size_x=1))
+    assert result.select(st.to_wkt()).to_series().to_list() == [
+        "POLYGON EMPTY",
+        "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0))",
+        "MULTIPOLYGON (((2 2, 4 2, 2 4, 2 2)))",
+    ]
+    with pytest.raises(pl.exceptions.ComputeError, match="non-negative"):
+        gdf.select(st.snap_to_grid(size_x=-1))
+
+
+def test_simplify_vw():
+    gdf = st.GeoDataFrame([
+        "LINESTRING (0 0, 1 0.1, 2 0, 3 2, 4 0)",
+        "LINESTRING Z (0 0 1, 1 0.1 2, 2 0 3)",
+        "POLYGON ((0 0, 2 0, 2 0.1, 2 2, 0 2, 0 0))",
+        "POLYGON ((0 0, 1 0, 0 1, 0 0))",
+        "POINT (1 2)",
+        None,
+    ]).select(st.set_srid(srid=3857))
+    result = gdf.select(st.simplify_vw(area_tolerance=0.5))
+    assert result.select(st.to_wkt()).to_series().to_list() == [
+        "LINESTRING (0 0, 2 0, 3 2, 4 0)",
+        "LINESTRING Z (0 0 1, 2 0 3)",
+        "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+        "POLYGON ((0 0, 1 0, 0 1, 0 0))",
+        "POINT (1 2)",
+        None,
+    ]
+    assert result.select(st.srid()).to_series().to_list()[:5] == [3857] * 5
+    result = gdf.head(1).select(st.simplify_vw(area_tolerance=10))
+    assert result.select(st.to_wkt()).item() == "LINESTRING (0 0, 4 0)"
+
+
+def test_simplify_coverage():
+    gdf = st.GeoDataFrame({
+        "geometry": [
+            "POLYGON ((0 0, 2 0, 2.1 1, 2 2, 0 2, 0 0))",
+            None,
+            "POLYGON ((2 0, 4 0, 4 2, 2 2, 2.1 1, 2 0))",
+            "POLYGON ((10 0, 11 0, 11 1, 10 1, 10 0))",
+        ],
+        "coverage": [0, 0, 0, 1],
+    }).with_columns(st.set_srid(srid=3857))
+    result = gdf.select(st.simplify_coverage(tolerance=1.0))
+    assert result.select(st.count_coordinates()).to_series().to_list() == [5, None, 5, 5]
+    assert result.select(st.srid()).to_series().to_list() == [3857, None, 3857, 3857]
+    assert result.select(st.area().sum()).item() == pytest.approx(9.0)
+    union = result.select(st.coverage_union_all())
+    assert union.select(st.area()).item() == pytest.approx(9.0)
+    result = gdf.select(st.simplify_coverage(tolerance=1.0).over("coverage"))
+    assert result.select(st.count_coordinates()).to_series().to_list() == [5, None, 5, 5]
+    result = gdf.select(st.simplify_coverage(tolerance=1.0, preserve_boundary=True))
+    assert result.select(st.count_coordinates()).to_series().to_list() == [5, None, 5, 5]
+    result = st.GeoDataFrame([
+        "POLYGON ((0 0, 2 0, 2.1 1, 2 2, 0 2, 0 0))",
+        "MULTIPOLYGON EMPTY",
+    ]).select(st.simplify_coverage(tolerance=1.0).st.to_wkt())
+    assert result.to_series().to_list() == [
+        "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+        "MULTIPOLYGON EMPTY",
+    ]
+    with pytest.raises(pl.exceptions.ComputeError, match="polygons"):
+        line_2d.select(st.simplify_coverage(tolerance=1.0))
+
+
+def test_delaunay_triangles_per_geometry():
+    gdf = st.GeoDataFrame([
+        "MULTIPOINT ((0 0), (1 0), (0 1))",
+        "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))",
+        "MULTIPOINT EMPTY",
+        None,
+    ]).select(st.set_srid(srid=3857))
+    result = gdf.select(st.delaunay_triangles(per_geometry=True))
+    assert result.select(st.count_geometries()).to_series().to_list() == [1, 2, 0, None]
+    assert result.select(st.srid()).to_series().to_list() == [3857, 3857, 3857, None]
+    edges = gdf.select(st.delaunay_triangles(only_edges=True, per_geometry=True))
+    assert edges.select(st.count_geometries()).to_series().to_list() == [3, 5, 0, None]
+    aggregated = gdf.select(st.delaunay_triangles())
+    assert aggregated.height == 1
+
+
+def test_voronoi_polygons_per_geometry():
+    gdf = st.GeoDataFrame([
+        "MULTIPOINT ((0 0), (1 0))",
+        "MULTIPOINT ((0 0), (1 0), (1 1), (0 1))",
+        "MULTIPOINT EMPTY",
+        None,
+    ]).select(st.set_srid(srid=3857))
+    result = gdf.select(st.voronoi_polygons(per_geometry=True))
+    assert result.select(st.count_geometries()).to_series().to_list() == [2, 4, 0, None]
+    assert result.select(st.srid()).to_series().to_list() == [3857, 3857, 3857, None]
+    extend_to = st.GeoSeries(["POLYGON ((-10 -10, 10 -10, 10 10, -10 10, -10 -10))"]).item()
+    result = gdf.head(1).select(st.voronoi_polygons(extend_to=extend_to, per_geometry=True))
+    assert result.select(st.area()).item() == pytest.approx(400.0)
+    aggregated = gdf.select(st.voronoi_polygons())
+    assert aggregated.height == 1
+
+
+def test_make_valid_methods():
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 2 2, 2 0, 0 2, 0 0))",
+        "POLYGON ((0 0, 1 1, 1 2, 1 1, 0 0))",
+        None,
+    ]).select(st.set_srid(srid=3857))
+    for method in ["linework", "structure"]:
+        result = gdf.select(st.make_valid(method=method))  # type: ignore[arg-type]
+        assert result.select(st.is_valid()).to_series().to_list() == [True, True, None]
+        assert result.select(st.area()).to_series().to_list() == [2.0, 0.0, None]
+    structure = gdf.select(st.make_valid(method="structure", keep_collapsed=False))
+    assert structure.select(st.is_empty()).to_series().to_list() == [False, True, None]
+    assert structure.select(st.srid()).to_series().to_list() == [3857, 3857, None]
+    with pytest.raises(pl.exceptions.ComputeError, match="keep_collapsed"):
+        gdf.select(st.make_valid(keep_collapsed=False))
+
+
+def test_is_valid_detail():
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 2 2, 2 0, 0 2, 0 0))",
+        "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+        None,
+    ]).select(st.set_srid(srid=3857))
+    detail = gdf.select(st.is_valid_detail()).unnest("geometry")
+    assert detail["valid"].to_list() == [False, True, None]
+    assert detail["reason"].to_list() == ["Self-intersection", None, None]
+    location = detail.select(st.geom("location"))
+    assert location.select(st.to_wkt()).to_series().to_list() == ["POINT (1 1)", None, None]
+    assert location.select(st.srid()).to_series().to_list() == [3857, None, None]
+
+
+def test_azimuth():
+    gdf = st.GeoDataFrame(["POINT (0 1)", "POINT (1 0)", "POINT (0 0)", "POINT EMPTY", None])
+    origin = st.from_wkt(pl.lit("POINT (0 0)"))
+    result = gdf.select(st.geom().st.azimuth(origin)).to_series().to_list()
+    assert result[:2] == pytest.approx([math.pi, 3 * math.pi / 2])
+    assert math.isnan(result[2])
+    assert math.isnan(result[3])
+    assert result[4] is None
+    empty_line = st.from_wkt(pl.lit("LINESTRING EMPTY"))
+    assert math.isnan(gdf.head(1).select(st.geom().st.azimuth(empty_line)).item())
+    result = gdf.head(2).select(st.geom().st.azimuth(origin, degrees=True))
+    assert result.to_series().to_list() == pytest.approx([180.0, 270.0])
+    with pytest.raises(pl.exceptions.ComputeError, match="points"):
+        gdf.select(st.geom().st.azimuth(st.from_wkt(pl.lit("LINESTRING (0 0, 1 1)"))))
+
+
+def test_azimuth_geodesic():
+    gdf = st.GeoDataFrame(["POINT (0 0)", "POINT (0 10)"]).select(st.set_srid(srid=4326))
+    target = st.from_wkt(pl.lit("POINT (10 0)"))
+    result = gdf.select(st.geom().st.azimuth_geodesic(target, degrees=True))
+    east, southeast = result.to_series().to_list()
+    assert east == pytest.approx(90.0)
+    assert 90.0 < southeast < 180.0
+    with pytest.raises(pl.exceptions.ComputeError, match="EPSG:4326"):
+        gdf.select(st.set_srid(srid=3857).st.azimuth_geodesic(target))
+
+
+def test_segments():
+    gdf = st.GeoDataFrame([
+        "LINESTRING Z (0 0 1, 1 0 2, 1 1 3)",
+        "POLYGON ((0 0, 4 0, 4 4, 0 0), (1 1, 2 1, 2 2, 1 1))",
+        "POINT (0 0)",
+        "LINESTRING EMPTY",
+        None,
+    ]).select(st.set_srid(srid=3857))
+    segments = gdf.select(st.segments())
+    assert segments.select(pl.col("geometry").list.len()).to_series().to_list() == [
+        2,
+        6,
+        0,
+        0,
+        None,
+    ]
+    exploded = segments.explode("geometry").drop_nulls()
+    assert exploded.select(st.count_points()).to_series().unique().to_list() == [2]
+    assert exploded.select(st.srid()).to_series().unique().to_list() == [3857]
+    assert exploded.head(2).select(st.to_wkt()).to_series().to_list() == [
+        "LINESTRING Z (0 0 1, 1 0 2)",
+        "LINESTRING Z (1 0 2, 1 1 3)",
+    ]
+
+
+def test_dump_points():
+    gdf = st.GeoDataFrame([
+        "MULTIPOLYGON (((0 0, 1 0, 0 1, 0 0)), ((2 2, 5 2, 2 5, 2 2), (3 3, 3 4, 4 3, 3 3)))",
+        "GEOMETRYCOLLECTION (POINT Z (0 0 1), MULTIPOINT ((1 1), (2 2)))",
+        "LINESTRING (0 0, 1 1)",
+        "POINT EMPTY",
+        None,
+    ]).select(st.set_srid(srid=3857))
+    dumped = gdf.select(st.dump_points())
+    assert dumped.select(pl.col("geometry").list.len()).to_series().to_list() == [12, 3, 2, 0, None]
+    points = (
+        dumped.with_row_index()
+        .explode("geometry")
+        .filter(pl.col("geometry").is_not_null())
+        .unnest("geometry")
+    )
+    rows = points.select("index", "part_index", "ring_index", "vertex_index").rows()
+    assert rows == [
+        *[(0, 0, 0, i) for i in range(4)],
+        *[(0, 1, 0, i) for i in range(4)],
+        *[(0, 1, 1, i) for i in range(4)],
+        (1, 0, None, 0),
+        (1, 1, None, 0),
+        (1, 1, None, 0),
+        (2, 0, None, 0),
+        (2, 0, None, 1),
+    ]
+    wkt = points.select(st.geom("point").st.to_wkt()).to_series().to_list()
+    assert wkt[12:15] == ["POINT Z (0 0 1)", "POINT (1 1)", "POINT (2 2)"]
+    assert points.select(st.geom("point").st.srid()).to_series().unique().to_list() == [3857]
+
+
+def test_dump_points_curves():
+    gdf = st.GeoDataFrame([
+        "CURVEPOLYGON (COMPOUNDCURVE (CIRCULARSTRING (0 0, 1 1, 2 0), (2 0, 0 0)))",
+        "MULTICURVE ((0 0, 1 0), CIRCULARSTRING (1 0, 2 1, 3 0))",
+    ])
+    points = gdf.select(st.dump_points()).with_row_index().explode("geometry").unnest("geometry")
+    rows = points.select("index", "part_index", "ring_index", "vertex_index").rows()
+    assert rows == [
+        *[(0, 0, 0, i) for i in range(4)],
+        *[(1, 0, None, i) for i in range(2)],
+        *[(1, 1, None, i) for i in range(3)],
+    ]
+    wkt = points.select(st.geom("point").st.to_wkt()).to_series().to_list()
+    assert wkt[:4] == ["POINT (0 0)", "POINT (1 1)", "POINT (2 0)", "POINT (0 0)"]
+
+
+def test_longest_line():
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 2 0, 2 1, 0 1, 0 0))",
+        "LINESTRING (0 0, 1 1, 2 0)",
+        "POINT EMPTY",
+        None,
+    ]).select(st.set_srid(srid=3857))
+    other = st.from_wkt(pl.lit("MULTIPOINT ((3 0), (4 4))"))
+    result = gdf.select(st.geom().st.longest_line(other))
+    assert result.select(st.to_wkt()).to_series().to_list() == [
+        "LINESTRING (0 0, 4 4)",
+        "LINESTRING (0 0, 4 4)",
+        "LINESTRING EMPTY",
+        None,
+    ]
+    assert result.select(st.srid()).to_series().to_list() == [3857, 3857, 3857, None]
+    distance = gdf.head(1).select(st.geom().st.distance(other)).item()
+    length = result.head(1).select(st.length()).item()
+    assert length > distance
+
+
+def test_closest_point():
+    gdf = st.GeoDataFrame([
+        "LINESTRING (0 0, 2 0)",
+        "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0))",
+        "POINT EMPTY",
+        None,
+    ]).select(st.set_srid(srid=3857))
+    other = st.from_wkt(pl.lit("POINT (1 1)"))
+    result = gdf.select(st.geom().st.closest_point(other))
+    assert result.select(st.to_wkt()).to_series().to_list() == [
+        "POINT (1 0)",
+        "POINT (1 1)",
+        "POINT EMPTY",
+        None,
+    ]
+    assert result.select(st.srid()).to_series().to_list() == [3857, 3857, 3857, None]
+
+
+def test_buffer_single_sided():
+    gdf = st.GeoDataFrame(["LINESTRING (0 0, 10 0)"])
+    left = gdf.select(st.buffer(distance=2, single_sided=True))
+    right = gdf.select(st.buffer(distance=-2, single_sided=True))
+    assert left.select(st.bounds()).item().to_list() == [0.0, 0.0, 10.0, 2.0]
+    assert right.select(st.bounds()).item().to_list() == [0.0, -2.0, 10.0, 0.0]
+    assert left.select(st.area()).item() == pytest.approx(20.0)
+    assert right.select(st.area()).item() == pytest.approx(20.0)
+
+
+def test_force_4d():
+    gdf = st.GeoDataFrame([
+        "POINT (0 1)",
+        "LINESTRING Z (0 0 1, 1 1 2)",
+        "POLYGON M ((0 0 1, 1 0 2, 1 1 3, 0 0 1))",
+        "POINT ZM (6 7 8 9)",
+        "POINT EMPTY",
+        None,
+    ]).select(st.set_srid(srid=3857))
+    result = gdf.select(st.force_4d(z=-1, m=5))
+    assert result.select(st.to_wkt()).to_series().to_list() == [
+        "POINT ZM (0 1 -1 5)",
+        "LINESTRING ZM (0 0 1 5, 1 1 2 5)",
+        "POLYGON ZM ((0 0 -1 1, 1 0 -1 2, 1 1 -1 3, 0 0 -1 1))",
+        "POINT ZM (6 7 8 9)",
+        "POINT EMPTY",
+        None,
+    ]
+    assert result.select(st.srid()).to_series().to_list() == [3857] * 5 + [None]
+    points = st.GeoDataFrame({"geometry": ["POINT (0 0)", "POINT (1 1)"], "m": [1.0, 2.0]})
+    assert points.select(st.force_4d(m="m").st.m()).to_series().to_list() == [1.0, 2.0]
+
+
+def test_bounds_3d_and_m_range():
+    gdf = st.GeoDataFrame([
+        "POLYGON Z ((0 0 1, 4 0 2, 4 4 3, 0 0 1), (1 1 -5, 2 1 -5, 2 2 -5, 1 1 -5))",
+        "GEOMETRYCOLLECTION ZM (POINT ZM (0 0 1 10), LINESTRING ZM (1 1 2 -3, 2 2 3 4))",
+        "POINT (1 2)",
+        "POINT EMPTY",
+        None,
+    ])
+    bounds = gdf.select(st.bounds_3d()).to_series().to_list()
+    assert bounds[:2] == [[0.0, 0.0, -5.0, 4.0, 4.0, 3.0], [0.0, 0.0, 1.0, 2.0, 2.0, 3.0]]
+    assert bounds[2][:2] == [1.0, 2.0]
+    assert math.isnan(bounds[2][2])
+    assert all(math.isnan(v) for v in bounds[3])
+    assert bounds[4] is None
+    ranges = gdf.select(st.m_range()).to_series().to_list()
+    assert ranges[1] == [-3.0, 10.0]
+    assert all(math.isnan(v) for r in [ranges[0], ranges[2], ranges[3]] for v in r)
+    assert ranges[4] is None
+    measured = st.GeoDataFrame(["LINESTRING M (0 0 5, 1 1 7)"])
+    assert measured.select(st.m_range()).item().to_list() == [5.0, 7.0]
+    assert math.isnan(measured.select(st.bounds_3d()).item()[2])
+
+
+def test_length_3d():
+    gdf = st.GeoDataFrame([
+        "LINESTRING Z (0 0 0, 3 0 4, 3 0 10)",
+        "MULTILINESTRING ((0 0, 3 4), (0 0, 0 1))",
+        "POLYGON Z ((0 0 0, 1 0 0, 1 0 1, 0 0 0))",
+        "POINT Z (1 2 3)",
+        None,
+    ])
+    result = gdf.select(st.length_3d()).to_series().to_list()
+    assert result == pytest.approx([11.0, 6.0, 2.0 + math.sqrt(2), 0.0, None])
+
+
+def test_distance_3d():
+    gdf = st.GeoDataFrame([
+        "POINT Z (0 0 4)",
+        "LINESTRING Z (0 0 1, 0 0 9)",
+        "MULTIPOINT Z ((10 10 10), (3 0 0))",
+        "POINT (0 0)",
+        "POINT EMPTY",
+        None,
+    ])
+    line = st.from_wkt(pl.lit("LINESTRING Z (3 -1 0, 3 1 0)"))
+    result = gdf.select(st.geom().st.distance_3d(line)).to_series().to_list()
+    assert result[:4] == pytest.approx([5.0, math.sqrt(10), 0.0, 3.0])
+    assert math.isnan(result[4])
+    assert result[5] is None
+
+
+def test_distance_3d_polygon():
+    gdf = st.GeoDataFrame([
+        "POINT Z (1 1 5)",
+        "POINT Z (6 2 0)",
+        "POINT (2 2)",
+        "POINT Z (2 2 1)",
+        "LINESTRING Z (0.5 0.5 -1, 0.5 0.5 1)",
+        "POLYGON Z ((10 0 0, 10 4 0, 10 4 4, 10 0 4, 10 0 0))",
+    ])
+    square = "(0 0 0, 4 0 0, 4 4 0, 0 4 0, 0 0 0)"
+    hole = "(1 1 0, 3 1 0, 3 3 0, 1 3 0, 1 1 0)"
+    polygon = st.from_wkt(pl.lit(f"POLYGON Z ({square}, {hole})"))
+    result = gdf.select(st.geom().st.distance_3d(polygon)).to_series().to_list()
+    assert result == pytest.approx([5.0, 2.0, 1.0, math.sqrt(2), 0.0, 6.0])
+
+
+def test_curve_to_linear():
+    gdf = st.GeoDataFrame([
+        "CIRCULARSTRING (1 0, 0 1, -1 0)",
+        "COMPOUNDCURVE (CIRCULARSTRING (1 0, 0 1, -1 0), (-1 0, 1 0))",
+        "CURVEPOLYGON (CIRCULARSTRING (1 0, -1 0, 1 0))",
+        "MULTICURVE ((0 0, 1 0), CIRCULARSTRING (1 0, 0 1, -1 0))",
+        "LINESTRING (0 0, 1 1)",
+        None,
+    ]).select(st.set_srid(srid=3857))
+    assert gdf.select(st.has_curves()).to_series().to_list() == [
+        True, True, True, True, False, None,
+    ]
+    linear = gdf.select(st.curve_to_linear(tolerance=0.001))
+    assert linear.select(st.has_curves()).to_series().to_list() == [
+        False, False, False, False, False, None,
+    ]
+    assert linear.select(st.geometry_type()).to_series().to_list() == [
+        "LineString", "LineString", "Polygon", "MultiLineString", "LineString", None,
+    ]
+    assert linear.select(st.srid()).to_series().to_list() == [3857] * 5 + [None]
+    result = linear.select(st.length(), st.area()).rows()
+    assert result[0][0] == pytest.approx(math.pi, rel=1e-3)
+    assert result[1][0] == pytest.approx(math.pi + 2, rel=1e-3)
+    assert result[2][1] == pytest.approx(math.pi, rel=1e-3)
+    assert result[4][0] == pytest.approx(math.sqrt(2))
+    with pytest.raises(pl.exceptions.ComputeError, match="must be positive"):
+        gdf.select(st.curve_to_linear(tolerance=0))
+
+
+def test_linear_to_curve():
+    gdf = st.GeoDataFrame([
+        "CIRCULARSTRING (1 0, 0 1, -1 0)",
+        "COMPOUNDCURVE (CIRCULARSTRING (1 0, 0 1, -1 0), (-1 0, 1 0))",
+        "CURVEPOLYGON (CIRCULARSTRING (1 0, -1 0, 1 0))",
+        "LINESTRING (0 0, 1 1)",
+        "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))",
+        None,
+    ]).select(st.set_srid(srid=3857))
+    linear = gdf.select(st.curve_to_linear(tolerance=0.001))
+    curved = linear.select(st.linear_to_curve(tolerance=1e-6))
+    assert curved.select(st.geometry_type()).to_series().to_list() == [
+        "CircularString", "CompoundCurve", "CurvePolygon", "LineString", "Polygon", None,
+    ]
+    assert curved.select(st.srid()).to_series().to_list() == [3857] * 5 + [None]
+    assert curved.select(st.count_points()).item(0, 0) == 3
+    roundtrip = curved.select(st.curve_to_linear(tolerance=0.001).st.length()).to_series()
+    expected = linear.select(st.length()).to_series()
+    assert roundtrip.to_list()[:5] == pytest.approx(expected.to_list()[:5], rel=1e-3)
+    with pytest.raises(pl.exceptions.ComputeError, match="must be positive"):
+        gdf.select(st.linear_to_curve(tolerance=-1))
+
+
+def test_curves_postgis_outputs():
+    # Outputs of the PostGIS documentation examples of ST_CurveToLine and ST_LineToCurve.
+    # Vertices are placed differently, so results are compared up to the tolerance.
+    arc = st.GeoDataFrame(["CIRCULARSTRING (0 0, 100 -100, 200 0)"])
+    postgis_linear = st.from_wkt(pl.lit(
+        "LINESTRING (0 0, 50 -86.6025403784438, 150 -86.6025403784439, 200 0)",
+    ))
+    linear = arc.select(st.curve_to_linear(tolerance=20))
+    assert linear.select(st.count_points()).item() == 5
+    assert linear.select(st.hausdorff_distance(postgis_linear)).item() <= 20
+
+    buffer = st.GeoDataFrame(["POINT (1 3)"]).select(st.buffer(3))
+    postgis_curve = st.from_wkt(pl.lit(
+        "CURVEPOLYGON (CIRCULARSTRING (4 3, 3.12132034355964 0.878679656440359, -2 3, "
+        "3.12132034355965 5.12132034355963, 4 3))",
+    ))
+    curve = buffer.select(st.linear_to_curve(tolerance=1e-6))
+    assert curve.select(st.geometry_type()).item() == "CurvePolygon"
+    assert curve.select(st.count_points()).item() == 5
+    distance = curve.select(
+        st.curve_to_linear(tolerance=1e-3).st.hausdorff_distance(
+            postgis_curve.st.curve_to_linear(tolerance=1e-3),
+        ),
+    )
+    assert distance.item() < 1e-2
+    area = curve.select(st.curve_to_linear(tolerance=1e-3).st.area()).item()
+    assert area == pytest.approx(9 * math.pi, rel=1e-3)
+
+
+def test_collection_extract():
+    gdf = st.GeoDataFrame([
+        "GEOMETRYCOLLECTION (POINT (0 0), LINESTRING (0 0, 1 1), POLYGON ((0 0, 1 0, 1 1, 0 0)))",
+        "GEOMETRYCOLLECTION (MULTIPOINT ((0 0), (1 1)), GEOMETRYCOLLECTION (POINT (2 2)))",
+        "MULTILINESTRING ((0 0, 1 1), (2 2, 3 3))",
+        "POINT (0 0)",
+        "POINT EMPTY",
+        None,
+    ]).select(st.set_srid(srid=3857))
+
+    def extract(type: str) -> list[str | None]:  # noqa: A002
+        result = gdf.select(st.collection_extract(type=type))
+        assert result.select(st.srid()).to_series().to_list() == [3857] * 5 + [None]
+        return result.select(st.to_wkt()).to_series().to_list()
+
+    assert extract("point") == [
+        "MULTIPOINT ((0 0))",
+        "MULTIPOINT ((0 0), (1 1), (2 2))",
+        "MULTIPOINT EMPTY",
+        "POINT (0 0)",
+        "POINT EMPTY",
+        None,
+    ]
+    assert extract("line") == [
+        "MULTILINESTRING ((0 0, 1 1))",
+        "MULTILINESTRING EMPTY",
+        "MULTILINESTRING ((0 0, 1 1), (2 2, 3 3))",
+        "LINESTRING EMPTY",
+        "LINESTRING EMPTY",
+        None,
+    ]
+    assert extract("polygon")[:4] == [
+        "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)))",
+        "MULTIPOLYGON EMPTY",
+        "MULTIPOLYGON EMPTY",
+        "POLYGON EMPTY",
+    ]
+
+
+def test_unnest_collection():
+    gdf = st.GeoDataFrame([
+        "GEOMETRYCOLLECTION (POLYGON ((0 0, 1 0, 1 1, 0 0)), "
+        "MULTIPOLYGON (((2 2, 3 2, 2 3, 2 2))))",
+        "GEOMETRYCOLLECTION (LINESTRING (0 0, 1 1), GEOMETRYCOLLECTION (POINT EMPTY))",
+        "GEOMETRYCOLLECTION (POINT (0 0), GEOMETRYCOLLECTION (LINESTRING (0 0, 1 1)))",
+        "GEOMETRYCOLLECTION EMPTY",
+        "MULTIPOINT ((0 0), (1 1))",
+        "POINT (0 0)",
+        None,
+    ]).select(st.set_srid(srid=3857))
+    result = gdf.select(st.unnest_collection())
+    assert result.select(st.srid()).to_series().to_list() == [3857] * 6 + [None]
+    assert result.select(st.to_wkt()).to_series().to_list() == [
+        "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((2 2, 3 2, 2 3, 2 2)))",
+        "MULTILINESTRING ((0 0, 1 1))",
+        "GEOMETRYCOLLECTION (POINT (0 0), LINESTRING (0 0, 1 1))",
+        "GEOMETRYCOLLECTION EMPTY",
+        "MULTIPOINT ((0 0), (1 1))",
+        "POINT (0 0)",
+        None,
+    ]
+
+
+@pytest.mark.parametrize(
+    "ewkt",
+    [
+        "POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 2 4, 4 4, 4 2, 2 2))",
+        "MULTIPOLYGON (((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 2 4, 4 4, 4 2, 2 2)), ((20 20, 30 20, 30 30, 20 30, 20 20)))",
+        "LINESTRING Z (0 0 1, 1 1 2)",
+        "MULTILINESTRING M ((0 0 1, 1 1 2), (2 2 3, 3 3 4))",
+        "POLYGON ZM ((0 0 1 5, 1 0 2 6, 1 1 3 7, 0 0 1 5))",
+        "SRID=3857;MULTIPOINT ((0 0), (1 1))",
+        "SRID=4326;POINT ZM (1 2 3 4)",
+    ],
+)
+def test_esrijson_roundtrip(ewkt: str):
+    gdf = st.GeoDataFrame([ewkt])
+    result = gdf.select(st.to_esrijson()).select(st.from_esrijson("geometry"))
+
+    def describe(df: pl.DataFrame) -> tuple:
+        return df.select(
+            srid=st.srid(),
+            has_z=st.has_z(),
+            has_m=st.has_m(),
+            ewkt=st.normalize().st.to_ewkt(output_dimension=4),
+        ).row(0)
+
+    assert describe(result) == describe(gdf)
+
+
+def test_to_esrijson_members():
+    gdf = st.GeoDataFrame([
+        "SRID=3857;LINESTRING Z (0 0 1, 1 1 2)",
+        "POLYGON M ((0 0 1, 1 0 2, 1 1 3, 0 0 1))",
+    ])
+    esrijson = st.to_esrijson().str.json_decode(infer_schema_length=None)
+    result = gdf.select(esrijson).unnest("geometry")
+    assert result["hasZ"].to_list() == [True, None]
+    assert result["hasM"].to_list() == [None, True]
+    assert result["spatialReference"].struct.field("wkid").to_list() == [3857, None]
+
+
+def test_esrijson_nulls():
+    gdf = st.GeoDataFrame(["GEOMETRYCOLLECTION (POINT (0 0))", "GEOMETRYCOLLECTION EMPTY", None])
+    assert gdf.select(st.to_esrijson()).to_series().to_list() == [None, None, None]
+    features = pl.Series("geometry", ['{"attributes": {}, "geometry": null}', None]).to_frame()
+    assert features.select(st.from_esrijson("geometry")).to_series().to_list() == [None, None]
+
+
+def test_write_esrijson_feature_set():
+    gdf = st.GeoDataFrame({
+        "geometry": ["SRID=3857;POLYGON ((0 0, 1 0, 1 1, 0 0))", None, "SRID=3857;MULTIPOLYGON EMPTY"],
+        "name": ["A", "B", "C"],
+    })
+    feature_set = pl.read_ndjson(gdf.st.write_esrijson().encode())
+    assert feature_set["geometryType"].item() == "esriGeometryPolygon"
+    assert feature_set["spatialReference"].struct.field("wkid").item() == 3857
+    with pytest.raises(ValueError, match="one type"):
+        st.GeoDataFrame(["POINT (0 0)", "LINESTRING (0 0, 1 1)"]).st.write_esrijson()
+    with pytest.raises(ValueError, match="SRIDs"):
+        st.GeoDataFrame(["SRID=4326;POINT (0 0)", "SRID=3857;POINT (0 0)"]).st.write_esrijson()
+
+
+
+def test_to_kml_srid():
+    gdf = st.GeoDataFrame(["SRID=4326;POINT (1 2)", "POINT (3 4)", None])
+    assert gdf.select(st.to_kml()).to_series().to_list() == [
+        "<Point><coordinates>1,2</coordinates></Point>",
+        "<Point><coordinates>3,4</coordinates></Point>",
+        None,
+    ]
+    with pytest.raises(pl.exceptions.ComputeError, match="EPSG:4326"):
+        st.GeoDataFrame(["SRID=3857;POINT (1 2)"]).select(st.to_kml())
+
+
+def test_to_svg():
+    gdf = st.GeoDataFrame([
+        "MULTIPOINT ((0 0), (1 1))",
+        "GEOMETRYCOLLECTION (POINT (0 1), LINESTRING (0 0, 1 1))",
+        "POINT EMPTY",
+        "POLYGON EMPTY",
+        "GEOMETRYCOLLECTION EMPTY",
+        None,
+    ])
+    assert gdf.select(st.to_svg()).to_series().to_list() == [
+        '<g><circle cx="0" cy="0" r="1"/><circle cx="1" cy="-1" r="1"/></g>',
+        '<g><circle cx="0" cy="-1" r="1"/><path d="M0 0 L1 -1" fill="none"/></g>',
+        "",
+        "",
+        "<g></g>",
+        None,
+    ]
+    result = gdf.select(st.to_svg(flip_y=False)).to_series().to_list()
+    assert result[1] == '<g><circle cx="0" cy="1" r="1"/><path d="M0 0 L1 1" fill="none"/></g>'
+
+
+@pytest.mark.parametrize(
+    ("wkt", "flip_y", "expected"),
+    [
+        (
+            "LINESTRING (0 0, 50 100)",
+            True,
+            'viewBox="-5 -105 60 110" fill="currentColor" fill-opacity="0.5" stroke="currentColor" stroke-width="1"><path d="M0 0 L50 -100" fill="none"/></svg>',
+        ),
+        (
+            "LINESTRING (0 0, 50 100)",
+            False,
+            'viewBox="-5 -5 60 110" fill="currentColor" fill-opacity="0.5" stroke="currentColor" stroke-width="1"><path d="M0 0 L50 100" fill="none"/></svg>',
+        ),
+        (
+            "LINESTRING EMPTY",
+            True,
+            'viewBox="-0.05 -0.05 0.1 0.1" fill="currentColor" fill-opacity="0.5" stroke="currentColor" stroke-width="0.01"></svg>',
+        ),
+    ],
+)
+def test_to_svg_view_box(wkt: str, flip_y: bool, expected: str):
+    gdf = st.GeoDataFrame([wkt])
+    result = gdf.select(st.to_svg(view_box=True, flip_y=flip_y)).item()
+    assert result == f'<svg xmlns="http://www.w3.org/2000/svg" {expected}'
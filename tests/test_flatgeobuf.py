@@ -0,0 +1,82 @@
+from pathlib import Path
+
+import polars as pl
+import pytest
+
+import polars_st as st
+
+
+def test_scan_flatgeobuf_bbox(tmp_path: Path):
+    path = tmp_path / "points.fgb"
+    gdf = st.GeoDataFrame({
+        "geometry": ["POINT (0 0)", "POINT (1 2)", "POINT (5 5)"],
+        "name": ["A", "B", "C"],
+    })
+    gdf.st.write_file(str(path), driver="FlatGeobuf")
+
+    result = st.scan_flatgeobuf(path, bbox=(0.5, 0.5, 6, 6)).select("name").collect()
+    assert result.to_series().to_list() == ["B", "C"]
+
+
+def test_read_flatgeobuf(tmp_path: Path):
+    path = tmp_path / "points.fgb"
+    gdf = st.GeoDataFrame({"geometry": ["POINT (0 0)", "POINT (1 2)"], "name": ["A", "B"]})
+    gdf.st.write_file(str(path), driver="FlatGeobuf")
+
+    result = st.read_flatgeobuf(path, columns=["geometry"])
+    assert result.st.to_wkt().to_series().to_list() == ["POINT (0 0)", "POINT (1 2)"]
+
+
+def test_scan_flatgeobuf_filter_unprojected_column(tmp_path: Path):
+    path = tmp_path / "points.fgb"
+    gdf = st.GeoDataFrame({
+        "geometry": ["POINT (0 0)", "POINT (1 2)", "POINT (5 5)"],
+        "name": ["A", "B", "C"],
+        "value": [1, 2, 3],
+    })
+    gdf.st.write_file(str(path), driver="FlatGeobuf")
+
+    result = st.scan_flatgeobuf(path).filter(pl.col("value") > 1).select("name").collect()
+    assert result.to_series().to_list() == ["B", "C"]
+
+
+@pytest.mark.parametrize("spatial_index", ["YES", "NO"])
+def test_scan_flatgeobuf_bbox_many_features(tmp_path: Path, spatial_index: str):
+    # Enough features for the R-tree to have several levels of 16-item nodes.
+    path = tmp_path / "grid.fgb"
+    points = [(x, y) for x in range(20) for y in range(20)]
+    gdf = st.GeoDataFrame({
+        "geometry": [f"POINT ({x} {y})" for x, y in points],
+        "id": list(range(len(points))),
+    }).with_columns(st.set_srid(srid=3857))
+    options = {"SPATIAL_INDEX": spatial_index}
+    gdf.st.write_file(str(path), driver="FlatGeobuf", layer_options=options)
+
+    result = st.scan_flatgeobuf(path, bbox=(2.5, 3.5, 6, 5)).collect()
+    expected = [i for i, (x, y) in enumerate(points) if 2.5 <= x <= 6 and 3.5 <= y <= 5]
+    assert sorted(result["id"].to_list()) == expected
+    assert result.select(st.srid().unique()).to_series().to_list() == [3857]
+    assert len(st.read_flatgeobuf(path)) == len(points)
+
+
+def test_read_flatgeobuf_types(tmp_path: Path):
+    path = tmp_path / "types.fgb"
+    gdf = st.GeoDataFrame({
+        "geometry": ["POLYGON Z ((0 0 1, 1 0 1, 1 1 1, 0 0 1))", None],
+        "integer": pl.Series([1, None], dtype=pl.Int32),
+        "float": [0.5, 1.5],
+        "boolean": [True, False],
+        "string": ["a", None],
+    })
+    gdf.st.write_file(str(path), driver="FlatGeobuf", layer_options={"SPATIAL_INDEX": "NO"})
+
+    result = st.read_flatgeobuf(path)
+    assert result.st.to_wkt().to_series().to_list() == [
+        "POLYGON Z ((0 0 1, 1 0 1, 1 1 1, 0 0 1))",
+        None,
+    ]
+    assert result.drop("geometry").to_dicts() == [
+        {"integer": 1, "float": 0.5, "boolean": True, "string": "a"},
+        {"integer": None, "float": 1.5, "boolean": False, "string": None},
+    ]
+    assert result.schema["integer"] == pl.Int32
@@ -0,0 +1,131 @@
+from pathlib import Path
+
+import polars as pl
+import pytest
+
+import polars_st as st
+from polars_st.geometry import GeometryType
+from polars_st.utils.geoarrow import from_geoarrow_table
+
+
+@pytest.mark.parametrize(
+    ("wkt", "geometry_type"),
+    [
+        ("POINT (1 2)", "Point"),
+        ("LINESTRING (0 0, 1 1, 2 0)", "LineString"),
+        ("POLYGON ((0 0, 0 4, 4 4, 4 0, 0 0), (1 1, 2 1, 2 2, 1 1))", "Polygon"),
+        ("MULTIPOINT ((0 0), (1 1))", "MultiPoint"),
+        ("MULTILINESTRING ((0 0, 1 1), (2 2, 3 3))", "MultiLineString"),
+        ("MULTIPOLYGON (((0 0, 0 1, 1 1, 0 0)), ((2 2, 2 3, 3 3, 2 2)))", "MultiPolygon"),
+    ],
+)
+def test_geoarrow_roundtrip(wkt: str, geometry_type: GeometryType):
+    gs = st.GeoSeries([wkt, None])
+    encoded = gs.st.to_geoarrow(geometry_type)
+    assert encoded.null_count() == 1
+    result = pl.select(st.from_geoarrow(encoded, geometry_type)).to_series()
+    assert result.st.to_wkt().to_list() == [wkt, None]
+
+
+def test_to_geoarrow_dimensions():
+    gs = st.GeoSeries(["POINT Z (1 2 3)", "POINT (4 5)", "POINT EMPTY"])
+    result = gs.st.to_geoarrow("Point", "xyz")
+    assert result.dtype == pl.Struct({"x": pl.Float64, "y": pl.Float64, "z": pl.Float64})
+    assert result.struct.field("z").to_list()[0] == 3
+    assert result.struct.field("x").is_nan().to_list() == [False, False, True]
+
+
+def test_to_geoarrow_promotes_single_parts():
+    gs = st.GeoSeries(["POLYGON ((0 0, 0 1, 1 1, 0 0))"])
+    result = gs.st.to_geoarrow("MultiPolygon")
+    assert result.list.len().item() == 1
+    decoded = pl.select(st.from_geoarrow(result, "MultiPolygon")).to_series()
+    assert decoded.st.to_wkt().item() == "MULTIPOLYGON (((0 0, 0 1, 1 1, 0 0)))"
+
+
+def test_to_geoarrow_invalid_type():
+    gs = st.GeoSeries(["LINESTRING (0 0, 1 1)"])
+    with pytest.raises(pl.exceptions.ComputeError):
+        gs.st.to_geoarrow("Point")
+
+
+def test_from_geoarrow_interleaved():
+    dtype = pl.List(pl.Array(pl.Float64, 3))
+    coords = pl.Series([[[0.0, 0.0, 1.0], [1.0, 1.0, 2.0]]], dtype=dtype)
+    result = pl.select(st.from_geoarrow(coords, "LineString")).to_series()
+    assert result.st.to_wkt().item() == "LINESTRING Z (0 0 1, 1 1 2)"
+
+
+@pytest.mark.parametrize(
+    ("fields", "wkt"),
+    [
+        ({"x": 1.0, "y": 2.0, "m": 3.0}, "POINT M (1 2 3)"),
+        ({"x": 1.0, "y": 2.0, "z": 3.0, "m": 4.0}, "POINT ZM (1 2 3 4)"),
+        ({"m": 4.0, "z": 3.0, "y": 2.0, "x": 1.0}, "POINT ZM (1 2 3 4)"),
+    ],
+)
+def test_from_geoarrow_measures(fields: dict[str, float], wkt: str):
+    coords = pl.Series([fields])
+    result = pl.select(st.from_geoarrow(coords, "Point")).to_series()
+    assert result.st.to_wkt(output_dimension=4).item() == wkt
+
+
+@pytest.mark.parametrize(
+    "fields",
+    [{"x": 1.0, "y": 2.0, "z": 3.0}, {"z": 3.0, "y": 2.0, "x": 1.0}],
+)
+def test_from_geoarrow_reads_z_field(fields: dict[str, float]):
+    coords = pl.Series([fields])
+    result = pl.select(st.from_geoarrow(coords, "Point")).to_series()
+    assert result.st.to_wkt().item() == "POINT Z (1 2 3)"
+    df = pl.DataFrame(fields)
+    result = df.select(st.point(pl.concat_arr("x", "y", "z")).st.to_wkt())
+    assert result.item() == "POINT Z (1 2 3)"
+
+
+def test_to_arrow_geoarrow_metadata():
+    gdf = st.GeoDataFrame({"geometry": ["POINT (1 2)"], "name": ["a"]})
+    gdf = gdf.with_columns(st.set_srid(srid=3857))
+    table = gdf.st.to_arrow()
+    field = table.schema.field("geometry")
+    assert field.metadata[b"ARROW:extension:name"] == b"geoarrow.wkb"
+    assert b"EPSG:3857" in field.metadata[b"ARROW:extension:metadata"]
+    assert table.schema.field("name").metadata is None
+
+
+def test_arrow_c_stream_roundtrip():
+    import pyarrow as pa
+
+    gdf = st.GeoDataFrame({"geometry": ["POINT (1 2)", "LINESTRING (0 0, 1 1)"]})
+    table = pa.table(gdf.st)
+    assert table.schema.field("geometry").metadata[b"ARROW:extension:name"] == b"geoarrow.wkb"
+    result = from_geoarrow_table(table).select(st.to_wkt()).to_series()
+    assert result.to_list() == ["POINT (1 2)", "LINESTRING (0 0, 1 1)"]
+
+
+def test_plot_serializable_properties():
+    pytest.importorskip("altair")
+
+    gdf = st.GeoDataFrame({
+        "geometry": ["POINT (1 2)", None],
+        "time": pl.Series(["2024-01-01", None]).str.to_datetime(),
+        "other": st.GeoSeries(["POINT (0 0)", None]),
+    })
+    values = gdf.st.plot().to_dict()["data"]["values"]
+    assert values[0]["geometry"] == {"type": "Point", "coordinates": [1.0, 2.0]}
+    assert values[1]["geometry"] is None
+    assert isinstance(values[0]["properties"]["time"], str)
+
+
+def test_geoarrow_ipc_roundtrip(tmp_path: Path):
+    import pyarrow.feather as feather
+
+    gdf = st.GeoDataFrame({"geometry": ["POINT (1 2)", None], "name": ["a", "b"]})
+    gdf = gdf.with_columns(st.set_srid(srid=3857))
+    path = tmp_path / "test.arrow"
+    gdf.st.write_geoarrow_ipc(path)
+    field = feather.read_table(path).schema.field("geometry")
+    assert field.metadata[b"ARROW:extension:name"] == b"geoarrow.wkb"
+    result = st.read_geoarrow_ipc(path)
+    assert result.select(st.to_ewkt()).to_series().to_list() == ["SRID=3857;POINT (1 2)", None]
+    assert result["name"].to_list() == ["a", "b"]
@@ -0,0 +1,79 @@
+import json
+from pathlib import Path
+
+import polars as pl
+
+import polars_st as st
+
+
+def test_scan_geojsonseq(tmp_path: Path):
+    path = tmp_path / "points.geojsonl"
+    gdf = st.GeoDataFrame({
+        "geometry": ["POINT (0 0)", "POINT (1 2)", "POINT (5 5)"],
+        "name": ["A", "B", "C"],
+        "value": [1, 2, 3],
+    })
+    gdf.st.write_ndgeojson(path)
+
+    lf = st.scan_geojsonseq(path)
+    assert lf.collect_schema() == pl.Schema({
+        "name": pl.String(),
+        "value": pl.Int64(),
+        "geometry": pl.Binary(),
+    })
+    result = lf.filter(pl.col("value") > 1).select("geometry").collect()
+    assert result.st.to_wkt().to_series().to_list() == ["POINT (1 2)", "POINT (5 5)"]
+
+
+def test_read_geojsonseq_record_separator(tmp_path: Path):
+    path = tmp_path / "points.geojsons"
+    path.write_text(
+        '\x1e{"type": "Feature", "properties": null, '
+        '"geometry": {"type": "Point", "coordinates": [1, 2]}}\n'
+        '\x1e{"type": "Feature", "properties": null, "geometry": null}\n',
+    )
+
+    result = st.read_geojsonseq(path)
+    assert result.columns == ["geometry"]
+    assert result.st.to_wkt().to_series().to_list() == ["POINT (1 2)", None]
+
+
+def test_sink_geojsonl(tmp_path: Path):
+    path = tmp_path / "points.geojsonl"
+    lf = st.GeoLazyFrame({
+        "geometry": ["POINT (0 0)", "POINT (1 2)", None],
+        "name": ["A", 'B, "C"', None],
+    })
+    lf.filter(pl.col("name").ne_missing("A")).st.sink_geojsonl(path)
+
+    features = [json.loads(line) for line in path.read_text().splitlines()]
+    assert features == [
+        {
+            "type": "Feature",
+            "properties": {"name": 'B, "C"'},
+            "geometry": {"type": "Point", "coordinates": [1.0, 2.0]},
+        },
+        {"type": "Feature", "properties": {"name": None}, "geometry": None},
+    ]
+    result = st.read_geojsonseq(path)
+    assert result.st.to_wkt().to_series().to_list() == ["POINT (1 2)", None]
+
+
+def test_to_geojson_feature():
+    gdf = st.GeoDataFrame({
+        "geometry": ["POINT (1 2)", None],
+        "id": ["a", None],
+        "name": ["Alice", "Bob"],
+    })
+    features = gdf.select(st.to_geojson_feature(properties=pl.struct("name"), feature_id="id"))
+    assert [json.loads(f) for f in features.to_series()] == [
+        {
+            "type": "Feature",
+            "id": "a",
+            "properties": {"name": "Alice"},
+            "geometry": {"type": "Point", "coordinates": [1.0, 2.0]},
+        },
+        {"type": "Feature", "id": None, "properties": {"name": "Bob"}, "geometry": None},
+    ]
+    feature = gdf.select(st.to_geojson_feature()).item(0, 0)
+    assert json.loads(feature)["properties"] is None
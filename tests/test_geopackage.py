@@ -0,0 +1,107 @@
+import sqlite3
+from contextlib import closing
+from pathlib import Path
+
+import polars as pl
+
+import polars_st as st
+
+
+def test_read_geopackage(tmp_path: Path):
+    path = tmp_path / "points.gpkg"
+    gdf = st.GeoDataFrame({
+        "geometry": st.GeoSeries(["POINT (0 0)", "POINT (1 2)", None]).st.set_srid(4326),
+        "name": ["A", "B", "C"],
+        "value": [1, 2, 3],
+    })
+    gdf.st.write_file(
+        str(path),
+        layer="points",
+        driver="GPKG",
+        layer_options={"GEOMETRY_NAME": "geometry"},
+    )
+
+    result = st.read_geopackage(path, layer="points", columns=["geometry", "name", "value"])
+    assert result.st.to_ewkt().equals(gdf.st.to_ewkt())
+
+
+def test_scan_geopackage_filter(tmp_path: Path):
+    path = tmp_path / "points.gpkg"
+    gdf = st.GeoDataFrame({
+        "geometry": ["POINT (0 0)", "POINT (1 2)", "POINT (5 5)"],
+        "value": [1, 2, 3],
+    })
+    gdf.st.write_file(str(path), driver="GPKG", layer_options={"GEOMETRY_NAME": "geometry"})
+
+    result = st.scan_geopackage(path).filter(pl.col("value") > 1).select("geometry").collect()
+    assert result.st.to_wkt().to_series().to_list() == ["POINT (1 2)", "POINT (5 5)"]
+
+
+
+def test_scan_geopackage_special_characters(tmp_path: Path):
+    path = tmp_path / "100% points?#.gpkg"
+    gdf = st.GeoDataFrame({"geometry": st.GeoSeries(["POINT (0 0)"]).st.set_srid(4326)})
+    gdf.st.write_geopackage(path)
+
+    result = st.read_geopackage(path)
+    assert result.select(st.to_ewkt()).to_series().to_list() == ["SRID=4326;POINT (0 0)"]
+
+
+def test_write_geopackage_roundtrip(tmp_path: Path):
+    path = tmp_path / "points.gpkg"
+    gdf = st.GeoDataFrame({
+        "geometry": st.GeoSeries(["POINT (0 0)", "POINT (1 2)", None]).st.set_srid(4326),
+        "name": ["A", "B", "C"],
+        "value": [1.5, 2.5, None],
+    })
+    gdf.st.write_geopackage(path, layer="points")
+
+    result = st.read_geopackage(path, layer="points", columns=["geometry", "name", "value"])
+    assert result.st.to_ewkt().equals(gdf.st.to_ewkt())
+
+    pyogrio_result = st.read_file(path, layer="points")
+    assert pyogrio_result.st.to_ewkt().to_series().to_list() == [
+        "SRID=4326;POINT (0 0)",
+        "SRID=4326;POINT (1 2)",
+        None,
+    ]
+
+
+def test_write_geopackage_metadata(tmp_path: Path):
+    path = tmp_path / "points.gpkg"
+    gdf = st.GeoDataFrame({
+        "geometry": st.GeoSeries(["POINT Z (0 0 1)", "POINT Z (1 2 3)"]).st.set_srid(3857),
+    })
+    gdf.st.write_geopackage(path, layer="points")
+
+    with closing(sqlite3.connect(path)) as connection:
+        definition, description = connection.execute(
+            "SELECT definition, description FROM gpkg_spatial_ref_sys WHERE srs_id = 3857",
+        ).fetchone()
+        geometry_type, z = connection.execute(
+            "SELECT geometry_type_name, z FROM gpkg_geometry_columns",
+        ).fetchone()
+        (blob,) = connection.execute("SELECT geometry FROM points").fetchone()
+    assert definition.startswith(("PROJCS[", "PROJCRS["))
+    assert description is None
+    assert (geometry_type, z) == ("POINT", 1)
+    # 8 bytes header and 32 bytes envelope, then ISO WKB of a Point Z
+    assert int.from_bytes(blob[41:45], "little") == 1001
+    assert st.read_file(path).st.to_ewkt().to_series().to_list() == [
+        "SRID=3857;POINT Z (0 0 1)",
+        "SRID=3857;POINT Z (1 2 3)",
+    ]
+
+
+def test_write_geopackage_esri_crs(tmp_path: Path):
+    path = tmp_path / "points.gpkg"
+    gdf = st.GeoDataFrame({"geometry": st.GeoSeries(["POINT (0 0)"]).st.set_srid(102100)})
+    gdf.st.write_geopackage(path)
+
+    with closing(sqlite3.connect(path)) as connection:
+        row = connection.execute(
+            "SELECT organization, organization_coordsys_id, definition, description "
+            "FROM gpkg_spatial_ref_sys WHERE srs_id = 102100",
+        ).fetchone()
+    assert row[:3] == ("ESRI", 102100, "undefined")
+    assert row[3].startswith("+proj=merc")
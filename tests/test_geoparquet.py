@@ -0,0 +1,102 @@
+import json
+import sys
+from pathlib import Path
+
+import pyarrow as pa
+import pyarrow.parquet as pq
+import pytest
+
+import polars_st as st
+from polars_st.utils.geoarrow import get_extension_name
+
+
+def test_write_geoparquet_metadata(tmp_path: Path):
+    path = tmp_path / "points.parquet"
+    gdf = st.GeoDataFrame({
+        "geometry": st.GeoSeries(["POINT (0 0)", "POINT (1 2)", None]).st.set_srid(4326),
+        "name": ["A", "B", "C"],
+    })
+    gdf.st.write_geoparquet(path, write_covering_bbox=True)
+
+    metadata = json.loads(pq.read_schema(path).metadata[b"geo"])
+    assert metadata["primary_column"] == "geometry"
+    column = metadata["columns"]["geometry"]
+    assert column["encoding"] == "WKB"
+    assert column["geometry_types"] == ["Point"]
+    assert column["crs"]["id"] == {"authority": "EPSG", "code": 4326}
+    assert column["bbox"] == [0.0, 0.0, 1.0, 2.0]
+    assert column["covering"]["bbox"]["xmin"] == ["bbox", "xmin"]
+    assert get_extension_name(pq.read_schema(path).field("geometry")) == "geoarrow.wkb"
+
+
+def test_write_geoparquet_esri_crs(tmp_path: Path):
+    pytest.importorskip("pyproj")
+    path = tmp_path / "points.parquet"
+    gdf = st.GeoDataFrame({"geometry": st.GeoSeries(["POINT (0 0)"]).st.set_srid(102100)})
+    gdf.st.write_geoparquet(path)
+
+    metadata = json.loads(pq.read_schema(path).metadata[b"geo"])
+    assert metadata["columns"]["geometry"]["crs"]["id"] == {"authority": "ESRI", "code": 102100}
+
+
+def test_write_geoparquet_without_pyproj(tmp_path: Path, monkeypatch: pytest.MonkeyPatch):
+    monkeypatch.setitem(sys.modules, "pyproj", None)
+    gdf = st.GeoDataFrame({"geometry": st.GeoSeries(["POINT (0 0)"]).st.set_srid(4326)})
+    with pytest.raises(ImportError, match="polars-st\\[geoparquet\\]"):
+        gdf.st.write_geoparquet(tmp_path / "points.parquet")
+
+
+def test_geoparquet_roundtrip(tmp_path: Path):
+    path = tmp_path / "points.parquet"
+    gdf = st.GeoDataFrame({
+        "geometry": st.GeoSeries(["POINT (0 0)", "POINT (1 2)"]).st.set_srid(4326),
+        "name": ["A", "B"],
+    })
+    gdf.st.write_geoparquet(path)
+
+    result = st.read_geoparquet(path)
+    assert result.columns == ["geometry", "name"]
+    assert result.st.to_ewkt().equals(gdf.st.to_ewkt())
+
+
+def test_scan_geoparquet_bbox(tmp_path: Path):
+    path = tmp_path / "points.parquet"
+    gdf = st.GeoDataFrame({"geometry": ["POINT (0 0)", "POINT (1 2)", "POINT (5 5)"]})
+    gdf.st.write_geoparquet(path, write_covering_bbox=True, row_group_size=1)
+
+    result = st.scan_geoparquet(path, columns=["geometry"], bbox=(0.5, 0.5, 6, 6)).collect()
+    assert result.st.to_wkt().to_series().to_list() == ["POINT (1 2)", "POINT (5 5)"]
+
+
+def test_scan_geoparquet_bbox_covering(tmp_path: Path):
+    path = tmp_path / "points.parquet"
+    geometry = st.GeoSeries(["POINT (0 0)", "POINT (1 2)", "POINT (5 5)"])
+    # The covering of the last row is deliberately wrong, so that the row is only dropped if the
+    # filter on the covering column, which is pushed down to the row group statistics, is applied.
+    bbox = [(0.0, 0.0, 0.0, 0.0), (1.0, 2.0, 1.0, 2.0), (10.0, 10.0, 10.0, 10.0)]
+    geo = {
+        "version": "1.1.0",
+        "primary_column": "geometry",
+        "columns": {
+            "geometry": {
+                "encoding": "WKB",
+                "geometry_types": ["Point"],
+                "covering": {
+                    "bbox": {key: ["bbox", key] for key in ("xmin", "ymin", "xmax", "ymax")},
+                },
+            },
+        },
+    }
+    table = pa.table({
+        "geometry": pa.array(geometry.to_list(), pa.binary()),
+        "bbox": [dict(zip(("xmin", "ymin", "xmax", "ymax"), values)) for values in bbox],
+    })
+    pq.write_table(
+        table.replace_schema_metadata({"geo": json.dumps(geo)}),
+        path,
+        row_group_size=1,
+    )
+    assert pq.ParquetFile(path).metadata.num_row_groups == 3
+
+    result = st.scan_geoparquet(path, columns=["geometry"], bbox=(0.5, 0.5, 6, 6)).collect()
+    assert result.st.to_wkt().to_series().to_list() == ["POINT (1 2)"]
@@ -0,0 +1,57 @@
+from datetime import datetime, timezone
+from pathlib import Path
+
+import polars_st as st
+
+GPX = """<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="test" xmlns="http://www.topografix.com/GPX/1/1">
+  <wpt lat="48.0" lon="2.0"><ele>35</ele><name>Start</name></wpt>
+  <trk>
+    <name>Morning run</name>
+    <trkseg>
+      <trkpt lat="48.0" lon="2.0"><ele>35</ele><time>2024-05-01T08:00:00Z</time></trkpt>
+      <trkpt lat="48.1" lon="2.1"><ele>40.5</ele><time>2024-05-01T10:05:30+02:00</time></trkpt>
+    </trkseg>
+  </trk>
+</gpx>
+"""
+
+
+def test_read_gpx_points(tmp_path: Path):
+    path = tmp_path / "run.gpx"
+    path.write_text(GPX)
+
+    gdf = st.read_gpx(path)
+    assert gdf.columns == [
+        "geometry",
+        "type",
+        "name",
+        "track_id",
+        "segment_id",
+        "elevation",
+        "time",
+    ]
+    assert gdf.st.to_ewkt().to_series().to_list() == [
+        "SRID=4326;POINT (2 48)",
+        "SRID=4326;POINT (2 48)",
+        "SRID=4326;POINT (2.1 48.1)",
+    ]
+    assert gdf["type"].to_list() == ["waypoint", "trackpoint", "trackpoint"]
+    assert gdf["name"].to_list() == ["Start", "Morning run", "Morning run"]
+    assert gdf["track_id"].to_list() == [None, 0, 0]
+    assert gdf["elevation"].to_list() == [35.0, 35.0, 40.5]
+    assert gdf["time"].to_list() == [
+        None,
+        datetime(2024, 5, 1, 8, 0, 0, tzinfo=timezone.utc),
+        datetime(2024, 5, 1, 8, 5, 30, tzinfo=timezone.utc),
+    ]
+
+
+def test_read_gpx_tracks(tmp_path: Path):
+    path = tmp_path / "run.gpx"
+    path.write_text(GPX)
+
+    gdf = st.read_gpx(path, tracks=True)
+    assert gdf.columns == ["geometry", "name", "start_time", "end_time"]
+    assert gdf.st.to_wkt().to_series().to_list() == ["MULTILINESTRING ((2 48, 2.1 48.1))"]
+    assert gdf["end_time"].to_list() == [datetime(2024, 5, 1, 8, 5, 30, tzinfo=timezone.utc)]
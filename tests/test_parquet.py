@@ -0,0 +1,67 @@
+from pathlib import Path
+
+import pyarrow.parquet as pq
+import pytest
+
+import polars_st as st
+
+@pytest.mark.parametrize(
+    ("geography", "logical_type"),
+    [(False, "GEOMETRY"), (True, "GEOGRAPHY")],
+)
+def test_parquet_roundtrip(tmp_path: Path, geography: bool, logical_type: str):
+    path = tmp_path / "points.parquet"
+    gdf = st.GeoDataFrame({
+        "geometry": st.GeoSeries(["POINT (0 0)", "POINT (1 2)"]).st.set_srid(4326),
+        "name": ["A", "B"],
+    })
+    gdf.st.write_parquet(path, geography=geography)
+
+    parquet_file = pq.ParquetFile(path)
+    assert parquet_file.schema.column(0).logical_type.type == logical_type
+
+    result = st.read_parquet(path)
+    assert result.columns == ["geometry", "name"]
+    assert result.st.to_ewkt().equals(gdf.st.to_ewkt())
+
+
+def test_parquet_geometry_columns(tmp_path: Path):
+    path = tmp_path / "features.parquet"
+    gdf = st.GeoDataFrame({
+        "name": ["A", "B"],
+        "geometry": ["POINT (0 0)", "LINESTRING (0 0, 1 1)"],
+        "centroid": ["POINT (0 0)", "POINT (0.5 0.5)"],
+    })
+    gdf.st.write_parquet(path, "geometry", "centroid")
+
+    schema = pq.ParquetFile(path).schema
+    logical_types = [schema.column(i).logical_type.type for i in range(len(gdf.columns))]
+    assert logical_types == ["STRING", "GEOMETRY", "GEOMETRY"]
+
+
+def test_parquet_crs(tmp_path: Path):
+    path = tmp_path / "points.parquet"
+    gdf = st.GeoDataFrame({"geometry": st.GeoSeries(["POINT (0 0)"]).st.set_srid(3857)})
+    gdf.st.write_parquet(path)
+
+    # The CRS is either inlined in the logical type, or referenced from the file metadata.
+    parquet_file = pq.ParquetFile(path)
+    key_value_metadata = parquet_file.metadata.metadata or {}
+    crs_sources = [parquet_file.schema.column(0).logical_type.to_json()]
+    crs_sources += [value.decode(errors="ignore") for value in key_value_metadata.values()]
+    assert any("3857" in source for source in crs_sources)
+    assert st.read_parquet(path).select(st.geom().st.srid()).item() == 3857
+
+
+def test_parquet_bbox_statistics(tmp_path: Path):
+    path = tmp_path / "points.parquet"
+    gdf = st.GeoDataFrame({"geometry": ["POINT (0 0)", "POINT (1 2)", "POINT (5 5)"]})
+    gdf.st.write_parquet(path, row_group_size=2)
+
+    metadata = pq.ParquetFile(path).metadata
+    assert metadata.num_row_groups == 2
+    bounds = []
+    for i in range(metadata.num_row_groups):
+        statistics = metadata.row_group(i).column(0).geo_statistics
+        bounds.append((statistics.xmin, statistics.ymin, statistics.xmax, statistics.ymax))
+    assert bounds == [(0.0, 0.0, 1.0, 2.0), (5.0, 5.0, 5.0, 5.0)]
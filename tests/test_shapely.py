@@ -0,0 +1,35 @@
+import polars as pl
+import pytest
+
+import polars_st as st
+
+
+@pytest.mark.parametrize(
+    "wkts",
+    [
+        ["POINT (1 2)", None, "POINT EMPTY"],
+        ["LINESTRING Z (0 0 1, 1 1 2)", "LINESTRING (2 2, 3 3)"],
+        ["POLYGON ((0 0, 0 1, 1 1, 0 0))", "MULTIPOLYGON (((2 2, 2 3, 3 3, 2 2)))"],
+        ["MULTIPOINT ((0 0), (1 1))", None],
+        ["GEOMETRYCOLLECTION (POINT (0 0), LINESTRING (0 0, 1 1))", "POINT (1 2)"],
+        [None, None],
+        [],
+    ],
+)
+def test_shapely_roundtrip(wkts: list[str | None]):
+    gs = st.GeoSeries(wkts)
+    geoms = gs.st.to_shapely()
+    assert geoms.dtype == pl.Object
+    assert [g is None for g in geoms] == [wkt is None for wkt in wkts]
+    result = st.GeoSeries(geoms)
+    assert result.st.equals_exact(gs).fill_null(value=True).all()
+    assert result.st.has_z().to_list() == gs.st.has_z().to_list()
+    assert result.is_null().to_list() == gs.is_null().to_list()
+
+
+def test_shapely_roundtrip_srid():
+    gs = st.GeoSeries(["SRID=4326;POINT (1 2)", "SRID=4326;POINT (3 4)"], geometry_format="ewkt")
+    geoms = gs.st.to_shapely()
+    assert geoms[0].wkt == "POINT (1 2)"
+    result = st.GeoSeries(geoms)
+    assert result.st.srid().to_list() == [4326, 4326]
@@ -0,0 +1,247 @@
+import polars as pl
+import pytest
+
+import polars_st as st
+
+left = st.GeoDataFrame({
+    "geometry": [
+        "POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))",
+        "POLYGON ((10 10, 12 10, 12 12, 10 12, 10 10))",
+        "POLYGON ((20 20, 22 20, 22 22, 20 22, 20 20))",
+    ],
+    "left_id": [0, 1, 2],
+})
+
+right = st.GeoDataFrame({
+    "geometry": [
+        "POINT (1 1)",
+        "POINT (1.5 1.5)",
+        "POINT (11 11)",
+        "POINT (50 50)",
+        "POINT (60 60)",
+    ],
+    "right_id": [0, 1, 2, 3, 4],
+})
+
+
+def join_ids(how: str) -> list[tuple[int | None, int | None]]:
+    result = left.st.sjoin(right, how=how)  # type: ignore[arg-type]
+    return sorted(
+        result.select("left_id", "right_id").iter_rows(),
+        key=lambda row: tuple(-1 if v is None else v for v in row),
+    )
+
+
+def test_sjoin_inner():
+    assert join_ids("inner") == [(0, 0), (0, 1), (1, 2)]
+
+
+def test_sjoin_left():
+    assert join_ids("left") == [(0, 0), (0, 1), (1, 2), (2, None)]
+
+
+def test_sjoin_right():
+    assert join_ids("right") == [(None, 3), (None, 4), (0, 0), (0, 1), (1, 2)]
+
+
+def test_sjoin_full():
+    assert join_ids("full") == [(None, 3), (None, 4), (0, 0), (0, 1), (1, 2), (2, None)]
+
+
+@pytest.mark.parametrize(("how", "expected"), [("semi", [0, 1]), ("anti", [2])])
+def test_sjoin_semi_anti(how: str, expected: list[int]):
+    result = left.st.sjoin(right, how=how)  # type: ignore[arg-type]
+    assert result.columns == ["geometry", "left_id"]
+    assert sorted(result["left_id"]) == expected
+
+
+def test_sjoin_null_geometries():
+    left_with_null = left.with_columns(pl.when(pl.col("left_id") != 0).then("geometry"))
+    result = left_with_null.st.sjoin(right, how="left")
+    assert sorted(result.select("left_id", "right_id").iter_rows()) == [
+        (0, None),
+        (1, 2),
+        (2, None),
+    ]
+
+
+def test_sjoin_cross():
+    with pytest.raises(ValueError, match="cross"):
+        left.st.sjoin(right, how="cross")
+
+
+def test_sjoin_dwithin():
+    result = left.st.sjoin(right, how="right", predicate="dwithin", distance=10)
+    assert sorted(result.select("right_id", "left_id").iter_rows()) == [
+        (0, 0),
+        (1, 0),
+        (2, 1),
+        (3, None),
+        (4, None),
+    ]
+
+
+def test_sjoin_dwithin_requires_distance():
+    with pytest.raises(ValueError, match="distance"):
+        left.st.sjoin(right, predicate="dwithin")
+    with pytest.raises(ValueError, match="distance"):
+        left.st.sjoin(right, distance=10)
+
+
+def test_sjoin_distance_col():
+    result = left.st.sjoin(
+        right,
+        how="left",
+        predicate="dwithin",
+        distance=12.5,
+        distance_col="dist",
+    )
+    assert result.columns[-1] == "dist"
+    assert sorted(result.select("left_id", "right_id", "dist").iter_rows()) == [
+        (0, 0, 0.0),
+        (0, 1, 0.0),
+        (1, 1, pytest.approx(8.5 * 2**0.5)),
+        (1, 2, 0.0),
+        (2, None, None),
+    ]
+
+
+def test_sjoin_distance_col_with_predicate():
+    result = right.st.sjoin(left, predicate="intersects_bbox", distance_col="dist")
+    assert result["dist"].to_list() == [0.0, 0.0, 0.0]
+
+
+def test_sjoin_semi_anti_many_matches():
+    semi = left.st.sjoin(right, how="semi", predicate="dwithin", distance=100)
+    anti = left.st.sjoin(right, how="anti", predicate="dwithin", distance=100)
+    assert semi.equals(left)
+    assert anti.is_empty()
+    assert anti.schema == left.schema
+
+
+@pytest.mark.parametrize("algorithm", ["rtree", "sweep"])
+def test_sjoin_many_geometries(algorithm: str):
+    squares = st.GeoDataFrame({
+        "geometry": [
+            f"POLYGON (({x} {y}, {x + 3} {y}, {x + 3} {y + 3}, {x} {y + 3}, {x} {y}))"
+            for x in range(0, 50, 2)
+            for y in range(0, 50, 5)
+        ],
+    }).with_row_index("left_id")
+    points = st.GeoDataFrame({
+        "geometry": [f"POINT ({x * 1.25} {y * 1.25})" for x in range(40) for y in range(40)],
+    }).with_row_index("right_id")
+    result = squares.st.sjoin(points, how="inner", algorithm=algorithm)  # type: ignore[arg-type]
+    expected = (
+        squares.join(points, how="cross")
+        .filter(st.geom("geometry").st.intersects(st.geom("geometry_right")))
+        .select("left_id", "right_id")
+    )
+    assert sorted(result.select("left_id", "right_id").iter_rows()) == sorted(
+        expected.iter_rows()
+    )
+
+
+@pytest.mark.parametrize(
+    ("predicate", "expected"),
+    [
+        ("within", [(0, 0), (1, 0), (2, 1)]),
+        ("covered_by", [(0, 0), (1, 0), (2, 1)]),
+        ("contains", []),
+        ("contains_properly", []),
+        ("intersects", [(0, 0), (1, 0), (2, 1)]),
+    ],
+)
+def test_sjoin_smaller_right(predicate: str, expected: list[tuple[int, int]]):
+    # The right frame is the smaller one here, so it's the one being indexed.
+    result = right.st.sjoin(left, predicate=predicate)  # type: ignore[arg-type]
+    assert sorted(result.select("right_id", "left_id").iter_rows()) == expected
+
+
+def test_sjoin_intersects_bbox_geometry_types():
+    indexed = st.GeoDataFrame({
+        "geometry": [
+            "POINT Z (1 1 5)",
+            "LINESTRING M (0 5 1, 3 8 2)",
+            "MULTIPOLYGON (((10 0, 11 0, 11 1, 10 0)), ((20 0, 21 0, 21 1, 20 0)))",
+            "GEOMETRYCOLLECTION (POINT (30 30), LINESTRING (31 31, 32 32))",
+            "CIRCULARSTRING (40 0, 41.70710678 0.70710678, 41 -1)",
+            "MULTIPOINT ((50 50), (51 51))",
+            "POLYGON EMPTY",
+            None,
+        ],
+        "left_id": range(8),
+    })
+    probes = st.GeoDataFrame({
+        "geometry": [
+            "POINT (1 1)",
+            "POINT (2 6)",
+            "POINT (15 0.5)",
+            "POINT (31.5 31.5)",
+            "POINT (41.9 0)",
+            "POINT (50.5 50.5)",
+            *(["POINT (100 100)"] * 4),
+        ],
+        "right_id": range(10),
+    })
+    # The indexed frame is the smaller one, so that envelopes are read from its WKB.
+    result = indexed.st.sjoin(probes, predicate="intersects_bbox")
+    assert sorted(result.select("left_id", "right_id").iter_rows()) == [
+        (0, 0),
+        (1, 1),
+        (2, 2),
+        (3, 3),
+        (4, 4),
+        (5, 5),
+    ]
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "right", "full", "semi", "anti"])
+def test_sjoin_sweep(how: str):
+    kwargs = {"how": how, "predicate": "dwithin", "distance": 10}
+    expected = left.st.sjoin(right, **kwargs)  # type: ignore[arg-type]
+    result = left.st.sjoin(right, **kwargs, algorithm="sweep")  # type: ignore[arg-type]
+    assert result.equals(expected)
+
+
+def test_sjoin_sweep_many_candidates():
+    # More candidate pairs than the sweep evaluates at once.
+    squares = st.GeoDataFrame({"geometry": ["POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0))"] * 300})
+    points = st.GeoDataFrame({"geometry": [f"POINT ({i / 40} 5)" for i in range(300)]})
+    expected = squares.st.sjoin(points, how="inner")
+    result = squares.st.sjoin(points, how="inner", algorithm="sweep")
+    assert len(result) == 300 * 300
+    assert result.sort(pl.all()).equals(expected.sort(pl.all()))
+
+
+@pytest.mark.parametrize(
+    ("pattern", "expected"),
+    [
+        ("T*F**F***", [(0, 0), (1, 0), (2, 1)]),
+        ("0********", [(0, 0), (1, 0), (2, 1)]),
+        ("F0*******", []),
+    ],
+)
+def test_sjoin_relate(pattern: str, expected: list[tuple[int, int]]):
+    # Both sides are joined in each direction, so that the pattern is also
+    # evaluated with the right side indexed.
+    result = right.st.sjoin(left, predicate="relate", pattern=pattern)
+    assert sorted(result.select("right_id", "left_id").iter_rows()) == expected
+    transposed = pattern[0::3] + pattern[1::3] + pattern[2::3]
+    result = left.st.sjoin(right, predicate="relate", pattern=transposed)
+    assert sorted(result.select("right_id", "left_id").iter_rows()) == expected
+
+
+def test_sjoin_relate_boundary():
+    points = st.GeoDataFrame({"geometry": ["POINT (0 1)", "POINT (1 1)"], "id": [0, 1]})
+    result = points.st.sjoin(left, predicate="relate", pattern="F0F******")
+    assert result["id"].to_list() == [0]
+
+
+def test_sjoin_relate_requires_pattern():
+    with pytest.raises(ValueError, match="pattern"):
+        left.st.sjoin(right, predicate="relate")
+    with pytest.raises(ValueError, match="pattern"):
+        left.st.sjoin(right, pattern="T********")
+    with pytest.raises(pl.exceptions.ComputeError, match="DE-9IM"):
+        left.st.sjoin(right, predicate="relate", pattern="T*F")
@@ -0,0 +1,41 @@
+import struct
+
+import polars as pl
+import pytest
+
+import polars_st as st
+
+
+@pytest.mark.parametrize(
+    "wkt",
+    [
+        "SRID=4326;POINT (1 2)",
+        "LINESTRING Z (0 0 1, 1 1 2)",
+        "POLYGON ((0 0, 0 4, 4 4, 4 0, 0 0), (1 1, 2 1, 2 2, 1 1))",
+        "SRID=3857;MULTIPOLYGON (((0 0, 0 1, 1 1, 0 0)), ((2 2, 2 3, 3 3, 2 2)))",
+        "GEOMETRYCOLLECTION (POINT (0 0), LINESTRING (0 0, 1 1))",
+    ],
+)
+def test_spatialite_roundtrip(wkt: str):
+    blobs = st.GeoSeries([wkt], geometry_format="ewkt").st.to_spatialite()
+    result = pl.select(st.from_spatialite(blobs)).to_series()
+    assert result.st.to_ewkt().item() == wkt
+
+
+def test_from_spatialite_compressed_big_endian():
+    # Compressed LINESTRING: first and last vertices as doubles, delta in between
+    blob = (
+        b"\x00\x00"
+        + struct.pack(">i", 4326)
+        + struct.pack(">4d", 0, 0, 3, 3)
+        + b"\x7c"
+        + struct.pack(">I", 1_000_002)
+        + struct.pack(">I", 3)
+        + struct.pack(">2d", 0, 0)
+        + struct.pack(">2f", 1, 2)
+        + struct.pack(">2d", 3, 3)
+        + b"\xfe"
+    )
+    df = pl.DataFrame({"geometry": [blob]})
+    result = df.select(st.from_spatialite("geometry"))
+    assert result.st.to_ewkt().item() == "SRID=4326;LINESTRING (0 0, 1 2, 3 3)"
@@ -0,0 +1,42 @@
+import json
+
+import polars_st as st
+
+
+def test_to_topojson_shared_arcs():
+    gdf = st.GeoDataFrame({
+        "geometry": [
+            "POLYGON ((0 0, 0 2, 2 2, 2 0, 0 0))",
+            "POLYGON ((2 0, 2 2, 4 2, 4 0, 2 0))",
+            "POINT (1 1)",
+            None,
+        ],
+        "name": ["A", "B", "C", "D"],
+    })
+    topology = json.loads(gdf.st.to_topojson(object_name="features", quantization=5))
+
+    assert topology["type"] == "Topology"
+    assert topology["bbox"] == [0.0, 0.0, 4.0, 2.0]
+    assert topology["transform"] == {"scale": [1.0, 0.5], "translate": [0.0, 0.0]}
+    geometries = topology["objects"]["features"]["geometries"]
+    assert [g["type"] for g in geometries] == ["Polygon", "Polygon", "Point", None]
+    assert [g["properties"] for g in geometries] == [{"name": n} for n in "ABCD"]
+    assert geometries[2]["coordinates"] == [1, 2]
+
+    # The boundary shared by both polygons is a single arc, used in both directions
+    assert len(topology["arcs"]) == 3
+    (a_arcs,) = geometries[0]["arcs"]
+    (b_arcs,) = geometries[1]["arcs"]
+    shared = set(a_arcs) & {~i for i in b_arcs}
+    assert len(shared) == 1
+
+
+def test_to_topojson_noding():
+    # The vertex (2 1) of the second polygon lies on an edge of the first one
+    gdf = st.GeoDataFrame([
+        "POLYGON ((0 0, 0 2, 2 2, 2 0, 0 0))",
+        "POLYGON ((2 0, 2 1, 2 2, 4 2, 4 0, 2 0))",
+    ])
+    topology = json.loads(gdf.st.to_topojson(quantization=None))
+    assert [2.0, 1.0] in topology["arcs"][0] + topology["arcs"][1]
+    assert len(topology["arcs"]) == 3